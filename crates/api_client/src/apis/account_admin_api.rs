@@ -123,6 +123,15 @@ pub enum PostUpdateNewsTranslationError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`post_warn_account`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PostWarnAccountError {
+    Status401(),
+    Status500(),
+    UnknownValue(serde_json::Value),
+}
+
 
 pub async fn delete_news_item(configuration: &configuration::Configuration, nid: i64) -> Result<(), Error<DeleteNewsItemError>> {
     let local_var_configuration = configuration;
@@ -555,3 +564,39 @@ pub async fn post_update_news_translation(configuration: &configuration::Configu
     }
 }
 
+/// Give a warning to an account  The account state changes to [model::AccountState::WarningPending] until the account acknowledges the warning. If the account has received too many warnings recently, the account is also banned temporarily.  # Access  Permission [model_account::Permissions::admin_ban_account] is required.
+pub async fn post_warn_account(configuration: &configuration::Configuration, warn_account: models::WarnAccount) -> Result<(), Error<PostWarnAccountError>> {
+    let local_var_configuration = configuration;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!("{}/account_api/warn_account", local_var_configuration.base_path);
+    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
+        let local_var_key = local_var_apikey.key.clone();
+        let local_var_value = match local_var_apikey.prefix {
+            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
+            None => local_var_key,
+        };
+        local_var_req_builder = local_var_req_builder.header("x-access-token", local_var_value);
+    };
+    local_var_req_builder = local_var_req_builder.json(&warn_account);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        Ok(())
+    } else {
+        let local_var_entity: Option<PostWarnAccountError> = serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
@@ -22,6 +22,8 @@ pub enum AccountState {
     Banned,
     #[serde(rename = "PendingDeletion")]
     PendingDeletion,
+    #[serde(rename = "WarningPending")]
+    WarningPending,
 
 }
 
@@ -32,6 +34,7 @@ impl std::fmt::Display for AccountState {
             Self::Normal => write!(f, "Normal"),
             Self::Banned => write!(f, "Banned"),
             Self::PendingDeletion => write!(f, "PendingDeletion"),
+            Self::WarningPending => write!(f, "WarningPending"),
         }
     }
 }
@@ -19,6 +19,8 @@ pub struct AccountStateContainer {
     pub initial_setup_completed: Option<bool>,
     #[serde(rename = "pending_deletion", skip_serializing_if = "Option::is_none")]
     pub pending_deletion: Option<bool>,
+    #[serde(rename = "warning_pending", skip_serializing_if = "Option::is_none")]
+    pub warning_pending: Option<bool>,
 }
 
 impl AccountStateContainer {
@@ -27,6 +29,7 @@ impl AccountStateContainer {
             banned: None,
             initial_setup_completed: None,
             pending_deletion: None,
+            warning_pending: None,
         }
     }
 }
@@ -0,0 +1,28 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClusterStatus {
+    /// Status of the manager which handled the request and status of its remote managers.
+    #[serde(rename = "managers")]
+    pub managers: Vec<models::ManagerStatus>,
+}
+
+impl ClusterStatus {
+    pub fn new(managers: Vec<models::ManagerStatus>) -> ClusterStatus {
+        ClusterStatus {
+            managers,
+        }
+    }
+}
+
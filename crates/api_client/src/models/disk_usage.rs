@@ -0,0 +1,46 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DiskUsage {
+    /// Available space for unprivileged users.
+    #[serde(rename = "available_bytes")]
+    pub available_bytes: i64,
+    #[serde(rename = "device")]
+    pub device: String,
+    #[serde(rename = "inodes_total")]
+    pub inodes_total: i64,
+    #[serde(rename = "inodes_used")]
+    pub inodes_used: i64,
+    #[serde(rename = "mount_point")]
+    pub mount_point: String,
+    #[serde(rename = "total_bytes")]
+    pub total_bytes: i64,
+    #[serde(rename = "used_bytes")]
+    pub used_bytes: i64,
+}
+
+impl DiskUsage {
+    pub fn new(available_bytes: i64, device: String, inodes_total: i64, inodes_used: i64, mount_point: String, total_bytes: i64, used_bytes: i64) -> DiskUsage {
+        DiskUsage {
+            available_bytes,
+            device,
+            inodes_total,
+            inodes_used,
+            mount_point,
+            total_bytes,
+            used_bytes,
+        }
+    }
+}
+
@@ -0,0 +1,33 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LoadAverage {
+    #[serde(rename = "fifteen")]
+    pub fifteen: f64,
+    #[serde(rename = "five")]
+    pub five: f64,
+    #[serde(rename = "one")]
+    pub one: f64,
+}
+
+impl LoadAverage {
+    pub fn new(fifteen: f64, five: f64, one: f64) -> LoadAverage {
+        LoadAverage {
+            fifteen,
+            five,
+            one,
+        }
+    }
+}
+
@@ -0,0 +1,44 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ManagerStatus {
+    /// Errors from failed status requests.
+    #[serde(rename = "errors")]
+    pub errors: Vec<String>,
+    #[serde(rename = "installed_software", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub installed_software: Option<Option<Box<models::SoftwareInfo>>>,
+    #[serde(rename = "name")]
+    pub name: String,
+    /// True if at least one status request was successful.
+    #[serde(rename = "reachable")]
+    pub reachable: bool,
+    #[serde(rename = "scheduled_tasks", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub scheduled_tasks: Option<Option<Box<models::ScheduledTaskStatus>>>,
+    #[serde(rename = "system_info", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub system_info: Option<Option<Box<models::SystemInfo>>>,
+}
+
+impl ManagerStatus {
+    pub fn new(errors: Vec<String>, name: String, reachable: bool) -> ManagerStatus {
+        ManagerStatus {
+            errors,
+            installed_software: None,
+            name,
+            reachable,
+            scheduled_tasks: None,
+            system_info: None,
+        }
+    }
+}
+
@@ -0,0 +1,30 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MemoryUsage {
+    #[serde(rename = "total_bytes")]
+    pub total_bytes: i64,
+    #[serde(rename = "used_bytes")]
+    pub used_bytes: i64,
+}
+
+impl MemoryUsage {
+    pub fn new(total_bytes: i64, used_bytes: i64) -> MemoryUsage {
+        MemoryUsage {
+            total_bytes,
+            used_bytes,
+        }
+    }
+}
+
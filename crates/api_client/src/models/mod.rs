@@ -62,6 +62,8 @@ pub mod client_type;
 pub use self::client_type::ClientType;
 pub mod client_version;
 pub use self::client_version::ClientVersion;
+pub mod cluster_status;
+pub use self::cluster_status::ClusterStatus;
 pub mod command_output;
 pub use self::command_output::CommandOutput;
 pub mod content_id;
@@ -102,6 +104,8 @@ pub mod demo_mode_password;
 pub use self::demo_mode_password::DemoModePassword;
 pub mod demo_mode_token;
 pub use self::demo_mode_token::DemoModeToken;
+pub mod disk_usage;
+pub use self::disk_usage::DiskUsage;
 pub mod event_to_client;
 pub use self::event_to_client::EventToClient;
 pub mod event_type;
@@ -166,6 +170,8 @@ pub mod latest_viewed_message_changed;
 pub use self::latest_viewed_message_changed::LatestViewedMessageChanged;
 pub mod limited_action_status;
 pub use self::limited_action_status::LimitedActionStatus;
+pub mod load_average;
+pub use self::load_average::LoadAverage;
 pub mod location;
 pub use self::location::Location;
 pub mod login_result;
@@ -174,6 +180,8 @@ pub mod maintenance_task;
 pub use self::maintenance_task::MaintenanceTask;
 pub mod manager_instance_name_list;
 pub use self::manager_instance_name_list::ManagerInstanceNameList;
+pub mod manager_status;
+pub use self::manager_status::ManagerStatus;
 pub mod matches_iterator_session_id;
 pub use self::matches_iterator_session_id::MatchesIteratorSessionId;
 pub mod matches_page;
@@ -186,6 +194,8 @@ pub mod media_content_sync_version;
 pub use self::media_content_sync_version::MediaContentSyncVersion;
 pub mod media_content_type;
 pub use self::media_content_type::MediaContentType;
+pub mod memory_usage;
+pub use self::memory_usage::MemoryUsage;
 pub mod message_number;
 pub use self::message_number::MessageNumber;
 pub mod moderation_queue_type;
@@ -210,6 +220,8 @@ pub mod news_sync_version;
 pub use self::news_sync_version::NewsSyncVersion;
 pub mod news_translation_version;
 pub use self::news_translation_version::NewsTranslationVersion;
+pub mod nsfw_scores;
+pub use self::nsfw_scores::NsfwScores;
 pub mod page_item_count_for_new_likes;
 pub use self::page_item_count_for_new_likes::PageItemCountForNewLikes;
 pub mod page_item_count_for_new_public_news;
@@ -238,6 +250,8 @@ pub mod post_moderate_profile_name;
 pub use self::post_moderate_profile_name::PostModerateProfileName;
 pub mod post_moderate_profile_text;
 pub use self::post_moderate_profile_text::PostModerateProfileText;
+pub mod process_usage;
+pub use self::process_usage::ProcessUsage;
 pub mod profile;
 pub use self::profile::Profile;
 pub mod profile_age_counts;
@@ -348,6 +362,10 @@ pub mod reset_received_likes_iterator_result;
 pub use self::reset_received_likes_iterator_result::ResetReceivedLikesIteratorResult;
 pub mod scheduled_maintenance_status;
 pub use self::scheduled_maintenance_status::ScheduledMaintenanceStatus;
+pub mod scheduled_software_update_outcome;
+pub use self::scheduled_software_update_outcome::ScheduledSoftwareUpdateOutcome;
+pub mod scheduled_software_update_result;
+pub use self::scheduled_software_update_result::ScheduledSoftwareUpdateResult;
 pub mod scheduled_task_status;
 pub use self::scheduled_task_status::ScheduledTaskStatus;
 pub mod scheduled_task_type;
@@ -396,6 +414,8 @@ pub mod sync_version;
 pub use self::sync_version::SyncVersion;
 pub mod system_info;
 pub use self::system_info::SystemInfo;
+pub mod system_stats;
+pub use self::system_stats::SystemStats;
 pub mod time_granularity;
 pub use self::time_granularity::TimeGranularity;
 pub mod translation;
@@ -412,3 +432,5 @@ pub mod update_news_translation;
 pub use self::update_news_translation::UpdateNewsTranslation;
 pub mod update_news_translation_result;
 pub use self::update_news_translation_result::UpdateNewsTranslationResult;
+pub mod warn_account;
+pub use self::warn_account::WarnAccount;
@@ -0,0 +1,41 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// NsfwScores : NSFW detection scores. Scores are between 0.0 and 1.0.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NsfwScores {
+    #[serde(rename = "drawings")]
+    pub drawings: f64,
+    #[serde(rename = "hentai")]
+    pub hentai: f64,
+    #[serde(rename = "neutral")]
+    pub neutral: f64,
+    #[serde(rename = "porn")]
+    pub porn: f64,
+    #[serde(rename = "sexy")]
+    pub sexy: f64,
+}
+
+impl NsfwScores {
+    /// NSFW detection scores. Scores are between 0.0 and 1.0.
+    pub fn new(drawings: f64, hentai: f64, neutral: f64, porn: f64, sexy: f64) -> NsfwScores {
+        NsfwScores {
+            drawings,
+            hentai,
+            neutral,
+            porn,
+            sexy,
+        }
+    }
+}
+
@@ -0,0 +1,37 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProcessUsage {
+    /// CPU usage percentage. Value 100 means one fully used CPU core.
+    #[serde(rename = "cpu_usage")]
+    pub cpu_usage: f32,
+    #[serde(rename = "memory_bytes")]
+    pub memory_bytes: i64,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "pid")]
+    pub pid: i32,
+}
+
+impl ProcessUsage {
+    pub fn new(cpu_usage: f32, memory_bytes: i64, name: String, pid: i32) -> ProcessUsage {
+        ProcessUsage {
+            cpu_usage,
+            memory_bytes,
+            name,
+            pid,
+        }
+    }
+}
+
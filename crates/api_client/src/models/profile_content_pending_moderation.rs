@@ -17,6 +17,8 @@ pub struct ProfileContentPendingModeration {
     pub account_id: Box<models::AccountId>,
     #[serde(rename = "content_id")]
     pub content_id: Box<models::ContentId>,
+    #[serde(rename = "nsfw_scores", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub nsfw_scores: Option<Option<Box<models::NsfwScores>>>,
 }
 
 impl ProfileContentPendingModeration {
@@ -24,6 +26,7 @@ impl ProfileContentPendingModeration {
         ProfileContentPendingModeration {
             account_id: Box::new(account_id),
             content_id: Box::new(content_id),
+            nsfw_scores: None,
         }
     }
 }
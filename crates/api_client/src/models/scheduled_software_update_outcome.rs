@@ -0,0 +1,47 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ScheduledSoftwareUpdateOutcome {
+    #[serde(rename = "Installed")]
+    Installed,
+    #[serde(rename = "AlreadyInstalled")]
+    AlreadyInstalled,
+    #[serde(rename = "VersionNotAllowed")]
+    VersionNotAllowed,
+    #[serde(rename = "RolledBack")]
+    RolledBack,
+    #[serde(rename = "Failed")]
+    Failed,
+
+}
+
+impl std::fmt::Display for ScheduledSoftwareUpdateOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Installed => write!(f, "Installed"),
+            Self::AlreadyInstalled => write!(f, "AlreadyInstalled"),
+            Self::VersionNotAllowed => write!(f, "VersionNotAllowed"),
+            Self::RolledBack => write!(f, "RolledBack"),
+            Self::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+impl Default for ScheduledSoftwareUpdateOutcome {
+    fn default() -> ScheduledSoftwareUpdateOutcome {
+        Self::Installed
+    }
+}
+
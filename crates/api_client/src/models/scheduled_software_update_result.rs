@@ -0,0 +1,37 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledSoftwareUpdateResult {
+    #[serde(rename = "details", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub details: Option<Option<String>>,
+    #[serde(rename = "outcome")]
+    pub outcome: models::ScheduledSoftwareUpdateOutcome,
+    /// Latest release from the update source if it was found.
+    #[serde(rename = "release", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub release: Option<Option<Box<models::SoftwareInfo>>>,
+    #[serde(rename = "time")]
+    pub time: Box<models::UnixTime>,
+}
+
+impl ScheduledSoftwareUpdateResult {
+    pub fn new(outcome: models::ScheduledSoftwareUpdateOutcome, time: models::UnixTime) -> ScheduledSoftwareUpdateResult {
+        ScheduledSoftwareUpdateResult {
+            details: None,
+            outcome,
+            release: None,
+            time: Box::new(time),
+        }
+    }
+}
+
@@ -15,14 +15,22 @@ use serde::{Deserialize, Serialize};
 pub struct ScheduledTaskStatus {
     #[serde(rename = "backend_restart", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub backend_restart: Option<Option<Box<models::MaintenanceTask>>>,
+    /// Download and install the latest release and restart the backend.
+    #[serde(rename = "software_update", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub software_update: Option<Option<Box<models::MaintenanceTask>>>,
+    /// Results of previous scheduled software updates. The latest result is the last item.
+    #[serde(rename = "software_update_history")]
+    pub software_update_history: Vec<models::ScheduledSoftwareUpdateResult>,
     #[serde(rename = "system_reboot", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub system_reboot: Option<Option<Box<models::MaintenanceTask>>>,
 }
 
 impl ScheduledTaskStatus {
-    pub fn new() -> ScheduledTaskStatus {
+    pub fn new(software_update_history: Vec<models::ScheduledSoftwareUpdateResult>) -> ScheduledTaskStatus {
         ScheduledTaskStatus {
             backend_restart: None,
+            software_update: None,
+            software_update_history,
             system_reboot: None,
         }
     }
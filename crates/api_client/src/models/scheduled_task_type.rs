@@ -18,6 +18,8 @@ pub enum ScheduledTaskType {
     BackendRestart,
     #[serde(rename = "SystemReboot")]
     SystemReboot,
+    #[serde(rename = "SoftwareUpdate")]
+    SoftwareUpdate,
 
 }
 
@@ -26,6 +28,7 @@ impl std::fmt::Display for ScheduledTaskType {
         match self {
             Self::BackendRestart => write!(f, "BackendRestart"),
             Self::SystemReboot => write!(f, "SystemReboot"),
+            Self::SoftwareUpdate => write!(f, "SoftwareUpdate"),
         }
     }
 }
@@ -15,8 +15,14 @@ use serde::{Deserialize, Serialize};
 pub struct SoftwareUpdateStatus {
     #[serde(rename = "downloaded", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub downloaded: Option<Option<Box<models::SoftwareInfo>>>,
+    /// Release signing key name which signature the downloaded software has.
+    #[serde(rename = "downloaded_signer", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub downloaded_signer: Option<Option<String>>,
     #[serde(rename = "installed", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub installed: Option<Option<Box<models::SoftwareInfo>>>,
+    /// Release signing key name which signature the installed software has.
+    #[serde(rename = "installed_signer", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub installed_signer: Option<Option<String>>,
     #[serde(rename = "state")]
     pub state: models::SoftwareUpdateState,
 }
@@ -25,7 +31,9 @@ impl SoftwareUpdateStatus {
     pub fn new(state: models::SoftwareUpdateState) -> SoftwareUpdateStatus {
         SoftwareUpdateStatus {
             downloaded: None,
+            downloaded_signer: None,
             installed: None,
+            installed_signer: None,
             state,
         }
     }
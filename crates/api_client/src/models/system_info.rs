@@ -15,12 +15,15 @@ use serde::{Deserialize, Serialize};
 pub struct SystemInfo {
     #[serde(rename = "info")]
     pub info: Vec<models::CommandOutput>,
+    #[serde(rename = "stats", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Option<Box<models::SystemStats>>>,
 }
 
 impl SystemInfo {
     pub fn new(info: Vec<models::CommandOutput>) -> SystemInfo {
         SystemInfo {
             info,
+            stats: None,
         }
     }
 }
@@ -0,0 +1,43 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SystemStats {
+    #[serde(rename = "disks")]
+    pub disks: Vec<models::DiskUsage>,
+    #[serde(rename = "load_average")]
+    pub load_average: Box<models::LoadAverage>,
+    #[serde(rename = "memory")]
+    pub memory: Box<models::MemoryUsage>,
+    /// Manager and backend processes. Backend process is missing if it is not running.
+    #[serde(rename = "processes")]
+    pub processes: Vec<models::ProcessUsage>,
+    #[serde(rename = "swap")]
+    pub swap: Box<models::MemoryUsage>,
+    #[serde(rename = "uptime_seconds")]
+    pub uptime_seconds: i64,
+}
+
+impl SystemStats {
+    pub fn new(disks: Vec<models::DiskUsage>, load_average: models::LoadAverage, memory: models::MemoryUsage, processes: Vec<models::ProcessUsage>, swap: models::MemoryUsage, uptime_seconds: i64) -> SystemStats {
+        SystemStats {
+            disks,
+            load_average: Box::new(load_average),
+            memory: Box::new(memory),
+            processes,
+            swap: Box::new(swap),
+            uptime_seconds,
+        }
+    }
+}
+
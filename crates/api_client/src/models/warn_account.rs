@@ -0,0 +1,33 @@
+/*
+ * afrodite-backend
+ *
+ * Dating app backend API
+ *
+ * The version of the OpenAPI document: 0.1.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WarnAccount {
+    #[serde(rename = "account")]
+    pub account: Box<models::AccountId>,
+    #[serde(rename = "reason_category", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub reason_category: Option<Option<Box<models::AccountBanReasonCategory>>>,
+    #[serde(rename = "reason_details", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub reason_details: Option<Option<Box<models::AccountBanReasonDetails>>>,
+}
+
+impl WarnAccount {
+    pub fn new(account: models::AccountId) -> WarnAccount {
+        WarnAccount {
+            account: Box::new(account),
+            reason_category: None,
+            reason_details: None,
+        }
+    }
+}
+
@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use error_stack::{Result, ResultExt};
 use serde::{Deserialize, Deserializer};
+pub use simple_backend_config::file::NsfwDetectionThresholds;
 use url::Url;
 
 use crate::{args::TestMode, file::ConfigFileError};
@@ -386,14 +387,6 @@ pub struct NsfwDetectionConfig {
     pub accept: Option<NsfwDetectionThresholds>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct NsfwDetectionThresholds {
-    pub drawings: Option<f32>,
-    pub hentai: Option<f32>,
-    pub neutral: Option<f32>,
-    pub porn: Option<f32>,
-    pub sexy: Option<f32>,
-}
 
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct AdminBotConfig {
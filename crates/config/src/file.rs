@@ -1,9 +1,9 @@
 use std::{
-    collections::HashSet, num::NonZeroU8, path::{Path, PathBuf}
+    collections::HashSet, num::{NonZeroU16, NonZeroU8}, path::{Path, PathBuf}
 };
 
 use error_stack::{Result, ResultExt};
-use model::{AccountId, ClientVersion};
+use model::{AccountId, ClientVersion, Permissions};
 // Re-export for test-mode crate
 pub use model_server_data::EmailAddress;
 use model_server_state::DemoModeId;
@@ -36,6 +36,13 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # [grant_admin_access]
 # email = "admin@example.com"
 
+# [[admin_role]]
+# name = "content moderator"
+# permissions.admin_moderate_media_content = true
+# permissions.admin_moderate_profile_names = true
+# permissions.admin_moderate_profile_texts = true
+# permissions.admin_process_reports = true
+
 # [location]
 # latitude_top_left = 70.1
 # longitude_top_left = 19.5
@@ -47,8 +54,25 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # profile_name = "90d"
 # profile_text = "90d"
 
+# [limits.common.report_triage]
+# distinct_reporters = 5
+# window_duration = "1d"
+# underaged_account_report = true
+
+# [limits.common.moderation_claim]
+# lease_duration = "10m"
+# completed_claim_retention_duration = "90d"
+
 # [limits.account]
 # account_deletion_wait_duration = "90d"
+# account_warning_expiration_duration = "90d"
+# account_warning_strike_threshold = 3
+# account_warning_strike_window_duration = "30d"
+# account_warning_strike_ban_duration = "7d"
+
+# [limits.profile]
+# profile_visit_retention_duration = "30d"
+# seen_profile_expiration_duration = "7d"
 
 # [limits.chat]
 # like_limit_reset_time_utc_offset_hours = 0
@@ -58,6 +82,10 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # max_content_count = 20
 # unused_content_wait_duration = "90d"
 
+# [limits.media.duplicate_image_detection]
+# max_hash_distance = 5
+# move_duplicates_to_human_moderation = false
+
 # [[profile_name_allowlist]]
 # csv_file = "names.csv"
 # delimiter = ";"
@@ -95,6 +123,7 @@ pub struct ConfigFile {
 
     pub components: Option<Components>,
     pub grant_admin_access: Option<GrantAdminAccessConfig>,
+    pub admin_role: Option<Vec<AdminRoleConfig>>,
     pub location: Option<LocationConfig>,
     pub external_services: Option<ExternalServices>,
     pub demo_mode: Option<Vec<DemoModeConfig>>,
@@ -110,6 +139,7 @@ impl ConfigFile {
             api: ApiConfig::default(),
             components: Some(Components::default()),
             grant_admin_access: None,
+            admin_role: None,
             location: None,
             external_services: None,
             demo_mode: None,
@@ -125,6 +155,18 @@ impl ConfigFile {
                 .change_context(ConfigFileError::SimpleBackendError)?;
         let file: ConfigFile = toml::from_str(&config_string).change_context(ConfigFileError::LoadConfig)?;
 
+        if let Some(roles) = &file.admin_role {
+            let mut set = HashSet::<&str>::new();
+
+            for r in roles {
+                if set.contains(r.name.as_str()) {
+                    return Err(ConfigFileError::InvalidConfig.report())
+                        .attach_printable(format!("Duplicate admin role config for role {}", r.name))
+                }
+                set.insert(&r.name);
+            }
+        }
+
         if let Some(remote_bots) = &file.remote_bot {
             let mut set = HashSet::<AccountId>::new();
 
@@ -188,6 +230,16 @@ pub struct GrantAdminAccessConfig {
     pub email: EmailAddress,
 }
 
+/// Admin role preset. Role is created to database when server starts
+/// if a role with the same name does not exist. Existing roles are not
+/// modified.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AdminRoleConfig {
+    pub name: String,
+    #[serde(default)]
+    pub permissions: Permissions,
+}
+
 /// Base URLs for defining server to server connections.
 /// Only used in microservice mode.
 #[derive(Debug, Deserialize, Default, Serialize, Clone)]
@@ -234,6 +286,7 @@ impl Default for LocationConfig {
 pub struct LimitsConfig {
     pub common: Option<CommonLimitsConfig>,
     pub account: Option<AccountLimitsConfig>,
+    pub profile: Option<ProfileLimitsConfig>,
     pub chat: Option<ChatLimitsConfig>,
     pub media: Option<MediaLimitsConfig>,
 }
@@ -242,6 +295,44 @@ pub struct LimitsConfig {
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct CommonLimitsConfig {
     pub processed_report_deletion_wait_duration: ProcessedReportDeletionConfig,
+    /// Automatic report triage is disabled if this is `None`.
+    pub report_triage: Option<ReportTriageConfig>,
+    #[serde(default)]
+    pub moderation_claim: ModerationClaimConfig,
+}
+
+/// Fetching a moderation queue page claims the returned items for the
+/// moderator. Other moderators do not see claimed items until the
+/// lease expires or the item is moderated.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ModerationClaimConfig {
+    pub lease_duration: DurationValue,
+    /// Completed claims are used for moderator throughput statistics.
+    pub completed_claim_retention_duration: DurationValue,
+}
+
+impl Default for ModerationClaimConfig {
+    fn default() -> Self {
+        Self {
+            lease_duration: DurationValue::from_minutes(10),
+            completed_claim_retention_duration: DurationValue::from_days(90),
+        }
+    }
+}
+
+/// Automatic report triage hides the reported profile from profile
+/// iterator and moves the related reports to the front of the waiting
+/// report queue.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReportTriageConfig {
+    /// Triage starts when the account has this many distinct
+    /// reporters for the same report type.
+    pub distinct_reporters: NonZeroU16,
+    /// Only reports created within this duration are counted.
+    pub window_duration: DurationValue,
+    /// Triage starts also when account report has the underaged
+    /// flag enabled.
+    pub underaged_account_report: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -263,12 +354,44 @@ impl Default for ProcessedReportDeletionConfig {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct AccountLimitsConfig {
     pub account_deletion_wait_duration: DurationValue,
+    /// Warnings older than this are not counted as strikes.
+    pub account_warning_expiration_duration: DurationValue,
+    /// Account is banned automatically when it receives this many
+    /// warnings within the strike window. Value 0 disables automatic bans.
+    pub account_warning_strike_threshold: u16,
+    pub account_warning_strike_window_duration: DurationValue,
+    /// Duration of the automatic ban.
+    pub account_warning_strike_ban_duration: DurationValue,
 }
 
 impl Default for AccountLimitsConfig {
     fn default() -> Self {
         Self {
             account_deletion_wait_duration: DurationValue::from_days(90),
+            account_warning_expiration_duration: DurationValue::from_days(90),
+            account_warning_strike_threshold: 3,
+            account_warning_strike_window_duration: DurationValue::from_days(30),
+            account_warning_strike_ban_duration: DurationValue::from_days(7),
+        }
+    }
+}
+
+/// Profile related limits config
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProfileLimitsConfig {
+    /// Profile visits older than this are deleted.
+    pub profile_visit_retention_duration: DurationValue,
+    /// Profile iterator hides seen profiles until this duration is elapsed
+    /// if the related filter setting is enabled. Seen profiles are
+    /// only stored in memory, so server restart resets them.
+    pub seen_profile_expiration_duration: DurationValue,
+}
+
+impl Default for ProfileLimitsConfig {
+    fn default() -> Self {
+        Self {
+            profile_visit_retention_duration: DurationValue::from_days(30),
+            seen_profile_expiration_duration: DurationValue::from_days(7),
         }
     }
 }
@@ -287,6 +410,8 @@ pub struct MediaLimitsConfig {
     pub concurrent_content_uploads: usize,
     pub max_content_count: u8,
     pub unused_content_wait_duration: DurationValue,
+    /// Duplicate image detection is disabled if this is not set.
+    pub duplicate_image_detection: Option<DuplicateImageDetectionConfig>,
 }
 
 impl Default for MediaLimitsConfig {
@@ -295,6 +420,31 @@ impl Default for MediaLimitsConfig {
             concurrent_content_uploads: 10,
             max_content_count: 20,
             unused_content_wait_duration: DurationValue::from_days(90),
+            duplicate_image_detection: None,
+        }
+    }
+}
+
+/// Uploaded images are compared to images of other accounts and
+/// rejected images using perceptual hashes.
+///
+/// Hashes are compared only with images which have a 16 bit part of
+/// the hash within distance `max_hash_distance / 4` from the same part
+/// of the new image's hash, so large values make the detection slower.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DuplicateImageDetectionConfig {
+    /// Images are near-duplicates if Hamming distance between
+    /// the hashes is this or less.
+    pub max_hash_distance: u8,
+    /// Uploads which have near-duplicates skip bot moderation.
+    pub move_duplicates_to_human_moderation: bool,
+}
+
+impl Default for DuplicateImageDetectionConfig {
+    fn default() -> Self {
+        Self {
+            max_hash_distance: 5,
+            move_duplicates_to_human_moderation: false,
         }
     }
 }
@@ -19,7 +19,7 @@ use std::{path::Path, sync::Arc};
 use args::{AppMode, ArgsConfig};
 use chrono::FixedOffset;
 use error_stack::{Result, ResultExt};
-use file::{AccountLimitsConfig, ChatLimitsConfig, CommonLimitsConfig, DemoModeConfig, GrantAdminAccessConfig, MediaLimitsConfig, MinClientVersion, RemoteBotConfig};
+use file::{AccountLimitsConfig, AdminRoleConfig, ChatLimitsConfig, CommonLimitsConfig, DemoModeConfig, GrantAdminAccessConfig, MediaLimitsConfig, MinClientVersion, ProfileLimitsConfig, RemoteBotConfig};
 use file_dynamic::ConfigFileDynamic;
 use file_email_content::EmailContentFile;
 use model::BotConfig;
@@ -140,6 +140,10 @@ impl Config {
         self.file.grant_admin_access.as_ref()
     }
 
+    pub fn admin_role_config(&self) -> Option<&Vec<AdminRoleConfig>> {
+        self.file.admin_role.as_ref()
+    }
+
     pub fn bot_config(&self) -> Option<&BotConfig> {
         self.file_dynamic.backend_config.bots.as_ref()
     }
@@ -160,6 +164,10 @@ impl Config {
         self.file.limits.as_ref().and_then(|v| v.account.as_ref().cloned()).unwrap_or_default()
     }
 
+    pub fn limits_profile(&self) -> ProfileLimitsConfig {
+        self.file.limits.as_ref().and_then(|v| v.profile.as_ref().cloned()).unwrap_or_default()
+    }
+
     pub fn limits_chat(&self) -> ChatLimitsConfig {
         self.file.limits.as_ref().and_then(|v| v.chat.as_ref().cloned()).unwrap_or_default()
     }
@@ -1,7 +1,7 @@
 use config::file::Components;
-use diesel::{alias, prelude::*};
+use diesel::{alias, dsl::count_distinct, prelude::*};
 use error_stack::Result;
-use model::{AccountId, AccountIdDb, AccountIdInternal, AccountInteractionInternal, ContentId, ReportAccountInfo, ReportChatInfo, ReportChatInfoInteractionState, ReportContent, ReportDetailed, ReportDetailedInfo, ReportDetailedWithId, ReportIdDb, ReportInternal, ReportProcessingState, ReportTypeNumber};
+use model::{AccountId, AccountIdDb, AccountIdInternal, AccountInteractionInternal, ContentId, ReportAccountInfo, ReportChatInfo, ReportChatInfoInteractionState, ReportContent, ReportDetailed, ReportDetailedInfo, ReportDetailedWithId, ReportIdDb, ReportInternal, ReportOutcome, ReportProcessingState, ReportTypeNumber, UnixTime};
 
 use crate::{define_current_read_commands, DieselDatabaseError, IntoDatabaseError};
 
@@ -19,7 +19,7 @@ impl CurrentReadCommonReport<'_> {
         let (creator_aid, target_aid) =
             alias!(account_id as creator_aid, account_id as target_aid);
 
-        let values: Vec<(AccountId, AccountIdDb, AccountId, AccountIdDb, ReportIdDb, ReportProcessingState)> = common_report
+        let values: Vec<(AccountId, AccountIdDb, AccountId, AccountIdDb, ReportIdDb, ReportProcessingState, Option<ReportOutcome>, Option<String>)> = common_report
             .inner_join(creator_aid.on(creator_account_id.eq(creator_aid.field(account_id::id))))
             .inner_join(target_aid.on(target_account_id.eq(target_aid.field(account_id::id))))
             .filter(creator_account_id.eq(creator.as_db_id()))
@@ -32,17 +32,21 @@ impl CurrentReadCommonReport<'_> {
                 target_account_id,
                 id,
                 processing_state,
+                processing_outcome,
+                moderator_note,
             ))
             .load(self.conn())
             .into_db_error(())?;
 
-        let values = values.into_iter().map(|(creator, creator_db_id, target, target_db_id, report_id, state)| {
+        let values = values.into_iter().map(|(creator, creator_db_id, target, target_db_id, report_id, state, outcome, note)| {
             ReportInternal {
                 info: ReportDetailedInfo {
                     creator,
                     target,
                     processing_state: state,
                     report_type,
+                    outcome,
+                    moderator_note: note,
                 },
                 id: report_id,
                 creator_db_id,
@@ -101,6 +105,11 @@ impl CurrentReadCommonReport<'_> {
                 } else {
                     None
                 },
+                ban_appeal: if report.info.report_type == ReportTypeNumber::BanAppeal {
+                    self.ban_appeal_report(report.id)?
+                } else {
+                    None
+                },
             },
             info: report.info,
             creator_info: if components.profile {
@@ -128,6 +137,56 @@ impl CurrentReadCommonReport<'_> {
         Ok(detailed)
     }
 
+    pub fn distinct_reporter_count(
+        &mut self,
+        target: AccountIdInternal,
+        report_type: ReportTypeNumber,
+        reports_created_after: UnixTime,
+    ) -> Result<i64, DieselDatabaseError> {
+        use crate::schema::common_report::dsl::*;
+
+        common_report
+            .filter(target_account_id.eq(target.as_db_id()))
+            .filter(report_type_number.eq(report_type))
+            .filter(creation_unix_time.ge(reports_created_after))
+            .filter(processing_state.eq(ReportProcessingState::Waiting))
+            .select(count_distinct(creator_account_id))
+            .first(self.conn())
+            .into_db_error(target)
+    }
+
+    /// Check both common and account reports.
+    pub fn prioritized_waiting_reports_exist(
+        &mut self,
+        target: AccountIdInternal,
+    ) -> Result<bool, DieselDatabaseError> {
+        let common_reports: i64 = {
+            use crate::schema::common_report::dsl::*;
+
+            common_report
+                .filter(target_account_id.eq(target.as_db_id()))
+                .filter(processing_state.eq(ReportProcessingState::Waiting))
+                .filter(prioritized.eq(true))
+                .count()
+                .get_result(self.conn())
+                .into_db_error(target)?
+        };
+
+        let account_reports: i64 = {
+            use crate::schema::account_report::dsl::*;
+
+            account_report
+                .filter(target_account_id.eq(target.as_db_id()))
+                .filter(processing_state.eq(ReportProcessingState::Waiting))
+                .filter(prioritized.eq(true))
+                .count()
+                .get_result(self.conn())
+                .into_db_error(target)?
+        };
+
+        Ok(common_reports > 0 || account_reports > 0)
+    }
+
     fn profile_name_report(
         &mut self,
         id: ReportIdDb
@@ -184,6 +243,20 @@ impl CurrentReadCommonReport<'_> {
             .map(|v| v.flatten())
     }
 
+    fn ban_appeal_report(
+        &mut self,
+        id: ReportIdDb
+    ) -> Result<Option<String>, DieselDatabaseError> {
+        use crate::schema::account_report_ban_appeal::dsl::*;
+
+        account_report_ban_appeal.find(id)
+            .select(appeal_text)
+            .first(self.conn())
+            .optional()
+            .into_db_error(())
+            .map(|v| v.flatten())
+    }
+
     fn get_report_account_info(
         &mut self,
         id: AccountIdDb
@@ -1,10 +1,15 @@
 use crate::define_current_read_commands;
 
+mod moderation_claim;
 mod report;
 
 define_current_read_commands!(CurrentReadCommonAdmin);
 
 impl<'a> CurrentReadCommonAdmin<'a> {
+    pub fn moderation_claim(self) -> moderation_claim::CurrentReadCommonAdminModerationClaim<'a> {
+        moderation_claim::CurrentReadCommonAdminModerationClaim::new(self.cmds)
+    }
+
     pub fn report(self) -> report::CurrentReadCommonAdminReport<'a> {
         report::CurrentReadCommonAdminReport::new(self.cmds)
     }
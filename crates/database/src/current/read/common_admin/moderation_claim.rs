@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use diesel::{dsl::count_star, prelude::*};
+use error_stack::Result;
+use model::{
+    schema::moderation_claim, schema_sqlite_types::Integer, AccountId, AccountIdDb, AccountIdInternal, Db,
+    GetModeratorThroughputList, ModerationClaimQueue, ModeratorThroughput,
+    ModeratorThroughputQuery, UnixTime,
+};
+
+use crate::{define_current_read_commands, DieselDatabaseError, IntoDatabaseError};
+
+define_current_read_commands!(CurrentReadCommonAdminModerationClaim);
+
+impl CurrentReadCommonAdminModerationClaim<'_> {
+    /// Subquery for item IDs which other moderators have claimed
+    /// and the claim lease is not expired.
+    ///
+    /// Moderation queue queries use this to skip claimed items.
+    pub fn items_claimed_by_other_moderators(
+        &self,
+        queue: ModerationClaimQueue,
+        moderator_id: AccountIdInternal,
+    ) -> moderation_claim::BoxedQuery<'static, Db, Integer> {
+        let current_time = UnixTime::current_time();
+
+        moderation_claim::table
+            .filter(moderation_claim::queue_type.eq(queue))
+            .filter(moderation_claim::moderator_account_id.ne(moderator_id.as_db_id()))
+            .filter(moderation_claim::completed_unix_time.is_null())
+            .filter(moderation_claim::lease_expiration_unix_time.gt(current_time))
+            .select(moderation_claim::item_id)
+            .into_boxed()
+    }
+
+    pub fn moderator_throughput(
+        &mut self,
+        query: ModeratorThroughputQuery,
+    ) -> Result<GetModeratorThroughputList, DieselDatabaseError> {
+        use crate::schema::account_id;
+
+        let counts: Vec<(AccountIdDb, ModerationClaimQueue, i64)> = moderation_claim::table
+            .filter(moderation_claim::completed_unix_time.ge(query.start_time))
+            .filter(moderation_claim::completed_unix_time.le(query.end_time))
+            .group_by((moderation_claim::moderator_account_id, moderation_claim::queue_type))
+            .select((
+                moderation_claim::moderator_account_id,
+                moderation_claim::queue_type,
+                count_star(),
+            ))
+            .load(self.conn())
+            .into_db_error(())?;
+
+        let moderator_db_ids: Vec<AccountIdDb> = counts.iter().map(|(id, _, _)| *id).collect();
+        let moderators: HashMap<AccountIdDb, AccountId> = account_id::table
+            .filter(account_id::id.eq_any(&moderator_db_ids))
+            .select((account_id::id, account_id::uuid))
+            .load(self.conn())
+            .into_db_error(())?
+            .into_iter()
+            .collect();
+
+        let values = counts
+            .into_iter()
+            .filter_map(|(moderator_db_id, queue, completed)| {
+                moderators
+                    .get(&moderator_db_id)
+                    .map(|moderator| ModeratorThroughput {
+                        moderator: *moderator,
+                        queue,
+                        completed,
+                    })
+            })
+            .collect();
+
+        Ok(GetModeratorThroughputList { values })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use diesel::{prelude::*, Connection, SqliteConnection};
+    use diesel_migrations::MigrationHarness;
+    use model::{
+        schema::{account_id, moderation_claim},
+        AccountId, AccountIdDb, ModerationClaimQueue, ModeratorThroughputQuery, UnixTime,
+    };
+
+    use super::CurrentReadCommonAdminModerationClaim;
+    use crate::DIESEL_MIGRATIONS;
+
+    fn insert_account(conn: &mut SqliteConnection) -> (AccountIdDb, AccountId) {
+        let uuid = AccountId::new_random();
+        let id = diesel::insert_into(account_id::table)
+            .values(account_id::uuid.eq(uuid))
+            .returning(account_id::id)
+            .get_result(conn)
+            .unwrap();
+        (id, uuid)
+    }
+
+    fn insert_claim(
+        conn: &mut SqliteConnection,
+        moderator: AccountIdDb,
+        queue: ModerationClaimQueue,
+        item: i64,
+        completed: Option<i64>,
+    ) {
+        diesel::insert_into(moderation_claim::table)
+            .values((
+                moderation_claim::queue_type.eq(queue),
+                moderation_claim::item_id.eq(item),
+                moderation_claim::moderator_account_id.eq(moderator),
+                moderation_claim::claim_unix_time.eq(UnixTime::new(0)),
+                moderation_claim::lease_expiration_unix_time.eq(UnixTime::new(0)),
+                moderation_claim::completed_unix_time.eq(completed.map(UnixTime::new)),
+            ))
+            .execute(conn)
+            .unwrap();
+    }
+
+    #[test]
+    fn moderator_throughput_counts_completed_items_in_time_range() {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        conn.run_pending_migrations(DIESEL_MIGRATIONS).unwrap();
+
+        let (m1_db, m1) = insert_account(&mut conn);
+        let (m2_db, m2) = insert_account(&mut conn);
+        insert_claim(&mut conn, m1_db, ModerationClaimQueue::MediaContent, 1, Some(10));
+        insert_claim(&mut conn, m1_db, ModerationClaimQueue::MediaContent, 2, Some(20));
+        insert_claim(&mut conn, m1_db, ModerationClaimQueue::ProfileText, 3, Some(20));
+        insert_claim(&mut conn, m2_db, ModerationClaimQueue::MediaContent, 4, Some(30));
+        // Not completed
+        insert_claim(&mut conn, m2_db, ModerationClaimQueue::MediaContent, 5, None);
+        // Outside of the time range
+        insert_claim(&mut conn, m2_db, ModerationClaimQueue::Report, 6, Some(100));
+
+        let mut values = CurrentReadCommonAdminModerationClaim::new(&mut conn)
+            .moderator_throughput(ModeratorThroughputQuery {
+                start_time: UnixTime::new(10),
+                end_time: UnixTime::new(30),
+            })
+            .unwrap()
+            .values
+            .into_iter()
+            .map(|v| (v.moderator, v.queue as i64, v.completed))
+            .collect::<Vec<_>>();
+        values.sort_by_key(|(moderator, queue, _)| (*moderator != m1, *queue));
+
+        assert_eq!(
+            values,
+            vec![
+                (m1, ModerationClaimQueue::MediaContent as i64, 2),
+                (m1, ModerationClaimQueue::ProfileText as i64, 1),
+                (m2, ModerationClaimQueue::MediaContent as i64, 1),
+            ]
+        );
+    }
+}
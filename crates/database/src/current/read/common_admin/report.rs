@@ -2,16 +2,21 @@ use crate::{current::read::GetDbReadCommandsCommon, define_current_read_commands
 use config::file::Components;
 use diesel::{alias, prelude::*};
 use error_stack::Result;
-use model::{AccountId, AccountIdDb, GetReportList, ReportDetailedInfo, ReportIdDb, ReportInternal, ReportIteratorMode, ReportIteratorQueryInternal, ReportProcessingState, ReportTypeNumber};
+use model::{AccountId, AccountIdDb, AccountIdInternal, GetReportList, ModerationClaimQueue, ReportDetailedInfo, ReportIdDb, ReportInternal, ReportIteratorMode, ReportIteratorQueryInternal, ReportOutcome, ReportProcessingState, ReportTypeNumber};
 
 define_current_read_commands!(CurrentReadCommonAdminReport);
 
 impl CurrentReadCommonAdminReport<'_> {
+    /// Waiting reports excluding ban appeals. Reports which other
+    /// moderators have claimed are skipped.
+    ///
+    /// Returns also the report IDs for claiming the reports.
     pub fn get_reports_page(
         &mut self,
+        moderator_id: AccountIdInternal,
         components: Components,
-    ) -> Result<GetReportList, DieselDatabaseError> {
-        let reports = self.get_waiting_reports_page()?;
+    ) -> Result<(GetReportList, Vec<ReportIdDb>), DieselDatabaseError> {
+        let reports = self.get_waiting_reports_page(moderator_id, false)?;
 
         let mut page = vec![];
 
@@ -20,13 +25,43 @@ impl CurrentReadCommonAdminReport<'_> {
             page.push(detailed);
         }
 
-        Ok(GetReportList {
+        let report_ids = page.iter().map(|v| v.id).collect();
+        let list = GetReportList {
             values: page.into_iter().map(|v| v.report).collect(),
-        })
+        };
+
+        Ok((list, report_ids))
+    }
+
+    /// Ban appeals which other moderators have claimed are skipped.
+    ///
+    /// Returns also the report IDs for claiming the ban appeals.
+    pub fn get_ban_appeals_page(
+        &mut self,
+        moderator_id: AccountIdInternal,
+        components: Components,
+    ) -> Result<(GetReportList, Vec<ReportIdDb>), DieselDatabaseError> {
+        let reports = self.get_waiting_reports_page(moderator_id, true)?;
+
+        let mut page = vec![];
+
+        for r in reports {
+            let detailed = self.read().common().report().convert_to_detailed_report(r, components)?;
+            page.push(detailed);
+        }
+
+        let report_ids = page.iter().map(|v| v.id).collect();
+        let list = GetReportList {
+            values: page.into_iter().map(|v| v.report).collect(),
+        };
+
+        Ok((list, report_ids))
     }
 
     fn get_waiting_reports_page(
         &mut self,
+        moderator_id: AccountIdInternal,
+        ban_appeals: bool,
     ) -> Result<Vec<ReportInternal>, DieselDatabaseError> {
         use crate::schema::{account_id, common_report::dsl::*};
 
@@ -35,12 +70,27 @@ impl CurrentReadCommonAdminReport<'_> {
 
         const PAGE_SIZE: i64 = 25;
 
+        let queue = if ban_appeals {
+            ModerationClaimQueue::BanAppeal
+        } else {
+            ModerationClaimQueue::Report
+        };
+        let claimed_by_others = self
+            .read()
+            .common_admin()
+            .moderation_claim()
+            .items_claimed_by_other_moderators(queue, moderator_id);
+
         let values: Vec<(AccountId, AccountIdDb, AccountId, AccountIdDb, ReportIdDb, ReportTypeNumber)> = common_report
             .inner_join(creator_aid.on(creator_account_id.eq(creator_aid.field(account_id::id))))
             .inner_join(target_aid.on(target_account_id.eq(target_aid.field(account_id::id))))
             .filter(
                 processing_state.eq(ReportProcessingState::Waiting)
             )
+            .filter(
+                report_type_number.eq(ReportTypeNumber::BanAppeal).eq(ban_appeals)
+            )
+            .filter(id.ne_all(claimed_by_others))
             .select((
                 creator_aid.field(account_id::uuid),
                 creator_account_id,
@@ -50,6 +100,7 @@ impl CurrentReadCommonAdminReport<'_> {
                 report_type_number,
             ))
             .order((
+                prioritized.desc(),
                 creation_unix_time.asc(),
                 creator_account_id.asc(),
             ))
@@ -64,6 +115,8 @@ impl CurrentReadCommonAdminReport<'_> {
                     target,
                     processing_state: ReportProcessingState::Waiting,
                     report_type,
+                    outcome: None,
+                    moderator_note: None,
                 },
                 id: report_id,
                 creator_db_id,
@@ -108,7 +161,7 @@ impl CurrentReadCommonAdminReport<'_> {
             .inner_join(creator_aid.on(creator_account_id.eq(creator_aid.field(account_id::id))))
             .inner_join(target_aid.on(target_account_id.eq(target_aid.field(account_id::id))));
 
-        let values: Vec<(AccountId, AccountIdDb, AccountId, AccountIdDb, ReportIdDb, ReportProcessingState, ReportTypeNumber)> = match query.mode {
+        let values: Vec<(AccountId, AccountIdDb, AccountId, AccountIdDb, ReportIdDb, ReportProcessingState, ReportTypeNumber, Option<ReportOutcome>, Option<String>)> = match query.mode {
             ReportIteratorMode::Received => db_query
                 .filter(target_account_id.eq(query.aid.as_db_id()))
                 .filter(creation_unix_time.le(query.start_position))
@@ -120,6 +173,8 @@ impl CurrentReadCommonAdminReport<'_> {
                     id,
                     processing_state,
                     report_type_number,
+                    processing_outcome,
+                    moderator_note,
                 ))
                 .order((
                     creation_unix_time.desc(),
@@ -140,6 +195,8 @@ impl CurrentReadCommonAdminReport<'_> {
                     id,
                     processing_state,
                     report_type_number,
+                    processing_outcome,
+                    moderator_note,
                 ))
                 .order((
                     creation_unix_time.desc(),
@@ -151,13 +208,15 @@ impl CurrentReadCommonAdminReport<'_> {
                 .into_db_error(())?,
         };
 
-        let values = values.into_iter().map(|(creator, creator_db_id, target, target_db_id, report_id, report_state, report_type)| {
+        let values = values.into_iter().map(|(creator, creator_db_id, target, target_db_id, report_id, report_state, report_type, outcome, note)| {
             ReportInternal {
                 info: ReportDetailedInfo {
                     creator,
                     target,
                     processing_state: report_state,
                     report_type,
+                    outcome,
+                    moderator_note: note,
                 },
                 id: report_id,
                 creator_db_id,
@@ -1,4 +1,4 @@
-use diesel::{delete, insert_into, prelude::*};
+use diesel::{delete, insert_into, prelude::*, update};
 use error_stack::Result;
 use model::{AccountIdInternal, ReportIdDb, ReportProcessingState, ReportTypeNumber, UnixTime};
 use simple_backend_utils::current_unix_time;
@@ -35,6 +35,24 @@ impl CurrentWriteCommonReport<'_> {
         Ok(db_id)
     }
 
+    pub fn prioritize_waiting_reports(
+        &mut self,
+        target: AccountIdInternal,
+        type_number: ReportTypeNumber,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::common_report::dsl::*;
+
+        update(common_report)
+            .filter(target_account_id.eq(target.as_db_id()))
+            .filter(report_type_number.eq(type_number))
+            .filter(processing_state.eq(ReportProcessingState::Waiting))
+            .set(prioritized.eq(true))
+            .execute(self.conn())
+            .into_db_error(target)?;
+
+        Ok(())
+    }
+
     pub fn delete_old_reports(
         &mut self,
         type_number: ReportTypeNumber,
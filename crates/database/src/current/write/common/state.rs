@@ -59,6 +59,36 @@ impl CurrentWriteCommonState<'_> {
         Ok(())
     }
 
+    pub fn update_profile_verified(
+        &mut self,
+        id: AccountIdInternal,
+        profile_verified_value: bool,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::shared_state::dsl::*;
+
+        update(shared_state.find(id.as_db_id()))
+            .set(profile_verified.eq(profile_verified_value))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+
+    pub fn update_profile_hidden_pending_review(
+        &mut self,
+        id: AccountIdInternal,
+        value: bool,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::shared_state::dsl::*;
+
+        update(shared_state.find(id.as_db_id()))
+            .set(profile_hidden_pending_review.eq(value))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+
     pub fn update_birthdate(
         &mut self,
         id: AccountIdInternal,
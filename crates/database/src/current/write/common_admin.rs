@@ -1,10 +1,15 @@
 use crate::define_current_write_commands;
 
+mod moderation_claim;
 mod report;
 
 define_current_write_commands!(CurrentWriteCommonAdmin);
 
 impl<'a> CurrentWriteCommonAdmin<'a> {
+    pub fn moderation_claim(self) -> moderation_claim::CurrentWriteCommonAdminModerationClaim<'a> {
+        moderation_claim::CurrentWriteCommonAdminModerationClaim::new(self.cmds)
+    }
+
     pub fn report(self) -> report::CurrentWriteCommonAdminReport<'a> {
         report::CurrentWriteCommonAdminReport::new(self.cmds)
     }
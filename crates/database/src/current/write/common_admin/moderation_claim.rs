@@ -0,0 +1,124 @@
+use diesel::{delete, insert_into, prelude::*, update};
+use error_stack::Result;
+use model::{AccountIdInternal, ModerationClaimQueue, UnixTime};
+
+use crate::{define_current_write_commands, DieselDatabaseError, IntoDatabaseError};
+
+define_current_write_commands!(CurrentWriteCommonAdminModerationClaim);
+
+impl CurrentWriteCommonAdminModerationClaim<'_> {
+    /// Claim items for the moderator. Previous not completed claims
+    /// for the items are replaced, so the caller must check that
+    /// other moderators do not have valid claims for the items.
+    pub fn claim_items(
+        &mut self,
+        moderator_id: AccountIdInternal,
+        queue: ModerationClaimQueue,
+        items: Vec<i64>,
+        lease_expiration: UnixTime,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::moderation_claim::dsl::*;
+
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        delete(moderation_claim)
+            .filter(queue_type.eq(queue))
+            .filter(item_id.eq_any(&items))
+            .filter(completed_unix_time.is_null())
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        let time = UnixTime::current_time();
+        let values: Vec<_> = items
+            .into_iter()
+            .map(|item| {
+                (
+                    queue_type.eq(queue),
+                    item_id.eq(item),
+                    moderator_account_id.eq(moderator_id.as_db_id()),
+                    claim_unix_time.eq(time),
+                    lease_expiration_unix_time.eq(lease_expiration),
+                )
+            })
+            .collect();
+
+        insert_into(moderation_claim)
+            .values(values)
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+
+    /// Mark the moderator's claim for the item completed and remove
+    /// other claims for the item. Completed claim is created if
+    /// the moderator did not have a claim for the item.
+    pub fn complete_item(
+        &mut self,
+        moderator_id: AccountIdInternal,
+        queue: ModerationClaimQueue,
+        item: i64,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::moderation_claim::dsl::*;
+
+        let time = UnixTime::current_time();
+
+        let updated = update(moderation_claim)
+            .filter(queue_type.eq(queue))
+            .filter(item_id.eq(item))
+            .filter(moderator_account_id.eq(moderator_id.as_db_id()))
+            .filter(completed_unix_time.is_null())
+            .set(completed_unix_time.eq(time))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        if updated == 0 {
+            insert_into(moderation_claim)
+                .values((
+                    queue_type.eq(queue),
+                    item_id.eq(item),
+                    moderator_account_id.eq(moderator_id.as_db_id()),
+                    claim_unix_time.eq(time),
+                    lease_expiration_unix_time.eq(time),
+                    completed_unix_time.eq(time),
+                ))
+                .execute(self.conn())
+                .into_db_error(())?;
+        }
+
+        delete(moderation_claim)
+            .filter(queue_type.eq(queue))
+            .filter(item_id.eq(item))
+            .filter(completed_unix_time.is_null())
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+
+    /// Delete claims which lease has expired and completed claims
+    /// which are older than `completed_before`.
+    pub fn delete_old_claims(
+        &mut self,
+        completed_before: UnixTime,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::moderation_claim::dsl::*;
+
+        let time = UnixTime::current_time();
+
+        delete(moderation_claim)
+            .filter(completed_unix_time.is_null())
+            .filter(lease_expiration_unix_time.le(time))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        delete(moderation_claim)
+            .filter(completed_unix_time.lt(completed_before))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+}
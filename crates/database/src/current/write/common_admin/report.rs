@@ -3,7 +3,7 @@ use crate::{
 };
 use diesel::{prelude::*, update, ExpressionMethods};
 use error_stack::Result;
-use model::{ReportIdDb, ReportProcessingState, UnixTime};
+use model::{ReportIdDb, ReportOutcome, ReportProcessingState, UnixTime};
 use model::AccountIdInternal;
 
 define_current_write_commands!(CurrentWriteCommonAdminReport);
@@ -13,6 +13,8 @@ impl CurrentWriteCommonAdminReport<'_> {
         &mut self,
         moderator_id: AccountIdInternal,
         report_id: ReportIdDb,
+        outcome: ReportOutcome,
+        note: Option<String>,
     ) -> Result<(), DieselDatabaseError> {
         use model::schema::common_report::dsl::*;
 
@@ -24,6 +26,8 @@ impl CurrentWriteCommonAdminReport<'_> {
                 moderator_account_id.eq(moderator_id.as_db_id()),
                 processing_state.eq(ReportProcessingState::Done),
                 processing_state_change_unix_time.eq(time),
+                processing_outcome.eq(outcome),
+                moderator_note.eq(note),
             ))
             .execute(self.conn())
             .into_db_error(())?;
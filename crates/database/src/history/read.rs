@@ -1 +1,15 @@
-pub trait GetDbHistoryReadCommandsCommon {}
+use common_admin::HistoryReadCommonAdmin;
+
+use crate::DbReadAccessProviderHistory;
+
+pub mod common_admin;
+
+pub trait GetDbHistoryReadCommandsCommon {
+    fn common_admin_history(&mut self) -> HistoryReadCommonAdmin<'_>;
+}
+
+impl<I: DbReadAccessProviderHistory> GetDbHistoryReadCommandsCommon for I {
+    fn common_admin_history(&mut self) -> HistoryReadCommonAdmin<'_> {
+        HistoryReadCommonAdmin::new(self.handle())
+    }
+}
@@ -0,0 +1,43 @@
+use diesel::prelude::*;
+use error_stack::Result;
+use model::{AdminAction, AdminActionIteratorQuery, GetAdminActionList};
+use simple_backend_database::diesel_db::DieselDatabaseError;
+
+use crate::{define_current_read_commands, IntoDatabaseError};
+
+define_current_read_commands!(HistoryReadCommonAdmin);
+
+impl HistoryReadCommonAdmin<'_> {
+    pub fn admin_action_iterator_page(
+        &mut self,
+        query: AdminActionIteratorQuery,
+    ) -> Result<GetAdminActionList, DieselDatabaseError> {
+        use model::schema::history_admin_action::dsl::*;
+
+        const PAGE_SIZE: i64 = 25;
+
+        let mut db_query = history_admin_action
+            .filter(unix_time.le(query.start_position))
+            .into_boxed();
+
+        if let Some(admin) = query.admin {
+            db_query = db_query.filter(admin_account_id.eq(admin));
+        }
+        if let Some(target) = query.target {
+            db_query = db_query.filter(target_account_id.eq(target));
+        }
+        if let Some(action_type) = query.action {
+            db_query = db_query.filter(action.eq(action_type));
+        }
+
+        let values = db_query
+            .select(AdminAction::as_select())
+            .order((unix_time.desc(), id.desc()))
+            .limit(PAGE_SIZE)
+            .offset(PAGE_SIZE.saturating_mul(query.page))
+            .load(self.conn())
+            .into_db_error(())?;
+
+        Ok(GetAdminActionList { values })
+    }
+}
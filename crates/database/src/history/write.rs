@@ -1,15 +1,22 @@
 use common::HistoryWriteCommon;
+use common_admin::HistoryWriteCommonAdmin;
 
 use crate::DbWriteAccessProviderHistory;
 
 pub mod common;
+pub mod common_admin;
 
 pub trait GetDbHistoryWriteCommandsCommon {
     fn common_history(&mut self) -> HistoryWriteCommon<'_>;
+    fn common_admin_history(&mut self) -> HistoryWriteCommonAdmin<'_>;
 }
 
 impl<I: DbWriteAccessProviderHistory> GetDbHistoryWriteCommandsCommon for I {
     fn common_history(&mut self) -> HistoryWriteCommon<'_> {
         HistoryWriteCommon::new(self.handle())
     }
+
+    fn common_admin_history(&mut self) -> HistoryWriteCommonAdmin<'_> {
+        HistoryWriteCommonAdmin::new(self.handle())
+    }
 }
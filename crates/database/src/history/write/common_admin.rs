@@ -0,0 +1,33 @@
+use diesel::{insert_into, ExpressionMethods, RunQueryDsl};
+use error_stack::Result;
+use model::{AccountId, AdminActionType, UnixTime};
+use simple_backend_database::diesel_db::DieselDatabaseError;
+
+use crate::{define_current_write_commands, IntoDatabaseError};
+
+define_current_write_commands!(HistoryWriteCommonAdmin);
+
+impl HistoryWriteCommonAdmin<'_> {
+    pub fn insert_admin_action(
+        &mut self,
+        admin: AccountId,
+        action_type: AdminActionType,
+        target: Option<AccountId>,
+        parameters_json: String,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::history_admin_action::dsl::*;
+
+        insert_into(history_admin_action)
+            .values((
+                unix_time.eq(UnixTime::current_time()),
+                admin_account_id.eq(admin),
+                action.eq(action_type),
+                target_account_id.eq(target),
+                parameters.eq(parameters_json),
+            ))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+}
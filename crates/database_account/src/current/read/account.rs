@@ -10,6 +10,7 @@ mod email;
 mod news;
 mod sign_in_with;
 mod report;
+mod warning;
 
 impl<'a> CurrentReadAccount<'a> {
     pub fn ban(self) -> ban::CurrentReadAccountBan<'a> {
@@ -43,4 +44,8 @@ impl<'a> CurrentReadAccount<'a> {
     pub fn report(self) -> report::CurrentReadAccountReport<'a> {
         report::CurrentReadAccountReport::new(self.cmds)
     }
+
+    pub fn warning(self) -> warning::CurrentReadAccountWarning<'a> {
+        warning::CurrentReadAccountWarning::new(self.cmds)
+    }
 }
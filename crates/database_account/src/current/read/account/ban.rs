@@ -1,7 +1,7 @@
 use database::{define_current_read_commands, DieselDatabaseError};
 use diesel::prelude::*;
 use error_stack::Result;
-use model::AccountIdInternal;
+use model::{AccountIdInternal, ReportTypeNumber, UnixTime};
 use model_account::GetAccountBanTimeResult;
 
 use crate::IntoDatabaseError;
@@ -26,4 +26,36 @@ impl CurrentReadAccountBan<'_> {
             .into_db_error(id)
             .map(|(banned_until, reason_category, reason_details)| GetAccountBanTimeResult { banned_until, reason_category, reason_details })
     }
+
+    pub fn account_ban_state_change_time(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<Option<UnixTime>, DieselDatabaseError> {
+        use crate::schema::account_state::dsl::*;
+
+        account_state
+            .filter(account_id.eq(id.as_db_id()))
+            .select(account_banned_state_change_unix_time)
+            .first(self.conn())
+            .into_db_error(id)
+    }
+
+    pub fn ban_appeal_exists(
+        &mut self,
+        id: AccountIdInternal,
+        ban_state_change_time: UnixTime,
+    ) -> Result<bool, DieselDatabaseError> {
+        use crate::schema::{account_report_ban_appeal, common_report};
+
+        let count: i64 = common_report::table
+            .inner_join(account_report_ban_appeal::table.on(account_report_ban_appeal::report_id.eq(common_report::id)))
+            .filter(common_report::creator_account_id.eq(id.as_db_id()))
+            .filter(common_report::report_type_number.eq(ReportTypeNumber::BanAppeal))
+            .filter(account_report_ban_appeal::ban_state_change_unix_time.eq(ban_state_change_time))
+            .count()
+            .get_result(self.conn())
+            .into_db_error(id)?;
+
+        Ok(count > 0)
+    }
 }
@@ -0,0 +1,28 @@
+use database::{define_current_read_commands, DieselDatabaseError};
+use diesel::prelude::*;
+use error_stack::Result;
+use model::{AccountIdInternal, UnixTime};
+use model_account::AccountWarning;
+
+use crate::IntoDatabaseError;
+
+define_current_read_commands!(CurrentReadAccountWarning);
+
+impl CurrentReadAccountWarning<'_> {
+    /// Latest warning is first.
+    pub fn warnings_created_after(
+        &mut self,
+        id: AccountIdInternal,
+        time: UnixTime,
+    ) -> Result<Vec<AccountWarning>, DieselDatabaseError> {
+        use crate::schema::account_warning::dsl::*;
+
+        account_warning
+            .filter(account_id.eq(id.as_db_id()))
+            .filter(creation_unix_time.gt(time))
+            .order((creation_unix_time.desc(), crate::schema::account_warning::id.desc()))
+            .select(AccountWarning::as_select())
+            .load(self.conn())
+            .into_db_error(())
+    }
+}
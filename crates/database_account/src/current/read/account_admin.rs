@@ -5,6 +5,7 @@ define_current_read_commands!(CurrentReadAccountAdmin);
 mod news;
 mod search;
 mod report;
+mod role;
 
 impl<'a> CurrentReadAccountAdmin<'a> {
     pub fn news(self) -> news::CurrentReadAccountNewsAdmin<'a> {
@@ -16,4 +17,7 @@ impl<'a> CurrentReadAccountAdmin<'a> {
     pub fn report(self) -> report::CurrentReadAccountReport<'a> {
         report::CurrentReadAccountReport::new(self.cmds)
     }
+    pub fn role(self) -> role::CurrentReadAccountRoleAdmin<'a> {
+        role::CurrentReadAccountRoleAdmin::new(self.cmds)
+    }
 }
@@ -28,6 +28,7 @@ impl CurrentReadAccountReport<'_> {
                 AccountReportContent::as_select(),
             ))
             .order((
+                prioritized.desc(),
                 content_edit_unix_time.asc(),
                 creator_account_id.asc(),
             ))
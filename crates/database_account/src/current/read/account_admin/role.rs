@@ -0,0 +1,138 @@
+use database::{define_current_read_commands, DieselDatabaseError};
+use diesel::prelude::*;
+use error_stack::{Result, ResultExt};
+use model::{AccountIdInternal, Permissions, UnixTime};
+use model_account::{AdminRole, AdminRoleAssignment};
+
+use crate::IntoDatabaseError;
+
+define_current_read_commands!(CurrentReadAccountRoleAdmin);
+
+impl CurrentReadAccountRoleAdmin<'_> {
+    pub fn all_roles(&mut self) -> Result<Vec<AdminRole>, DieselDatabaseError> {
+        use crate::schema::admin_role::dsl::*;
+
+        let roles: Vec<(String, String)> = admin_role
+            .order(name.asc())
+            .select((name, permissions))
+            .load(self.conn())
+            .into_db_error(())?;
+
+        roles
+            .into_iter()
+            .map(|(role_name, json)| {
+                Ok(AdminRole {
+                    name: role_name,
+                    permissions: parse_permissions(&json)?,
+                })
+            })
+            .collect()
+    }
+
+    pub fn role_id(&mut self, role_name: String) -> Result<Option<i64>, DieselDatabaseError> {
+        use crate::schema::admin_role::dsl::*;
+
+        admin_role
+            .filter(name.eq(role_name))
+            .select(id)
+            .first(self.conn())
+            .optional()
+            .into_db_error(())
+    }
+
+    pub fn accounts_with_role(
+        &mut self,
+        role: i64,
+    ) -> Result<Vec<AccountIdInternal>, DieselDatabaseError> {
+        use crate::schema::{account_admin_role, account_id};
+
+        account_admin_role::table
+            .inner_join(account_id::table)
+            .filter(account_admin_role::role_id.eq(role))
+            .select(AccountIdInternal::as_select())
+            .load(self.conn())
+            .into_db_error(())
+    }
+
+    pub fn accounts_with_expired_roles(
+        &mut self,
+        time: UnixTime,
+    ) -> Result<Vec<AccountIdInternal>, DieselDatabaseError> {
+        use crate::schema::{account_admin_role, account_id};
+
+        account_admin_role::table
+            .inner_join(account_id::table)
+            .filter(account_admin_role::expires_unix_time.le(time))
+            .select(AccountIdInternal::as_select())
+            .distinct()
+            .load(self.conn())
+            .into_db_error(())
+    }
+
+    pub fn account_roles(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<Vec<AdminRoleAssignment>, DieselDatabaseError> {
+        use crate::schema::{account_admin_role, admin_role};
+
+        let roles: Vec<(String, Option<UnixTime>)> = account_admin_role::table
+            .inner_join(admin_role::table)
+            .filter(account_admin_role::account_id.eq(id.as_db_id()))
+            .order(admin_role::name.asc())
+            .select((admin_role::name, account_admin_role::expires_unix_time))
+            .load(self.conn())
+            .into_db_error(id)?;
+
+        Ok(roles
+            .into_iter()
+            .map(|(name, expires)| AdminRoleAssignment { name, expires })
+            .collect())
+    }
+
+    /// Permissions and expiration times of roles which have not
+    /// expired at `time`.
+    pub fn active_role_permissions(
+        &mut self,
+        id: AccountIdInternal,
+        time: UnixTime,
+    ) -> Result<Vec<(Permissions, Option<UnixTime>)>, DieselDatabaseError> {
+        use crate::schema::{account_admin_role, admin_role};
+
+        let roles: Vec<(String, Option<UnixTime>)> = account_admin_role::table
+            .inner_join(admin_role::table)
+            .filter(account_admin_role::account_id.eq(id.as_db_id()))
+            .filter(
+                account_admin_role::expires_unix_time
+                    .is_null()
+                    .or(account_admin_role::expires_unix_time.gt(time)),
+            )
+            .select((admin_role::permissions, account_admin_role::expires_unix_time))
+            .load(self.conn())
+            .into_db_error(id)?;
+
+        roles
+            .into_iter()
+            .map(|(json, expires)| Ok((parse_permissions(&json)?, expires)))
+            .collect()
+    }
+
+    /// Returns `None` if admin roles have not been used with the account.
+    pub fn individual_permissions(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<Option<Permissions>, DieselDatabaseError> {
+        use crate::schema::account_permissions::dsl::*;
+
+        let json: Option<String> = account_permissions
+            .find(id.as_db_id())
+            .select(individual_permissions)
+            .first(self.conn())
+            .into_db_error(id)?;
+
+        json.map(|v| parse_permissions(&v)).transpose()
+    }
+}
+
+fn parse_permissions(json: &str) -> Result<Permissions, DieselDatabaseError> {
+    serde_json::from_str(json).change_context(DieselDatabaseError::SerdeDeserialize)
+}
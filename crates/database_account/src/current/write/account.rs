@@ -7,6 +7,7 @@ mod email;
 mod news;
 mod sign_in_with;
 mod report;
+mod warning;
 
 define_current_write_commands!(CurrentWriteAccount);
 
@@ -38,4 +39,8 @@ impl<'a> CurrentWriteAccount<'a> {
     pub fn report(self) -> report::CurrentWriteAccountReport<'a> {
         report::CurrentWriteAccountReport::new(self.cmds)
     }
+
+    pub fn warning(self) -> warning::CurrentWriteAccountWarning<'a> {
+        warning::CurrentWriteAccountWarning::new(self.cmds)
+    }
 }
@@ -1,7 +1,7 @@
-use database::{define_current_write_commands, DieselDatabaseError};
-use diesel::{insert_into, prelude::*, ExpressionMethods};
+use database::{current::write::GetDbWriteCommandsCommon, define_current_write_commands, DieselDatabaseError};
+use diesel::{insert_into, prelude::*, update, ExpressionMethods};
 use error_stack::Result;
-use model::{AccountIdInternal, ReportProcessingState, UnixTime};
+use model::{AccountIdInternal, ReportProcessingState, ReportTypeNumber, UnixTime};
 use model_account::AccountReportContent;
 
 use crate::IntoDatabaseError;
@@ -48,4 +48,51 @@ impl CurrentWriteAccountReport<'_> {
 
         Ok(())
     }
+
+    pub fn prioritize_waiting_report(
+        &mut self,
+        creator: AccountIdInternal,
+        target: AccountIdInternal,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_report::dsl::*;
+
+        update(account_report)
+            .filter(creator_account_id.eq(creator.as_db_id()))
+            .filter(target_account_id.eq(target.as_db_id()))
+            .filter(processing_state.eq(ReportProcessingState::Waiting))
+            .set(prioritized.eq(true))
+            .execute(self.conn())
+            .into_db_error((creator, target))?;
+
+        Ok(())
+    }
+
+    pub fn insert_ban_appeal(
+        &mut self,
+        account: AccountIdInternal,
+        ban_state_change_time: UnixTime,
+        text: String,
+    ) -> Result<(), DieselDatabaseError> {
+        let id = self.write().common().report().insert_report_content(
+            account,
+            account,
+            ReportTypeNumber::BanAppeal,
+            ReportProcessingState::Waiting,
+        )?;
+
+        {
+            use model::schema::account_report_ban_appeal::dsl::*;
+
+            insert_into(account_report_ban_appeal)
+                .values((
+                    report_id.eq(id),
+                    ban_state_change_unix_time.eq(ban_state_change_time),
+                    appeal_text.eq(text),
+                ))
+                .execute(self.conn())
+                .into_db_error(account)?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,28 @@
+use database::{define_current_write_commands, DieselDatabaseError};
+use diesel::{prelude::*, update};
+use error_stack::Result;
+use model::{AccountIdInternal, UnixTime};
+
+use crate::IntoDatabaseError;
+
+define_current_write_commands!(CurrentWriteAccountWarning);
+
+impl CurrentWriteAccountWarning<'_> {
+    pub fn acknowledge_warnings(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_warning::dsl::*;
+
+        let current_time = UnixTime::current_time();
+
+        update(account_warning)
+            .filter(account_id.eq(id.as_db_id()))
+            .filter(acknowledged_unix_time.is_null())
+            .set(acknowledged_unix_time.eq(current_time))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+}
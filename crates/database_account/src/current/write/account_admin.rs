@@ -3,6 +3,8 @@ use database::define_current_write_commands;
 mod ban;
 mod news;
 mod report;
+mod role;
+mod warning;
 
 define_current_write_commands!(CurrentWriteAccountAdmin);
 
@@ -18,4 +20,12 @@ impl<'a> CurrentWriteAccountAdmin<'a> {
     pub fn report(self) -> report::CurrentWriteAccountAdminReport<'a> {
         report::CurrentWriteAccountAdminReport::new(self.cmds)
     }
+
+    pub fn role(self) -> role::CurrentWriteAccountRoleAdmin<'a> {
+        role::CurrentWriteAccountRoleAdmin::new(self.cmds)
+    }
+
+    pub fn warning(self) -> warning::CurrentWriteAccountWarningAdmin<'a> {
+        warning::CurrentWriteAccountWarningAdmin::new(self.cmds)
+    }
 }
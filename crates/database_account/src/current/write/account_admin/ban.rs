@@ -1,7 +1,7 @@
 use database::{define_current_read_commands, DieselDatabaseError};
 use diesel::{prelude::*, update};
 use error_stack::Result;
-use model::{AccountIdInternal, UnixTime};
+use model::{AccountIdInternal, BanAppealDecision, ReportIdDb, UnixTime};
 use model_account::{AccountBanReasonCategory, AccountBanReasonDetails};
 
 use crate::IntoDatabaseError;
@@ -35,4 +35,38 @@ impl CurrentWriteAccountBanAdmin<'_> {
 
         Ok(())
     }
+
+    /// Change only the ban end time. The ban state change time is not
+    /// modified, so ban appeals stay linked to the current ban.
+    pub fn update_banned_until(
+        &mut self,
+        id: AccountIdInternal,
+        banned_until: UnixTime,
+    ) -> Result<(), DieselDatabaseError> {
+        use crate::schema::account_state::dsl::*;
+
+        update(account_state)
+            .filter(account_id.eq(id.as_db_id()))
+            .set(account_banned_until_unix_time.eq(banned_until))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+
+    pub fn set_ban_appeal_decision(
+        &mut self,
+        id: ReportIdDb,
+        value: BanAppealDecision,
+    ) -> Result<(), DieselDatabaseError> {
+        use crate::schema::account_report_ban_appeal::dsl::*;
+
+        update(account_report_ban_appeal)
+            .filter(report_id.eq(id))
+            .set(decision.eq(value))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,116 @@
+use database::{define_current_write_commands, DieselDatabaseError};
+use diesel::{delete, insert_into, prelude::*, update, upsert::excluded};
+use error_stack::{Result, ResultExt};
+use model::{AccountIdInternal, Permissions, UnixTime};
+
+use crate::IntoDatabaseError;
+
+define_current_write_commands!(CurrentWriteAccountRoleAdmin);
+
+impl CurrentWriteAccountRoleAdmin<'_> {
+    pub fn upsert_role(
+        &mut self,
+        role_name: String,
+        role_permissions: &Permissions,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::admin_role::dsl::*;
+
+        let json = permissions_to_json(role_permissions)?;
+
+        insert_into(admin_role)
+            .values((name.eq(role_name), permissions.eq(json)))
+            .on_conflict(name)
+            .do_update()
+            .set(permissions.eq(excluded(permissions)))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+
+    pub fn delete_role(&mut self, role: i64) -> Result<(), DieselDatabaseError> {
+        use model::schema::admin_role::dsl::*;
+
+        delete(admin_role.filter(id.eq(role)))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+
+    pub fn upsert_account_role(
+        &mut self,
+        account: AccountIdInternal,
+        role: i64,
+        expires: Option<UnixTime>,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_admin_role::dsl::*;
+
+        insert_into(account_admin_role)
+            .values((
+                account_id.eq(account.as_db_id()),
+                role_id.eq(role),
+                expires_unix_time.eq(expires),
+            ))
+            .on_conflict((account_id, role_id))
+            .do_update()
+            .set(expires_unix_time.eq(expires))
+            .execute(self.conn())
+            .into_db_error(account)?;
+
+        Ok(())
+    }
+
+    pub fn delete_account_role(
+        &mut self,
+        account: AccountIdInternal,
+        role: i64,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_admin_role::dsl::*;
+
+        delete(account_admin_role)
+            .filter(account_id.eq(account.as_db_id()))
+            .filter(role_id.eq(role))
+            .execute(self.conn())
+            .into_db_error(account)?;
+
+        Ok(())
+    }
+
+    pub fn delete_expired_account_roles(
+        &mut self,
+        account: AccountIdInternal,
+        time: UnixTime,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_admin_role::dsl::*;
+
+        delete(account_admin_role)
+            .filter(account_id.eq(account.as_db_id()))
+            .filter(expires_unix_time.le(time))
+            .execute(self.conn())
+            .into_db_error(account)?;
+
+        Ok(())
+    }
+
+    pub fn update_individual_permissions(
+        &mut self,
+        id: AccountIdInternal,
+        value: &Permissions,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_permissions::dsl::*;
+
+        let json = permissions_to_json(value)?;
+
+        update(account_permissions.find(id.as_db_id()))
+            .set(individual_permissions.eq(json))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+}
+
+fn permissions_to_json(value: &Permissions) -> Result<String, DieselDatabaseError> {
+    serde_json::to_string(value).change_context(DieselDatabaseError::SerdeSerialize)
+}
@@ -0,0 +1,36 @@
+use database::{define_current_write_commands, DieselDatabaseError};
+use diesel::{insert_into, prelude::*};
+use error_stack::Result;
+use model::{AccountIdInternal, UnixTime};
+use model_account::{AccountBanReasonCategory, AccountBanReasonDetails};
+
+use crate::IntoDatabaseError;
+
+define_current_write_commands!(CurrentWriteAccountWarningAdmin);
+
+impl CurrentWriteAccountWarningAdmin<'_> {
+    pub fn insert_warning(
+        &mut self,
+        id: AccountIdInternal,
+        admin_id: Option<AccountIdInternal>,
+        category: Option<AccountBanReasonCategory>,
+        details: Option<AccountBanReasonDetails>,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::account_warning::dsl::*;
+
+        let current_time = UnixTime::current_time();
+
+        insert_into(account_warning)
+            .values((
+                account_id.eq(id.as_db_id()),
+                admin_account_id.eq(admin_id.map(|v| v.into_db_id())),
+                creation_unix_time.eq(current_time),
+                reason_category.eq(category),
+                reason_details.eq(details),
+            ))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+}
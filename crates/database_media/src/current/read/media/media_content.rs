@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+
 use database::{define_current_read_commands, DieselDatabaseError};
 use diesel::prelude::*;
 use error_stack::Result;
-use model::ContentIdInternal;
+use model::{ContentIdInternal, PerceptualHash};
 use model_media::{
     AccountIdInternal, ContentId, ContentIdDb, ContentModerationState, ContentSlot, CurrentAccountMediaInternal, CurrentAccountMediaRaw, MediaContentRaw
 };
@@ -150,4 +152,58 @@ impl CurrentReadMediaMediaContent<'_> {
             .optional()
             .into_db_error((slot_owner, slot))
     }
+
+    /// Find near-duplicates for new content of `content_owner`. The hash is
+    /// compared to content of other accounts and rejected content of the
+    /// content owner.
+    ///
+    /// Only content which has a perceptual hash band close enough to
+    /// the hash band of the new content is compared.
+    ///
+    /// Returns content IDs and hash distances.
+    pub fn find_duplicate_content(
+        &mut self,
+        content_owner: AccountIdInternal,
+        hash: PerceptualHash,
+        max_hash_distance: u8,
+    ) -> Result<Vec<(ContentIdDb, u32)>, DieselDatabaseError> {
+        use crate::schema::{media_content, media_content_hash_band};
+
+        let mut candidates = HashMap::<ContentIdDb, PerceptualHash>::new();
+        for (i, band_values) in hash
+            .candidate_band_values(max_hash_distance)
+            .into_iter()
+            .enumerate()
+        {
+            let band_candidates: Vec<(ContentIdDb, PerceptualHash)> = media_content_hash_band::table
+                .inner_join(media_content::table)
+                .filter(media_content_hash_band::band_index.eq(i as i64))
+                .filter(media_content_hash_band::band_value.eq_any(band_values))
+                .filter(
+                    media_content::account_id
+                        .ne(content_owner.as_db_id())
+                        .or(media_content::moderation_state.eq(ContentModerationState::RejectedByBot))
+                        .or(media_content::moderation_state.eq(ContentModerationState::RejectedByHuman)),
+                )
+                .filter(media_content::perceptual_hash.is_not_null())
+                .select((media_content::id, media_content::perceptual_hash.assume_not_null()))
+                .load(self.conn())
+                .into_db_error(content_owner)?;
+            candidates.extend(band_candidates);
+        }
+
+        let duplicates = candidates
+            .into_iter()
+            .filter_map(|(duplicate_id, duplicate_hash)| {
+                let distance = hash.distance(duplicate_hash);
+                if distance <= max_hash_distance.into() {
+                    Some((duplicate_id, distance))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Ok(duplicates)
+    }
 }
@@ -1,6 +1,7 @@
 use database::define_current_read_commands;
 
 mod content;
+mod duplicate;
 
 define_current_read_commands!(CurrentReadMediaAdmin);
 
@@ -8,4 +9,8 @@ impl<'a> CurrentReadMediaAdmin<'a> {
     pub fn content(self) -> content::CurrentReadMediaAdminContent<'a> {
         content::CurrentReadMediaAdminContent::new(self.cmds)
     }
+
+    pub fn duplicate(self) -> duplicate::CurrentReadMediaAdminDuplicate<'a> {
+        duplicate::CurrentReadMediaAdminDuplicate::new(self.cmds)
+    }
 }
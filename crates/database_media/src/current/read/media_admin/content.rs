@@ -2,7 +2,7 @@ use database::{current::read::GetDbReadCommandsCommon, define_current_read_comma
 use diesel::prelude::*;
 use error_stack::Result;
 use model_media::{
-    AccountIdInternal, ContentModerationState, GetProfileContentPendingModerationList, GetProfileContentPendingModerationParams, ModerationQueueType, ProfileContentPendingModeration
+    AccountId, AccountIdInternal, ContentId, ContentIdDb, ContentModerationState, GetProfileContentPendingModerationList, GetProfileContentPendingModerationParams, ModerationClaimQueue, ModerationQueueType, NsfwScores, ProfileContentPendingModeration
 };
 
 use crate::IntoDatabaseError;
@@ -10,11 +10,13 @@ use crate::IntoDatabaseError;
 define_current_read_commands!(CurrentReadMediaAdminContent);
 
 impl CurrentReadMediaAdminContent<'_> {
+    /// Content which other moderators have claimed is skipped.
+    /// Returns also the content IDs for claiming the content.
     pub fn profile_content_pending_moderation_list(
         &mut self,
         moderator_id: AccountIdInternal,
         params: GetProfileContentPendingModerationParams,
-    ) -> Result<GetProfileContentPendingModerationList, DieselDatabaseError> {
+    ) -> Result<(GetProfileContentPendingModerationList, Vec<ContentIdDb>), DieselDatabaseError> {
         use crate::schema::{account_id, media_content};
 
         const LIMIT: i64 = 25;
@@ -39,7 +41,14 @@ impl CurrentReadMediaAdminContent<'_> {
             ModerationQueueType::MediaModeration => false,
         };
 
-        let values = media_content::table
+        let claimed_by_others = self
+            .read()
+            .common_admin()
+            .moderation_claim()
+            .items_claimed_by_other_moderators(ModerationClaimQueue::MediaContent, moderator_id);
+
+        type NsfwScoreValues = (Option<f64>, Option<f64>, Option<f64>, Option<f64>, Option<f64>);
+        let values: Vec<(AccountId, ContentId, ContentIdDb, NsfwScoreValues)> = media_content::table
             .inner_join(account_id::table)
             .filter(
                 show_bot_moderations
@@ -54,15 +63,39 @@ impl CurrentReadMediaAdminContent<'_> {
             )
             .filter(media_content::content_type_number.eq(params.content_type))
             .filter(media_content::initial_content.eq(initial_content_value))
-            .select((account_id::uuid, media_content::uuid))
+            .filter(media_content::id.ne_all(claimed_by_others))
+            .select((
+                account_id::uuid,
+                media_content::uuid,
+                media_content::id,
+                (
+                    media_content::nsfw_score_drawings,
+                    media_content::nsfw_score_hentai,
+                    media_content::nsfw_score_neutral,
+                    media_content::nsfw_score_porn,
+                    media_content::nsfw_score_sexy,
+                ),
+            ))
             .order((
                 media_content::creation_unix_time.asc(),
                 account_id::id.asc(),
             ))
             .limit(LIMIT)
-            .load::<ProfileContentPendingModeration>(self.conn())
+            .load(self.conn())
             .into_db_error(())?;
 
-        Ok(GetProfileContentPendingModerationList { values })
+        let content_db_ids = values.iter().map(|(_, _, db_id, _)| *db_id).collect();
+        let values = values
+            .into_iter()
+            .map(|(account_id, content_id, _, (drawings, hentai, neutral, porn, sexy))| {
+                ProfileContentPendingModeration {
+                    account_id,
+                    content_id,
+                    nsfw_scores: NsfwScores::from_db_values(drawings, hentai, neutral, porn, sexy),
+                }
+            })
+            .collect();
+
+        Ok((GetProfileContentPendingModerationList { values }, content_db_ids))
     }
 }
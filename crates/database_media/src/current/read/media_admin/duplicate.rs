@@ -0,0 +1,95 @@
+use database::{define_current_read_commands, DieselDatabaseError};
+use diesel::{alias, prelude::*};
+use error_stack::Result;
+use model_media::{
+    AccountId, ContentId, ContentModerationState, GetMediaContentDuplicateList,
+    MediaContentDuplicate, MediaContentDuplicateIteratorQuery, UnixTime,
+};
+
+use crate::IntoDatabaseError;
+
+define_current_read_commands!(CurrentReadMediaAdminDuplicate);
+
+impl CurrentReadMediaAdminDuplicate<'_> {
+    /// Latest detected near-duplicates are first.
+    pub fn media_content_duplicate_page(
+        &mut self,
+        query: MediaContentDuplicateIteratorQuery,
+    ) -> Result<GetMediaContentDuplicateList, DieselDatabaseError> {
+        use crate::schema::{account_id, media_content, media_content_duplicate::dsl::*};
+
+        const PAGE_SIZE: i64 = 25;
+
+        let (content, duplicate) = alias!(media_content as content, media_content as duplicate);
+        let (content_owner, duplicate_owner) =
+            alias!(account_id as content_owner, account_id as duplicate_owner);
+
+        let values: Vec<(
+            AccountId,
+            ContentId,
+            AccountId,
+            ContentId,
+            ContentModerationState,
+            i64,
+            UnixTime,
+        )> = media_content_duplicate
+            .inner_join(content.on(content_id.eq(content.field(media_content::id))))
+            .inner_join(duplicate.on(duplicate_content_id.eq(duplicate.field(media_content::id))))
+            .inner_join(
+                content_owner.on(content
+                    .field(media_content::account_id)
+                    .eq(content_owner.field(account_id::id))),
+            )
+            .inner_join(
+                duplicate_owner.on(duplicate
+                    .field(media_content::account_id)
+                    .eq(duplicate_owner.field(account_id::id))),
+            )
+            .filter(detection_unix_time.le(query.start_position))
+            .select((
+                content_owner.field(account_id::uuid),
+                content.field(media_content::uuid),
+                duplicate_owner.field(account_id::uuid),
+                duplicate.field(media_content::uuid),
+                duplicate.field(media_content::moderation_state),
+                hash_distance,
+                detection_unix_time,
+            ))
+            .order((
+                detection_unix_time.desc(),
+                content_id.desc(),
+                duplicate_content_id.desc(),
+            ))
+            .limit(PAGE_SIZE)
+            .offset(PAGE_SIZE.saturating_mul(query.page))
+            .load(self.conn())
+            .into_db_error(())?;
+
+        let values = values
+            .into_iter()
+            .map(
+                |(
+                    account,
+                    content_uuid,
+                    duplicate_account,
+                    duplicate_uuid,
+                    duplicate_state,
+                    distance,
+                    time,
+                )| {
+                    MediaContentDuplicate {
+                        account_id: account,
+                        content_id: content_uuid,
+                        duplicate_account_id: duplicate_account,
+                        duplicate_content_id: duplicate_uuid,
+                        duplicate_state,
+                        hash_distance: distance,
+                        detection_time: time,
+                    }
+                },
+            )
+            .collect();
+
+        Ok(GetMediaContentDuplicateList { values })
+    }
+}
@@ -1,9 +1,9 @@
 use database::{current::read::GetDbReadCommandsCommon, define_current_write_commands, DieselDatabaseError};
 use diesel::{delete, insert_into, prelude::*, update};
 use error_stack::{Result, ResultExt};
-use model::{ContentIdInternal, SyncVersion, UnixTime};
+use model::{ContentIdInternal, PerceptualHash, SyncVersion, UnixTime};
 use model_media::{
-    AccountIdInternal, ContentId, ContentIdDb, ContentModerationState, ContentSlot, MediaContentRaw, MediaContentType, NewContentParams, ProfileContentEditedTime, ProfileContentVersion, SetProfileContent
+    AccountIdInternal, ContentId, ContentIdDb, ContentModerationState, ContentSlot, MediaContentRaw, MediaContentType, NewContentParams, NsfwScores, ProfileContentEditedTime, ProfileContentVersion, SetProfileContent
 };
 use simple_backend_utils::ContextExt;
 
@@ -133,7 +133,7 @@ impl CurrentWriteMediaContent<'_> {
                     .write()
                     .media_admin()
                     .media_content()
-                    .update_content_moderation_state(content_id, ContentModerationState::WaitingBotOrHumanModeration)?;
+                    .update_content_moderation_state(content_id, state.waiting_moderation_state())?;
             }
         }
 
@@ -178,7 +178,7 @@ impl CurrentWriteMediaContent<'_> {
                 .write()
                 .media_admin()
                 .media_content()
-                .update_content_moderation_state(content_id, ContentModerationState::WaitingBotOrHumanModeration)?;
+                .update_content_moderation_state(content_id, state.waiting_moderation_state())?;
         }
 
         Ok(())
@@ -205,6 +205,8 @@ impl CurrentWriteMediaContent<'_> {
         slot: Option<ContentSlot>,
         content_params: NewContentParams,
         face_detected_value: bool,
+        perceptual_hash_value: Option<PerceptualHash>,
+        nsfw_scores: Option<NsfwScores>,
     ) -> Result<(), DieselDatabaseError> {
         use model::schema::media_content::dsl::*;
 
@@ -229,6 +231,12 @@ impl CurrentWriteMediaContent<'_> {
                 initial_content.eq(initial_content_value),
                 creation_unix_time.eq(current_time),
                 moderation_state.eq(state_value),
+                perceptual_hash.eq(perceptual_hash_value),
+                nsfw_score_drawings.eq(nsfw_scores.map(|v| v.drawings)),
+                nsfw_score_hentai.eq(nsfw_scores.map(|v| v.hentai)),
+                nsfw_score_neutral.eq(nsfw_scores.map(|v| v.neutral)),
+                nsfw_score_porn.eq(nsfw_scores.map(|v| v.porn)),
+                nsfw_score_sexy.eq(nsfw_scores.map(|v| v.sexy)),
             ))
             .execute(self.conn())
             .into_db_error((content_uploader, content_id))?;
@@ -236,6 +244,91 @@ impl CurrentWriteMediaContent<'_> {
         Ok(())
     }
 
+    /// Save perceptual hash bands which `find_duplicate_content` uses
+    /// for finding candidates.
+    pub fn insert_perceptual_hash_bands(
+        &mut self,
+        content_id: ContentIdInternal,
+        hash: PerceptualHash,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::media_content_hash_band;
+
+        let values: Vec<_> = hash
+            .bands()
+            .into_iter()
+            .enumerate()
+            .map(|(i, band)| {
+                (
+                    media_content_hash_band::band_index.eq(i as i64),
+                    media_content_hash_band::band_value.eq(band),
+                    media_content_hash_band::content_id.eq(content_id.as_db_id()),
+                )
+            })
+            .collect();
+
+        insert_into(media_content_hash_band::table)
+            .values(values)
+            .execute(self.conn())
+            .into_db_error(content_id)?;
+
+        Ok(())
+    }
+
+    /// Save near-duplicates of the content which `find_duplicate_content`
+    /// returned.
+    pub fn save_duplicate_content(
+        &mut self,
+        content_id: ContentIdInternal,
+        duplicates: Vec<(ContentIdDb, u32)>,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::media_content_duplicate;
+
+        if duplicates.is_empty() {
+            return Ok(());
+        }
+
+        let time = UnixTime::current_time();
+        let values: Vec<_> = duplicates
+            .into_iter()
+            .map(|(duplicate_id, distance)| {
+                (
+                    media_content_duplicate::content_id.eq(content_id.as_db_id()),
+                    media_content_duplicate::duplicate_content_id.eq(duplicate_id),
+                    media_content_duplicate::hash_distance.eq(i64::from(distance)),
+                    media_content_duplicate::detection_unix_time.eq(time),
+                )
+            })
+            .collect();
+
+        insert_into(media_content_duplicate::table)
+            .values(values)
+            .execute(self.conn())
+            .into_db_error(content_id)?;
+
+        Ok(())
+    }
+
+    /// Content skips bot moderation when it is moved to moderation.
+    pub fn require_human_moderation(
+        &mut self,
+        content_id: ContentIdInternal,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::media_content::dsl::*;
+
+        update(media_content.find(content_id.as_db_id()))
+            .set(human_moderation_required.eq(true))
+            .execute(self.conn())
+            .into_db_error(content_id)?;
+
+        update(media_content.find(content_id.as_db_id()))
+            .filter(moderation_state.eq(ContentModerationState::WaitingBotOrHumanModeration))
+            .set(moderation_state.eq(ContentModerationState::WaitingHumanModeration))
+            .execute(self.conn())
+            .into_db_error(content_id)?;
+
+        Ok(())
+    }
+
     pub fn delete_content_from_slot(
         &mut self,
         request_creator: AccountIdInternal,
@@ -2,8 +2,10 @@ use database::define_current_read_commands;
 
 mod data;
 mod favorite;
+mod pass;
 mod profile_name_allowlist;
 mod report;
+mod visit;
 
 define_current_read_commands!(CurrentReadProfile);
 
@@ -14,6 +16,9 @@ impl<'a> CurrentReadProfile<'a> {
     pub fn favorite(self) -> favorite::CurrentReadProfileFavorite<'a> {
         favorite::CurrentReadProfileFavorite::new(self.cmds)
     }
+    pub fn pass(self) -> pass::CurrentReadProfilePass<'a> {
+        pass::CurrentReadProfilePass::new(self.cmds)
+    }
     pub fn profile_name_allowlist(
         self,
     ) -> profile_name_allowlist::CurrentReadProfileNameAllowlist<'a> {
@@ -22,4 +27,7 @@ impl<'a> CurrentReadProfile<'a> {
     pub fn report(self) -> report::CurrentReadProfileReport<'a> {
         report::CurrentReadProfileReport::new(self.cmds)
     }
+    pub fn visit(self) -> visit::CurrentReadProfileVisit<'a> {
+        visit::CurrentReadProfileVisit::new(self.cmds)
+    }
 }
@@ -36,6 +36,7 @@ impl CurrentReadProfileData<'_> {
             profile_state.profile_text_moderation_state,
             attributes,
             other_shared_state.unlimited_likes,
+            other_shared_state.profile_verified,
         ))
     }
 
@@ -55,6 +56,7 @@ impl CurrentReadProfileData<'_> {
             profile_state.profile_text_moderation_state,
             attributes,
             other_shared_state.unlimited_likes,
+            other_shared_state.profile_verified,
         );
         let r = GetMyProfileResult {
             p,
@@ -1,7 +1,7 @@
 use database::{define_current_read_commands, DieselDatabaseError};
 use diesel::prelude::*;
 use error_stack::{Result, ResultExt};
-use model::AccountIdInternal;
+use model::{AccountId, AccountIdInternal, AccountInteractionState};
 
 define_current_read_commands!(CurrentReadProfileFavorite);
 
@@ -27,4 +27,28 @@ impl CurrentReadProfileFavorite<'_> {
 
         Ok(favorites)
     }
+
+    /// Accounts which have liked the account and the like is
+    /// not yet a match.
+    pub fn received_like_senders(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<Vec<AccountId>, DieselDatabaseError> {
+        use crate::schema::{account_id, account_interaction};
+
+        let senders = account_interaction::table
+            .inner_join(
+                account_id::table.on(account_interaction::account_id_sender
+                    .assume_not_null()
+                    .eq(account_id::id)),
+            )
+            .filter(account_interaction::account_id_sender.is_not_null())
+            .filter(account_interaction::account_id_receiver.eq(id.as_db_id()))
+            .filter(account_interaction::state_number.eq(AccountInteractionState::Like))
+            .select(account_id::uuid)
+            .load(self.conn())
+            .change_context(DieselDatabaseError::Execute)?;
+
+        Ok(senders)
+    }
 }
@@ -0,0 +1,45 @@
+use database::{define_current_read_commands, DieselDatabaseError};
+use diesel::prelude::*;
+use error_stack::{Result, ResultExt};
+use model::{AccountId, AccountIdInternal, UnixTime};
+
+define_current_read_commands!(CurrentReadProfilePass);
+
+impl CurrentReadProfilePass<'_> {
+    pub fn passed_profiles(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<Vec<AccountId>, DieselDatabaseError> {
+        use crate::schema::{account_id, passed_profile};
+
+        let passed = passed_profile::table
+            .inner_join(
+                account_id::table.on(passed_profile::passed_account_id.eq(account_id::id)),
+            )
+            .filter(passed_profile::account_id.eq(id.as_db_id()))
+            .select(account_id::uuid)
+            .load(self.conn())
+            .change_context(DieselDatabaseError::Execute)?;
+
+        Ok(passed)
+    }
+
+    /// Seen profiles which have not expired yet
+    pub fn seen_profiles(
+        &mut self,
+        id: AccountIdInternal,
+        current_time: UnixTime,
+    ) -> Result<Vec<(AccountId, UnixTime)>, DieselDatabaseError> {
+        use crate::schema::{account_id, seen_profile};
+
+        let seen = seen_profile::table
+            .inner_join(account_id::table.on(seen_profile::seen_account_id.eq(account_id::id)))
+            .filter(seen_profile::account_id.eq(id.as_db_id()))
+            .filter(seen_profile::expiration_unix_time.gt(current_time))
+            .select((account_id::uuid, seen_profile::expiration_unix_time))
+            .load(self.conn())
+            .change_context(DieselDatabaseError::Execute)?;
+
+        Ok(seen)
+    }
+}
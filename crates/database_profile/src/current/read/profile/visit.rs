@@ -0,0 +1,67 @@
+use database::{define_current_read_commands, DieselDatabaseError};
+use diesel::{dsl::max, prelude::*};
+use error_stack::{Result, ResultExt};
+use model_profile::{AccountId, AccountIdInternal, ProfileVisitId, ProfileVisitor, UnixTime};
+
+define_current_read_commands!(CurrentReadProfileVisit);
+
+impl CurrentReadProfileVisit<'_> {
+    /// Returns [ProfileVisitId::NO_PROFILE_VISIT_ID] if there is
+    /// no profile visits.
+    pub fn latest_profile_visit_id(&mut self) -> Result<ProfileVisitId, DieselDatabaseError> {
+        use crate::schema::profile_visit::dsl::*;
+
+        let latest: Option<ProfileVisitId> = profile_visit
+            .select(max(id))
+            .first(self.conn())
+            .change_context(DieselDatabaseError::Execute)?;
+
+        Ok(latest.unwrap_or(ProfileVisitId::NO_PROFILE_VISIT_ID))
+    }
+
+    /// Visit ordering goes from recent to older starting
+    /// from `profile_visit_id_value`.
+    pub fn paged_profile_visitors(
+        &mut self,
+        id_value: AccountIdInternal,
+        profile_visit_id_value: ProfileVisitId,
+        page: i64,
+    ) -> Result<Vec<ProfileVisitor>, DieselDatabaseError> {
+        use crate::schema::{account_id, profile_visit};
+
+        const PAGE_SIZE: i64 = 25;
+
+        let visitors: Vec<(AccountId, UnixTime)> = profile_visit::table
+            .inner_join(
+                account_id::table.on(profile_visit::visitor_account_id.eq(account_id::id)),
+            )
+            .filter(profile_visit::target_account_id.eq(id_value.as_db_id()))
+            .filter(profile_visit::id.le(profile_visit_id_value))
+            .select((account_id::uuid, profile_visit::unix_time))
+            .order((profile_visit::id.desc(),))
+            .limit(PAGE_SIZE)
+            .offset(PAGE_SIZE.saturating_mul(page))
+            .load(self.conn())
+            .change_context(DieselDatabaseError::Execute)?;
+
+        let visitors = visitors
+            .into_iter()
+            .map(|(aid, ut)| ProfileVisitor { aid, ut })
+            .collect();
+
+        Ok(visitors)
+    }
+
+    pub fn profile_visit_count_since(
+        &mut self,
+        time: UnixTime,
+    ) -> Result<i64, DieselDatabaseError> {
+        use crate::schema::profile_visit::dsl::*;
+
+        profile_visit
+            .filter(unix_time.ge(time))
+            .count()
+            .get_result(self.conn())
+            .change_context(DieselDatabaseError::Execute)
+    }
+}
@@ -1,19 +1,32 @@
-use database::{define_current_read_commands, DieselDatabaseError, IntoDatabaseError};
+use database::{
+    current::read::GetDbReadCommandsCommon, define_current_read_commands, DieselDatabaseError,
+    IntoDatabaseError,
+};
 use diesel::prelude::*;
 use error_stack::Result;
 use model_profile::{
-    GetProfileNamePendingModerationList, ProfileNameModerationState, ProfileNamePendingModeration,
+    AccountId, AccountIdDb, AccountIdInternal, GetProfileNamePendingModerationList,
+    ModerationClaimQueue, ProfileNameModerationState, ProfileNamePendingModeration,
 };
 
 define_current_read_commands!(CurrentReadProfileNameAllowlist);
 
 impl CurrentReadProfileNameAllowlist<'_> {
+    /// Profile names which other moderators have claimed are skipped.
+    /// Returns also the profile name owner IDs for claiming the names.
     pub fn profile_name_pending_moderation_list(
         &mut self,
-    ) -> Result<GetProfileNamePendingModerationList, DieselDatabaseError> {
+        moderator_id: AccountIdInternal,
+    ) -> Result<(GetProfileNamePendingModerationList, Vec<AccountIdDb>), DieselDatabaseError> {
         use crate::schema::{account_id, profile::dsl::*, profile_state};
 
-        let values = profile
+        let claimed_by_others = self
+            .read()
+            .common_admin()
+            .moderation_claim()
+            .items_claimed_by_other_moderators(ModerationClaimQueue::ProfileName, moderator_id);
+
+        let values: Vec<(AccountId, String, AccountIdDb)> = profile
             .inner_join(account_id::table)
             .inner_join(profile_state::table.on(profile_state::account_id.eq(account_id::id)))
             .filter(
@@ -22,11 +35,21 @@ impl CurrentReadProfileNameAllowlist<'_> {
                     .or(profile_state::profile_name_moderation_state
                         .eq(ProfileNameModerationState::WaitingHumanModeration)),
             )
-            .select((account_id::uuid, name))
+            .filter(account_id::id.ne_all(claimed_by_others))
+            .select((account_id::uuid, name, account_id::id))
             .order(account_id::id.asc())
-            .load::<ProfileNamePendingModeration>(self.conn())
+            .load(self.conn())
             .into_db_error(())?;
 
-        Ok(GetProfileNamePendingModerationList { values })
+        let account_db_ids = values.iter().map(|(_, _, db_id)| *db_id).collect();
+        let values = values
+            .into_iter()
+            .map(|(id, profile_name, _)| ProfileNamePendingModeration {
+                id,
+                name: profile_name,
+            })
+            .collect();
+
+        Ok((GetProfileNamePendingModerationList { values }, account_db_ids))
     }
 }
@@ -5,18 +5,21 @@ use database::{
 use diesel::prelude::*;
 use error_stack::Result;
 use model_profile::{
-    AccountIdInternal, GetProfileTextPendingModerationList, GetProfileTextPendingModerationParams,
-    ProfileTextModerationState, ProfileTextPendingModeration,
+    AccountId, AccountIdDb, AccountIdInternal, GetProfileTextPendingModerationList,
+    GetProfileTextPendingModerationParams, ModerationClaimQueue, ProfileTextModerationState,
+    ProfileTextPendingModeration,
 };
 
 define_current_read_commands!(CurrentReadProfileText);
 
 impl CurrentReadProfileText<'_> {
+    /// Profile texts which other moderators have claimed are skipped.
+    /// Returns also the profile text owner IDs for claiming the texts.
     pub fn profile_text_pending_moderation_list(
         &mut self,
         moderator_id: AccountIdInternal,
         params: GetProfileTextPendingModerationParams,
-    ) -> Result<GetProfileTextPendingModerationList, DieselDatabaseError> {
+    ) -> Result<(GetProfileTextPendingModerationList, Vec<AccountIdDb>), DieselDatabaseError> {
         use crate::schema::{account_id, profile, profile_state};
 
         const LIMIT: i64 = 25;
@@ -36,7 +39,13 @@ impl CurrentReadProfileText<'_> {
                 params.show_texts_which_bots_can_moderate,
             );
 
-        let values = profile::table
+        let claimed_by_others = self
+            .read()
+            .common_admin()
+            .moderation_claim()
+            .items_claimed_by_other_moderators(ModerationClaimQueue::ProfileText, moderator_id);
+
+        let values: Vec<(AccountId, String, AccountIdDb)> = profile::table
             .inner_join(account_id::table)
             .inner_join(profile_state::table.on(profile_state::account_id.eq(account_id::id)))
             .filter(
@@ -50,15 +59,22 @@ impl CurrentReadProfileText<'_> {
                             .eq(ProfileTextModerationState::WaitingHumanModeration),
                     )),
             )
-            .select((account_id::uuid, profile::profile_text))
+            .filter(account_id::id.ne_all(claimed_by_others))
+            .select((account_id::uuid, profile::profile_text, account_id::id))
             .order((
                 profile_state::profile_text_edit_time_unix_time.asc(),
                 account_id::id.asc(),
             ))
             .limit(LIMIT)
-            .load::<ProfileTextPendingModeration>(self.conn())
+            .load(self.conn())
             .into_db_error(())?;
 
-        Ok(GetProfileTextPendingModerationList { values })
+        let account_db_ids = values.iter().map(|(_, _, db_id)| *db_id).collect();
+        let values = values
+            .into_iter()
+            .map(|(id, text, _)| ProfileTextPendingModeration { id, text })
+            .collect();
+
+        Ok((GetProfileTextPendingModerationList { values }, account_db_ids))
     }
 }
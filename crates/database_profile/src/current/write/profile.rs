@@ -2,9 +2,11 @@ use database::define_current_write_commands;
 
 mod data;
 mod favorite;
+mod pass;
 mod profile_name_allowlist;
 mod profile_text;
 mod report;
+mod visit;
 
 define_current_write_commands!(CurrentWriteProfile);
 
@@ -17,6 +19,10 @@ impl<'a> CurrentWriteProfile<'a> {
         favorite::CurrentWriteProfileFavorite::new(self.cmds)
     }
 
+    pub fn pass(self) -> pass::CurrentWriteProfilePass<'a> {
+        pass::CurrentWriteProfilePass::new(self.cmds)
+    }
+
     pub fn profile_name_allowlist(
         self,
     ) -> profile_name_allowlist::CurrentWriteProfileNameAllowlist<'a> {
@@ -30,4 +36,8 @@ impl<'a> CurrentWriteProfile<'a> {
     pub fn report(self) -> report::CurrentWriteProfileReport<'a> {
         report::CurrentWriteProfileReport::new(self.cmds)
     }
+
+    pub fn visit(self) -> visit::CurrentWriteProfileVisit<'a> {
+        visit::CurrentWriteProfileVisit::new(self.cmds)
+    }
 }
@@ -261,10 +261,13 @@ impl CurrentWriteProfileData<'_> {
             .set((
                 last_seen_time_filter.eq(settings.last_seen_time_filter),
                 unlimited_likes_filter.eq(settings.unlimited_likes_filter),
+                verified_filter.eq(settings.verified_filter),
                 max_distance_km_filter.eq(settings.max_distance_km_filter),
                 profile_created_time_filter.eq(settings.profile_created_filter),
                 profile_edited_time_filter.eq(settings.profile_edited_filter),
                 random_profile_order.eq(settings.random_profile_order),
+                mutual_interest_profile_order.eq(settings.mutual_interest_profile_order),
+                hide_seen_profiles.eq(settings.hide_seen_profiles),
             ))
             .execute(self.conn())
             .into_db_error(())?;
@@ -0,0 +1,101 @@
+use database::{define_current_write_commands, DieselDatabaseError};
+use diesel::{delete, insert_into, prelude::*, ExpressionMethods};
+use error_stack::Result;
+use model::{AccountIdInternal, UnixTime};
+use simple_backend_utils::current_unix_time;
+
+use crate::IntoDatabaseError;
+
+define_current_write_commands!(CurrentWriteProfilePass);
+
+impl CurrentWriteProfilePass<'_> {
+    pub fn insert_passed_profile(
+        &mut self,
+        id: AccountIdInternal,
+        passed: AccountIdInternal,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::passed_profile::dsl::*;
+
+        let time = current_unix_time();
+
+        insert_into(passed_profile)
+            .values((
+                account_id.eq(id.as_db_id()),
+                passed_account_id.eq(passed.as_db_id()),
+                unix_time.eq(time),
+            ))
+            .on_conflict((account_id, passed_account_id))
+            .do_nothing()
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+
+    pub fn remove_all_passed_profiles(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::passed_profile::dsl::*;
+
+        delete(passed_profile)
+            .filter(account_id.eq(id.as_db_id()))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+
+    pub fn upsert_seen_profiles(
+        &mut self,
+        id: AccountIdInternal,
+        seen: &[AccountIdInternal],
+        expiration_time: UnixTime,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::seen_profile::dsl::*;
+
+        for s in seen {
+            insert_into(seen_profile)
+                .values((
+                    account_id.eq(id.as_db_id()),
+                    seen_account_id.eq(s.as_db_id()),
+                    expiration_unix_time.eq(expiration_time),
+                ))
+                .on_conflict((account_id, seen_account_id))
+                .do_update()
+                .set(expiration_unix_time.eq(expiration_time))
+                .execute(self.conn())
+                .into_db_error(id)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_all_seen_profiles(
+        &mut self,
+        id: AccountIdInternal,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::seen_profile::dsl::*;
+
+        delete(seen_profile)
+            .filter(account_id.eq(id.as_db_id()))
+            .execute(self.conn())
+            .into_db_error(id)?;
+
+        Ok(())
+    }
+
+    pub fn delete_expired_seen_profiles(
+        &mut self,
+        current_time: UnixTime,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::seen_profile::dsl::*;
+
+        delete(seen_profile)
+            .filter(expiration_unix_time.le(current_time))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+}
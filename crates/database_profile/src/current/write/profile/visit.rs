@@ -0,0 +1,50 @@
+use database::{define_current_write_commands, DieselDatabaseError};
+use diesel::{delete, insert_into, prelude::*, ExpressionMethods};
+use error_stack::Result;
+use model_profile::{AccountIdInternal, ProfileVisitDay, UnixTime};
+
+use crate::IntoDatabaseError;
+
+define_current_write_commands!(CurrentWriteProfileVisit);
+
+impl CurrentWriteProfileVisit<'_> {
+    /// Only the first visit during a day is stored.
+    pub fn insert_profile_visit(
+        &mut self,
+        target: AccountIdInternal,
+        visitor: AccountIdInternal,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::profile_visit::dsl::*;
+
+        let time = UnixTime::current_time();
+        let day = ProfileVisitDay::from_unix_time(time);
+
+        insert_into(profile_visit)
+            .values((
+                target_account_id.eq(target.as_db_id()),
+                visitor_account_id.eq(visitor.as_db_id()),
+                visit_day.eq(day.day),
+                unix_time.eq(time),
+            ))
+            .on_conflict((target_account_id, visitor_account_id, visit_day))
+            .do_nothing()
+            .execute(self.conn())
+            .into_db_error(target)?;
+
+        Ok(())
+    }
+
+    pub fn delete_profile_visits_older_than(
+        &mut self,
+        time: UnixTime,
+    ) -> Result<(), DieselDatabaseError> {
+        use model::schema::profile_visit::dsl::*;
+
+        delete(profile_visit)
+            .filter(unix_time.lt(time))
+            .execute(self.conn())
+            .into_db_error(())?;
+
+        Ok(())
+    }
+}
@@ -26,6 +26,7 @@ impl HistoryReadProfileAdminStatistics<'_> {
             S::Public {
                 gender: Some(StatisticsGender::NonBinary),
             } => self.count_changes_non_binary(),
+            S::ProfileVisits => self.count_changes_profile_visits(),
             S::AgeChange { gender: None, age } => self.age_changes_all_genders(age),
             S::AgeChange {
                 gender: Some(StatisticsGender::Man),
@@ -94,6 +95,11 @@ define_read_count_change_methods!(
     history_profile_statistics_count_changes_all_genders,
 );
 
+define_read_count_change_methods!(
+    fn count_changes_profile_visits,
+    history_profile_statistics_count_changes_profile_visits,
+);
+
 macro_rules! define_read_age_change_methods {
     (
         fn $method_name:ident,
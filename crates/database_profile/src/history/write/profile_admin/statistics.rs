@@ -21,6 +21,7 @@ impl<'a> HistoryWriteProfileAdminStatistics<'a> {
                 + r.public_profile_counts.woman
                 + r.public_profile_counts.non_binary,
         )?;
+        self.save_count_if_needed_profile_visits(time_id, r.profile_visit_count)?;
 
         type SaveMethod<'b> = fn(
             &mut HistoryWriteProfileAdminStatistics<'b>,
@@ -135,6 +136,11 @@ define_integer_change_method!(
     history_profile_statistics_count_changes_all_genders,
 );
 
+define_integer_change_method!(
+    fn save_count_if_needed_profile_visits,
+    history_profile_statistics_count_changes_profile_visits,
+);
+
 macro_rules! define_age_change_method {
     (
         fn $method_name:ident,
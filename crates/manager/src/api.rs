@@ -2,7 +2,7 @@
 
 use manager_config::Config;
 
-use crate::server::{client::ApiManager, scheduled_task::ScheduledTaskManagerHandle, task::TaskManagerHandle, update::UpdateManagerHandle};
+use crate::server::{client::ApiManager, scheduled_task::ScheduledTaskManagerHandle, secure_storage::SecureStorageManager, task::TaskManagerHandle, update::UpdateManagerHandle};
 
 pub mod server;
 pub mod client;
@@ -29,3 +29,7 @@ pub trait GetTaskManager {
 pub trait GetScheduledTaskManager {
     fn scheduled_task_manager(&self) -> &ScheduledTaskManagerHandle;
 }
+
+pub trait GetSecureStorageManager {
+    fn secure_storage_manager(&self) -> SecureStorageManager;
+}
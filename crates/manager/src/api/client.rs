@@ -28,10 +28,12 @@ impl<'a> LocalOrRemoteApiClient<'a> {
         request: JsonRpcRequest,
     ) -> Result<JsonRpcResponse, ClientError> {
         if self.state.config().manager_name() == request.receiver {
-            handle_request_type(
+            // Boxing is needed because cluster status request handling
+            // uses this client.
+            Box::pin(handle_request_type(
                 request.request,
                 self.state,
-            )
+            ))
                 .await
                 .change_context(ClientError::LocalApiRequest)
         } else if let Some(m) = self.state.config().find_remote_manager(&request.receiver)  {
@@ -24,27 +24,61 @@
 //!
 //! ## [manager_model::ManagerProtocolMode::ListenServerEvents]
 //!
-//! Server sends [manager_model::ServerEvent] JSONs.
+//! Server sends [manager_model::ServerEvent] JSONs. Only events which
+//! older backends support are sent.
 //!
 //! 1. Server sends u32 little-endian JSON length in bytes.
 //! 2. Server sends UTF-8 JSON bytes.
 //! 3. Move to step 1.
+//!
+//! ## [manager_model::ManagerProtocolMode::ListenAllServerEvents]
+//!
+//! Same as [manager_model::ManagerProtocolMode::ListenServerEvents] but
+//! all events are sent.
+//!
+//! ## [manager_model::ManagerProtocolMode::DownloadInstalledBackend]
+//!
+//! Server sends installed backend release file and its signature file.
+//!
+//! 1. Server sends u32 little-endian JSON length in bytes.
+//! 2. Server sends UTF-8 JSON bytes. The JSON is
+//!    `Option<`[manager_model::SoftwareInfo]`>`. If the value is `null`,
+//!    server closes the connection.
+//! 3. Server sends u64 little-endian release file length in bytes.
+//! 4. Server sends release file bytes.
+//! 5. Server sends byte 1 if signature file exists. Byte 0 is sent and
+//!    connection is closed if signature file does not exist.
+//! 6. Server sends u64 little-endian signature file length in bytes.
+//! 7. Server sends signature file bytes.
+//! 8. Server closes the connection.
+//!
+//! ## [manager_model::ManagerProtocolMode::FollowLogs]
+//!
+//! Client sends [manager_model::LogFilter] JSON and server sends matching
+//! new [manager_model::LogEntry] JSONs. Framing is the same as in
+//! [manager_model::ManagerProtocolMode::ListenServerEvents].
+//!
+//! 1. Client sends u32 little-endian JSON length in bytes.
+//! 2. Client sends UTF-8 JSON bytes.
+//! 3. Server sends u32 little-endian JSON length in bytes.
+//! 4. Server sends UTF-8 JSON bytes.
+//! 5. Move to step 3.
 
 use std::net::SocketAddr;
 use json_rpc::handle_json_rpc;
 use manager_api::protocol::{ClientConnectionReadWrite, ClientConnectionWrite};
-use manager_model::{ManagerProtocolMode, ServerEvent};
+use manager_model::{LogFilter, ManagerProtocolMode, ServerEvent};
 
 use manager_api::protocol::{ConnectionUtilsRead, ConnectionUtilsWrite};
 use tokio::io::AsyncReadExt;
 use tracing::info;
 
-use crate::{server::app::S, utils::ContextExt};
+use crate::{server::{app::S, log::LogFollower}, utils::ContextExt};
 
 use error_stack::{Result, ResultExt};
 use manager_model::ManagerProtocolVersion;
 
-use super::utils::validate_api_key;
+use super::{utils::validate_api_key, GetConfig, GetUpdateManager};
 
 pub mod json_rpc;
 
@@ -82,6 +116,10 @@ pub enum ServerError {
     Client,
     #[error("Server event channel is broken")]
     ServerEventChannelBroken,
+    #[error("Update manager error")]
+    UpdateManager,
+    #[error("Log error")]
+    Log,
 }
 
 pub async fn handle_connection_to_server<
@@ -136,16 +174,59 @@ async fn handle_connection_to_server_with_error<
 
     match mode {
         ManagerProtocolMode::JsonRpc => handle_json_rpc(c, address, state).await,
-        ManagerProtocolMode::ListenServerEvents => handle_server_events(c, address, state).await,
+        ManagerProtocolMode::ListenServerEvents => handle_server_events(c, address, state, false).await,
+        ManagerProtocolMode::ListenAllServerEvents => handle_server_events(c, address, state, true).await,
+        ManagerProtocolMode::DownloadInstalledBackend => handle_download_installed_backend(c, address, state).await,
+        ManagerProtocolMode::FollowLogs => handle_follow_logs(c, address, state).await,
     }
 }
 
+async fn handle_download_installed_backend<
+    C: ClientConnectionReadWrite,
+>(
+    mut c: C,
+    address: SocketAddr,
+    state: S,
+) -> Result<(), ServerError> {
+    info!("Download installed backend: {} connected", address);
+
+    let release = state.update_manager()
+        .installed_release(state.config())
+        .await
+        .change_context(ServerError::UpdateManager)?;
+
+    let info = release.as_ref().map(|v| v.info.clone());
+    let info = serde_json::to_string(&info)
+        .change_context(ServerError::Serialize)?;
+    c.send_string_with_u32_len(info)
+        .await
+        .change_context(ServerError::Write)?;
+
+    if let Some(release) = release {
+        c.send_file_with_u64_len(&release.file)
+            .await
+            .change_context(ServerError::Write)?;
+        if release.signature.exists() {
+            c.send_u8(1).await.change_context(ServerError::Write)?;
+            c.send_file_with_u64_len(&release.signature)
+                .await
+                .change_context(ServerError::Write)?;
+        } else {
+            c.send_u8(0).await.change_context(ServerError::Write)?;
+        }
+    }
+
+    info!("Download installed backend: {} completed", address);
+    Ok(())
+}
+
 async fn handle_server_events<
     C: ClientConnectionReadWrite,
 >(
     c: C,
     address: SocketAddr,
     state: S,
+    all_events: bool,
 ) -> Result<(), ServerError> {
     info!("Server events: {} connected", address);
 
@@ -157,7 +238,7 @@ async fn handle_server_events<
             // Server disconnected
             Ok(())
         }
-        r = send_server_events(writer, state) => {
+        r = send_server_events(writer, state, all_events) => {
             r
         },
     };
@@ -171,6 +252,7 @@ async fn send_server_events<
 >(
     mut c: C,
     state: S,
+    all_events: bool,
 ) -> Result<(), ServerError> {
     let mut receiver = state.backend_events_receiver();
 
@@ -178,6 +260,10 @@ async fn send_server_events<
         let events: Vec<ServerEvent> = receiver.borrow_and_update().clone();
 
         for e in events.iter() {
+            if !all_events && !e.event().supported_by_listen_server_events_mode() {
+                continue;
+            }
+
             c.send_server_event(e)
                 .await
                 .change_context(ServerError::Write)?;
@@ -188,3 +274,59 @@ async fn send_server_events<
         }
     }
 }
+
+/// Log entries use separate protocol mode instead of server events.
+/// See [ManagerProtocolMode::FollowLogs].
+async fn handle_follow_logs<
+    C: ClientConnectionReadWrite,
+>(
+    mut c: C,
+    address: SocketAddr,
+    state: S,
+) -> Result<(), ServerError> {
+    let filter = c.receive_log_filter()
+        .await
+        .change_context(ServerError::Read)?;
+
+    let follower = LogFollower::new(state.config(), &filter)
+        .await
+        .change_context(ServerError::Log)?;
+
+    info!("Follow logs: {} connected", address);
+
+    let (mut reader, writer) = tokio::io::split(c);
+
+    let mut read_buffer = [0u8];
+    let r = tokio::select! {
+        _ = reader.read(&mut read_buffer) => {
+            // Client disconnected
+            Ok(())
+        }
+        r = send_log_entries(writer, follower, filter) => {
+            r
+        },
+    };
+
+    info!("Follow logs: {} disconnected", address);
+    r
+}
+
+async fn send_log_entries<
+    C: ClientConnectionWrite,
+>(
+    mut c: C,
+    mut follower: LogFollower,
+    filter: LogFilter,
+) -> Result<(), ServerError> {
+    loop {
+        let entry = follower.next_entry()
+            .await
+            .change_context(ServerError::Log)?;
+
+        if filter.is_match(&entry) {
+            c.send_log_entry(&entry)
+                .await
+                .change_context(ServerError::Write)?;
+        }
+    }
+}
@@ -6,6 +6,8 @@ use manager_api::ManagerClient;
 use manager_model::JsonRpcRequest;
 use manager_model::JsonRpcRequestType;
 use manager_model::JsonRpcResponse;
+use cluster::RpcCluster;
+use log::RpcLog;
 use scheduled_task::RpcScheduledTask;
 use secure_storage::RpcSecureStorage;
 use software::RpcSoftware;
@@ -29,6 +31,8 @@ pub mod secure_storage;
 pub mod system_info;
 pub mod task;
 pub mod scheduled_task;
+pub mod cluster;
+pub mod log;
 
 
 #[derive(thiserror::Error, Debug)]
@@ -45,6 +49,12 @@ pub enum JsonRpcError {
     ScheduledTaskManager,
     #[error("Update manager error")]
     UpdateManager,
+    #[error("Log error")]
+    Log,
+    #[error("Secure storage error")]
+    SecureStorage,
+    #[error("Backend data snapshot error")]
+    BackendDataSnapshot,
 }
 
 
@@ -131,5 +141,25 @@ pub async fn handle_request_type(
             state.rpc_unschedule_task(task).await,
         JsonRpcRequestType::GetScheduledTasksStatus =>
             state.rpc_get_scheduled_tasks_status().await,
+        JsonRpcRequestType::GetClusterStatus =>
+            state.rpc_get_cluster_status().await,
+        JsonRpcRequestType::GetLogs(query) =>
+            state.rpc_get_logs(query).await,
+        JsonRpcRequestType::GetSecureStorageEncryptionKeyInfo(name) =>
+            state.rpc_get_secure_storage_encryption_key_info(name).await,
+        JsonRpcRequestType::CreatePendingSecureStorageEncryptionKey(name) =>
+            state.rpc_create_pending_secure_storage_encryption_key(name).await,
+        JsonRpcRequestType::CommitPendingSecureStorageEncryptionKey(commit) =>
+            state.rpc_commit_pending_secure_storage_encryption_key(commit).await,
+        JsonRpcRequestType::RotateSecureStorageEncryptionKey =>
+            state.rpc_rotate_secure_storage_encryption_key().await,
+        JsonRpcRequestType::ExpandSecureStorage(expansion) =>
+            state.rpc_expand_secure_storage(expansion).await,
+        JsonRpcRequestType::GetSecureStorageAuditLog =>
+            state.rpc_get_secure_storage_audit_log().await,
+        JsonRpcRequestType::GetBackendDataSnapshotList =>
+            state.rpc_get_backend_data_snapshot_list().await,
+        JsonRpcRequestType::RestoreBackendDataSnapshot(snapshot) =>
+            state.rpc_restore_backend_data_snapshot(snapshot).await,
     }
 }
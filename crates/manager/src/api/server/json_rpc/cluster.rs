@@ -0,0 +1,17 @@
+use manager_model::JsonRpcResponse;
+use crate::api::GetApiManager;
+
+use error_stack::Result;
+
+use super::JsonRpcError;
+
+pub trait RpcCluster: GetApiManager {
+    async fn rpc_get_cluster_status(&self) -> Result<JsonRpcResponse, JsonRpcError> {
+        let status = self.api_manager()
+            .get_cluster_status()
+            .await;
+        Ok(JsonRpcResponse::cluster_status(status))
+    }
+}
+
+impl <T: GetApiManager> RpcCluster for T {}
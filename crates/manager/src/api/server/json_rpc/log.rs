@@ -0,0 +1,23 @@
+use error_stack::ResultExt;
+use manager_model::JsonRpcResponse;
+use manager_model::LogQuery;
+use crate::api::GetConfig;
+use crate::server::log::LogReader;
+
+use error_stack::Result;
+
+use super::JsonRpcError;
+
+pub trait RpcLog: GetConfig {
+    async fn rpc_get_logs(
+        &self,
+        query: LogQuery,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        let page = LogReader::search(self.config(), query)
+            .await
+            .change_context(JsonRpcError::Log)?;
+        Ok(JsonRpcResponse::logs(page))
+    }
+}
+
+impl <T: GetConfig> RpcLog for T {}
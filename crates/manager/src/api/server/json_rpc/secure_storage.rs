@@ -1,15 +1,16 @@
-
 use error_stack::report;
 use manager_model::JsonRpcResponse;
 use manager_model::ManagerInstanceName;
+use manager_model::SecureStorageEncryptionKeyCommit;
+use manager_model::SecureStorageExpansion;
 
-use crate::api::GetConfig;
+use crate::api::{GetConfig, GetSecureStorageManager};
 
 use error_stack::{Result, ResultExt};
 
 use super::JsonRpcError;
 
-pub trait RpcSecureStorage: GetConfig {
+pub trait RpcSecureStorage: GetConfig + GetSecureStorageManager {
     async fn rpc_get_secure_storage_encryption_key(
         &self,
         name: ManagerInstanceName,
@@ -26,6 +27,70 @@ pub trait RpcSecureStorage: GetConfig {
 
         Ok(JsonRpcResponse::secure_storage_encryption_key(key))
     }
+
+    async fn rpc_get_secure_storage_encryption_key_info(
+        &self,
+        name: ManagerInstanceName,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        let info = self.secure_storage_manager()
+            .key_info(&name)
+            .await
+            .change_context(JsonRpcError::SecureStorage)?;
+        Ok(JsonRpcResponse::secure_storage_encryption_key_info(info))
+    }
+
+    async fn rpc_create_pending_secure_storage_encryption_key(
+        &self,
+        name: ManagerInstanceName,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        let key = self.secure_storage_manager()
+            .create_pending_key(&name)
+            .await
+            .change_context(JsonRpcError::SecureStorage)?;
+        Ok(JsonRpcResponse::secure_storage_encryption_key(key))
+    }
+
+    async fn rpc_commit_pending_secure_storage_encryption_key(
+        &self,
+        commit: SecureStorageEncryptionKeyCommit,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        self.secure_storage_manager()
+            .commit_pending_key(commit)
+            .await
+            .change_context(JsonRpcError::SecureStorage)?;
+        Ok(JsonRpcResponse::successful())
+    }
+
+    async fn rpc_rotate_secure_storage_encryption_key(
+        &self,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        self.secure_storage_manager()
+            .rotate_encryption_key()
+            .await
+            .change_context(JsonRpcError::SecureStorage)?;
+        Ok(JsonRpcResponse::successful())
+    }
+
+    async fn rpc_expand_secure_storage(
+        &self,
+        expansion: SecureStorageExpansion,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        self.secure_storage_manager()
+            .expand(expansion)
+            .await
+            .change_context(JsonRpcError::SecureStorage)?;
+        Ok(JsonRpcResponse::successful())
+    }
+
+    async fn rpc_get_secure_storage_audit_log(
+        &self,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        let log = self.secure_storage_manager()
+            .audit_log()
+            .await
+            .change_context(JsonRpcError::SecureStorage)?;
+        Ok(JsonRpcResponse::secure_storage_audit_log(log))
+    }
 }
 
-impl <T: GetConfig> RpcSecureStorage for T {}
+impl <T: GetConfig + GetSecureStorageManager> RpcSecureStorage for T {}
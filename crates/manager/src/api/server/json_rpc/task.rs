@@ -1,8 +1,14 @@
 
 
-use manager_model::{JsonRpcResponse, ManualTaskType};
+use manager_model::{BackendDataSnapshotName, JsonRpcResponse, ManualTaskType};
 use tracing::warn;
-use crate::api::{GetConfig, GetTaskManager};
+use crate::{
+    api::{GetConfig, GetTaskManager},
+    server::{
+        task::TaskManagerMessage,
+        update::backend::{backend_data_snapshot_dir, backend_data_snapshots},
+    },
+};
 
 use error_stack::{Result, ResultExt};
 
@@ -26,6 +32,12 @@ pub trait RpcTask: GetConfig + GetTaskManager {
                     return Ok(JsonRpcResponse::successful())
                 }
             }
+            ManualTaskType::BackendRollback => {
+                if !self.config().manual_tasks_config().allow_backend_rollback {
+                    warn!("Skipping backend rollback request because it is disabled from config file");
+                    return Ok(JsonRpcResponse::successful())
+                }
+            }
             ManualTaskType::SystemReboot => {
                 if !self.config().manual_tasks_config().allow_system_reboot {
                     warn!("Skipping system reboot request because it is disabled from config file");
@@ -35,7 +47,32 @@ pub trait RpcTask: GetConfig + GetTaskManager {
         }
 
         self.task_manager()
-            .send_message(task)
+            .send_message(TaskManagerMessage::ManualTask(task))
+            .await
+            .change_context(JsonRpcError::TaskManager)?;
+        Ok(JsonRpcResponse::successful())
+    }
+
+    async fn rpc_get_backend_data_snapshot_list(
+        &self,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        let list = backend_data_snapshots(&backend_data_snapshot_dir(self.config()))
+            .await
+            .change_context(JsonRpcError::BackendDataSnapshot)?;
+        Ok(JsonRpcResponse::backend_data_snapshot_list(list))
+    }
+
+    async fn rpc_restore_backend_data_snapshot(
+        &self,
+        snapshot: BackendDataSnapshotName,
+    ) -> Result<JsonRpcResponse, JsonRpcError> {
+        if self.config().manual_tasks_config().allow_backend_data_reset.is_none() {
+            warn!("Skipping backend data snapshot restore request because backend data reset is disabled from config file");
+            return Ok(JsonRpcResponse::successful())
+        }
+
+        self.task_manager()
+            .send_message(TaskManagerMessage::RestoreBackendDataSnapshot(snapshot))
             .await
             .change_context(JsonRpcError::TaskManager)?;
         Ok(JsonRpcResponse::successful())
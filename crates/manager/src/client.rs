@@ -2,7 +2,8 @@
 //!
 
 use error_stack::{Result, ResultExt};
-use manager_model::{ManagerInstanceName, SoftwareInfo, SoftwareUpdateTaskType};
+use manager_model::{BackendDataSnapshotName, LogEntry, LogQuery, ManagerInstanceName, SecureStorageExpansion, SoftwareInfo, SoftwareUpdateTaskType};
+use simple_backend_model::UnixTime;
 
 use manager_config::args::{ApiCommand, ManagerApiClientMode};
 use manager_api::{ClientConfig, ClientError, ManagerClient, protocol::RequestSenderCmds};
@@ -29,8 +30,21 @@ pub async fn handle_api_client_mode(args: ManagerApiClientMode) -> Result<(), Cl
 
     let client = ManagerClient::connect(config)
         .await
-        .change_context(ClientError::RemoteApiRequest)?
-        .request_to(manager_name);
+        .change_context(ClientError::RemoteApiRequest)?;
+
+    if let ApiCommand::FollowLogs { filter } = &args.api_command {
+        let mut listener = client.follow_logs(filter.to_log_filter())
+            .await
+            .change_context(ClientError::RemoteApiRequest)?;
+        loop {
+            let entry = listener.next_entry()
+                .await
+                .change_context(ClientError::RemoteApiRequest)?;
+            print_log_entry(&entry);
+        }
+    }
+
+    let client = client.request_to(manager_name);
 
     match args.api_command {
         ApiCommand::AvailableInstances => {
@@ -72,7 +86,75 @@ pub async fn handle_api_client_mode(args: ManagerApiClientMode) -> Result<(), Cl
                 .await
                 .change_context(ClientError::RemoteApiRequest)?
         }
+        ApiCommand::ClusterStatus => {
+            let status = client.get_cluster_status()
+                .await
+                .change_context(ClientError::RemoteApiRequest)?;
+            println!("{:#?}", status);
+        }
+        ApiCommand::Logs { filter, since, until, cursor, limit } => {
+            let query = LogQuery {
+                filter: filter.to_log_filter(),
+                since: since.map(UnixTime::new),
+                until: until.map(UnixTime::new),
+                cursor,
+                limit,
+            };
+            let page = client.get_logs(query)
+                .await
+                .change_context(ClientError::RemoteApiRequest)?;
+            for entry in page.entries.iter() {
+                print_log_entry(entry);
+            }
+            if let Some(cursor) = page.next_cursor {
+                println!("Next page cursor: {}", cursor);
+            }
+        }
+        ApiCommand::EncryptionKeyInfo { encryption_key_name } => {
+            let info = client.get_secure_storage_encryption_key_info(
+                ManagerInstanceName::new(encryption_key_name),
+            )
+                .await
+                .change_context(ClientError::RemoteApiRequest)?;
+            println!("{:#?}", info);
+            println!("Key age: {} days", info.age_seconds / (60 * 60 * 24));
+        }
+        ApiCommand::RotateEncryptionKey => {
+            client.rotate_secure_storage_encryption_key()
+                .await
+                .change_context(ClientError::RemoteApiRequest)?
+        }
+        ApiCommand::ExpandSecureStorage { size_increase_mib } => {
+            client.expand_secure_storage(SecureStorageExpansion { size_increase_mib })
+                .await
+                .change_context(ClientError::RemoteApiRequest)?
+        }
+        ApiCommand::SecureStorageAuditLog => {
+            let log = client.get_secure_storage_audit_log()
+                .await
+                .change_context(ClientError::RemoteApiRequest)?;
+            println!("{:#?}", log);
+        }
+        ApiCommand::BackendDataSnapshots => {
+            let list = client.get_backend_data_snapshot_list()
+                .await
+                .change_context(ClientError::RemoteApiRequest)?;
+            println!("{:#?}", list);
+        }
+        ApiCommand::RestoreBackendDataSnapshot { name } => {
+            client.restore_backend_data_snapshot(BackendDataSnapshotName { name })
+                .await
+                .change_context(ClientError::RemoteApiRequest)?
+        }
+        ApiCommand::FollowLogs { .. } => (), // Handled already
     }
 
     Ok(())
 }
+
+fn print_log_entry(entry: &LogEntry) {
+    let time = chrono::DateTime::from_timestamp(entry.time.ut, 0)
+        .map(|v| v.to_rfc3339())
+        .unwrap_or_else(|| entry.time.ut.to_string());
+    println!("{} {:?} {}: {}", time, entry.level, entry.service, entry.message);
+}
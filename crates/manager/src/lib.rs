@@ -9,7 +9,13 @@
     clippy::while_let_loop,
 )]
 
+// Ignore unused depenency warning
+use tls_client as _;
+
 pub mod api;
 pub mod client;
 pub mod server;
 pub mod utils;
+
+#[cfg(test)]
+mod test_utils;
@@ -9,7 +9,7 @@ use app::S;
 use futures::future::poll_fn;
 use manager_config::Config;
 use scheduled_task::ScheduledTaskManager;
-use task::TaskManager;
+use task::{start_backend_with_automatic_rollback, TaskManager};
 use tokio::{
     net::TcpListener,
     signal::{
@@ -32,14 +32,18 @@ use crate::{
     },
 };
 
+pub mod alert;
 pub mod app;
+pub mod automatic_update;
 pub mod backend_controller;
 pub mod backend_events;
 pub mod client;
 pub mod info;
+pub mod log;
 pub mod mount;
 pub mod task;
 pub mod scheduled_task;
+pub mod secure_storage;
 pub mod reboot;
 pub mod state;
 pub mod update;
@@ -130,6 +134,16 @@ impl AppServer {
             server_quit_watcher.resubscribe(),
         );
 
+        let automatic_update_manager_quit_handle = automatic_update::AutomaticUpdateManager::new_manager(
+            app.state(),
+            server_quit_watcher.resubscribe(),
+        );
+
+        let alert_manager_quit_handle = alert::AlertManager::new_manager(
+            app.state(),
+            server_quit_watcher.resubscribe(),
+        );
+
         // Start update manager
 
         let update_manager_quit_handle = update::UpdateManager::new_manager(
@@ -191,7 +205,7 @@ impl AppServer {
         if let Some(update_config) = self.config.software_update_provider() {
             if update_config.backend_install_location.exists() {
                 info!("Starting backend");
-                match BackendController::new(&self.config).start_backend().await {
+                match start_backend_with_automatic_rollback(&app.state()).await {
                     Ok(()) => {
                         info!("Backend started");
                     }
@@ -225,6 +239,8 @@ impl AppServer {
         }
 
         reboot_manager_quit_handle.wait_quit().await;
+        automatic_update_manager_quit_handle.wait_quit().await;
+        alert_manager_quit_handle.wait_quit().await;
         scheduled_task_manager_quit_handle.wait_quit().await;
         task_manager_quit_handle.wait_quit().await;
         update_manager_quit_handle.wait_quit().await;
@@ -0,0 +1,743 @@
+//! Send alerts when the manager detects problems
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use error_stack::{Result, ResultExt};
+use lettre::{
+    message::Mailbox,
+    transport::smtp::{authentication::Credentials, PoolConfig},
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use manager_config::file::{AlertEmailConfig, AlertingConfig};
+use manager_model::{ManagerInstanceName, ScheduledSoftwareUpdateOutcome};
+use serde::Serialize;
+use simple_backend_model::UnixTime;
+use tokio::{io::AsyncWriteExt, task::JoinHandle, time::sleep};
+use tracing::{error, info, warn};
+
+use super::{
+    app::S, backend_controller::BackendController, info::SystemInfoGetter, ServerQuitWatcher,
+};
+use crate::api::{GetConfig, GetScheduledTaskManager, GetUpdateManager};
+
+/// Interval for checking the latest release from the update source.
+const UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(thiserror::Error, Debug)]
+enum AlertError {
+    #[error("Reading system stats failed")]
+    SystemStats,
+
+    #[error("Reading the latest release failed")]
+    LatestRelease,
+
+    #[error("Email address parsing failed")]
+    EmailAddressParsing,
+
+    #[error("Email message building failed")]
+    EmailMessageBuilding,
+
+    #[error("Email sending failed")]
+    EmailSending,
+
+    #[error("Webhook request failed")]
+    Webhook,
+
+    #[error("Alert file writing failed")]
+    FileWrite,
+
+    #[error("Serializing failed")]
+    Serialize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    BackendDown,
+    ScheduledTaskFailed,
+    DiskUsage,
+    UpdateAvailable,
+    SecureStorageUnmounted,
+}
+
+/// Alert which is sent to the configured sinks. Webhook and file
+/// sinks receive this as JSON.
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub time: UnixTime,
+    pub manager: ManagerInstanceName,
+    pub kind: AlertKind,
+    /// Deduplication key. For example disk usage alerts have separate
+    /// key for every mount point.
+    pub key: String,
+    pub message: String,
+    /// The problem is no longer detected.
+    pub resolved: bool,
+}
+
+struct Problem {
+    kind: AlertKind,
+    key: String,
+    message: String,
+}
+
+impl Problem {
+    fn new(kind: AlertKind, key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+}
+
+struct ActiveAlert {
+    kind: AlertKind,
+    message: String,
+    last_sent: Instant,
+}
+
+/// Alert which should be sent. Created from [Problem] or
+/// resolved [ActiveAlert].
+#[derive(Debug, PartialEq)]
+struct AlertUpdate {
+    kind: AlertKind,
+    key: String,
+    message: String,
+    resolved: bool,
+}
+
+/// Deduplicates alerts using [Problem::key].
+#[derive(Default)]
+struct ActiveAlerts {
+    active: HashMap<String, ActiveAlert>,
+}
+
+impl ActiveAlerts {
+    /// Returns new alerts, active alerts which quiet period has elapsed
+    /// and resolved alerts. Active alerts of `unknown_kinds` are not
+    /// resolved.
+    fn update(
+        &mut self,
+        problems: Vec<Problem>,
+        unknown_kinds: &HashSet<AlertKind>,
+        quiet_period: Duration,
+        now: Instant,
+    ) -> Vec<AlertUpdate> {
+        let mut updates = vec![];
+        let mut current_keys = HashSet::new();
+
+        for problem in problems {
+            current_keys.insert(problem.key.clone());
+
+            let send_needed = self
+                .active
+                .get(&problem.key)
+                .map(|v| now.saturating_duration_since(v.last_sent) >= quiet_period)
+                .unwrap_or(true);
+            if !send_needed {
+                continue;
+            }
+
+            updates.push(AlertUpdate {
+                kind: problem.kind,
+                key: problem.key.clone(),
+                message: problem.message.clone(),
+                resolved: false,
+            });
+            self.active.insert(
+                problem.key,
+                ActiveAlert {
+                    kind: problem.kind,
+                    message: problem.message,
+                    last_sent: now,
+                },
+            );
+        }
+
+        let mut resolved_keys: Vec<String> = self
+            .active
+            .iter()
+            .filter(|(key, alert)| {
+                !current_keys.contains(*key) && !unknown_kinds.contains(&alert.kind)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        resolved_keys.sort();
+
+        for key in resolved_keys {
+            if let Some(resolved) = self.active.remove(&key) {
+                updates.push(AlertUpdate {
+                    kind: resolved.kind,
+                    key,
+                    message: resolved.message,
+                    resolved: true,
+                });
+            }
+        }
+
+        updates
+    }
+}
+
+struct LatestReleaseCheck {
+    time: Instant,
+    name: Option<String>,
+}
+
+struct EmailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl EmailSender {
+    fn new(config: &AlertEmailConfig) -> Result<Self, AlertError> {
+        let transport = if config.use_starttls_instead_of_smtps {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_server_address)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_server_address)
+        }
+        .change_context(AlertError::EmailSending)?
+        .credentials(Credentials::new(
+            config.username.clone(),
+            config.password.clone(),
+        ))
+        .pool_config(PoolConfig::new().max_size(1))
+        .build();
+
+        let from = Mailbox::from_str(&config.email_from_header)
+            .change_context(AlertError::EmailAddressParsing)
+            .attach_printable_lazy(|| config.email_from_header.clone())?;
+
+        let mut to = vec![];
+        for address in &config.email_to {
+            let mailbox = Mailbox::from_str(address)
+                .change_context(AlertError::EmailAddressParsing)
+                .attach_printable_lazy(|| address.clone())?;
+            to.push(mailbox);
+        }
+
+        Ok(Self {
+            transport,
+            from,
+            to,
+        })
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), AlertError> {
+        let status = if alert.resolved { "Resolved" } else { "Alert" };
+        let subject = format!("[{}] {}: {}", alert.manager.0, status, alert.message);
+        let body = format!(
+            "Manager: {}\nKind: {:?}\nTime: {}\nStatus: {}\n\n{}\n",
+            alert.manager.0,
+            alert.kind,
+            alert.time.ut,
+            status,
+            alert.message,
+        );
+
+        let mut builder = Message::builder().from(self.from.clone());
+        for mailbox in &self.to {
+            builder = builder.to(mailbox.clone());
+        }
+        let message = builder
+            .subject(subject)
+            .body(body)
+            .change_context(AlertError::EmailMessageBuilding)?;
+
+        let response = self
+            .transport
+            .send(message)
+            .await
+            .change_context(AlertError::EmailSending)?;
+
+        if response.is_positive() {
+            Ok(())
+        } else {
+            let response_message = response.message().collect::<Vec<_>>().join(" ");
+            Err(AlertError::EmailSending)
+                .attach_printable(format!("code: {}, message: {}", response.code(), response_message))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AlertManagerQuitHandle {
+    task: JoinHandle<()>,
+}
+
+impl AlertManagerQuitHandle {
+    pub async fn wait_quit(self) {
+        match self.task.await {
+            Ok(()) => (),
+            Err(e) => {
+                warn!("Alert manager quit failed. Error: {:?}", e);
+            }
+        }
+    }
+}
+
+pub struct AlertManager {
+    state: S,
+    client: reqwest::Client,
+    email: Option<EmailSender>,
+    active: ActiveAlerts,
+    latest_release: Option<LatestReleaseCheck>,
+}
+
+impl AlertManager {
+    pub fn new_manager(
+        state: S,
+        quit_notification: ServerQuitWatcher,
+    ) -> AlertManagerQuitHandle {
+        let email = state
+            .config()
+            .alerting()
+            .and_then(|v| v.email.as_ref())
+            .and_then(|v| match EmailSender::new(v) {
+                Ok(sender) => Some(sender),
+                Err(e) => {
+                    error!("Alert email sending is disabled. Error: {:?}", e);
+                    None
+                }
+            });
+
+        let manager = Self {
+            state,
+            client: reqwest::Client::new(),
+            email,
+            active: ActiveAlerts::default(),
+            latest_release: None,
+        };
+
+        let task = tokio::spawn(manager.run(quit_notification));
+
+        AlertManagerQuitHandle {
+            task,
+        }
+    }
+
+    async fn run(mut self, mut quit_notification: ServerQuitWatcher) {
+        let state = self.state.clone();
+        let Some(config) = state.config().alerting() else {
+            info!("Alerting is disabled");
+            let _ = quit_notification.recv().await;
+            return;
+        };
+
+        let check_interval = Duration::from_secs(config.check_interval_seconds.into());
+
+        loop {
+            tokio::select! {
+                _ = sleep(check_interval) => (),
+                _ = quit_notification.recv() => {
+                    return;
+                }
+            }
+
+            tokio::select! {
+                _ = self.check_and_send_alerts(config) => (),
+                _ = quit_notification.recv() => {
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn check_and_send_alerts(&mut self, config: &AlertingConfig) {
+        let mut problems = vec![];
+        // Active alerts of these kinds are not resolved because
+        // the check failed.
+        let mut unknown_kinds = HashSet::new();
+
+        let rules = &config.rules;
+
+        if let Some(health_check) = &rules.backend_health_check {
+            let result = BackendController::new(self.state.config())
+                .wait_backend_health(health_check)
+                .await;
+            if let Err(e) = result {
+                warn!("Backend health check failed. Error: {:?}", e);
+                problems.push(Problem::new(
+                    AlertKind::BackendDown,
+                    "backend_down",
+                    "Backend health check failed",
+                ));
+            }
+        }
+
+        if rules.scheduled_task_failed {
+            let status = self.state.scheduled_task_manager().status().await;
+            if let Some(latest) = status.software_update_history.last() {
+                if latest.outcome == ScheduledSoftwareUpdateOutcome::Failed {
+                    let details = latest.details.as_deref().unwrap_or("no details");
+                    problems.push(Problem::new(
+                        AlertKind::ScheduledTaskFailed,
+                        "scheduled_software_update_failed",
+                        format!("Scheduled software update failed: {}", details),
+                    ));
+                }
+            }
+        }
+
+        if let Some(max_percent) = rules.disk_usage_percent {
+            match self.disk_usage_problems(max_percent).await {
+                Ok(disk_problems) => problems.extend(disk_problems),
+                Err(e) => {
+                    warn!("Disk usage check failed. Error: {:?}", e);
+                    unknown_kinds.insert(AlertKind::DiskUsage);
+                }
+            }
+        }
+
+        if rules.update_available {
+            match self.update_available_problem().await {
+                Ok(problem) => problems.extend(problem),
+                Err(e) => {
+                    warn!("Update availability check failed. Error: {:?}", e);
+                    unknown_kinds.insert(AlertKind::UpdateAvailable);
+                }
+            }
+        }
+
+        if rules.secure_storage_unmounted {
+            if let Some(storage_config) = self.state.config().secure_storage_config() {
+                if !storage_config.availability_check_path.exists() {
+                    problems.push(Problem::new(
+                        AlertKind::SecureStorageUnmounted,
+                        "secure_storage_unmounted",
+                        "Secure storage is not mounted",
+                    ));
+                }
+            }
+        }
+
+        self.handle_problems(config, problems, unknown_kinds).await;
+    }
+
+    async fn disk_usage_problems(&self, max_percent: u8) -> Result<Vec<Problem>, AlertError> {
+        let stats = SystemInfoGetter::system_stats(self.state.config())
+            .await
+            .change_context(AlertError::SystemStats)?;
+
+        let max_percent = u64::from(max_percent);
+        let mut problems = vec![];
+        for disk in stats.disks {
+            let usage = [
+                ("Disk space", disk.used_bytes, disk.total_bytes),
+                ("Disk inode", disk.inodes_used, disk.inodes_total),
+            ];
+            for (name, used, total) in usage {
+                if total == 0 {
+                    continue;
+                }
+                let percent = used.saturating_mul(100) / total;
+                if percent >= max_percent {
+                    problems.push(Problem::new(
+                        AlertKind::DiskUsage,
+                        format!("disk_usage:{}:{}", name, disk.mount_point),
+                        format!("{} usage is {}% on {}", name, percent, disk.mount_point),
+                    ));
+                }
+            }
+        }
+
+        Ok(problems)
+    }
+
+    async fn update_available_problem(&mut self) -> Result<Option<Problem>, AlertError> {
+        let check_needed = self
+            .latest_release
+            .as_ref()
+            .map(|v| v.time.elapsed() >= UPDATE_CHECK_INTERVAL)
+            .unwrap_or(true);
+
+        if check_needed {
+            let name = self
+                .state
+                .update_manager()
+                .latest_release_name(self.state.config(), &self.client)
+                .await
+                .change_context(AlertError::LatestRelease)?;
+            self.latest_release = Some(LatestReleaseCheck {
+                time: Instant::now(),
+                name,
+            });
+        }
+
+        let Some(latest) = self.latest_release.as_ref().and_then(|v| v.name.clone()) else {
+            return Ok(None);
+        };
+
+        let installed = self.state.update_manager().read_state().await.installed;
+        if installed.map(|v| v.name) == Some(latest.clone()) {
+            return Ok(None);
+        }
+
+        Ok(Some(Problem::new(
+            AlertKind::UpdateAvailable,
+            "update_available",
+            format!("Software update {} is available", latest),
+        )))
+    }
+
+    /// Send new alerts, repeat active alerts after the quiet period and
+    /// send resolved notifications.
+    async fn handle_problems(
+        &mut self,
+        config: &AlertingConfig,
+        problems: Vec<Problem>,
+        unknown_kinds: HashSet<AlertKind>,
+    ) {
+        let quiet_period = Duration::from_secs(u64::from(config.quiet_period_minutes) * 60);
+        let updates = self.active.update(problems, &unknown_kinds, quiet_period, Instant::now());
+
+        for update in updates {
+            let alert = self.alert(update);
+            self.send_alert(config, &alert).await;
+        }
+    }
+
+    fn alert(&self, update: AlertUpdate) -> Alert {
+        Alert {
+            time: UnixTime::current_time(),
+            manager: self.state.config().manager_name(),
+            kind: update.kind,
+            key: update.key,
+            message: update.message,
+            resolved: update.resolved,
+        }
+    }
+
+    async fn send_alert(&self, config: &AlertingConfig, alert: &Alert) {
+        if alert.resolved {
+            info!("Alert resolved: {}", alert.message);
+        } else {
+            warn!("Alert: {}", alert.message);
+        }
+
+        if let Some(email) = &self.email {
+            if let Err(e) = email.send(alert).await {
+                error!("Sending alert email failed. Error: {:?}", e);
+            }
+        }
+
+        if let Some(webhook) = &config.webhook {
+            let mut request = self.client.post(webhook.url.clone()).json(alert);
+            if let Some(token) = &webhook.token {
+                request = request.bearer_auth(token);
+            }
+            let result = request
+                .send()
+                .await
+                .and_then(|v| v.error_for_status())
+                .change_context(AlertError::Webhook);
+            if let Err(e) = result {
+                error!("Sending alert webhook failed. Error: {:?}", e);
+            }
+        }
+
+        if let Some(file) = &config.file {
+            if let Err(e) = Self::append_to_file(&file.path, alert).await {
+                error!("Writing alert to file failed. Error: {:?}", e);
+            }
+        }
+    }
+
+    async fn append_to_file(path: &std::path::Path, alert: &Alert) -> Result<(), AlertError> {
+        let mut line = serde_json::to_string(alert).change_context(AlertError::Serialize)?;
+        line.push('\n');
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+            .change_context(AlertError::FileWrite)?;
+        file.write_all(line.as_bytes())
+            .await
+            .change_context(AlertError::FileWrite)?;
+        file.flush().await.change_context(AlertError::FileWrite)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashSet,
+        time::{Duration, Instant},
+    };
+
+    use super::{ActiveAlerts, AlertKind, AlertUpdate, Problem};
+
+    const QUIET_PERIOD: Duration = Duration::from_secs(60);
+
+    fn problem(kind: AlertKind, key: &str) -> Problem {
+        Problem::new(kind, key, format!("{} message", key))
+    }
+
+    fn update(kind: AlertKind, key: &str, resolved: bool) -> AlertUpdate {
+        AlertUpdate {
+            kind,
+            key: key.to_string(),
+            message: format!("{} message", key),
+            resolved,
+        }
+    }
+
+    #[test]
+    fn new_problem_is_sent_once_during_quiet_period() {
+        let mut alerts = ActiveAlerts::default();
+        let start = Instant::now();
+
+        let updates = alerts.update(
+            vec![problem(AlertKind::BackendDown, "backend_down")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+        assert_eq!(updates, vec![update(AlertKind::BackendDown, "backend_down", false)]);
+
+        let updates = alerts.update(
+            vec![problem(AlertKind::BackendDown, "backend_down")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start + QUIET_PERIOD / 2,
+        );
+        assert!(updates.is_empty());
+    }
+
+    #[test]
+    fn active_problem_is_sent_again_after_quiet_period() {
+        let mut alerts = ActiveAlerts::default();
+        let start = Instant::now();
+
+        alerts.update(
+            vec![problem(AlertKind::BackendDown, "backend_down")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+        let updates = alerts.update(
+            vec![problem(AlertKind::BackendDown, "backend_down")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start + QUIET_PERIOD,
+        );
+        assert_eq!(updates, vec![update(AlertKind::BackendDown, "backend_down", false)]);
+
+        // Quiet period starts again from the latest sent alert
+        let updates = alerts.update(
+            vec![problem(AlertKind::BackendDown, "backend_down")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start + QUIET_PERIOD + QUIET_PERIOD / 2,
+        );
+        assert!(updates.is_empty());
+    }
+
+    #[test]
+    fn problems_with_different_keys_are_separate_alerts() {
+        let mut alerts = ActiveAlerts::default();
+        let start = Instant::now();
+
+        let updates = alerts.update(
+            vec![
+                problem(AlertKind::DiskUsage, "disk_usage:/"),
+                problem(AlertKind::DiskUsage, "disk_usage:/data"),
+            ],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+        assert_eq!(
+            updates,
+            vec![
+                update(AlertKind::DiskUsage, "disk_usage:/", false),
+                update(AlertKind::DiskUsage, "disk_usage:/data", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_problem_is_resolved_once() {
+        let mut alerts = ActiveAlerts::default();
+        let start = Instant::now();
+
+        alerts.update(
+            vec![
+                problem(AlertKind::DiskUsage, "disk_usage:/"),
+                problem(AlertKind::BackendDown, "backend_down"),
+            ],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+
+        let updates = alerts.update(
+            vec![problem(AlertKind::DiskUsage, "disk_usage:/")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+        assert_eq!(updates, vec![update(AlertKind::BackendDown, "backend_down", true)]);
+
+        let updates = alerts.update(
+            vec![problem(AlertKind::DiskUsage, "disk_usage:/")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+        assert!(updates.is_empty());
+    }
+
+    #[test]
+    fn alert_of_unknown_kind_is_not_resolved() {
+        let mut alerts = ActiveAlerts::default();
+        let start = Instant::now();
+
+        alerts.update(
+            vec![
+                problem(AlertKind::DiskUsage, "disk_usage:/"),
+                problem(AlertKind::UpdateAvailable, "update_available"),
+            ],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+
+        let unknown_kinds = HashSet::from([AlertKind::DiskUsage]);
+        let updates = alerts.update(vec![], &unknown_kinds, QUIET_PERIOD, start);
+        assert_eq!(updates, vec![update(AlertKind::UpdateAvailable, "update_available", true)]);
+
+        let updates = alerts.update(vec![], &HashSet::new(), QUIET_PERIOD, start);
+        assert_eq!(updates, vec![update(AlertKind::DiskUsage, "disk_usage:/", true)]);
+    }
+
+    #[test]
+    fn resolved_problem_is_sent_again_when_detected() {
+        let mut alerts = ActiveAlerts::default();
+        let start = Instant::now();
+
+        alerts.update(
+            vec![problem(AlertKind::BackendDown, "backend_down")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+        alerts.update(vec![], &HashSet::new(), QUIET_PERIOD, start);
+
+        let updates = alerts.update(
+            vec![problem(AlertKind::BackendDown, "backend_down")],
+            &HashSet::new(),
+            QUIET_PERIOD,
+            start,
+        );
+        assert_eq!(updates, vec![update(AlertKind::BackendDown, "backend_down", false)]);
+    }
+}
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
 use manager_config::Config;
-use manager_model::{ServerEvent, ServerEventType};
-use tokio::sync::watch;
+use manager_model::{ServerEvent, ServerEventType, SoftwareRollback};
+use tokio::sync::{watch, Mutex};
 
-use super::{backend_events::BackendEventsHandle, client::ApiManager, scheduled_task::ScheduledTaskManagerHandle, task::TaskManagerHandle, update::UpdateManagerHandle};
-use crate::api::{GetApiManager, GetConfig, GetScheduledTaskManager, GetTaskManager, GetUpdateManager};
+use super::{backend_events::BackendEventsHandle, client::ApiManager, scheduled_task::ScheduledTaskManagerHandle, secure_storage::SecureStorageManager, task::TaskManagerHandle, update::UpdateManagerHandle};
+use crate::api::{GetApiManager, GetConfig, GetScheduledTaskManager, GetSecureStorageManager, GetTaskManager, GetUpdateManager};
 
 pub type S = AppState;
 
@@ -16,6 +16,9 @@ pub struct AppState {
     task_manager: Arc<TaskManagerHandle>,
     scheduled_task_manager: Arc<ScheduledTaskManagerHandle>,
     backend_events: Arc<BackendEventsHandle>,
+    latest_software_rollback: Arc<Mutex<Option<SoftwareRollback>>>,
+    secure_storage_operation_lock: Arc<Mutex<()>>,
+    key_storage_operation_lock: Arc<Mutex<()>>,
 }
 
 impl AppState {
@@ -25,13 +28,24 @@ impl AppState {
                 self.scheduled_task_manager.maintenance_time_for_backend_event().await
             ),
         };
-        vec![event]
+        let mut events = vec![event];
+        if let Some(rollback) = self.latest_software_rollback.lock().await.clone() {
+            events.push(ServerEvent {
+                event: ServerEventType::SoftwareRollback(rollback),
+            });
+        }
+        events
     }
 
     pub async fn refresh_state_to_backend(&self) {
         self.backend_events.send(self.current_state_as_server_events().await);
     }
 
+    pub async fn send_software_rollback_event(&self, rollback: SoftwareRollback) {
+        *self.latest_software_rollback.lock().await = Some(rollback);
+        self.refresh_state_to_backend().await;
+    }
+
     pub fn backend_events_receiver(&self) -> watch::Receiver<Vec<ServerEvent>> {
         self.backend_events.receiver()
     }
@@ -67,6 +81,16 @@ impl GetApiManager for AppState {
     }
 }
 
+impl GetSecureStorageManager for AppState {
+    fn secure_storage_manager(&self) -> SecureStorageManager {
+        SecureStorageManager::new(
+            self,
+            &self.secure_storage_operation_lock,
+            &self.key_storage_operation_lock,
+        )
+    }
+}
+
 
 pub struct App {
     pub state: AppState,
@@ -85,6 +109,9 @@ impl App {
             task_manager,
             scheduled_task_manager,
             backend_events: BackendEventsHandle::new(vec![]).into(),
+            latest_software_rollback: Mutex::new(None).into(),
+            secure_storage_operation_lock: Mutex::new(()).into(),
+            key_storage_operation_lock: Mutex::new(()).into(),
         };
 
         state.refresh_state_to_backend().await;
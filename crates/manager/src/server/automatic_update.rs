@@ -0,0 +1,148 @@
+//! Handle automatic software updates
+
+use std::time::Duration;
+
+use error_stack::{Result, ResultExt};
+use manager_config::Config;
+use manager_model::ScheduledTaskType;
+use simple_backend_utils::time::sleep_until_current_time_is_at;
+use tokio::{task::JoinHandle, time::sleep};
+use tracing::{info, warn, error};
+
+use super::{
+    app::S, scheduled_task::{download_latest_release, LatestRelease}, ServerQuitWatcher
+};
+use crate::{api::{GetConfig, GetScheduledTaskManager}, server::scheduled_task::ScheduledTaskManagerMessage};
+
+#[derive(thiserror::Error, Debug)]
+enum AutomaticUpdateError {
+    #[error("Time related error")]
+    Time,
+
+    #[error("Config related error")]
+    Config,
+
+    #[error("Scheduled task error")]
+    ScheduledTask,
+}
+
+#[derive(Debug)]
+pub struct AutomaticUpdateManagerQuitHandle {
+    task: JoinHandle<()>,
+}
+
+impl AutomaticUpdateManagerQuitHandle {
+    pub async fn wait_quit(self) {
+        match self.task.await {
+            Ok(()) => (),
+            Err(e) => {
+                warn!("Automatic update manager quit failed. Error: {:?}", e);
+            }
+        }
+    }
+}
+
+pub struct AutomaticUpdateManager {
+    state: S,
+}
+
+impl AutomaticUpdateManager {
+    pub fn new_manager(
+        state: S,
+        quit_notification: ServerQuitWatcher,
+    ) -> AutomaticUpdateManagerQuitHandle {
+        let manager = Self {
+            state,
+        };
+
+        let task = tokio::spawn(manager.run(quit_notification));
+
+        AutomaticUpdateManagerQuitHandle {
+            task,
+        }
+    }
+
+    async fn run(self, mut quit_notification: ServerQuitWatcher) {
+        info!(
+            "Automatic software update status: {}",
+            self.state.config().automatic_software_update().is_some()
+        );
+
+        let mut check_cooldown = false;
+
+        loop {
+            tokio::select! {
+                _ = sleep(Duration::from_secs(120)), if check_cooldown => {
+                    check_cooldown = false;
+                }
+                result = Self::sleep_until_update_check(self.state.config()), if !check_cooldown => {
+                    match result {
+                        Ok(()) => {
+                            self.schedule_update_if_needed().await;
+                        },
+                        Err(e) => {
+                            warn!("Sleep until software update check failed. Error: {:?}", e);
+                        }
+                    }
+                    check_cooldown = true;
+                }
+                _ = quit_notification.recv() => {
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn schedule_update_if_needed(&self) {
+        let Some(scheduled_tasks) = self.state.config().scheduled_tasks() else {
+            warn!("Scheduled tasks are disabled. Automatic software update is not possible.");
+            return;
+        };
+
+        match download_latest_release(&self.state, scheduled_tasks).await {
+            Ok(LatestRelease::Installable(release)) => {
+                info!("New release {} is available. Scheduling software update.", release.name);
+            }
+            Ok(LatestRelease::AlreadyInstalled(_)) => {
+                info!("No software update needed");
+                return;
+            }
+            Ok(LatestRelease::VersionNotAllowed(release)) => {
+                warn!("Release {} version is not allowed for automatic software update", release.name);
+                return;
+            }
+            Ok(LatestRelease::RolledBack(release)) => {
+                warn!("Release {} was rolled back earlier. Skipping automatic software update.", release.name);
+                return;
+            }
+            Err(e) => {
+                error!("Checking the latest release failed: {:?}", e);
+                return;
+            }
+        }
+
+        let notify_backend = self.state.config()
+            .automatic_software_update()
+            .map(|v| v.notify_backend)
+            .unwrap_or_default();
+        let result = self.state
+            .scheduled_task_manager()
+            .send_message(ScheduledTaskManagerMessage::Schedule { task: ScheduledTaskType::SoftwareUpdate, notify_backend })
+            .await
+            .change_context(AutomaticUpdateError::ScheduledTask);
+        if let Err(e) = result {
+            error!("Software update scheduling failed: {:?}", e);
+        }
+    }
+
+    async fn sleep_until_update_check(config: &Config) -> Result<(), AutomaticUpdateError> {
+        if let Some(update) = config.automatic_software_update() {
+            sleep_until_current_time_is_at(update.scheduling_time)
+                .await
+                .change_context(AutomaticUpdateError::Time)
+        } else {
+            futures::future::pending::<()>().await;
+            Err(AutomaticUpdateError::Config.into())
+        }
+    }
+}
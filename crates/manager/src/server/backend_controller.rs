@@ -1,12 +1,12 @@
 //! Start and stop backend
 
-use std::process::ExitStatus;
+use std::{process::ExitStatus, time::{Duration, Instant}};
 
 use error_stack::{Result, ResultExt};
 use tokio::process::Command;
 use tracing::warn;
 
-use manager_config::Config;
+use manager_config::{file::BackendHealthCheckConfig, Config};
 
 #[derive(thiserror::Error, Debug)]
 pub enum ControllerError {
@@ -21,6 +21,9 @@ pub enum ControllerError {
 
     #[error("Command failed with exit status: {0}")]
     CommandFailed(ExitStatus),
+
+    #[error("Backend health check failed")]
+    HealthCheckFailed,
 }
 
 pub struct BackendController<'a> {
@@ -75,4 +78,29 @@ impl<'a> BackendController<'a> {
 
         Ok(())
     }
+
+    /// Poll backend health check URL until it returns successful HTTP status
+    /// or the time limit is reached.
+    pub async fn wait_backend_health(
+        &self,
+        config: &BackendHealthCheckConfig,
+    ) -> Result<(), ControllerError> {
+        let client = reqwest::Client::new();
+        let timeout = Duration::from_secs(config.timeout_seconds.into());
+        let start = Instant::now();
+
+        loop {
+            let error = match client.get(&config.url).timeout(timeout).send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => format!("HTTP status {}", response.status()),
+                Err(e) => e.to_string(),
+            };
+
+            if start.elapsed() >= timeout {
+                return Err(ControllerError::HealthCheckFailed).attach_printable(error);
+            }
+
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    }
 }
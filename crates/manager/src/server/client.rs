@@ -2,12 +2,14 @@ use std::time::Duration;
 
 use error_stack::{report, Result, ResultExt};
 use manager_api::protocol::RequestSenderCmds;
-use manager_model::SecureStorageEncryptionKey;
+use manager_model::{ClusterStatus, ManagerInstanceName, ManagerStatus, SecureStorageEncryptionKey};
 
 use crate::api::{client::LocalOrRemoteApiClient, GetConfig};
 
 use super::app::S;
 
+const CLUSTER_STATUS_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(thiserror::Error, Debug)]
 pub enum ApiError {
     #[error("Client build failed")]
@@ -73,4 +75,70 @@ impl<'a> ApiManager<'a> {
                 .change_context(ApiError::ApiRequest)
         }
     }
+
+    /// Get status of this manager and all remote managers concurrently.
+    pub async fn get_cluster_status(self) -> ClusterStatus {
+        let mut names = vec![self.state.config().manager_name()];
+        names.extend(
+            self.state
+                .config()
+                .remote_managers()
+                .iter()
+                .map(|v| v.name.clone())
+        );
+
+        let managers = futures::future::join_all(
+            names
+                .into_iter()
+                .map(|name| Self::get_manager_status(self.state, name))
+        ).await;
+
+        ClusterStatus { managers }
+    }
+
+    async fn get_manager_status(state: &S, name: ManagerInstanceName) -> ManagerStatus {
+        let client = || LocalOrRemoteApiClient::new(name.clone(), state);
+        let (system_info, software, scheduled_tasks) = tokio::join!(
+            Self::with_timeout(client().get_system_info()),
+            Self::with_timeout(client().get_software_update_status()),
+            Self::with_timeout(client().get_scheduled_tasks_status()),
+        );
+
+        let mut errors = vec![];
+        let system_info = Self::ok_or_save_error(system_info, &mut errors);
+        let software = Self::ok_or_save_error(software, &mut errors);
+        let scheduled_tasks = Self::ok_or_save_error(scheduled_tasks, &mut errors);
+
+        ManagerStatus {
+            reachable: system_info.is_some() || software.is_some() || scheduled_tasks.is_some(),
+            name,
+            errors,
+            system_info,
+            installed_software: software.and_then(|v| v.installed),
+            scheduled_tasks,
+        }
+    }
+
+    fn ok_or_save_error<T>(result: Result<T, ApiError>, errors: &mut Vec<String>) -> Option<T> {
+        match result {
+            Ok(v) => Some(v),
+            Err(e) => {
+                errors.push(format!("{:#}", e));
+                None
+            }
+        }
+    }
+
+    async fn with_timeout<T>(
+        request: impl std::future::Future<Output = Result<T, manager_api::ClientError>>,
+    ) -> Result<T, ApiError> {
+        tokio::select! {
+            _ = tokio::time::sleep(CLUSTER_STATUS_REQUEST_TIMEOUT) => {
+                Err(report!(ApiError::RequestTimeout))
+            }
+            r = request => {
+                r.change_context(ApiError::ApiRequest)
+            },
+        }
+    }
 }
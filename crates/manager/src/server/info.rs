@@ -1,10 +1,12 @@
 //! Get system info
 
-use std::process::ExitStatus;
+use std::{path::PathBuf, process::ExitStatus};
 
 use error_stack::{Result, ResultExt};
-use manager_model::{CommandOutput, SystemInfo};
+use manager_model::{CommandOutput, DiskUsage, LoadAverage, MemoryUsage, ProcessUsage, SystemInfo, SystemStats};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
 use tokio::process::Command;
+use tracing::warn;
 
 use manager_config::Config;
 
@@ -33,16 +35,152 @@ pub enum SystemInfoError {
 
     #[error("Api request failed")]
     ApiRequest,
+
+    #[error("File reading failed")]
+    FileReadingFailed,
+
+    #[error("Blocking task failed")]
+    BlockingTaskFailed,
 }
 
 pub struct SystemInfoGetter;
 
 impl SystemInfoGetter {
     pub async fn system_info(config: &Config) -> Result<SystemInfo, SystemInfoError> {
-        if config.system_info().is_none() {
+        let Some(info_config) = config.system_info() else {
             return Ok(SystemInfo::default());
+        };
+
+        let stats = match Self::system_stats(config).await {
+            Ok(stats) => Some(stats),
+            Err(e) => {
+                warn!("Collecting system statistics failed. Error: {:?}", e);
+                None
+            }
+        };
+
+        let info = if info_config.include_command_output {
+            Self::command_outputs(config).await?
+        } else {
+            vec![]
+        };
+
+        Ok(SystemInfo {
+            stats,
+            info,
+        })
+    }
+
+    pub async fn system_stats(config: &Config) -> Result<SystemStats, SystemInfoError> {
+        let backend_binary = config
+            .software_update_provider()
+            .map(|v| v.backend_install_location.clone());
+
+        tokio::task::spawn_blocking(move || Self::system_stats_blocking(backend_binary))
+            .await
+            .change_context(SystemInfoError::BlockingTaskFailed)?
+    }
+
+    fn system_stats_blocking(backend_binary: Option<PathBuf>) -> Result<SystemStats, SystemInfoError> {
+        let mut system = System::new();
+        let process_refresh = ProcessRefreshKind::nothing()
+            .with_cpu()
+            .with_memory()
+            .with_exe(sysinfo::UpdateKind::OnlyIfNotSet);
+        // CPU usage is calculated from the difference of two refreshes.
+        system.refresh_processes_specifics(ProcessesToUpdate::All, true, process_refresh);
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        system.refresh_processes_specifics(ProcessesToUpdate::All, true, process_refresh);
+        system.refresh_memory();
+
+        let manager_pid = Pid::from_u32(std::process::id());
+        let mut processes = vec![];
+        for (pid, process) in system.processes() {
+            let name = if *pid == manager_pid {
+                "manager"
+            } else if backend_binary.is_some() && process.exe() == backend_binary.as_deref() {
+                "backend"
+            } else {
+                continue;
+            };
+            processes.push(ProcessUsage {
+                name: name.to_string(),
+                pid: pid.as_u32(),
+                cpu_usage: process.cpu_usage(),
+                memory_bytes: process.memory(),
+            });
+        }
+
+        let load_average = System::load_average();
+
+        Ok(SystemStats {
+            uptime_seconds: System::uptime(),
+            load_average: LoadAverage {
+                one: load_average.one,
+                five: load_average.five,
+                fifteen: load_average.fifteen,
+            },
+            memory: MemoryUsage {
+                total_bytes: system.total_memory(),
+                used_bytes: system.used_memory(),
+            },
+            swap: MemoryUsage {
+                total_bytes: system.total_swap(),
+                used_bytes: system.used_swap(),
+            },
+            disks: Self::disk_usage()?,
+            processes,
+        })
+    }
+
+    /// Get disk and inode usage of mounted block devices.
+    fn disk_usage() -> Result<Vec<DiskUsage>, SystemInfoError> {
+        let mounts = std::fs::read_to_string("/proc/self/mounts")
+            .change_context(SystemInfoError::FileReadingFailed)?;
+
+        let mut disks: Vec<DiskUsage> = vec![];
+        for line in mounts.lines() {
+            let mut values = line.split_whitespace();
+            let (Some(device), Some(mount_point)) = (values.next(), values.next()) else {
+                continue;
+            };
+
+            if !device.starts_with("/dev/") || device.starts_with("/dev/loop") {
+                continue;
+            }
+
+            // Spaces are escaped in the mounts file.
+            let mount_point = mount_point.replace("\\040", " ");
+            if disks.iter().any(|v| v.mount_point == mount_point) {
+                continue;
+            }
+
+            let stat = match nix::sys::statvfs::statvfs(mount_point.as_str()) {
+                Ok(stat) => stat,
+                Err(e) => {
+                    warn!("Getting file system statistics failed for {}. Error: {}", mount_point, e);
+                    continue;
+                }
+            };
+
+            let block_size = stat.fragment_size() as u64;
+            let blocks = stat.blocks() as u64;
+            let inodes = stat.files() as u64;
+            disks.push(DiskUsage {
+                mount_point,
+                device: device.to_string(),
+                total_bytes: blocks * block_size,
+                used_bytes: blocks.saturating_sub(stat.blocks_free() as u64) * block_size,
+                available_bytes: stat.blocks_available() as u64 * block_size,
+                inodes_total: inodes,
+                inodes_used: inodes.saturating_sub(stat.files_free() as u64),
+            });
         }
 
+        Ok(disks)
+    }
+
+    async fn command_outputs(config: &Config) -> Result<Vec<CommandOutput>, SystemInfoError> {
         let df = Self::run_df().await?;
         let df_inodes = Self::run_df_inodes().await?;
         let uptime = Self::run_uptime().await?;
@@ -62,9 +200,7 @@ impl SystemInfoGetter {
             }
         }
 
-        Ok(SystemInfo {
-            info: commands,
-        })
+        Ok(commands)
     }
 
     async fn run_df() -> Result<CommandOutput, SystemInfoError> {
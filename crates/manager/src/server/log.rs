@@ -0,0 +1,706 @@
+//! Search and follow logs
+
+use std::{path::{Path, PathBuf}, process::{ExitStatus, Stdio}, time::Duration};
+
+use error_stack::{report, Result, ResultExt};
+use manager_config::Config;
+use manager_model::{LogEntry, LogFilter, LogLevel, LogPage, LogQuery, LogSource, BACKEND_LOG_FILE_NAME_PREFIX, BACKEND_LOG_FILE_NAME_SUFFIX};
+use serde_json::Value;
+use simple_backend_model::UnixTime;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncSeekExt, BufReader, Lines},
+    process::{Child, ChildStdout, Command},
+};
+
+const BACKEND_LOG_FILE_POLLING_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(thiserror::Error, Debug)]
+pub enum LogError {
+    #[error("Log API is not configured")]
+    NotConfigured,
+
+    #[error("Service is not allowed")]
+    ServiceNotAllowed,
+
+    #[error("Invalid cursor")]
+    InvalidCursor,
+
+    #[error("Process start failed")]
+    ProcessStartFailed,
+
+    #[error("Process wait failed")]
+    ProcessWaitFailed,
+
+    #[error("Process output reading failed")]
+    ProcessOutputReadingFailed,
+
+    #[error("Command failed with exit status: {0}")]
+    CommandFailed(ExitStatus),
+
+    #[error("File reading failed")]
+    FileReadingFailed,
+}
+
+pub struct LogReader;
+
+impl LogReader {
+    pub async fn search(config: &Config, query: LogQuery) -> Result<LogPage, LogError> {
+        let mut page = PageBuilder::new(&query);
+        if query.page_size() == 0 {
+            return Ok(page.finish());
+        }
+
+        match &query.filter.source {
+            LogSource::Journal { services } => {
+                let services = Self::allowed_services(config, services)?;
+                if services.is_empty() {
+                    return Ok(page.finish());
+                }
+                Self::search_journal(&services, &query, &mut page).await?;
+            }
+            LogSource::BackendLogFile => {
+                let dir = Self::backend_log_dir(config)?;
+                Self::search_backend_log_files(dir, &query, &mut page).await?;
+            }
+        }
+
+        Ok(page.finish())
+    }
+
+    async fn search_journal(
+        services: &[String],
+        query: &LogQuery,
+        page: &mut PageBuilder<'_>,
+    ) -> Result<(), LogError> {
+        let mut args = journalctl_args(services, query.filter.min_level);
+        if let Some(since) = query.since {
+            args.push("--since".to_string());
+            args.push(format!("@{}", since.ut));
+        }
+        if let Some(until) = query.until {
+            args.push("--until".to_string());
+            args.push(format!("@{}", until.ut));
+        }
+        if let Some(cursor) = &query.cursor {
+            args.push("--after-cursor".to_string());
+            args.push(cursor.clone());
+        }
+
+        let mut journal = JournalProcess::start(&args)?;
+        let mut page_full = false;
+        while let Some(entry) = journal.next_entry().await? {
+            if !page.add(entry) {
+                page_full = true;
+                break;
+            }
+        }
+
+        if page_full {
+            journal.kill().await
+        } else {
+            journal.wait().await
+        }
+    }
+
+    async fn search_backend_log_files(
+        dir: &Path,
+        query: &LogQuery,
+        page: &mut PageBuilder<'_>,
+    ) -> Result<(), LogError> {
+        let cursor = query.cursor
+            .as_deref()
+            .map(BackendLogFileCursor::parse)
+            .transpose()?;
+
+        for file_name in backend_log_files(dir).await? {
+            let offset = match &cursor {
+                Some(cursor) if file_name < cursor.file_name => continue,
+                Some(cursor) if file_name == cursor.file_name => cursor.offset,
+                _ => 0,
+            };
+
+            let mut file = BackendLogFile::open(dir, file_name, offset).await?;
+            while let Some(entry) = file.next_entry().await? {
+                if !page.add(entry) {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn allowed_services(config: &Config, services: &[String]) -> Result<Vec<String>, LogError> {
+        let info_config = config.system_info().ok_or_else(|| report!(LogError::NotConfigured))?;
+
+        if services.is_empty() {
+            return Ok(info_config.log_services.clone());
+        }
+
+        for s in services {
+            if !info_config.log_services.contains(s) {
+                return Err(report!(LogError::ServiceNotAllowed))
+                    .attach_printable(s.clone());
+            }
+        }
+
+        Ok(services.to_vec())
+    }
+
+    fn backend_log_dir(config: &Config) -> Result<&Path, LogError> {
+        config.system_info()
+            .and_then(|v| v.backend_log_dir.as_deref())
+            .ok_or_else(|| report!(LogError::NotConfigured))
+    }
+}
+
+/// Follow new log entries.
+pub enum LogFollower {
+    Journal(JournalProcess),
+    BackendLogFile {
+        dir: PathBuf,
+        /// Currently followed file. The value is `None` until
+        /// the first log file exists.
+        file: Option<BackendLogFile>,
+    },
+}
+
+impl LogFollower {
+    pub async fn new(config: &Config, filter: &LogFilter) -> Result<Self, LogError> {
+        match &filter.source {
+            LogSource::Journal { services } => {
+                let services = LogReader::allowed_services(config, services)?;
+                if services.is_empty() {
+                    return Err(report!(LogError::NotConfigured));
+                }
+                let mut args = journalctl_args(&services, filter.min_level);
+                args.push("--follow".to_string());
+                args.push("--lines=0".to_string());
+                Ok(Self::Journal(JournalProcess::start(&args)?))
+            }
+            LogSource::BackendLogFile => {
+                let dir = LogReader::backend_log_dir(config)?.to_path_buf();
+                // Follow only new log entries.
+                let file = match backend_log_files(&dir).await?.pop() {
+                    Some(file_name) => {
+                        let offset = tokio::fs::metadata(dir.join(&file_name))
+                            .await
+                            .change_context(LogError::FileReadingFailed)?
+                            .len();
+                        Some(BackendLogFile::open(&dir, file_name, offset).await?)
+                    }
+                    None => None,
+                };
+                Ok(Self::BackendLogFile { dir, file })
+            }
+        }
+    }
+
+    /// Wait next log entry. Filter is not applied to the returned value.
+    pub async fn next_entry(&mut self) -> Result<LogEntry, LogError> {
+        match self {
+            Self::Journal(journal) => {
+                match journal.next_entry().await? {
+                    Some(entry) => Ok(entry),
+                    None => {
+                        journal.wait().await?;
+                        Err(report!(LogError::ProcessOutputReadingFailed))
+                            .attach_printable("journalctl quit unexpectedly")
+                    }
+                }
+            }
+            Self::BackendLogFile { dir, file } => loop {
+                if let Some(current) = file {
+                    if let Some(entry) = current.next_entry().await? {
+                        return Ok(entry);
+                    }
+                }
+
+                // Move to the next file after log rotation
+                let current_file_name = file.as_ref().map(|v| v.file_name.as_str());
+                let next_file_name = backend_log_files(dir)
+                    .await?
+                    .into_iter()
+                    .find(|v| Some(v.as_str()) > current_file_name);
+                if let Some(next_file_name) = next_file_name {
+                    *file = Some(BackendLogFile::open(dir, next_file_name, 0).await?);
+                } else {
+                    tokio::time::sleep(BACKEND_LOG_FILE_POLLING_INTERVAL).await;
+                }
+            },
+        }
+    }
+}
+
+struct PageBuilder<'a> {
+    query: &'a LogQuery,
+    entries: Vec<LogEntry>,
+    next_cursor: Option<String>,
+}
+
+impl<'a> PageBuilder<'a> {
+    fn new(query: &'a LogQuery) -> Self {
+        Self {
+            query,
+            entries: vec![],
+            next_cursor: None,
+        }
+    }
+
+    /// Returns false if reading more log entries is not needed.
+    fn add(&mut self, entry: LogEntry) -> bool {
+        if let Some(until) = self.query.until {
+            if entry.time.ut >= until.ut {
+                return false;
+            }
+        }
+
+        if !self.query.is_in_time_range(entry.time) || !self.query.filter.is_match(&entry) {
+            return true;
+        }
+
+        if self.entries.len() >= self.query.page_size() {
+            self.next_cursor = self.entries.last().map(|v| v.cursor.clone());
+            return false;
+        }
+
+        self.entries.push(entry);
+        true
+    }
+
+    fn finish(self) -> LogPage {
+        LogPage {
+            entries: self.entries,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+fn journalctl_args(services: &[String], min_level: Option<LogLevel>) -> Vec<String> {
+    let mut args = vec![
+        "--no-pager".to_string(),
+        "--output=json".to_string(),
+    ];
+
+    for s in services {
+        args.push("--unit".to_string());
+        args.push(s.clone());
+    }
+
+    if let Some(min_level) = min_level {
+        let priority = match min_level {
+            LogLevel::Error => "err",
+            LogLevel::Warn => "warning",
+            LogLevel::Info => "info",
+            LogLevel::Debug | LogLevel::Trace => "debug",
+        };
+        args.push(format!("--priority={}", priority));
+    }
+
+    args
+}
+
+pub struct JournalProcess {
+    process: Child,
+    lines: Lines<BufReader<ChildStdout>>,
+}
+
+impl JournalProcess {
+    fn start(args: &[String]) -> Result<Self, LogError> {
+        let mut process = Command::new("journalctl")
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .change_context(LogError::ProcessStartFailed)?;
+
+        let stdout = process.stdout
+            .take()
+            .ok_or_else(|| report!(LogError::ProcessStartFailed))?;
+
+        Ok(Self {
+            process,
+            lines: BufReader::new(stdout).lines(),
+        })
+    }
+
+    async fn next_entry(&mut self) -> Result<Option<LogEntry>, LogError> {
+        while let Some(line) = self.lines
+            .next_line()
+            .await
+            .change_context(LogError::ProcessOutputReadingFailed)?
+        {
+            if let Some(entry) = parse_journal_entry(&line) {
+                return Ok(Some(entry));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn wait(&mut self) -> Result<(), LogError> {
+        let status = self.process
+            .wait()
+            .await
+            .change_context(LogError::ProcessWaitFailed)?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(report!(LogError::CommandFailed(status)))
+        }
+    }
+
+    async fn kill(&mut self) -> Result<(), LogError> {
+        self.process
+            .kill()
+            .await
+            .change_context(LogError::ProcessWaitFailed)
+    }
+}
+
+fn parse_journal_entry(line: &str) -> Option<LogEntry> {
+    let value: Value = serde_json::from_str(line).ok()?;
+    let field = |name: &str| value.get(name).and_then(|v| v.as_str());
+
+    let cursor = field("__CURSOR")?.to_string();
+    let time_micros: i64 = field("__REALTIME_TIMESTAMP")?.parse().ok()?;
+    let level = match field("PRIORITY").and_then(|v| v.parse::<u8>().ok()) {
+        Some(0..=3) => LogLevel::Error,
+        Some(4) => LogLevel::Warn,
+        Some(7) => LogLevel::Debug,
+        _ => LogLevel::Info,
+    };
+    let service = field("_SYSTEMD_UNIT")
+        .or(field("SYSLOG_IDENTIFIER"))
+        .unwrap_or_default()
+        .to_string();
+    // Non UTF-8 messages are byte arrays
+    let message = match value.get("MESSAGE") {
+        Some(Value::String(message)) => message.clone(),
+        Some(Value::Array(bytes)) => {
+            let bytes: Vec<u8> = bytes.iter()
+                .filter_map(|v| v.as_u64())
+                .map(|v| v as u8)
+                .collect();
+            String::from_utf8_lossy(&bytes).to_string()
+        }
+        _ => String::new(),
+    };
+
+    Some(LogEntry {
+        time: UnixTime::new(time_micros / 1_000_000),
+        level,
+        service,
+        message,
+        cursor,
+    })
+}
+
+/// Backend log file names sorted from oldest to newest.
+async fn backend_log_files(dir: &Path) -> Result<Vec<String>, LogError> {
+    let mut dir_reader = tokio::fs::read_dir(dir)
+        .await
+        .change_context(LogError::FileReadingFailed)
+        .attach_printable(dir.display().to_string())?;
+
+    let mut files = vec![];
+    while let Some(entry) = dir_reader.next_entry()
+        .await
+        .change_context(LogError::FileReadingFailed)?
+    {
+        let Some(name) = entry.file_name().to_str().map(|v| v.to_string()) else {
+            continue;
+        };
+
+        if name.starts_with(&format!("{}.", BACKEND_LOG_FILE_NAME_PREFIX)) &&
+            name.ends_with(&format!(".{}", BACKEND_LOG_FILE_NAME_SUFFIX)) {
+            files.push(name);
+        }
+    }
+
+    // File names contain date in YYYY-MM-DD format
+    files.sort();
+    Ok(files)
+}
+
+/// Cursor `<file name>:<byte offset>` points to the start of the
+/// line after the log entry.
+struct BackendLogFileCursor {
+    file_name: String,
+    offset: u64,
+}
+
+impl BackendLogFileCursor {
+    fn parse(cursor: &str) -> Result<Self, LogError> {
+        let (file_name, offset) = cursor
+            .rsplit_once(':')
+            .ok_or_else(|| report!(LogError::InvalidCursor))?;
+        let offset = offset
+            .parse()
+            .change_context(LogError::InvalidCursor)?;
+        Ok(Self {
+            file_name: file_name.to_string(),
+            offset,
+        })
+    }
+}
+
+pub struct BackendLogFile {
+    file_name: String,
+    reader: BufReader<tokio::fs::File>,
+    offset: u64,
+}
+
+impl BackendLogFile {
+    async fn open(dir: &Path, file_name: String, offset: u64) -> Result<Self, LogError> {
+        let mut file = tokio::fs::File::open(dir.join(&file_name))
+            .await
+            .change_context(LogError::FileReadingFailed)
+            .attach_printable(file_name.clone())?;
+        file.seek(std::io::SeekFrom::Start(offset))
+            .await
+            .change_context(LogError::FileReadingFailed)?;
+        Ok(Self {
+            file_name,
+            reader: BufReader::new(file),
+            offset,
+        })
+    }
+
+    /// Returns `None` when there is no more complete lines.
+    async fn next_entry(&mut self) -> Result<Option<LogEntry>, LogError> {
+        loop {
+            let mut line = vec![];
+            let len = self.reader
+                .read_until(b'\n', &mut line)
+                .await
+                .change_context(LogError::FileReadingFailed)?;
+
+            if len == 0 {
+                return Ok(None);
+            }
+
+            if line.last() != Some(&b'\n') {
+                // The backend is still writing the line. Read it
+                // again later.
+                self.reader.seek(std::io::SeekFrom::Start(self.offset))
+                    .await
+                    .change_context(LogError::FileReadingFailed)?;
+                return Ok(None);
+            }
+
+            self.offset += len as u64;
+            let cursor = format!("{}:{}", self.file_name, self.offset);
+            if let Some(entry) = parse_backend_log_entry(&line, cursor) {
+                return Ok(Some(entry));
+            }
+        }
+    }
+}
+
+/// Parse JSON formatted line from tracing-subscriber.
+fn parse_backend_log_entry(line: &[u8], cursor: String) -> Option<LogEntry> {
+    let value: Value = serde_json::from_slice(line).ok()?;
+
+    let time = value.get("timestamp")
+        .and_then(|v| v.as_str())
+        .and_then(|v| chrono::DateTime::parse_from_rfc3339(v).ok())?;
+    let level = match value.get("level").and_then(|v| v.as_str())? {
+        "ERROR" => LogLevel::Error,
+        "WARN" => LogLevel::Warn,
+        "INFO" => LogLevel::Info,
+        "DEBUG" => LogLevel::Debug,
+        _ => LogLevel::Trace,
+    };
+
+    let mut message = String::new();
+    if let Some(fields) = value.get("fields").and_then(|v| v.as_object()) {
+        if let Some(text) = fields.get("message").and_then(|v| v.as_str()) {
+            message.push_str(text);
+        }
+        for (k, v) in fields.iter().filter(|(k, _)| *k != "message") {
+            message.push_str(&format!(" {}={}", k, v));
+        }
+    }
+
+    Some(LogEntry {
+        time: UnixTime::new(time.timestamp()),
+        level,
+        service: BACKEND_LOG_FILE_NAME_PREFIX.to_string(),
+        message,
+        cursor,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use manager_model::{LogEntry, LogFilter, LogLevel, LogQuery, LogSource, BACKEND_LOG_FILE_NAME_PREFIX};
+    use simple_backend_model::UnixTime;
+
+    use super::{parse_backend_log_entry, parse_journal_entry, BackendLogFileCursor, PageBuilder};
+
+    fn query(limit: u32) -> LogQuery {
+        LogQuery {
+            filter: LogFilter {
+                source: LogSource::BackendLogFile,
+                min_level: None,
+                text: None,
+            },
+            since: None,
+            until: None,
+            cursor: None,
+            limit,
+        }
+    }
+
+    fn entry(time: i64, level: LogLevel, cursor: &str) -> LogEntry {
+        LogEntry {
+            time: UnixTime::new(time),
+            level,
+            service: "test".to_string(),
+            message: "message".to_string(),
+            cursor: cursor.to_string(),
+        }
+    }
+
+    #[test]
+    fn page_has_next_cursor_when_page_is_full() {
+        let query = query(2);
+        let mut page = PageBuilder::new(&query);
+
+        assert!(page.add(entry(1, LogLevel::Info, "1")));
+        assert!(page.add(entry(2, LogLevel::Info, "2")));
+        assert!(!page.add(entry(3, LogLevel::Info, "3")));
+
+        let page = page.finish();
+        assert_eq!(page.entries.len(), 2);
+        assert_eq!(page.next_cursor.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn page_does_not_have_next_cursor_when_all_entries_fit() {
+        let query = query(2);
+        let mut page = PageBuilder::new(&query);
+
+        assert!(page.add(entry(1, LogLevel::Info, "1")));
+
+        let page = page.finish();
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn page_skips_entries_not_matching_the_query() {
+        let mut query = query(10);
+        query.filter.min_level = Some(LogLevel::Warn);
+        query.since = Some(UnixTime::new(2));
+        let mut page = PageBuilder::new(&query);
+
+        assert!(page.add(entry(1, LogLevel::Error, "1")));
+        assert!(page.add(entry(2, LogLevel::Info, "2")));
+        assert!(page.add(entry(3, LogLevel::Warn, "3")));
+
+        let page = page.finish();
+        assert_eq!(page.entries, vec![entry(3, LogLevel::Warn, "3")]);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn page_reading_stops_at_until_time() {
+        let mut query = query(10);
+        query.until = Some(UnixTime::new(2));
+        let mut page = PageBuilder::new(&query);
+
+        assert!(page.add(entry(1, LogLevel::Info, "1")));
+        assert!(!page.add(entry(2, LogLevel::Info, "2")));
+
+        let page = page.finish();
+        assert_eq!(page.entries, vec![entry(1, LogLevel::Info, "1")]);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn journal_entry_is_parsed() {
+        let line = r#"{"__CURSOR":"s=1;i=2","__REALTIME_TIMESTAMP":"1700000000123456","PRIORITY":"4","_SYSTEMD_UNIT":"backend.service","MESSAGE":"Hello"}"#;
+
+        let entry = parse_journal_entry(line).unwrap();
+
+        assert_eq!(entry.cursor, "s=1;i=2");
+        assert_eq!(entry.time, UnixTime::new(1700000000));
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.service, "backend.service");
+        assert_eq!(entry.message, "Hello");
+    }
+
+    #[test]
+    fn journal_entry_byte_array_message_and_syslog_identifier() {
+        let line = r#"{"__CURSOR":"c","__REALTIME_TIMESTAMP":"1000000","PRIORITY":"2","SYSLOG_IDENTIFIER":"kernel","MESSAGE":[72,105,255]}"#;
+
+        let entry = parse_journal_entry(line).unwrap();
+
+        assert_eq!(entry.time, UnixTime::new(1));
+        assert_eq!(entry.level, LogLevel::Error);
+        assert_eq!(entry.service, "kernel");
+        assert_eq!(entry.message, "Hi\u{FFFD}");
+    }
+
+    #[test]
+    fn journal_entry_level_defaults_to_info() {
+        let line = r#"{"__CURSOR":"c","__REALTIME_TIMESTAMP":"1000000","MESSAGE":"Hello"}"#;
+
+        let entry = parse_journal_entry(line).unwrap();
+
+        assert_eq!(entry.level, LogLevel::Info);
+        assert_eq!(entry.service, "");
+    }
+
+    #[test]
+    fn invalid_journal_entry_is_skipped() {
+        assert!(parse_journal_entry("not JSON").is_none());
+        assert!(parse_journal_entry(r#"{"__REALTIME_TIMESTAMP":"1000000"}"#).is_none());
+        assert!(parse_journal_entry(r#"{"__CURSOR":"c","__REALTIME_TIMESTAMP":"time"}"#).is_none());
+    }
+
+    #[test]
+    fn backend_log_entry_is_parsed() {
+        let line = br#"{"timestamp":"2024-01-01T00:00:10.123456Z","level":"WARN","fields":{"message":"Hello","count":2},"target":"server"}"#;
+
+        let entry = parse_backend_log_entry(line, "cursor".to_string()).unwrap();
+
+        assert_eq!(entry.time, UnixTime::new(1704067210));
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.service, BACKEND_LOG_FILE_NAME_PREFIX);
+        assert_eq!(entry.message, "Hello count=2");
+        assert_eq!(entry.cursor, "cursor");
+    }
+
+    #[test]
+    fn invalid_backend_log_entry_is_skipped() {
+        assert!(parse_backend_log_entry(b"not JSON", "c".to_string()).is_none());
+        assert!(parse_backend_log_entry(br#"{"level":"INFO"}"#, "c".to_string()).is_none());
+        assert!(parse_backend_log_entry(
+            br#"{"timestamp":"2024-01-01T00:00:00Z"}"#,
+            "c".to_string()
+        ).is_none());
+    }
+
+    #[test]
+    fn backend_log_file_cursor_is_parsed() {
+        let cursor = BackendLogFileCursor::parse("backend.2024-01-01.log:123").unwrap();
+        assert_eq!(cursor.file_name, "backend.2024-01-01.log");
+        assert_eq!(cursor.offset, 123);
+
+        let cursor = BackendLogFileCursor::parse("a:b:0").unwrap();
+        assert_eq!(cursor.file_name, "a:b");
+        assert_eq!(cursor.offset, 0);
+    }
+
+    #[test]
+    fn invalid_backend_log_file_cursor_is_error() {
+        assert!(BackendLogFileCursor::parse("backend.2024-01-01.log").is_err());
+        assert!(BackendLogFileCursor::parse("backend.2024-01-01.log:").is_err());
+        assert!(BackendLogFileCursor::parse("backend.2024-01-01.log:-1").is_err());
+    }
+}
@@ -5,19 +5,21 @@ use std::{
     sync::Arc, time::Duration,
 };
 
-use error_stack::{Result, ResultExt};
+use error_stack::{report, Result, ResultExt};
 use futures::lock::Mutex;
-use manager_config::file::ScheduledTasksConfig;
-use manager_model::{MaintenanceTask, MaintenanceTime, ManualTaskType, ScheduledTaskStatus, ScheduledTaskType};
+use manager_config::file::{ScheduledTasksConfig, SoftwareVersion};
+use manager_model::{MaintenanceTask, MaintenanceTime, ManualTaskType, ScheduledSoftwareUpdateOutcome, ScheduledSoftwareUpdateResult, ScheduledTaskStatus, ScheduledTaskType, SoftwareInfo, SoftwareUpdateTaskType};
 use simple_backend_model::UnixTime;
 use simple_backend_utils::time::{next_possible_utc_date_time_value_using_current_time, sleep_until_current_time_is_at};
 use tokio::{sync::mpsc, task::JoinHandle, time::sleep};
 use tracing::{info, warn};
 
 use super::{
-    app::S, ServerQuitWatcher
+    app::S, task::TaskManagerMessage, ServerQuitWatcher
 };
-use crate::api::{GetConfig, GetTaskManager};
+use crate::api::{GetConfig, GetTaskManager, GetUpdateManager};
+
+const SOFTWARE_UPDATE_HISTORY_MAX_LEN: usize = 10;
 
 
 #[derive(thiserror::Error, Debug)]
@@ -45,6 +47,15 @@ pub enum ScheduledTaskError {
 
     #[error("Getting encryption key failed")]
     GetKeyFailed,
+
+    #[error("Update manager error")]
+    UpdateManager,
+
+    #[error("Software download failed")]
+    SoftwareDownloadFailed,
+
+    #[error("Software install failed")]
+    SoftwareInstallFailed,
 }
 
 #[derive(Debug)]
@@ -109,10 +120,79 @@ impl ScheduledTaskManagerHandle {
             }
         }
 
+        if let Some(t) = state.software_update {
+            if t.notify_backend {
+                return Some(MaintenanceTime(t.time));
+            }
+        }
+
         None
     }
 }
 
+pub enum LatestRelease {
+    AlreadyInstalled(SoftwareInfo),
+    VersionNotAllowed(SoftwareInfo),
+    RolledBack(SoftwareInfo),
+    Installable(SoftwareInfo),
+}
+
+/// Download the latest release and check can it be installed
+/// as a scheduled software update.
+pub async fn download_latest_release(
+    state: &S,
+    config: &ScheduledTasksConfig,
+) -> Result<LatestRelease, ScheduledTaskError> {
+    // Check the latest release from the update source, so that
+    // previously downloaded release is not used if the
+    // update source is not available.
+    let latest_release_name = state.update_manager()
+        .latest_release_name(state.config(), &reqwest::Client::new())
+        .await
+        .change_context(ScheduledTaskError::UpdateManager)?;
+    let Some(latest_release_name) = latest_release_name else {
+        return Err(report!(ScheduledTaskError::SoftwareDownloadFailed))
+            .attach_printable("No release available");
+    };
+
+    state.update_manager()
+        .send_message_and_wait(SoftwareUpdateTaskType::Download)
+        .await
+        .change_context(ScheduledTaskError::UpdateManager)?;
+
+    let status = state.update_manager().read_state().await;
+    let Some(downloaded) = status.downloaded else {
+        return Err(report!(ScheduledTaskError::SoftwareDownloadFailed));
+    };
+
+    if downloaded.name != latest_release_name {
+        return Err(report!(ScheduledTaskError::SoftwareDownloadFailed))
+            .attach_printable(format!(
+                "Downloaded release {} is not the latest release {}",
+                downloaded.name,
+                latest_release_name,
+            ));
+    }
+
+    if status.installed.as_ref() == Some(&downloaded) {
+        return Ok(LatestRelease::AlreadyInstalled(downloaded));
+    }
+
+    let rolled_back = state.update_manager()
+        .is_rolled_back(state.config(), &downloaded)
+        .await
+        .change_context(ScheduledTaskError::UpdateManager)?;
+    if rolled_back {
+        return Ok(LatestRelease::RolledBack(downloaded));
+    }
+
+    match SoftwareVersion::find_from_text(&downloaded.name) {
+        Some(version) if config.is_software_update_version_allowed(version) =>
+            Ok(LatestRelease::Installable(downloaded)),
+        _ => Ok(LatestRelease::VersionNotAllowed(downloaded)),
+    }
+}
+
 pub struct ScheduledTaskManagerInternalState {
     sender: mpsc::Sender<ScheduledTaskManagerMessage>,
     receiver: mpsc::Receiver<ScheduledTaskManagerMessage>,
@@ -252,24 +332,47 @@ struct ScheduledTaksManagerInternal {
 
 impl ScheduledTaksManagerInternal {
     async fn run_scheduled_tasks(&self) {
-        let mut state = self.internal_state.lock().await;
-        let result = if state.system_reboot.is_some() {
+        let (system_reboot, backend_restart, software_update) = {
+            let mut state = self.internal_state.lock().await;
+            (
+                state.system_reboot.take(),
+                state.backend_restart.take(),
+                state.software_update.take(),
+            )
+        };
+
+        let mut restart_backend = backend_restart.is_some();
+
+        if software_update.is_some() {
+            let result = self.run_software_update().await;
+            if result.outcome == ScheduledSoftwareUpdateOutcome::Installed {
+                restart_backend = true;
+            }
+            let mut state = self.internal_state.lock().await;
+            state.software_update_history.push(result);
+            if state.software_update_history.len() > SOFTWARE_UPDATE_HISTORY_MAX_LEN {
+                state.software_update_history.remove(0);
+            }
+        }
+
+        let result = if system_reboot.is_some() {
             self.state
                 .task_manager()
-                .send_message(ManualTaskType::SystemReboot)
+                .send_message(TaskManagerMessage::ManualTask(ManualTaskType::SystemReboot))
                 .await
-        } else if state.backend_restart.is_some() {
+        } else if restart_backend {
             self.state
                 .task_manager()
-                .send_message(ManualTaskType::BackendRestart)
+                .send_message(TaskManagerMessage::ManualTask(ManualTaskType::BackendRestart))
                 .await
         } else {
+            if software_update.is_some() {
+                // Remove the maintenance notification
+                self.state.refresh_state_to_backend().await;
+            }
             return;
         };
 
-        state.backend_restart = None;
-        state.system_reboot = None;
-
         match result {
             Ok(()) => (),
             Err(e) => {
@@ -278,6 +381,60 @@ impl ScheduledTaksManagerInternal {
         }
     }
 
+    async fn run_software_update(&self) -> ScheduledSoftwareUpdateResult {
+        let result = match self.run_software_update_impl().await {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("Scheduled software update failed. Error: {:?}", e);
+                ScheduledSoftwareUpdateResult {
+                    time: UnixTime::current_time(),
+                    outcome: ScheduledSoftwareUpdateOutcome::Failed,
+                    release: None,
+                    details: Some(e.current_context().to_string()),
+                }
+            }
+        };
+        info!("Scheduled software update result: {:?}", result);
+        result
+    }
+
+    async fn run_software_update_impl(&self) -> Result<ScheduledSoftwareUpdateResult, ScheduledTaskError> {
+        let result = |outcome, release, details| ScheduledSoftwareUpdateResult {
+            time: UnixTime::current_time(),
+            outcome,
+            release: Some(release),
+            details,
+        };
+
+        let release = match download_latest_release(&self.state, &self.config).await? {
+            LatestRelease::AlreadyInstalled(release) =>
+                return Ok(result(ScheduledSoftwareUpdateOutcome::AlreadyInstalled, release, None)),
+            LatestRelease::VersionNotAllowed(release) => {
+                let details = match SoftwareVersion::find_from_text(&release.name) {
+                    Some(version) => format!("Version {} is not allowed", version),
+                    None => "Version not found from the release name".to_string(),
+                };
+                return Ok(result(ScheduledSoftwareUpdateOutcome::VersionNotAllowed, release, Some(details)));
+            }
+            LatestRelease::RolledBack(release) =>
+                return Ok(result(ScheduledSoftwareUpdateOutcome::RolledBack, release, None)),
+            LatestRelease::Installable(release) => release,
+        };
+
+        self.state.update_manager()
+            .send_message_and_wait(SoftwareUpdateTaskType::Install(release.clone()))
+            .await
+            .change_context(ScheduledTaskError::UpdateManager)?;
+
+        let status = self.state.update_manager().read_state().await;
+        if status.installed.as_ref() != Some(&release) {
+            return Err(report!(ScheduledTaskError::SoftwareInstallFailed))
+                .attach_printable(release.name);
+        }
+
+        Ok(result(ScheduledSoftwareUpdateOutcome::Installed, release, None))
+    }
+
     async fn handle_message(&self, message: ScheduledTaskManagerMessage) {
         let result = match message {
             ScheduledTaskManagerMessage::Schedule { task, notify_backend } =>
@@ -286,6 +443,8 @@ impl ScheduledTaksManagerInternal {
                         self.schedule_backend_restart(notify_backend).await,
                     ScheduledTaskType::SystemReboot =>
                         self.schedule_system_reboot(notify_backend).await,
+                    ScheduledTaskType::SoftwareUpdate =>
+                        self.schedule_software_update(notify_backend).await,
                 }
             ScheduledTaskManagerMessage::Unschedule { task } =>
                 match task {
@@ -293,6 +452,8 @@ impl ScheduledTaksManagerInternal {
                         self.unschedule_backend_restart().await,
                     ScheduledTaskType::SystemReboot =>
                         self.unschedule_system_reboot().await,
+                    ScheduledTaskType::SoftwareUpdate =>
+                        self.unschedule_software_update().await,
                 }
         };
 
@@ -328,6 +489,21 @@ impl ScheduledTaksManagerInternal {
         Ok(())
     }
 
+    async fn schedule_software_update(&self, notify_backend: bool) -> Result<(), ScheduledTaskError> {
+        if !self.config.allow_software_update {
+            return Ok(());
+        }
+
+        if self.state.config().software_update_provider().is_none() {
+            return Err(report!(ScheduledTaskError::ConfigError))
+                .attach_printable("Software update config is missing");
+        }
+
+        self.internal_state.lock().await.software_update =
+            Some(self.new_maintenance_task(notify_backend)?);
+        Ok(())
+    }
+
     async fn unschedule_backend_restart(&self) -> Result<(), ScheduledTaskError> {
         self.internal_state.lock().await.backend_restart = None;
         Ok(())
@@ -338,6 +514,11 @@ impl ScheduledTaksManagerInternal {
         Ok(())
     }
 
+    async fn unschedule_software_update(&self) -> Result<(), ScheduledTaskError> {
+        self.internal_state.lock().await.software_update = None;
+        Ok(())
+    }
+
     fn new_maintenance_task(&self, notify_backend: bool) -> Result<MaintenanceTask, ScheduledTaskError> {
         Ok(MaintenanceTask {
             time: self.task_start_time()?,
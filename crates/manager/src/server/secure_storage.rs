@@ -0,0 +1,561 @@
+//! Secure storage encryption key rotation and secure storage expansion
+//!
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::{ExitStatus, Stdio},
+    time::UNIX_EPOCH,
+};
+
+use base64::Engine;
+use error_stack::{report, Result, ResultExt};
+use manager_api::protocol::RequestSenderCmds;
+use manager_config::file::ServerEncryptionKey;
+use manager_model::{
+    ManagerInstanceName, SecureStorageAuditEntry, SecureStorageAuditLog,
+    SecureStorageEncryptionKey, SecureStorageEncryptionKeyCommit, SecureStorageEncryptionKeyInfo,
+    SecureStorageExpansion, SecureStorageOperation,
+};
+use ring::rand::SecureRandom;
+use sha2::Digest;
+use simple_backend_model::UnixTime;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    process::Command,
+    sync::Mutex,
+};
+use tracing::{error, info, warn};
+
+use super::app::S;
+use crate::api::{client::LocalOrRemoteApiClient, GetApiManager, GetConfig};
+
+const AUDIT_LOG_FILE_NAME: &str = "secure_storage_audit.jsonl";
+const AUDIT_LOG_MAX_RETURNED_ENTRIES: usize = 100;
+const ENCRYPTION_KEY_LENGTH_BYTES: usize = 32;
+
+#[derive(thiserror::Error, Debug)]
+pub enum SecureStorageError {
+    #[error("Secure storage is not configured")]
+    NotConfigured,
+
+    #[error("Encryption key not found")]
+    KeyNotFound,
+
+    #[error("Pending encryption key not found")]
+    PendingKeyNotFound,
+
+    #[error("Pending encryption key does not match")]
+    PendingKeyMismatch,
+
+    #[error("Getting key failed")]
+    GetKeyFailed,
+
+    #[error("Key storage request failed")]
+    KeyStorageRequestFailed,
+
+    #[error("Key generation failed")]
+    KeyGenerationFailed,
+
+    #[error("File reading failed")]
+    FileReadingFailed,
+
+    #[error("File writing failed")]
+    FileWritingFailed,
+
+    #[error("Script not found")]
+    ScriptNotFound,
+
+    #[error("Process start failed")]
+    ProcessStartFailed,
+
+    #[error("Process stdin writing failed")]
+    ProcessStdinFailed,
+
+    #[error("Command failed with exit status: {0}")]
+    CommandFailed(ExitStatus),
+}
+
+pub struct SecureStorageManager<'a> {
+    state: &'a S,
+    /// Prevents running multiple secure storage operations
+    /// at the same time.
+    operation_lock: &'a Mutex<()>,
+    /// Prevents running multiple key storage operations at the
+    /// same time. Separate lock is needed because this manager
+    /// instance might be the key storage manager of itself.
+    key_storage_lock: &'a Mutex<()>,
+}
+
+impl<'a> SecureStorageManager<'a> {
+    pub fn new(
+        state: &'a S,
+        operation_lock: &'a Mutex<()>,
+        key_storage_lock: &'a Mutex<()>,
+    ) -> Self {
+        Self { state, operation_lock, key_storage_lock }
+    }
+
+    /// Change secure storage to use a new key.
+    ///
+    /// 1. Key storage manager creates a new pending key.
+    /// 2. New key is added to the secure storage.
+    /// 3. Key storage manager replaces the current key with the
+    ///    pending key. If that fails, the new key is removed from
+    ///    the secure storage.
+    /// 4. Previous key is removed from the secure storage.
+    ///
+    /// Secure storage can be opened with the key from the key storage
+    /// manager even if the rotation is interrupted.
+    pub async fn rotate_encryption_key(&self) -> Result<(), SecureStorageError> {
+        let _lock = self.operation_lock.lock().await;
+        let result = self.rotate_encryption_key_locked().await;
+        self.audit(SecureStorageOperation::KeyRotation, self.state.config().manager_name(), &result)
+            .await;
+        result
+    }
+
+    async fn rotate_encryption_key_locked(&self) -> Result<(), SecureStorageError> {
+        let key_storage = self.key_storage_manager_name()?;
+        let manager_name = self.state.config().manager_name();
+
+        let current_key = self.state
+            .api_manager()
+            .get_encryption_key()
+            .await
+            .change_context(SecureStorageError::GetKeyFailed)?;
+
+        let new_key = LocalOrRemoteApiClient::new(key_storage.clone(), self.state)
+            .create_pending_secure_storage_encryption_key(manager_name.clone())
+            .await
+            .change_context(SecureStorageError::KeyStorageRequestFailed)?;
+
+        let keys = format!("{}\n{}\n", current_key.key, new_key.key);
+        run_script(self.state.config().script_locations().add_encryption_key(), &[], &keys)
+            .await?;
+        info!("New encryption key added to secure storage");
+
+        let commit = SecureStorageEncryptionKeyCommit {
+            key_owner: manager_name,
+            pending_key_sha256: encryption_key_sha256(&new_key.key),
+        };
+        let commit_result = LocalOrRemoteApiClient::new(key_storage, self.state)
+            .commit_pending_secure_storage_encryption_key(commit)
+            .await
+            .change_context(SecureStorageError::KeyStorageRequestFailed);
+
+        if let Err(e) = commit_result {
+            return Err(self.remove_uncommitted_key(&new_key, e).await);
+        }
+
+        run_script(
+            self.state.config().script_locations().remove_encryption_key(),
+            &[],
+            &format!("{}\n", current_key.key),
+        )
+            .await
+            .attach_printable("New key is in use but removing the previous key failed")?;
+        info!("Previous encryption key removed from secure storage");
+
+        Ok(())
+    }
+
+    /// Remove the new key from the secure storage if the key storage
+    /// manager did not commit it. The commit might have succeeded even
+    /// if the request failed, so the current key is checked first.
+    async fn remove_uncommitted_key(
+        &self,
+        new_key: &SecureStorageEncryptionKey,
+        commit_error: error_stack::Report<SecureStorageError>,
+    ) -> error_stack::Report<SecureStorageError> {
+        let current_key = match self.state.api_manager().get_encryption_key().await {
+            Ok(key) => key,
+            Err(e) => {
+                error!("Checking the current key failed. Error: {:?}", e);
+                return commit_error.attach_printable(
+                    "Key commit state is unknown, so the new key was not removed from the secure storage"
+                );
+            }
+        };
+
+        if current_key.key == new_key.key {
+            return commit_error.attach_printable(
+                "Key storage manager has the new key but the previous key was not removed from the secure storage"
+            );
+        }
+
+        let remove_result = run_script(
+            self.state.config().script_locations().remove_encryption_key(),
+            &[],
+            &format!("{}\n", new_key.key),
+        ).await;
+
+        match remove_result {
+            Ok(()) => {
+                info!("Uncommitted encryption key removed from secure storage");
+                commit_error
+            }
+            Err(e) => {
+                error!("Removing the uncommitted key failed. Error: {:?}", e);
+                commit_error.attach_printable("Removing the uncommitted key failed")
+            }
+        }
+    }
+
+    pub async fn expand(&self, expansion: SecureStorageExpansion) -> Result<(), SecureStorageError> {
+        let _lock = self.operation_lock.lock().await;
+        let result = self.expand_locked(expansion).await;
+        self.audit(SecureStorageOperation::Expansion, self.state.config().manager_name(), &result)
+            .await;
+        result
+    }
+
+    async fn expand_locked(&self, expansion: SecureStorageExpansion) -> Result<(), SecureStorageError> {
+        self.key_storage_manager_name()?;
+
+        let current_key = self.state
+            .api_manager()
+            .get_encryption_key()
+            .await
+            .change_context(SecureStorageError::GetKeyFailed)?;
+
+        run_script(
+            self.state.config().script_locations().expand_encryption(),
+            &[&expansion.size_increase_mib.to_string()],
+            &format!("{}\n", current_key.key),
+        )
+            .await?;
+        info!("Secure storage expanded {} MiB", expansion.size_increase_mib);
+
+        Ok(())
+    }
+
+    pub async fn key_info(
+        &self,
+        key_owner: &ManagerInstanceName,
+    ) -> Result<SecureStorageEncryptionKeyInfo, SecureStorageError> {
+        let key = self.find_key(key_owner)?;
+
+        let modified = tokio::fs::metadata(&key.key_path)
+            .await
+            .change_context(SecureStorageError::FileReadingFailed)?
+            .modified()
+            .change_context(SecureStorageError::FileReadingFailed)?;
+        let created = modified
+            .duration_since(UNIX_EPOCH)
+            .change_context(SecureStorageError::FileReadingFailed)?
+            .as_secs() as i64;
+        let created = UnixTime::new(created);
+
+        Ok(SecureStorageEncryptionKeyInfo {
+            created,
+            age_seconds: UnixTime::current_time().ut.saturating_sub(created.ut).max(0),
+            rotation_pending: pending_key_path(&key.key_path).exists(),
+        })
+    }
+
+    pub async fn create_pending_key(
+        &self,
+        key_owner: &ManagerInstanceName,
+    ) -> Result<SecureStorageEncryptionKey, SecureStorageError> {
+        let _lock = self.key_storage_lock.lock().await;
+        let result = self.create_pending_key_internal(key_owner).await;
+        self.audit(SecureStorageOperation::PendingKeyCreation, key_owner.clone(), &result)
+            .await;
+        result
+    }
+
+    async fn create_pending_key_internal(
+        &self,
+        key_owner: &ManagerInstanceName,
+    ) -> Result<SecureStorageEncryptionKey, SecureStorageError> {
+        let key = self.find_key(key_owner)?;
+        create_pending_key_file(&key.key_path).await
+    }
+
+    pub async fn commit_pending_key(
+        &self,
+        commit: SecureStorageEncryptionKeyCommit,
+    ) -> Result<(), SecureStorageError> {
+        let _lock = self.key_storage_lock.lock().await;
+        let result = self.commit_pending_key_internal(&commit).await;
+        self.audit(SecureStorageOperation::PendingKeyCommit, commit.key_owner, &result)
+            .await;
+        result
+    }
+
+    async fn commit_pending_key_internal(
+        &self,
+        commit: &SecureStorageEncryptionKeyCommit,
+    ) -> Result<(), SecureStorageError> {
+        let key = self.find_key(&commit.key_owner)?;
+        commit_pending_key_file(&key.key_path, &commit.pending_key_sha256).await
+    }
+
+    pub async fn audit_log(&self) -> Result<SecureStorageAuditLog, SecureStorageError> {
+        let path = self.audit_log_path();
+        if !path.exists() {
+            return Ok(SecureStorageAuditLog::default());
+        }
+
+        let file = tokio::fs::File::open(&path)
+            .await
+            .change_context(SecureStorageError::FileReadingFailed)?;
+        let mut lines = BufReader::new(file).lines();
+        let mut entries = vec![];
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .change_context(SecureStorageError::FileReadingFailed)?
+        {
+            match serde_json::from_str::<SecureStorageAuditEntry>(&line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => warn!("Invalid secure storage audit log entry. Error: {}", e),
+            }
+        }
+
+        let skip = entries.len().saturating_sub(AUDIT_LOG_MAX_RETURNED_ENTRIES);
+        entries.drain(..skip);
+
+        Ok(SecureStorageAuditLog { entries })
+    }
+
+    async fn audit<T>(
+        &self,
+        operation: SecureStorageOperation,
+        key_owner: ManagerInstanceName,
+        result: &Result<T, SecureStorageError>,
+    ) {
+        let entry = SecureStorageAuditEntry {
+            time: UnixTime::current_time(),
+            operation,
+            key_owner,
+            successful: result.is_ok(),
+            details: result.as_ref().err().map(|e| format!("{:#}", e)),
+        };
+
+        if let Err(e) = self.append_audit_entry(&entry).await {
+            warn!("Secure storage audit log writing failed. Entry: {:?}, Error: {:?}", entry, e);
+        }
+    }
+
+    async fn append_audit_entry(&self, entry: &SecureStorageAuditEntry) -> Result<(), SecureStorageError> {
+        let mut line = serde_json::to_string(entry)
+            .change_context(SecureStorageError::FileWritingFailed)?;
+        line.push('\n');
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(self.audit_log_path())
+            .await
+            .change_context(SecureStorageError::FileWritingFailed)?;
+        file.write_all(line.as_bytes())
+            .await
+            .change_context(SecureStorageError::FileWritingFailed)
+    }
+
+    fn audit_log_path(&self) -> PathBuf {
+        self.state.config().storage_dir().join(AUDIT_LOG_FILE_NAME)
+    }
+
+    fn key_storage_manager_name(&self) -> Result<ManagerInstanceName, SecureStorageError> {
+        self.state
+            .config()
+            .secure_storage_config()
+            .map(|v| v.key_storage_manager_name.clone())
+            .ok_or_else(|| report!(SecureStorageError::NotConfigured))
+    }
+
+    fn find_key(&self, key_owner: &ManagerInstanceName) -> Result<&ServerEncryptionKey, SecureStorageError> {
+        self.state
+            .config()
+            .encryption_keys()
+            .iter()
+            .find(|v| v.manager_name == *key_owner)
+            .ok_or_else(|| report!(SecureStorageError::KeyNotFound))
+            .attach_printable(key_owner.to_string())
+    }
+}
+
+fn pending_key_path(key_path: &Path) -> PathBuf {
+    let mut path: OsString = key_path.as_os_str().to_owned();
+    path.push(".pending");
+    PathBuf::from(path)
+}
+
+/// Lowercase hex encoded SHA-256 of the key.
+fn encryption_key_sha256(key: &str) -> String {
+    let hash = sha2::Sha256::digest(key.trim().as_bytes());
+    base16ct::lower::encode_string(&hash)
+}
+
+async fn create_pending_key_file(
+    key_path: &Path,
+) -> Result<SecureStorageEncryptionKey, SecureStorageError> {
+    let mut bytes = [0u8; ENCRYPTION_KEY_LENGTH_BYTES];
+    ring::rand::SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| report!(SecureStorageError::KeyGenerationFailed))?;
+    let new_key = base64::engine::general_purpose::STANDARD.encode(bytes);
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(pending_key_path(key_path))
+        .await
+        .change_context(SecureStorageError::FileWritingFailed)?;
+    file.write_all(new_key.as_bytes())
+        .await
+        .change_context(SecureStorageError::FileWritingFailed)?;
+    file.sync_all()
+        .await
+        .change_context(SecureStorageError::FileWritingFailed)?;
+
+    Ok(SecureStorageEncryptionKey { key: new_key })
+}
+
+/// Replace the key with the pending key if the pending key
+/// matches the hash.
+async fn commit_pending_key_file(
+    key_path: &Path,
+    pending_key_sha256: &str,
+) -> Result<(), SecureStorageError> {
+    let pending = pending_key_path(key_path);
+
+    if !pending.exists() {
+        return Err(report!(SecureStorageError::PendingKeyNotFound));
+    }
+
+    let pending_key = tokio::fs::read_to_string(&pending)
+        .await
+        .change_context(SecureStorageError::FileReadingFailed)?;
+
+    if encryption_key_sha256(&pending_key) != pending_key_sha256 {
+        return Err(report!(SecureStorageError::PendingKeyMismatch));
+    }
+
+    tokio::fs::rename(&pending, key_path)
+        .await
+        .change_context(SecureStorageError::FileWritingFailed)
+}
+
+async fn run_script(script: &Path, args: &[&str], stdin_text: &str) -> Result<(), SecureStorageError> {
+    if !script.exists() {
+        return Err(report!(SecureStorageError::ScriptNotFound))
+            .attach_printable(script.display().to_string());
+    }
+
+    let mut c = Command::new("sudo")
+        .arg(script)
+        .args(args)
+        .stdin(Stdio::piped())
+        .spawn()
+        .change_context(SecureStorageError::ProcessStartFailed)?;
+
+    if let Some(stdin) = c.stdin.as_mut() {
+        stdin
+            .write_all(stdin_text.as_bytes())
+            .await
+            .change_context(SecureStorageError::ProcessStdinFailed)?;
+        stdin
+            .shutdown()
+            .await
+            .change_context(SecureStorageError::ProcessStdinFailed)?;
+    }
+
+    let status = c
+        .wait()
+        .await
+        .change_context(SecureStorageError::ProcessStartFailed)?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(report!(SecureStorageError::CommandFailed(status)))
+            .attach_printable(script.display().to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        commit_pending_key_file, create_pending_key_file, encryption_key_sha256,
+        pending_key_path, SecureStorageError,
+    };
+    use crate::test_utils::TestDir;
+
+    fn test_dir(test_name: &str) -> TestDir {
+        let d = TestDir::new("secure-storage", test_name);
+        d.write("key", "current-key");
+        d
+    }
+
+    fn key(d: &TestDir) -> String {
+        std::fs::read_to_string(d.path("key")).unwrap()
+    }
+
+    #[test]
+    fn key_hash_ignores_surrounding_whitespace() {
+        assert_eq!(encryption_key_sha256("key\n"), encryption_key_sha256("key"));
+        assert_ne!(encryption_key_sha256("key1"), encryption_key_sha256("key2"));
+        assert_eq!(encryption_key_sha256("key").len(), 64);
+    }
+
+    #[tokio::test]
+    async fn pending_key_with_matching_hash_replaces_key() {
+        let d = test_dir("commit");
+        let new_key = create_pending_key_file(&d.path("key")).await.unwrap();
+        assert_eq!(key(&d), "current-key");
+
+        commit_pending_key_file(&d.path("key"), &encryption_key_sha256(&new_key.key))
+            .await
+            .unwrap();
+
+        assert_eq!(key(&d), new_key.key);
+        assert!(!pending_key_path(&d.path("key")).exists());
+    }
+
+    #[tokio::test]
+    async fn pending_key_with_other_hash_is_not_committed() {
+        let d = test_dir("mismatch");
+        create_pending_key_file(&d.path("key")).await.unwrap();
+
+        let e = commit_pending_key_file(&d.path("key"), &encryption_key_sha256("other-key"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), SecureStorageError::PendingKeyMismatch));
+        assert_eq!(key(&d), "current-key");
+        assert!(pending_key_path(&d.path("key")).exists());
+    }
+
+    #[tokio::test]
+    async fn replaced_pending_key_is_not_committed() {
+        let d = test_dir("replaced");
+        let first = create_pending_key_file(&d.path("key")).await.unwrap();
+        let second = create_pending_key_file(&d.path("key")).await.unwrap();
+        assert_ne!(first.key, second.key);
+
+        let e = commit_pending_key_file(&d.path("key"), &encryption_key_sha256(&first.key))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), SecureStorageError::PendingKeyMismatch));
+        assert_eq!(key(&d), "current-key");
+    }
+
+    #[tokio::test]
+    async fn commit_without_pending_key_is_error() {
+        let d = test_dir("no-pending");
+
+        let e = commit_pending_key_file(&d.path("key"), &encryption_key_sha256("key"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), SecureStorageError::PendingKeyNotFound));
+        assert_eq!(key(&d), "current-key");
+    }
+}
@@ -6,14 +6,14 @@ use std::{
 };
 
 use error_stack::{Result, ResultExt};
-use manager_model::ManualTaskType;
+use manager_model::{BackendDataSnapshotName, ManualTaskType};
 use tokio::{process::Command, sync::mpsc, task::JoinHandle};
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 use super::{
-    app::S, backend_controller::BackendController, client::ApiManager, state::MountStateStorage, update::backend::reset_backend_data, ServerQuitWatcher
+    app::S, backend_controller::BackendController, client::ApiManager, state::MountStateStorage, update::backend::{backend_data_snapshot_dir, create_backend_data_snapshot, reset_backend_data, restore_backend_data_snapshot}, ServerQuitWatcher
 };
-use crate::{api::GetConfig, server::mount::MountMode};
+use crate::{api::{GetConfig, GetUpdateManager}, server::mount::MountMode};
 
 #[derive(thiserror::Error, Debug)]
 pub enum TaskError {
@@ -49,13 +49,19 @@ pub enum TaskError {
 
     #[error("Backend utils")]
     BackendUtils,
+
+    #[error("Backend health check failed")]
+    HealthCheckFailed,
+
+    #[error("Update manager error")]
+    UpdateManager,
 }
 
 #[derive(Debug)]
 pub struct TaskManagerQuitHandle {
     task: JoinHandle<()>,
     // Make sure Receiver works until the manager quits.
-    _sender: mpsc::Sender<ManualTaskType>,
+    _sender: mpsc::Sender<TaskManagerMessage>,
 }
 
 impl TaskManagerQuitHandle {
@@ -69,13 +75,20 @@ impl TaskManagerQuitHandle {
     }
 }
 
+#[derive(Debug)]
+pub enum TaskManagerMessage {
+    ManualTask(ManualTaskType),
+    /// Replace backend data with the snapshot and restart the backend.
+    RestoreBackendDataSnapshot(BackendDataSnapshotName),
+}
+
 #[derive(Debug, Clone)]
 pub struct TaskManagerHandle {
-    sender: mpsc::Sender<ManualTaskType>,
+    sender: mpsc::Sender<TaskManagerMessage>,
 }
 
 impl TaskManagerHandle {
-    pub async fn send_message(&self, message: ManualTaskType) -> Result<(), TaskError> {
+    pub async fn send_message(&self, message: TaskManagerMessage) -> Result<(), TaskError> {
         self.sender
             .send(message)
             .await
@@ -86,12 +99,12 @@ impl TaskManagerHandle {
 }
 
 pub struct TaskManagerInternalState {
-    sender: mpsc::Sender<ManualTaskType>,
-    receiver: mpsc::Receiver<ManualTaskType>,
+    sender: mpsc::Sender<TaskManagerMessage>,
+    receiver: mpsc::Receiver<TaskManagerMessage>,
 }
 
 pub struct TaskManager {
-    receiver: mpsc::Receiver<ManualTaskType>,
+    receiver: mpsc::Receiver<TaskManagerMessage>,
     state: S,
     mount_state: Arc<MountStateStorage>,
 }
@@ -151,14 +164,18 @@ impl TaskManager {
         }
     }
 
-    pub async fn handle_message(&self, message: ManualTaskType) {
-        let result = match message {
-            ManualTaskType::SystemReboot =>
+    pub async fn handle_message(&self, message: TaskManagerMessage) {
+        let result = match &message {
+            TaskManagerMessage::ManualTask(ManualTaskType::SystemReboot) =>
                 self.run_reboot().await,
-            ManualTaskType::BackendRestart =>
+            TaskManagerMessage::ManualTask(ManualTaskType::BackendRestart) =>
                 self.backend_restart_and_optional_data_reset(false).await,
-            ManualTaskType::BackendDataReset =>
+            TaskManagerMessage::ManualTask(ManualTaskType::BackendDataReset) =>
                 self.backend_restart_and_optional_data_reset(true).await,
+            TaskManagerMessage::ManualTask(ManualTaskType::BackendRollback) =>
+                self.backend_rollback().await,
+            TaskManagerMessage::RestoreBackendDataSnapshot(snapshot) =>
+                self.backend_data_snapshot_restore(snapshot).await,
         };
 
         match result {
@@ -215,13 +232,102 @@ impl TaskManager {
             .await
             .change_context(TaskError::StopBackendFailed)?;
 
-        if data_reset {
-            if let Some(config) = self.state.config().manual_tasks_config().allow_backend_data_reset {
-                reset_backend_data(&config.backend_data_dir)
-                    .await
-                    .change_context(TaskError::BackendUtils)?
-            } else {
-                warn!("Skipping backend data reset because it is not enabled from config file");
+        let data_reset_result = if data_reset {
+            self.backend_data_reset().await
+        } else {
+            Ok(())
+        };
+
+        // Start the backend even if the data reset failed.
+        let start_result = start_backend_with_automatic_rollback(&self.state).await;
+        data_reset_result?;
+        start_result
+    }
+
+    async fn backend_data_reset(&self) -> Result<(), TaskError> {
+        let Some(config) = self.state.config().manual_tasks_config().allow_backend_data_reset else {
+            warn!("Skipping backend data reset because it is not enabled from config file");
+            return Ok(());
+        };
+
+        let snapshot = create_backend_data_snapshot(
+            &config.backend_data_dir,
+            &backend_data_snapshot_dir(self.state.config()),
+            &config.snapshot_retention,
+        )
+            .await
+            .change_context(TaskError::BackendUtils)?;
+        info!("Backend data snapshot {} created", snapshot.name);
+        reset_backend_data(&config.backend_data_dir)
+            .await
+            .change_context(TaskError::BackendUtils)
+    }
+
+    async fn backend_data_snapshot_restore(
+        &self,
+        snapshot: &BackendDataSnapshotName,
+    ) -> Result<(), TaskError> {
+        let Some(config) = self.state.config().manual_tasks_config().allow_backend_data_reset else {
+            warn!("Skipping backend data snapshot restore because backend data reset is not enabled from config file");
+            return Ok(());
+        };
+
+        let backend_controller = BackendController::new(self.state.config());
+
+        backend_controller
+            .stop_backend()
+            .await
+            .change_context(TaskError::StopBackendFailed)?;
+
+        let restore_result = restore_backend_data_snapshot(
+            &config.backend_data_dir,
+            &backend_data_snapshot_dir(self.state.config()),
+            &config.snapshot_retention,
+            snapshot,
+        )
+            .await
+            .change_context(TaskError::BackendUtils);
+
+        match &restore_result {
+            Ok(previous_data) => {
+                info!("Backend data snapshot {} restored", snapshot.name);
+                if let Some(previous_data) = previous_data {
+                    info!("Previous backend data is in snapshot {}", previous_data.name);
+                }
+            }
+            Err(e) => {
+                // Start the backend even if the restoring failed.
+                error!("Backend data snapshot restoring failed. Error: {:?}", e);
+            }
+        }
+
+        let start_result = start_backend_with_automatic_rollback(&self.state).await;
+        restore_result?;
+        start_result
+    }
+
+    async fn backend_rollback(&self) -> Result<(), TaskError> {
+        let backend_controller = BackendController::new(self.state.config());
+
+        backend_controller
+            .stop_backend()
+            .await
+            .change_context(TaskError::StopBackendFailed)?;
+
+        let result = self.state
+            .update_manager()
+            .rollback(self.state.config(), "Manual rollback".to_string())
+            .await
+            .change_context(TaskError::UpdateManager);
+
+        match result {
+            Ok(rollback) => {
+                info!("Backend rollback completed: {:?}", rollback);
+                self.state.send_software_rollback_event(rollback).await;
+            }
+            Err(e) => {
+                // Start the backend even if the rollback failed.
+                error!("Backend rollback failed. Error: {:?}", e);
             }
         }
 
@@ -235,3 +341,140 @@ impl TaskManager {
         ApiManager::new(&self.state)
     }
 }
+
+/// Start backend and if the installed backend is not yet
+/// checked, do the health check. If the backend start or the health check
+/// fails, restore the previously installed backend and start it.
+/// The backend is started also when the restoring fails.
+pub async fn start_backend_with_automatic_rollback(state: &S) -> Result<(), TaskError> {
+    let config = state.config();
+    let backend_controller = BackendController::new(config);
+
+    let pending_health_check = config
+        .software_update_provider()
+        .and_then(|v| v.health_check.as_ref())
+        .filter(|_| state.update_manager().is_health_check_pending(config));
+
+    let start_result = backend_controller
+        .start_backend()
+        .await
+        .change_context(TaskError::StartBackendFailed);
+
+    let error = match after_backend_start(start_result, pending_health_check) {
+        AfterBackendStart::Done(result) => return result,
+        AfterBackendStart::HealthCheck(health_check) => {
+            match backend_controller.wait_backend_health(health_check).await {
+                Ok(()) => {
+                    info!("Backend health check completed");
+                    return state
+                        .update_manager()
+                        .health_check_completed(config)
+                        .await
+                        .change_context(TaskError::UpdateManager);
+                }
+                Err(e) => {
+                    error!("Backend health check failed, restoring previous backend. Error: {:?}", e);
+                    e.change_context(TaskError::HealthCheckFailed)
+                }
+            }
+        }
+        AfterBackendStart::Rollback(e) => {
+            error!("Backend start failed, restoring previous backend. Error: {:?}", e);
+            e
+        }
+    };
+
+    backend_controller
+        .stop_backend()
+        .await
+        .change_context(TaskError::StopBackendFailed)?;
+
+    let rollback = state
+        .update_manager()
+        .rollback(config, "Backend start or health check failed after software update".to_string())
+        .await
+        .change_context(TaskError::UpdateManager);
+
+    let rollback_error = match rollback {
+        Ok(rollback) => {
+            info!("Backend rollback completed: {:?}", rollback);
+            state.send_software_rollback_event(rollback).await;
+            None
+        }
+        Err(e) => {
+            // Start the backend even if the rollback failed.
+            error!("Backend rollback failed. Error: {:?}", e);
+            Some(e)
+        }
+    };
+
+    backend_controller
+        .start_backend()
+        .await
+        .change_context(TaskError::StartBackendFailed)?;
+
+    if let Some(rollback_error) = rollback_error {
+        return Err(rollback_error.attach_printable(format!("{:?}", error)));
+    }
+
+    Err(error)
+}
+
+#[derive(Debug, PartialEq)]
+enum AfterBackendStart<T, E> {
+    /// Return the backend start result.
+    Done(std::result::Result<(), E>),
+    /// Check the health of the not yet checked backend.
+    HealthCheck(T),
+    /// Starting the not yet checked backend failed, so restore
+    /// the previous backend.
+    Rollback(E),
+}
+
+fn after_backend_start<T, E>(
+    start_result: std::result::Result<(), E>,
+    pending_health_check: Option<T>,
+) -> AfterBackendStart<T, E> {
+    match (start_result, pending_health_check) {
+        (Ok(()), Some(health_check)) => AfterBackendStart::HealthCheck(health_check),
+        (Err(e), Some(_)) => AfterBackendStart::Rollback(e),
+        (result, None) => AfterBackendStart::Done(result),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{after_backend_start, AfterBackendStart};
+
+    #[test]
+    fn started_backend_without_pending_health_check_is_done() {
+        assert_eq!(
+            after_backend_start::<(), ()>(Ok(()), None),
+            AfterBackendStart::Done(Ok(())),
+        );
+    }
+
+    #[test]
+    fn failed_start_without_pending_health_check_returns_error() {
+        assert_eq!(
+            after_backend_start::<(), &str>(Err("start failed"), None),
+            AfterBackendStart::Done(Err("start failed")),
+        );
+    }
+
+    #[test]
+    fn started_backend_with_pending_health_check_is_checked() {
+        assert_eq!(
+            after_backend_start::<&str, ()>(Ok(()), Some("health check")),
+            AfterBackendStart::HealthCheck("health check"),
+        );
+    }
+
+    #[test]
+    fn failed_start_with_pending_health_check_is_rolled_back() {
+        assert_eq!(
+            after_backend_start(Err("start failed"), Some("health check")),
+            AfterBackendStart::Rollback("start failed"),
+        );
+    }
+}
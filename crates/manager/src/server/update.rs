@@ -9,9 +9,12 @@ use std::{
 use archive::extract_backend_binary;
 use backend::BackendUtils;
 use error_stack::{report, Result, ResultExt};
-use github::GitHubApi;
-use manager_model::{SoftwareInfo, SoftwareUpdateState, SoftwareUpdateStatus, SoftwareUpdateTaskType};
+use serde::{de::DeserializeOwned, Serialize};
+use signature::verify_release_signature;
+use source::UpdateSource;
+use manager_model::{SoftwareInfo, SoftwareRollback, SoftwareUpdateState, SoftwareUpdateStatus, SoftwareUpdateTaskType};
 use sha2::Digest;
+use simple_backend_model::UnixTime;
 use simple_backend_utils::ContextExt;
 use tokio::{sync::Mutex, task::JoinHandle};
 use tracing::{info, warn, error};
@@ -26,7 +29,12 @@ use manager_config::{file::SoftwareUpdateConfig, Config};
 
 pub mod archive;
 pub mod github;
+pub mod https_directory;
+pub mod local_directory;
+pub mod remote_manager;
 pub mod backend;
+pub mod signature;
+pub mod source;
 
 #[derive(thiserror::Error, Debug)]
 pub enum UpdateError {
@@ -88,10 +96,19 @@ pub enum UpdateError {
     #[error("GitHub API related error")]
     GitHubApi,
 
+    #[error("HTTPS directory update source related error")]
+    HttpsDirectory,
+
+    #[error("Remote manager update source related error")]
+    RemoteManager,
+
+    #[error("Software update source is not configured")]
+    UpdateSourceMissing,
+
     #[error("Software download failed. More than one matching file name found.")]
     SotwareDownloadFailedAmbiguousFileName,
 
-    #[error("Latest software with matching file name not found from GitHub")]
+    #[error("Latest software not found from the update source")]
     SoftwareDownloadFailedNoMatchingFile,
 
     #[error("Software download failed. Unknown file uploader.")]
@@ -120,6 +137,24 @@ pub enum UpdateError {
 
     #[error("Multiple matching files in the archive")]
     ArchiveMultipleMatchingFiles,
+
+    #[error("Release signing key is not configured")]
+    ReleaseSigningKeyMissing,
+
+    #[error("Invalid release signing key")]
+    InvalidReleaseSigningKey,
+
+    #[error("Release signature is missing")]
+    ReleaseSignatureMissing,
+
+    #[error("Invalid release signature")]
+    InvalidReleaseSignature,
+
+    #[error("Release signature does not match any release signing key")]
+    ReleaseSignatureMismatch,
+
+    #[error("Software update is in progress")]
+    UpdateInProgress,
 }
 
 #[derive(Debug)]
@@ -154,12 +189,142 @@ impl UpdateManagerHandle {
             .change_context(UpdateError::SendMessageFailed)
     }
 
+    /// Send message and wait until it is handled.
+    pub async fn send_message_and_wait(&self, message: SoftwareUpdateTaskType) -> Result<(), UpdateError> {
+        self.send_message(message).await?;
+        self.sender.wait_message_handled().await;
+        Ok(())
+    }
+
     pub async fn read_state(&self) -> SoftwareUpdateStatus {
         self.state
             .lock()
             .await
             .clone()
     }
+
+    /// Returns true if the installed backend has not yet passed
+    /// the health check.
+    pub fn is_health_check_pending(&self, config: &Config) -> bool {
+        UpdateDirUtils { config }
+            .health_check_pending_path()
+            .exists()
+    }
+
+    pub async fn health_check_completed(&self, config: &Config) -> Result<(), UpdateError> {
+        let path = UpdateDirUtils { config }.health_check_pending_path();
+        if path.exists() {
+            tokio::fs::remove_file(path)
+                .await
+                .change_context(UpdateError::FileRemovingFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Get installed backend info and release files if the installed
+    /// release files are available.
+    pub async fn installed_release(
+        &self,
+        config: &Config,
+    ) -> Result<Option<InstalledRelease>, UpdateError> {
+        let update_dir = UpdateDirUtils { config };
+        let Some(info) = update_dir.installed_backend_info().await? else {
+            return Ok(None);
+        };
+        let file = update_dir.installed_backend_release_path();
+        if !file.exists() {
+            return Ok(None);
+        }
+        Ok(Some(InstalledRelease {
+            info,
+            file,
+            signature: update_dir.installed_backend_release_signature_path(),
+        }))
+    }
+
+    /// Get the latest release name from the update source without
+    /// downloading the release.
+    pub async fn latest_release_name(
+        &self,
+        config: &Config,
+        client: &reqwest::Client,
+    ) -> Result<Option<String>, UpdateError> {
+        let Some(update_config) = config.software_update_provider() else {
+            return Err(UpdateError::SoftwareUpdaterConfigMissing.report());
+        };
+        let Some(source_config) = update_config.source() else {
+            return Err(report!(UpdateError::UpdateSourceMissing));
+        };
+
+        let user_agent = config.update_manager_user_agent();
+        let source = UpdateSource::new(source_config, config, client, &user_agent);
+        let asset = source.get_latest_release_asset().await?;
+        Ok(asset.map(|v| v.name))
+    }
+
+    /// Returns true if the release was removed using rollback.
+    pub async fn is_rolled_back(
+        &self,
+        config: &Config,
+        info: &SoftwareInfo,
+    ) -> Result<bool, UpdateError> {
+        let rolled_back = UpdateDirUtils { config }.rolled_back_backends().await?;
+        Ok(rolled_back.contains(info))
+    }
+
+    /// Replace installed backend with the previously installed backend.
+    /// The removed backend is recorded, so that it will not be
+    /// installed again with scheduled software update.
+    ///
+    /// The backend should be stopped before calling this.
+    pub async fn rollback(
+        &self,
+        config: &Config,
+        reason: String,
+    ) -> Result<SoftwareRollback, UpdateError> {
+        let Some(update_config) = config.software_update_provider() else {
+            return Err(UpdateError::SoftwareUpdaterConfigMissing.report());
+        };
+
+        // Keep the lock to prevent starting software update
+        // during the rollback.
+        let mut state = self.state.lock().await;
+        if state.state != SoftwareUpdateState::Idle {
+            return Err(UpdateError::UpdateInProgress.report());
+        }
+
+        let update_dir = UpdateDirUtils { config };
+        let removed = update_dir.installed_backend_info().await?;
+
+        if let Some(removed) = &removed {
+            update_dir.add_rolled_back_backend(removed.clone()).await?;
+        }
+
+        BackendUtils { config: update_config }
+            .restore_previous_backend_binary()
+            .await
+            .change_context(UpdateError::BackendUtils)?;
+
+        update_dir.restore_previous_installed_backend_info().await?;
+
+        let restored = update_dir.installed_backend_info().await?;
+        state.installed = restored.clone();
+        state.installed_signer = update_dir.installed_backend_signer().await?;
+
+        Ok(SoftwareRollback {
+            removed,
+            restored,
+            reason,
+            time: UnixTime::current_time(),
+        })
+    }
+}
+
+pub struct InstalledRelease {
+    pub info: SoftwareInfo,
+    pub file: PathBuf,
+    /// Might not exist.
+    pub signature: PathBuf,
 }
 
 #[derive(Debug)]
@@ -288,10 +453,14 @@ impl UpdateManagerInternal {
         &self,
     ) -> Result<(), UpdateError> {
         let downloaded = self.update_dir().downloaded_backend_info().await?;
+        let downloaded_signer = self.update_dir().downloaded_backend_signer().await?;
         let installed = self.update_dir().installed_backend_info().await?;
+        let installed_signer = self.update_dir().installed_backend_signer().await?;
         let mut state = self.internal_state.lock().await;
         state.downloaded = downloaded;
+        state.downloaded_signer = downloaded_signer;
         state.installed = installed;
+        state.installed_signer = installed_signer;
         Ok(())
     }
 
@@ -340,13 +509,18 @@ impl UpdateManagerInternal {
     async fn software_download_impl(
         &self,
     ) -> Result<(), UpdateError> {
-        let github_api = GitHubApi {
-            client: &self.client,
-            updater_config: &self.config,
-            user_agent: &self.user_agent,
+        let Some(source_config) = self.config.source() else {
+            return Err(report!(UpdateError::UpdateSourceMissing));
         };
 
-        let Some(asset) = github_api.get_latest_release_asset().await? else {
+        let source = UpdateSource::new(
+            source_config,
+            self.state.config(),
+            &self.client,
+            &self.user_agent,
+        );
+
+        let Some(asset) = source.get_latest_release_asset().await? else {
             return Err(report!(UpdateError::SoftwareDownloadFailedNoMatchingFile));
         };
 
@@ -359,12 +533,32 @@ impl UpdateManagerInternal {
 
         self.update_dir().remove_downloaded_backend_and_info_json().await?;
 
-        self.internal_state.lock().await.downloaded = None;
+        {
+            let mut state = self.internal_state.lock().await;
+            state.downloaded = None;
+            state.downloaded_signer = None;
+        }
 
-        github_api.download_asset(
+        let download_result = source.download(
             &asset,
-            self.update_dir().downloaded_backend_path(),
-        ).await?;
+            &self.update_dir().downloaded_backend_path(),
+            &self.update_dir().downloaded_backend_signature_path(),
+        ).await;
+
+        if let Err(e) = download_result {
+            self.update_dir().remove_downloaded_backend_and_info_json().await?;
+            return Err(e).attach_printable(asset.name);
+        }
+
+        let signer = match self.verify_downloaded_backend_signature().await {
+            Ok(signer) => signer,
+            Err(e) => {
+                self.update_dir().remove_downloaded_backend_and_info_json().await?;
+                return Err(e).attach_printable(asset.name);
+            }
+        };
+
+        info!("Downloaded software is signed by {}", signer);
 
         let sha256 = self.update_dir().calculate_backend_sha256().await?;
 
@@ -373,12 +567,19 @@ impl UpdateManagerInternal {
             sha256,
         };
 
+        UpdateDirUtils::save_info_json(
+            &signer,
+            self.update_dir().downloaded_backend_signer_json_path(),
+        ).await?;
+
         UpdateDirUtils::save_info_json(
             &info,
             self.update_dir().downloaded_backend_info_json_path(),
         ).await?;
 
-        self.internal_state.lock().await.downloaded = Some(info);
+        let mut state = self.internal_state.lock().await;
+        state.downloaded = Some(info);
+        state.downloaded_signer = Some(signer);
 
         Ok(())
     }
@@ -402,7 +603,11 @@ impl UpdateManagerInternal {
             return Err(UpdateError::SelectedVersionNotFound.report());
         }
 
-        let backend_binary = if let Some(archive_file_path) = &self.config.github.archive_backend_binary_path {
+        // Verify again as the downloaded file might have been changed
+        // after downloading.
+        let signer = self.verify_downloaded_backend_signature().await?;
+
+        let backend_binary = if let Some(archive_file_path) = self.config.archive_backend_binary_path() {
             let extracted = self.update_dir().extracted_backend_path();
             extract_backend_binary(
                 self.update_dir().downloaded_backend_path(),
@@ -420,16 +625,52 @@ impl UpdateManagerInternal {
             .await
             .change_context(UpdateError::BackendUtils)?;
 
+        self.update_dir().move_installed_backend_info_to_previous().await?;
+
+        // Keep copy of the installed release so that other manager
+        // instances can download it.
+        tokio::fs::copy(
+            self.update_dir().downloaded_backend_path(),
+            self.update_dir().installed_backend_release_path(),
+        )
+            .await
+            .change_context(UpdateError::FileCopyingFailed)?;
+        tokio::fs::copy(
+            self.update_dir().downloaded_backend_signature_path(),
+            self.update_dir().installed_backend_release_signature_path(),
+        )
+            .await
+            .change_context(UpdateError::FileCopyingFailed)?;
+
+        UpdateDirUtils::save_info_json(
+            &signer,
+            self.update_dir().installed_backend_signer_json_path(),
+        ).await?;
+
         UpdateDirUtils::save_info_json(
             &info,
             self.update_dir().installed_backend_info_json_path(),
         ).await?;
 
-        self.internal_state.lock().await.installed = Some(info);
+        tokio::fs::write(self.update_dir().health_check_pending_path(), "")
+            .await
+            .change_context(UpdateError::FileWritingFailed)?;
+
+        let mut state = self.internal_state.lock().await;
+        state.installed = Some(info);
+        state.installed_signer = Some(signer);
 
         Ok(())
     }
 
+    async fn verify_downloaded_backend_signature(&self) -> Result<String, UpdateError> {
+        verify_release_signature(
+            self.config.release_signing_key.clone(),
+            self.update_dir().downloaded_backend_path(),
+            self.update_dir().downloaded_backend_signature_path(),
+        ).await
+    }
+
     fn backend_utils(&self) -> BackendUtils {
         BackendUtils {
             config: &self.config,
@@ -480,6 +721,16 @@ impl UpdateDirUtils<'_> {
             .join("downloaded_backend.json")
     }
 
+    fn downloaded_backend_signature_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("downloaded_backend.sig")
+    }
+
+    fn downloaded_backend_signer_json_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("downloaded_backend_signer.json")
+    }
+
     fn extracted_backend_path(&self) -> PathBuf {
         self.create_update_dir_if_needed()
             .join("extracted_backend")
@@ -490,6 +741,51 @@ impl UpdateDirUtils<'_> {
             .join("installed_backend.json")
     }
 
+    fn installed_backend_signer_json_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("installed_backend_signer.json")
+    }
+
+    fn installed_backend_release_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("installed_backend_release")
+    }
+
+    fn installed_backend_release_signature_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("installed_backend_release.sig")
+    }
+
+    fn previous_installed_backend_release_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("previous_installed_backend_release")
+    }
+
+    fn previous_installed_backend_release_signature_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("previous_installed_backend_release.sig")
+    }
+
+    fn previous_installed_backend_info_json_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("previous_installed_backend.json")
+    }
+
+    fn previous_installed_backend_signer_json_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("previous_installed_backend_signer.json")
+    }
+
+    fn rolled_back_backends_json_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("rolled_back_backends.json")
+    }
+
+    fn health_check_pending_path(&self) -> PathBuf {
+        self.create_update_dir_if_needed()
+            .join("installed_backend_health_check_pending")
+    }
+
     pub async fn downloaded_backend_info(&self) -> Result<Option<SoftwareInfo>, UpdateError> {
         Self::read_and_parse_info(
             self.downloaded_backend_info_json_path()
@@ -502,7 +798,38 @@ impl UpdateDirUtils<'_> {
         ).await
     }
 
-    async fn read_and_parse_info(path: PathBuf) -> Result<Option<SoftwareInfo>, UpdateError> {
+    pub async fn downloaded_backend_signer(&self) -> Result<Option<String>, UpdateError> {
+        Self::read_and_parse_info(
+            self.downloaded_backend_signer_json_path()
+        ).await
+    }
+
+    pub async fn installed_backend_signer(&self) -> Result<Option<String>, UpdateError> {
+        Self::read_and_parse_info(
+            self.installed_backend_signer_json_path()
+        ).await
+    }
+
+    pub async fn rolled_back_backends(&self) -> Result<Vec<SoftwareInfo>, UpdateError> {
+        Self::read_and_parse_info(
+            self.rolled_back_backends_json_path()
+        )
+            .await
+            .map(|v| v.unwrap_or_default())
+    }
+
+    async fn add_rolled_back_backend(&self, info: SoftwareInfo) -> Result<(), UpdateError> {
+        let mut rolled_back = self.rolled_back_backends().await?;
+        if !rolled_back.contains(&info) {
+            rolled_back.push(info);
+        }
+        Self::save_info_json(
+            &rolled_back,
+            self.rolled_back_backends_json_path(),
+        ).await
+    }
+
+    async fn read_and_parse_info<T: DeserializeOwned>(path: PathBuf) -> Result<Option<T>, UpdateError> {
         if !path.exists() {
             return Ok(None);
         }
@@ -525,9 +852,61 @@ impl UpdateDirUtils<'_> {
                 .change_context(UpdateError::FileRemovingFailed)?;
         }
 
-        let info = self.downloaded_backend_info_json_path();
-        if info.exists() {
-            tokio::fs::remove_file(info)
+        for file in [
+            self.downloaded_backend_info_json_path(),
+            self.downloaded_backend_signature_path(),
+            self.downloaded_backend_signer_json_path(),
+        ] {
+            if file.exists() {
+                tokio::fs::remove_file(file)
+                    .await
+                    .change_context(UpdateError::FileRemovingFailed)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn move_installed_backend_info_to_previous(&self) -> Result<(), UpdateError> {
+        Self::move_or_remove_target(
+            self.installed_backend_info_json_path(),
+            self.previous_installed_backend_info_json_path(),
+        ).await?;
+        Self::move_or_remove_target(
+            self.installed_backend_signer_json_path(),
+            self.previous_installed_backend_signer_json_path(),
+        ).await?;
+        Self::move_or_remove_target(
+            self.installed_backend_release_path(),
+            self.previous_installed_backend_release_path(),
+        ).await?;
+        Self::move_or_remove_target(
+            self.installed_backend_release_signature_path(),
+            self.previous_installed_backend_release_signature_path(),
+        ).await
+    }
+
+    pub async fn restore_previous_installed_backend_info(&self) -> Result<(), UpdateError> {
+        Self::move_or_remove_target(
+            self.previous_installed_backend_info_json_path(),
+            self.installed_backend_info_json_path(),
+        ).await?;
+        Self::move_or_remove_target(
+            self.previous_installed_backend_signer_json_path(),
+            self.installed_backend_signer_json_path(),
+        ).await?;
+        Self::move_or_remove_target(
+            self.previous_installed_backend_release_path(),
+            self.installed_backend_release_path(),
+        ).await?;
+        Self::move_or_remove_target(
+            self.previous_installed_backend_release_signature_path(),
+            self.installed_backend_release_signature_path(),
+        ).await?;
+
+        let pending = self.health_check_pending_path();
+        if pending.exists() {
+            tokio::fs::remove_file(pending)
                 .await
                 .change_context(UpdateError::FileRemovingFailed)?;
         }
@@ -535,8 +914,23 @@ impl UpdateDirUtils<'_> {
         Ok(())
     }
 
+    /// Move source file to target location. If source file does not exist,
+    /// remove the target file.
+    async fn move_or_remove_target(source: PathBuf, target: PathBuf) -> Result<(), UpdateError> {
+        if source.exists() {
+            tokio::fs::rename(source, target)
+                .await
+                .change_context(UpdateError::FileMovingFailed)?;
+        } else if target.exists() {
+            tokio::fs::remove_file(target)
+                .await
+                .change_context(UpdateError::FileRemovingFailed)?;
+        }
+        Ok(())
+    }
+
     pub async fn save_info_json(
-        info: &SoftwareInfo,
+        info: &impl Serialize,
         path: impl AsRef<Path>,
     ) -> Result<(), UpdateError> {
         let serialized = serde_json::to_string_pretty(info)
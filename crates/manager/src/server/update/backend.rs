@@ -1,12 +1,30 @@
 
-use std::{path::Path, process::ExitStatus};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    process::ExitStatus,
+    time::UNIX_EPOCH,
+};
 
-use error_stack::{Result, ResultExt};
-use manager_config::file::SoftwareUpdateConfig;
+use error_stack::{report, Result, ResultExt};
+use flate2::{bufread::GzDecoder, write::GzEncoder, Compression};
+use manager_config::{
+    file::{BackendDataSnapshotRetentionConfig, SoftwareUpdateConfig},
+    Config,
+};
+use manager_model::{BackendDataSnapshot, BackendDataSnapshotList, BackendDataSnapshotName};
+use simple_backend_model::UnixTime;
 use simple_backend_utils::ContextExt;
+use tar::Archive;
 use tokio::process::Command;
 
-use tracing::info;
+use tracing::{info, warn};
+
+const BACKEND_DATA_SNAPSHOT_DIR_NAME: &str = "backend_data_snapshots";
+const BACKEND_DATA_SNAPSHOT_NAME_PREFIX: &str = "backend-data-";
+const BACKEND_DATA_SNAPSHOT_NAME_SUFFIX: &str = ".tar.gz";
+const BACKEND_DATA_SNAPSHOT_BEFORE_RESTORE_LABEL: &str = "_before-restore";
 
 #[derive(thiserror::Error, Debug)]
 pub enum BackendUtilsError {
@@ -25,12 +43,29 @@ pub enum BackendUtilsError {
     #[error("File removing failed")]
     FileRemovingFailed,
 
+    #[error("Previous backend binary not found")]
+    PreviousBackendNotFound,
+
     #[error("Reset data directory was not directory or does not exist")]
     ResetDataDirectoryWasNotDirectory,
 
     #[error("Reset data directory missing file name")]
     ResetDataDirectoryNoFileName,
 
+    #[error("Backend data snapshot creating failed")]
+    SnapshotCreatingFailed,
+
+    #[error("Backend data snapshot restoring failed")]
+    SnapshotRestoringFailed,
+
+    #[error("Backend data snapshot listing failed")]
+    SnapshotListingFailed,
+
+    #[error("Backend data snapshot not found")]
+    SnapshotNotFound,
+
+    #[error("Backend data snapshot removing failed")]
+    SnapshotRemovingFailed,
 }
 
 pub struct BackendUtils<'a> {
@@ -67,6 +102,24 @@ impl BackendUtils<'_> {
 
         Ok(())
     }
+
+    /// Replace current backend binary with the binary which
+    /// [Self::replace_backend_binary] saved.
+    pub async fn restore_previous_backend_binary(&self) -> Result<(), BackendUtilsError> {
+        let target = self.config.backend_install_location.clone();
+        let previous = target.with_extension("old");
+
+        if !previous.exists() {
+            return Err(BackendUtilsError::PreviousBackendNotFound)
+                .attach_printable(previous.display().to_string());
+        }
+
+        tokio::fs::rename(&previous, &target)
+            .await
+            .change_context(BackendUtilsError::FileMovingFailed)?;
+
+        Ok(())
+    }
 }
 
 pub async fn reset_backend_data(backend_reset_data_dir: &Path) -> Result<(), BackendUtilsError> {
@@ -75,37 +128,565 @@ pub async fn reset_backend_data(backend_reset_data_dir: &Path) -> Result<(), Bac
             .attach_printable(backend_reset_data_dir.display().to_string());
     }
 
-    let mut old_dir_name = backend_reset_data_dir
+    info!(
+        "Data reset was requested. Removing {}",
+        backend_reset_data_dir.display()
+    );
+    tokio::fs::remove_dir_all(&backend_reset_data_dir)
+        .await
+        .change_context(BackendUtilsError::FileRemovingFailed)
+        .attach_printable(backend_reset_data_dir.display().to_string())?;
+
+    Ok(())
+}
+
+/// Backend data snapshots are stored to the manager storage directory.
+pub fn backend_data_snapshot_dir(config: &Config) -> PathBuf {
+    config.storage_dir().join(BACKEND_DATA_SNAPSHOT_DIR_NAME)
+}
+
+/// Create `backend-data-YYYY-MM-DD_HH-MM-SS.tar.gz` archive from
+/// the backend data directory. The backend must not be running.
+///
+/// Old snapshots are removed according to the retention config
+/// after the snapshot is created.
+pub async fn create_backend_data_snapshot(
+    backend_data_dir: &Path,
+    snapshot_dir: &Path,
+    retention: &BackendDataSnapshotRetentionConfig,
+) -> Result<BackendDataSnapshotName, BackendUtilsError> {
+    let name = create_backend_data_snapshot_with_label(backend_data_dir, snapshot_dir, "").await?;
+    prune_backend_data_snapshots(snapshot_dir, retention, &[&name], UnixTime::current_time())
+        .await?;
+    Ok(name)
+}
+
+/// The label is added after the time in the snapshot name.
+async fn create_backend_data_snapshot_with_label(
+    backend_data_dir: &Path,
+    snapshot_dir: &Path,
+    label: &str,
+) -> Result<BackendDataSnapshotName, BackendUtilsError> {
+    if !backend_data_dir.is_dir() {
+        return Err(BackendUtilsError::ResetDataDirectoryWasNotDirectory)
+            .attach_printable(backend_data_dir.display().to_string());
+    }
+
+    tokio::fs::create_dir_all(snapshot_dir)
+        .await
+        .change_context(BackendUtilsError::SnapshotCreatingFailed)
+        .attach_printable(snapshot_dir.display().to_string())?;
+
+    let name = format!(
+        "{}{}{}{}",
+        BACKEND_DATA_SNAPSHOT_NAME_PREFIX,
+        chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S"),
+        label,
+        BACKEND_DATA_SNAPSHOT_NAME_SUFFIX,
+    );
+    let snapshot = snapshot_dir.join(&name);
+    if snapshot.exists() {
+        return Err(report!(BackendUtilsError::SnapshotCreatingFailed))
+            .attach_printable(format!("Snapshot {} already exists", snapshot.display()));
+    }
+
+    info!(
+        "Creating backend data snapshot {} from {}",
+        snapshot.display(),
+        backend_data_dir.display()
+    );
+
+    // Write to a temporary file first so that incomplete snapshots
+    // are not listed.
+    let mut tmp_file_name = name.clone();
+    tmp_file_name.push_str(".tmp");
+    let tmp_snapshot = snapshot_dir.join(tmp_file_name);
+    let data_dir = backend_data_dir.to_path_buf();
+    let tmp_snapshot_for_archiving = tmp_snapshot.clone();
+    tokio::task::spawn_blocking(move || {
+        create_backend_data_archive_sync(&data_dir, &tmp_snapshot_for_archiving)
+    })
+    .await
+    .change_context(BackendUtilsError::SnapshotCreatingFailed)??;
+
+    tokio::fs::rename(&tmp_snapshot, &snapshot)
+        .await
+        .change_context(BackendUtilsError::FileMovingFailed)
+        .attach_printable(format!(
+            "{} -> {}",
+            tmp_snapshot.display(),
+            snapshot.display()
+        ))?;
+
+    Ok(BackendDataSnapshotName { name })
+}
+
+/// Remove snapshots which the retention config does not allow keeping.
+/// Snapshots in `keep` are never removed.
+async fn prune_backend_data_snapshots(
+    snapshot_dir: &Path,
+    retention: &BackendDataSnapshotRetentionConfig,
+    keep: &[&BackendDataSnapshotName],
+    current_time: UnixTime,
+) -> Result<(), BackendUtilsError> {
+    let snapshots = backend_data_snapshots(snapshot_dir).await?.snapshots;
+    let remove_count = retention
+        .max_count
+        .map(|max| snapshots.len().saturating_sub(max as usize))
+        .unwrap_or_default();
+    let min_created_time = retention
+        .max_age_days
+        .map(|days| current_time.ut - i64::from(days) * 60 * 60 * 24);
+
+    // Snapshots are sorted from oldest to newest
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        let too_many = i < remove_count;
+        let too_old = min_created_time.is_some_and(|min| snapshot.created.ut < min);
+        if keep.contains(&&snapshot.name) || (!too_many && !too_old) {
+            continue;
+        }
+
+        let path = snapshot_dir.join(&snapshot.name.name);
+        info!("Removing old backend data snapshot {}", path.display());
+        tokio::fs::remove_file(&path)
+            .await
+            .change_context(BackendUtilsError::SnapshotRemovingFailed)
+            .attach_printable(path.display().to_string())?;
+    }
+
+    Ok(())
+}
+
+fn create_backend_data_archive_sync(
+    backend_data_dir: &Path,
+    target: &Path,
+) -> Result<(), BackendUtilsError> {
+    let file = File::create(target)
+        .change_context(BackendUtilsError::SnapshotCreatingFailed)?;
+    let encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    builder.follow_symlinks(false);
+    builder
+        .append_dir_all(".", backend_data_dir)
+        .change_context(BackendUtilsError::SnapshotCreatingFailed)?;
+    let writer = builder
+        .into_inner()
+        .change_context(BackendUtilsError::SnapshotCreatingFailed)?
+        .finish()
+        .change_context(BackendUtilsError::SnapshotCreatingFailed)?;
+    writer
+        .into_inner()
+        .map_err(|e| e.into_error())
+        .change_context(BackendUtilsError::SnapshotCreatingFailed)?
+        .sync_all()
+        .change_context(BackendUtilsError::SnapshotCreatingFailed)
+}
+
+pub async fn backend_data_snapshots(
+    snapshot_dir: &Path,
+) -> Result<BackendDataSnapshotList, BackendUtilsError> {
+    if !snapshot_dir.exists() {
+        return Ok(BackendDataSnapshotList::default());
+    }
+
+    let mut snapshots = vec![];
+    let mut entries = tokio::fs::read_dir(snapshot_dir)
+        .await
+        .change_context(BackendUtilsError::SnapshotListingFailed)?;
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .change_context(BackendUtilsError::SnapshotListingFailed)?
+    {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.starts_with(BACKEND_DATA_SNAPSHOT_NAME_PREFIX) ||
+            !name.ends_with(BACKEND_DATA_SNAPSHOT_NAME_SUFFIX) {
+            continue;
+        }
+
+        let metadata = entry
+            .metadata()
+            .await
+            .change_context(BackendUtilsError::SnapshotListingFailed)?;
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let created = metadata
+            .modified()
+            .change_context(BackendUtilsError::SnapshotListingFailed)?
+            .duration_since(UNIX_EPOCH)
+            .change_context(BackendUtilsError::SnapshotListingFailed)?;
+
+        snapshots.push(BackendDataSnapshot {
+            name: BackendDataSnapshotName { name },
+            created: UnixTime::new(created.as_secs() as i64),
+            size_bytes: metadata.len(),
+        });
+    }
+
+    // The name contains the creation time
+    snapshots.sort_by(|a, b| a.name.name.cmp(&b.name.name));
+
+    Ok(BackendDataSnapshotList { snapshots })
+}
+
+/// Restore backend data snapshot. The backend must not be running.
+///
+/// 1. Snapshot of the current backend data is created.
+/// 2. The snapshot is extracted to a temporary directory.
+/// 3. The current backend data directory is removed like
+///    [reset_backend_data] does.
+/// 4. The temporary directory is moved to the backend data directory.
+///
+/// The current backend data is not modified if extracting fails.
+/// Old snapshots are removed according to the retention config
+/// after the snapshot is restored.
+/// Returns the name of the snapshot created from the current data.
+pub async fn restore_backend_data_snapshot(
+    backend_data_dir: &Path,
+    snapshot_dir: &Path,
+    retention: &BackendDataSnapshotRetentionConfig,
+    snapshot: &BackendDataSnapshotName,
+) -> Result<Option<BackendDataSnapshotName>, BackendUtilsError> {
+    // Only listed snapshots are allowed so that the name can not
+    // point outside the snapshot directory.
+    let snapshot_exists = backend_data_snapshots(snapshot_dir)
+        .await?
+        .snapshots
+        .iter()
+        .any(|v| v.name == *snapshot);
+    if !snapshot_exists {
+        return Err(report!(BackendUtilsError::SnapshotNotFound))
+            .attach_printable(snapshot.name.clone());
+    }
+
+    let current_data_snapshot = if backend_data_dir.exists() {
+        let name = create_backend_data_snapshot_with_label(
+            backend_data_dir,
+            snapshot_dir,
+            BACKEND_DATA_SNAPSHOT_BEFORE_RESTORE_LABEL,
+        ).await?;
+        info!("Backend data snapshot {} created before restoring", name.name);
+        Some(name)
+    } else {
+        None
+    };
+
+    let mut tmp_dir_name = backend_data_dir
         .file_name()
         .ok_or(BackendUtilsError::ResetDataDirectoryNoFileName.report())?
         .to_string_lossy()
         .to_string();
-    old_dir_name.push_str("-old");
-    let old_data_dir = backend_reset_data_dir.with_file_name(old_dir_name);
-    if old_data_dir.is_dir() {
-        info!(
-            "Data reset was requested. Removing existing old data directory {}",
-            old_data_dir.display()
-        );
-        tokio::fs::remove_dir_all(&old_data_dir)
+    tmp_dir_name.push_str("-restore");
+    let tmp_data_dir = backend_data_dir.with_file_name(tmp_dir_name);
+    if tmp_data_dir.exists() {
+        tokio::fs::remove_dir_all(&tmp_data_dir)
             .await
             .change_context(BackendUtilsError::FileRemovingFailed)
-            .attach_printable(old_data_dir.display().to_string())?;
+            .attach_printable(tmp_data_dir.display().to_string())?;
+    }
+
+    let snapshot_file = snapshot_dir.join(&snapshot.name);
+    info!(
+        "Extracting backend data snapshot {} to {}",
+        snapshot_file.display(),
+        tmp_data_dir.display()
+    );
+
+    if let Err(e) = extract_backend_data_snapshot(&snapshot_file, &tmp_data_dir).await {
+        if tmp_data_dir.exists() {
+            if let Err(remove_error) = tokio::fs::remove_dir_all(&tmp_data_dir).await {
+                warn!(
+                    "Removing {} failed. Error: {:?}",
+                    tmp_data_dir.display(),
+                    remove_error
+                );
+            }
+        }
+        return Err(e);
+    }
+
+    if backend_data_dir.exists() {
+        reset_backend_data(backend_data_dir).await?;
     }
 
     info!(
-        "Data reset was requested. Moving {} to {}",
-        backend_reset_data_dir.display(),
-        old_data_dir.display()
+        "Moving restored backend data {} to {}",
+        tmp_data_dir.display(),
+        backend_data_dir.display()
     );
-    tokio::fs::rename(&backend_reset_data_dir, &old_data_dir)
+    tokio::fs::rename(&tmp_data_dir, backend_data_dir)
         .await
         .change_context(BackendUtilsError::FileMovingFailed)
         .attach_printable(format!(
             "{} -> {}",
-            backend_reset_data_dir.display(),
-            old_data_dir.display()
+            tmp_data_dir.display(),
+            backend_data_dir.display()
         ))?;
 
-    Ok(())
+    let keep: Vec<_> = [Some(snapshot), current_data_snapshot.as_ref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    prune_backend_data_snapshots(snapshot_dir, retention, &keep, UnixTime::current_time())
+        .await?;
+
+    Ok(current_data_snapshot)
+}
+
+async fn extract_backend_data_snapshot(
+    snapshot: &Path,
+    target_dir: &Path,
+) -> Result<(), BackendUtilsError> {
+    tokio::fs::create_dir(target_dir)
+        .await
+        .change_context(BackendUtilsError::SnapshotRestoringFailed)
+        .attach_printable(target_dir.display().to_string())?;
+
+    let snapshot = snapshot.to_path_buf();
+    let target_dir = target_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let file = File::open(&snapshot)
+            .change_context(BackendUtilsError::SnapshotRestoringFailed)?;
+        let mut archive = Archive::new(GzDecoder::new(BufReader::new(file)));
+        archive.set_preserve_permissions(true);
+        archive
+            .unpack(&target_dir)
+            .change_context(BackendUtilsError::SnapshotRestoringFailed)
+    })
+    .await
+    .change_context(BackendUtilsError::SnapshotRestoringFailed)?
+}
+
+#[cfg(test)]
+mod test {
+    use manager_config::file::BackendDataSnapshotRetentionConfig;
+    use manager_model::BackendDataSnapshotName;
+    use simple_backend_model::UnixTime;
+
+    use super::{
+        backend_data_snapshots, create_backend_data_snapshot, extract_backend_data_snapshot,
+        prune_backend_data_snapshots, reset_backend_data, restore_backend_data_snapshot,
+        BackendUtilsError,
+    };
+    use crate::test_utils::TestDir;
+
+    fn test_dir(test_name: &str) -> TestDir {
+        let d = TestDir::new("backend-data", test_name);
+        d.write("data/a.txt", "a");
+        d.write("data/sub/b.txt", "b");
+        d
+    }
+
+    #[tokio::test]
+    async fn snapshot_restoring_round_trip() {
+        let d = test_dir("round-trip");
+        let snapshot = create_backend_data_snapshot(
+            &d.path("data"),
+            &d.path("snapshots"),
+            &BackendDataSnapshotRetentionConfig::default(),
+        )
+            .await
+            .unwrap();
+
+        d.write("data/a.txt", "changed");
+        d.write("data/c.txt", "c");
+
+        let previous_data = restore_backend_data_snapshot(
+            &d.path("data"),
+            &d.path("snapshots"),
+            &BackendDataSnapshotRetentionConfig::default(),
+            &snapshot,
+        )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(d.read("data/a.txt").as_deref(), Some("a"));
+        assert_eq!(d.read("data/sub/b.txt").as_deref(), Some("b"));
+        assert_eq!(d.read("data/c.txt"), None);
+        assert!(!d.path("data-restore").exists());
+        assert!(!d.path("data-old").exists());
+
+        let snapshots = backend_data_snapshots(&d.path("snapshots")).await.unwrap();
+        let names: Vec<_> = snapshots.snapshots.into_iter().map(|v| v.name).collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&snapshot));
+        assert!(names.contains(&previous_data));
+
+        // Snapshot created before restoring contains the changed data
+        let previous_data_dir = d.path("previous");
+        extract_backend_data_snapshot(
+            &d.path("snapshots").join(&previous_data.name),
+            &previous_data_dir,
+        )
+            .await
+            .unwrap();
+        assert_eq!(d.read("previous/a.txt").as_deref(), Some("changed"));
+        assert_eq!(d.read("previous/c.txt").as_deref(), Some("c"));
+    }
+
+    #[tokio::test]
+    async fn corrupted_snapshot_does_not_modify_data() {
+        let d = test_dir("corrupted");
+        let snapshot = BackendDataSnapshotName {
+            name: "backend-data-corrupted.tar.gz".to_string(),
+        };
+        d.write(format!("snapshots/{}", snapshot.name), "not an archive");
+
+        let e = restore_backend_data_snapshot(
+            &d.path("data"),
+            &d.path("snapshots"),
+            &BackendDataSnapshotRetentionConfig::default(),
+            &snapshot,
+        )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), BackendUtilsError::SnapshotRestoringFailed));
+        assert_eq!(d.read("data/a.txt").as_deref(), Some("a"));
+        assert_eq!(d.read("data/sub/b.txt").as_deref(), Some("b"));
+        assert!(!d.path("data-restore").exists());
+        assert!(!d.path("data-old").exists());
+    }
+
+    #[tokio::test]
+    async fn unknown_snapshot_is_not_restored() {
+        let d = test_dir("unknown");
+        let snapshot = BackendDataSnapshotName {
+            name: "../data".to_string(),
+        };
+
+        let e = restore_backend_data_snapshot(
+            &d.path("data"),
+            &d.path("snapshots"),
+            &BackendDataSnapshotRetentionConfig::default(),
+            &snapshot,
+        )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), BackendUtilsError::SnapshotNotFound));
+        assert_eq!(d.read("data/a.txt").as_deref(), Some("a"));
+    }
+
+    #[tokio::test]
+    async fn data_reset_removes_data_dir() {
+        let d = test_dir("reset");
+
+        reset_backend_data(&d.path("data")).await.unwrap();
+
+        assert!(!d.path("data").exists());
+        assert!(!d.path("data-old").exists());
+    }
+
+    fn create_snapshot_files(d: &TestDir, names: &[&str]) -> Vec<BackendDataSnapshotName> {
+        names
+            .iter()
+            .map(|name| {
+                d.write(format!("snapshots/{}", name), "snapshot");
+                BackendDataSnapshotName {
+                    name: name.to_string(),
+                }
+            })
+            .collect()
+    }
+
+    async fn snapshot_names(d: &TestDir) -> Vec<BackendDataSnapshotName> {
+        backend_data_snapshots(&d.path("snapshots"))
+            .await
+            .unwrap()
+            .snapshots
+            .into_iter()
+            .map(|v| v.name)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn oldest_snapshots_over_max_count_are_removed() {
+        let d = test_dir("max-count");
+        let snapshots = create_snapshot_files(
+            &d,
+            &[
+                "backend-data-2024-01-01_00-00-00.tar.gz",
+                "backend-data-2024-01-02_00-00-00.tar.gz",
+                "backend-data-2024-01-03_00-00-00.tar.gz",
+            ],
+        );
+        let retention = BackendDataSnapshotRetentionConfig {
+            max_count: Some(2),
+            max_age_days: None,
+        };
+
+        prune_backend_data_snapshots(
+            &d.path("snapshots"),
+            &retention,
+            &[&snapshots[2]],
+            UnixTime::current_time(),
+        )
+            .await
+            .unwrap();
+
+        assert_eq!(snapshot_names(&d).await, snapshots[1..]);
+    }
+
+    #[tokio::test]
+    async fn snapshots_older_than_max_age_are_removed() {
+        let d = test_dir("max-age");
+        let snapshots = create_snapshot_files(
+            &d,
+            &[
+                "backend-data-2024-01-01_00-00-00.tar.gz",
+                "backend-data-2024-01-02_00-00-00.tar.gz",
+            ],
+        );
+        let retention = BackendDataSnapshotRetentionConfig {
+            max_count: None,
+            max_age_days: Some(30),
+        };
+
+        prune_backend_data_snapshots(
+            &d.path("snapshots"),
+            &retention,
+            &[&snapshots[1]],
+            UnixTime::current_time(),
+        )
+            .await
+            .unwrap();
+        assert_eq!(snapshot_names(&d).await, snapshots);
+
+        let after_max_age = UnixTime::current_time().add_seconds(31 * 24 * 60 * 60);
+        prune_backend_data_snapshots(&d.path("snapshots"), &retention, &[&snapshots[1]], after_max_age)
+            .await
+            .unwrap();
+        assert_eq!(snapshot_names(&d).await, snapshots[1..]);
+    }
+
+    #[tokio::test]
+    async fn restored_snapshot_is_not_removed() {
+        let d = test_dir("restore-retention");
+        let snapshot = create_backend_data_snapshot(
+            &d.path("data"),
+            &d.path("snapshots"),
+            &BackendDataSnapshotRetentionConfig::default(),
+        )
+            .await
+            .unwrap();
+        let retention = BackendDataSnapshotRetentionConfig {
+            max_count: Some(1),
+            max_age_days: None,
+        };
+
+        let previous_data =
+            restore_backend_data_snapshot(&d.path("data"), &d.path("snapshots"), &retention, &snapshot)
+                .await
+                .unwrap()
+                .unwrap();
+
+        let names = snapshot_names(&d).await;
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&snapshot));
+        assert!(names.contains(&previous_data));
+    }
 }
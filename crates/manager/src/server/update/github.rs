@@ -1,39 +1,43 @@
 use std::path::Path;
 
 use error_stack::{report, Result, ResultExt};
-use manager_config::file::SoftwareUpdateConfig;
+use manager_config::file::SoftwareUpdateGitHubConfig;
 use reqwest::{header::{ACCEPT, USER_AGENT}, Client, StatusCode};
 use serde_json::Value;
 use tokio::io::AsyncWriteExt;
 use futures::StreamExt;
 
-use super::UpdateError;
+use super::{source::{AssetLocation, ReleaseAsset}, UpdateError};
 
 const GITHUB_API_VERSION: &str = "2022-11-28";
-
-pub struct ReleaseAsset {
-    pub name: String,
-    pub id: i64,
-}
+const GITHUB_API_URL: &str = "https://api.github.com";
 
 pub struct GitHubApi<'a> {
-    pub updater_config: &'a SoftwareUpdateConfig,
+    pub config: &'a SoftwareUpdateGitHubConfig,
     pub client: &'a Client,
     pub user_agent: &'a str,
 }
 
 impl GitHubApi<'_> {
+    fn api_url(&self) -> &str {
+        self.config
+            .api_url
+            .as_deref()
+            .unwrap_or(GITHUB_API_URL)
+            .trim_end_matches('/')
+    }
+
     pub async fn get_latest_release_asset(&self) -> Result<Option<ReleaseAsset>, UpdateError> {
-        let config = self.updater_config;
+        let config = self.config;
 
-        let url = format!("https://api.github.com/repos/{}/{}/releases/latest", config.github.owner, config.github.repository);
+        let url = format!("{}/repos/{}/{}/releases/latest", self.api_url(), config.owner, config.repository);
 
         let request = self.client.get(url)
             .header(ACCEPT, "application/vnd.github+json")
             .header(USER_AGENT, self.user_agent)
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION);
 
-        let request = if let Some(token) = config.github.token.clone() {
+        let request = if let Some(token) = config.token.clone() {
             request.bearer_auth(token)
         } else {
             request
@@ -69,7 +73,8 @@ impl GitHubApi<'_> {
             .map(|v| v.as_slice())
             .unwrap_or_default();
 
-        let mut selected_asset: Option<ReleaseAsset> = None;
+        let mut selected_asset: Option<(String, i64)> = None;
+        let mut signatures: Vec<(String, i64)> = vec![];
         for a in assets {
             let Some(name) = a.as_object()
                 .and_then(|v| v.get("name"))
@@ -88,15 +93,19 @@ impl GitHubApi<'_> {
                     return Err(report!(UpdateError::GitHubApi));
                 };
 
-            if name.ends_with(&config.github.file_name_ending) {
-                if let Some(selected) = selected_asset {
+            if name.ends_with(".sig") {
+                signatures.push((name.to_string(), id));
+            }
+
+            if name.ends_with(&config.file_name_ending) {
+                if let Some((selected_name, _)) = selected_asset {
                     return Err(
                         report!(UpdateError::SotwareDownloadFailedAmbiguousFileName)
-                            .attach_printable(selected.name.to_string())
+                            .attach_printable(selected_name)
                             .attach_printable(name.to_string())
                     );
                 } else {
-                    if let Some(required_uploader) = &config.github.uploader {
+                    if let Some(required_uploader) = &config.uploader {
                         if uploader != required_uploader {
                             return Err(
                                 report!(UpdateError::SotwareDownloadFailedUnknownFileUploader)
@@ -104,29 +113,39 @@ impl GitHubApi<'_> {
                             );
                         }
                     }
-                    selected_asset = Some(ReleaseAsset {
-                        name: name.to_string(),
-                        id,
-                    });
+                    selected_asset = Some((name.to_string(), id));
                 }
             }
         }
 
+        let selected_asset = selected_asset.map(|(name, id)| {
+            let signature_name = format!("{}.sig", name);
+            let signature_id = signatures
+                .iter()
+                .find(|(name, _)| *name == signature_name)
+                .map(|(_, id)| *id);
+            ReleaseAsset {
+                name,
+                location: AssetLocation::GitHub { id, signature_id },
+            }
+        });
+
         Ok(selected_asset)
     }
 
     pub async fn download_asset(
         &self,
-        asset: &ReleaseAsset,
+        asset_id: i64,
         download_location: impl AsRef<Path>,
     ) -> Result<(), UpdateError> {
-        let config = self.updater_config;
+        let config = self.config;
 
         let url = format!(
-            "https://api.github.com/repos/{}/{}/releases/assets/{}",
-            config.github.owner,
-            config.github.repository,
-            asset.id,
+            "{}/repos/{}/{}/releases/assets/{}",
+            self.api_url(),
+            config.owner,
+            config.repository,
+            asset_id,
         );
 
         let request = self.client.get(url)
@@ -134,7 +153,7 @@ impl GitHubApi<'_> {
             .header(USER_AGENT, self.user_agent)
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION);
 
-        let request = if let Some(token) = config.github.token.clone() {
+        let request = if let Some(token) = config.token.clone() {
             request.bearer_auth(token)
         } else {
             request
@@ -170,3 +189,116 @@ impl GitHubApi<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use error_stack::Result;
+    use manager_config::file::SoftwareUpdateGitHubConfig;
+    use reqwest::Client;
+    use serde_json::json;
+
+    use super::GitHubApi;
+    use crate::{
+        server::update::{
+            signature::verify_release_signature, source::UpdateSource, UpdateError,
+        },
+        test_utils::{FakeReleaseServer, TestDir, TestSigningKey},
+    };
+
+    const RELEASE: &[u8] = b"release file";
+
+    async fn release_server(release: &[u8], signature: String) -> FakeReleaseServer {
+        let latest = json!({
+            "assets": [
+                { "name": "backend.tar.gz", "id": 1, "uploader": { "login": "uploader" } },
+                { "name": "backend.tar.gz.sig", "id": 2, "uploader": { "login": "uploader" } },
+            ]
+        });
+        FakeReleaseServer::start(HashMap::from([
+            (
+                "/repos/owner/repository/releases/latest".to_string(),
+                latest.to_string().into_bytes(),
+            ),
+            ("/repos/owner/repository/releases/assets/1".to_string(), release.to_vec()),
+            ("/repos/owner/repository/releases/assets/2".to_string(), signature.into_bytes()),
+        ]))
+        .await
+    }
+
+    fn config(server: &FakeReleaseServer) -> SoftwareUpdateGitHubConfig {
+        SoftwareUpdateGitHubConfig {
+            token: None,
+            owner: "owner".to_string(),
+            repository: "repository".to_string(),
+            file_name_ending: ".tar.gz".to_string(),
+            uploader: Some("uploader".to_string()),
+            archive_backend_binary_path: None,
+            api_url: Some(server.url().to_string()),
+        }
+    }
+
+    /// Download the latest release like the software updater does and
+    /// verify the release signature.
+    async fn download_and_verify(
+        server: &FakeReleaseServer,
+        key: &TestSigningKey,
+        dir: &TestDir,
+    ) -> Result<String, UpdateError> {
+        let config = config(server);
+        let client = Client::new();
+        let source = UpdateSource::GitHub(GitHubApi {
+            config: &config,
+            client: &client,
+            user_agent: "manager-test",
+        });
+
+        let asset = source.get_latest_release_asset().await?.unwrap();
+        assert_eq!(asset.name, "backend.tar.gz");
+        source
+            .download(&asset, &dir.path("backend"), &dir.path("backend.sig"))
+            .await?;
+
+        verify_release_signature(
+            vec![key.config("release")],
+            dir.path("backend"),
+            dir.path("backend.sig"),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn signed_release_is_downloaded_and_verified() {
+        let key = TestSigningKey::generate();
+        let server = release_server(RELEASE, key.signature(RELEASE)).await;
+        let dir = TestDir::new("github", "signed");
+
+        let signer = download_and_verify(&server, &key, &dir).await.unwrap();
+
+        assert_eq!(signer, "release");
+        assert_eq!(std::fs::read(dir.path("backend")).unwrap(), RELEASE);
+    }
+
+    #[tokio::test]
+    async fn release_with_signature_for_other_file_is_rejected() {
+        let key = TestSigningKey::generate();
+        let server = release_server(RELEASE, key.signature(b"other release file")).await;
+        let dir = TestDir::new("github", "other-file");
+
+        let e = download_and_verify(&server, &key, &dir).await.unwrap_err();
+
+        assert!(matches!(e.current_context(), UpdateError::ReleaseSignatureMismatch));
+    }
+
+    #[tokio::test]
+    async fn missing_release_is_error() {
+        let key = TestSigningKey::generate();
+        let server = FakeReleaseServer::start(HashMap::new()).await;
+        let dir = TestDir::new("github", "missing");
+
+        let e = download_and_verify(&server, &key, &dir).await.unwrap_err();
+
+        assert!(matches!(e.current_context(), UpdateError::GitHubApi));
+    }
+}
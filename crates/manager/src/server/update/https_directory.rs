@@ -0,0 +1,195 @@
+use std::path::Path;
+
+use error_stack::{report, Result, ResultExt};
+use manager_config::file::SoftwareUpdateHttpsDirectoryConfig;
+use reqwest::{header::USER_AGENT, Client, StatusCode, Url};
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use futures::StreamExt;
+
+use super::{source::{AssetLocation, ReleaseAsset}, UpdateError};
+
+#[derive(Deserialize)]
+struct Manifest {
+    name: String,
+    file: String,
+    signature: String,
+}
+
+impl Manifest {
+    /// File paths are relative to the manifest URL.
+    fn into_release_asset(self, manifest_url: &Url) -> Result<ReleaseAsset, UpdateError> {
+        let file = manifest_url.join(&self.file)
+            .change_context(UpdateError::HttpsDirectory)?;
+
+        let signature = manifest_url.join(&self.signature)
+            .change_context(UpdateError::HttpsDirectory)?;
+
+        Ok(ReleaseAsset {
+            name: self.name,
+            location: AssetLocation::Url { file, signature },
+        })
+    }
+}
+
+pub struct HttpsDirectoryApi<'a> {
+    pub config: &'a SoftwareUpdateHttpsDirectoryConfig,
+    pub client: &'a Client,
+    pub user_agent: &'a str,
+}
+
+impl HttpsDirectoryApi<'_> {
+    pub async fn get_latest_release_asset(&self) -> Result<Option<ReleaseAsset>, UpdateError> {
+        let response = self.get(self.config.manifest_url.clone()).await?;
+
+        let manifest: Manifest = response.json()
+            .await
+            .change_context(UpdateError::HttpsDirectory)?;
+
+        manifest.into_release_asset(&self.config.manifest_url)
+            .map(Some)
+    }
+
+    pub async fn download(
+        &self,
+        url: Url,
+        download_location: impl AsRef<Path>,
+    ) -> Result<(), UpdateError> {
+        let response = self.get(url).await?;
+        Self::write_response_to_file(response, download_location).await
+    }
+
+    /// Download file if it exists. Returns `false` if the server
+    /// responded with 404 Not Found.
+    pub async fn download_if_exists(
+        &self,
+        url: Url,
+        download_location: impl AsRef<Path>,
+    ) -> Result<bool, UpdateError> {
+        let response = self.send_get(url).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        let response = Self::check_status(response)?;
+        Self::write_response_to_file(response, download_location).await?;
+        Ok(true)
+    }
+
+    async fn write_response_to_file(
+        response: reqwest::Response,
+        download_location: impl AsRef<Path>,
+    ) -> Result<(), UpdateError> {
+        let mut file = tokio::fs::File::create(download_location)
+            .await
+            .change_context(UpdateError::FileWritingFailed)?;
+
+        let mut stream = response.bytes_stream();
+        while let Some(bytes) = stream.next().await {
+            let bytes = bytes.change_context(UpdateError::HttpsDirectory)?;
+            file.write_all(&bytes)
+                .await
+                .change_context(UpdateError::FileWritingFailed)?;
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, url: Url) -> Result<reqwest::Response, UpdateError> {
+        let response = self.send_get(url).await?;
+        Self::check_status(response)
+    }
+
+    async fn send_get(&self, url: Url) -> Result<reqwest::Response, UpdateError> {
+        if url.scheme() != "https" {
+            return Err(report!(UpdateError::HttpsDirectory))
+                .attach_printable(format!("HTTPS is required, URL: {}", url));
+        }
+
+        let request = self.client.get(url)
+            .header(USER_AGENT, self.user_agent);
+
+        let request = if let Some(token) = self.config.token.clone() {
+            request.bearer_auth(token)
+        } else {
+            request
+        };
+
+        let request = request.build()
+            .change_context(UpdateError::HttpsDirectory)?;
+
+        self.client.execute(request)
+            .await
+            .change_context(UpdateError::HttpsDirectory)
+    }
+
+    fn check_status(response: reqwest::Response) -> Result<reqwest::Response, UpdateError> {
+        let status = response.status();
+        if status != StatusCode::OK {
+            return Err(
+                report!(UpdateError::HttpsDirectory)
+                    .attach_printable(status)
+            );
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use manager_config::file::SoftwareUpdateHttpsDirectoryConfig;
+    use reqwest::{Client, Url};
+
+    use super::{HttpsDirectoryApi, Manifest};
+    use crate::server::update::{source::AssetLocation, UpdateError};
+
+    fn manifest() -> Manifest {
+        Manifest {
+            name: "backend-1".to_string(),
+            file: "backend-1.tar.gz".to_string(),
+            signature: "signatures/backend-1.tar.gz.sig".to_string(),
+        }
+    }
+
+    #[test]
+    fn manifest_file_paths_are_relative_to_manifest_url() {
+        let manifest_url = Url::parse("https://example.com/releases/latest.json").unwrap();
+        let asset = manifest().into_release_asset(&manifest_url).unwrap();
+
+        assert_eq!(asset.name, "backend-1");
+        let AssetLocation::Url { file, signature } = asset.location else {
+            panic!("Unexpected asset location");
+        };
+        assert_eq!(file.as_str(), "https://example.com/releases/backend-1.tar.gz");
+        assert_eq!(
+            signature.as_str(),
+            "https://example.com/releases/signatures/backend-1.tar.gz.sig"
+        );
+    }
+
+    #[tokio::test]
+    async fn downloading_requires_https() {
+        let config = SoftwareUpdateHttpsDirectoryConfig {
+            manifest_url: Url::parse("http://localhost/latest.json").unwrap(),
+            token: None,
+            archive_backend_binary_path: None,
+        };
+        let client = Client::new();
+        let api = HttpsDirectoryApi {
+            config: &config,
+            client: &client,
+            user_agent: "test",
+        };
+        let location = std::env::temp_dir().join("manager-https-directory-test-not-created");
+
+        let e = api.get_latest_release_asset().await.err().unwrap();
+        assert!(matches!(e.current_context(), UpdateError::HttpsDirectory));
+
+        let e = api
+            .download_if_exists(config.manifest_url.clone(), &location)
+            .await
+            .unwrap_err();
+        assert!(matches!(e.current_context(), UpdateError::HttpsDirectory));
+        assert!(!location.exists());
+    }
+}
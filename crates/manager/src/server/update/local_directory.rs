@@ -0,0 +1,180 @@
+use std::path::Path;
+
+use error_stack::{report, Result, ResultExt};
+use manager_config::file::SoftwareUpdateLocalDirectoryConfig;
+
+use super::{source::{AssetLocation, ReleaseAsset}, UpdateError};
+
+pub struct LocalDirectorySource<'a> {
+    pub config: &'a SoftwareUpdateLocalDirectoryConfig,
+}
+
+impl LocalDirectorySource<'_> {
+    pub async fn get_latest_release_asset(&self) -> Result<Option<ReleaseAsset>, UpdateError> {
+        let mut dir = tokio::fs::read_dir(&self.config.dir)
+            .await
+            .change_context(UpdateError::FileReadingFailed)
+            .attach_printable(self.config.dir.display().to_string())?;
+
+        let mut selected_asset: Option<String> = None;
+        while let Some(entry) = dir.next_entry()
+            .await
+            .change_context(UpdateError::FileReadingFailed)?
+        {
+            let Some(name) = entry.file_name().to_str().map(|v| v.to_string()) else {
+                continue;
+            };
+
+            if !name.ends_with(&self.config.file_name_ending) {
+                continue;
+            }
+
+            if let Some(selected) = selected_asset {
+                return Err(
+                    report!(UpdateError::SotwareDownloadFailedAmbiguousFileName)
+                        .attach_printable(selected)
+                        .attach_printable(name)
+                );
+            }
+
+            selected_asset = Some(name);
+        }
+
+        let selected_asset = selected_asset.map(|name| {
+            let file = self.config.dir.join(&name);
+            let signature = self.config.dir.join(format!("{}.sig", name));
+            ReleaseAsset {
+                name,
+                location: AssetLocation::File { file, signature },
+            }
+        });
+
+        Ok(selected_asset)
+    }
+
+    pub async fn copy(
+        source: &Path,
+        target: &Path,
+    ) -> Result<(), UpdateError> {
+        tokio::fs::copy(source, target)
+            .await
+            .change_context(UpdateError::FileCopyingFailed)
+            .attach_printable(source.display().to_string())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use manager_config::file::SoftwareUpdateLocalDirectoryConfig;
+
+    use super::LocalDirectorySource;
+    use crate::{
+        server::update::{
+            source::{AssetLocation, UpdateSource},
+            UpdateError,
+        },
+        test_utils::TestDir,
+    };
+
+    fn config(d: &TestDir) -> SoftwareUpdateLocalDirectoryConfig {
+        SoftwareUpdateLocalDirectoryConfig {
+            dir: d.path("releases"),
+            file_name_ending: ".tar.gz".to_string(),
+            archive_backend_binary_path: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn file_with_matching_name_ending_is_selected() {
+        let d = TestDir::new("local-directory", "select");
+        d.write("releases/backend-1.tar.gz", "release");
+        d.write("releases/backend-1.tar.gz.sig", "signature");
+        d.write("releases/notes.txt", "notes");
+        let config = config(&d);
+
+        let asset = LocalDirectorySource { config: &config }
+            .get_latest_release_asset()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(asset.name, "backend-1.tar.gz");
+        let AssetLocation::File { file, signature } = asset.location else {
+            panic!("Unexpected asset location");
+        };
+        assert_eq!(file, config.dir.join("backend-1.tar.gz"));
+        assert_eq!(signature, config.dir.join("backend-1.tar.gz.sig"));
+    }
+
+    #[tokio::test]
+    async fn no_release_when_no_matching_file() {
+        let d = TestDir::new("local-directory", "empty");
+        d.write("releases/notes.txt", "notes");
+        let config = config(&d);
+
+        let asset = LocalDirectorySource { config: &config }
+            .get_latest_release_asset()
+            .await
+            .unwrap();
+
+        assert!(asset.is_none());
+    }
+
+    #[tokio::test]
+    async fn multiple_matching_files_is_error() {
+        let d = TestDir::new("local-directory", "ambiguous");
+        d.write("releases/backend-1.tar.gz", "release");
+        d.write("releases/backend-2.tar.gz", "release");
+        let config = config(&d);
+
+        let e = LocalDirectorySource { config: &config }
+            .get_latest_release_asset()
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            e.current_context(),
+            UpdateError::SotwareDownloadFailedAmbiguousFileName
+        ));
+    }
+
+    #[tokio::test]
+    async fn download_copies_release_and_signature() {
+        let d = TestDir::new("local-directory", "download");
+        d.write("releases/backend-1.tar.gz", "release");
+        d.write("releases/backend-1.tar.gz.sig", "signature");
+        let config = config(&d);
+        let source = UpdateSource::LocalDirectory(LocalDirectorySource { config: &config });
+        let asset = source.get_latest_release_asset().await.unwrap().unwrap();
+
+        source
+            .download(&asset, &d.path("backend"), &d.path("backend.sig"))
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read_to_string(d.path("backend")).unwrap(), "release");
+        assert_eq!(
+            std::fs::read_to_string(d.path("backend.sig")).unwrap(),
+            "signature"
+        );
+    }
+
+    #[tokio::test]
+    async fn download_without_signature_is_not_error() {
+        let d = TestDir::new("local-directory", "download-no-signature");
+        d.write("releases/backend-1.tar.gz", "release");
+        let config = config(&d);
+        let source = UpdateSource::LocalDirectory(LocalDirectorySource { config: &config });
+        let asset = source.get_latest_release_asset().await.unwrap().unwrap();
+
+        source
+            .download(&asset, &d.path("backend"), &d.path("backend.sig"))
+            .await
+            .unwrap();
+
+        assert!(d.path("backend").exists());
+        assert!(!d.path("backend.sig").exists());
+    }
+}
@@ -0,0 +1,124 @@
+use std::path::Path;
+
+use error_stack::{Result, ResultExt};
+use manager_api::{protocol::RequestSenderCmds, ClientConfig, ManagerClient};
+use manager_config::{file::SoftwareUpdateRemoteManagerConfig, Config};
+use manager_model::SoftwareInfo;
+use simple_backend_utils::ContextExt;
+
+use super::{source::{AssetLocation, ReleaseAsset}, UpdateError};
+
+/// Use installed backend of other manager instance as
+/// the latest release.
+pub struct RemoteManagerSource<'a> {
+    pub config: &'a SoftwareUpdateRemoteManagerConfig,
+    pub manager_config: &'a Config,
+}
+
+impl RemoteManagerSource<'_> {
+    pub async fn get_latest_release_asset(&self) -> Result<Option<ReleaseAsset>, UpdateError> {
+        let status = self.connect()
+            .await?
+            .request_to(self.config.manager_name.clone())
+            .get_software_update_status()
+            .await
+            .change_context(UpdateError::RemoteManager)?;
+
+        Ok(Self::release_asset(status.installed))
+    }
+
+    fn release_asset(installed: Option<SoftwareInfo>) -> Option<ReleaseAsset> {
+        installed.map(|info| ReleaseAsset {
+            name: info.name,
+            location: AssetLocation::RemoteManager,
+        })
+    }
+
+    pub async fn download(
+        &self,
+        asset: &ReleaseAsset,
+        download_location: &Path,
+        signature_location: &Path,
+    ) -> Result<(), UpdateError> {
+        let info = self.connect()
+            .await?
+            .download_installed_backend(download_location, signature_location)
+            .await
+            .change_context(UpdateError::RemoteManager)?;
+
+        Self::check_downloaded_release(asset, info)
+    }
+
+    fn check_downloaded_release(
+        asset: &ReleaseAsset,
+        downloaded: Option<SoftwareInfo>,
+    ) -> Result<(), UpdateError> {
+        match downloaded {
+            Some(info) if info.name == asset.name => Ok(()),
+            // Installed backend changed or was removed after
+            // checking the latest release.
+            _ => Err(UpdateError::SoftwareDownloadFailedNoMatchingFile.report()),
+        }
+    }
+
+    async fn connect(&self) -> Result<ManagerClient, UpdateError> {
+        let Some(remote_manager) = self.manager_config.find_remote_manager(&self.config.manager_name) else {
+            return Err(UpdateError::RemoteManager.report())
+                .attach_printable(self.config.manager_name.to_string());
+        };
+
+        let api_key = self.config
+            .api_key
+            .clone()
+            .unwrap_or_else(|| self.manager_config.api_key().to_string());
+
+        let config = ClientConfig {
+            url: remote_manager.url.clone(),
+            root_certificate: self.manager_config.root_certificate(),
+            api_key,
+        };
+
+        ManagerClient::connect(config)
+            .await
+            .change_context(UpdateError::RemoteManager)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use manager_model::SoftwareInfo;
+
+    use super::RemoteManagerSource;
+    use crate::server::update::{source::AssetLocation, UpdateError};
+
+    fn info(name: &str) -> SoftwareInfo {
+        SoftwareInfo {
+            name: name.to_string(),
+            sha256: "abcd".to_string(),
+        }
+    }
+
+    #[test]
+    fn installed_backend_is_latest_release() {
+        let asset = RemoteManagerSource::release_asset(Some(info("backend-1"))).unwrap();
+        assert_eq!(asset.name, "backend-1");
+        assert!(matches!(asset.location, AssetLocation::RemoteManager));
+    }
+
+    #[test]
+    fn no_release_when_remote_has_no_installed_backend() {
+        assert!(RemoteManagerSource::release_asset(None).is_none());
+    }
+
+    #[test]
+    fn downloaded_release_must_match_asset() {
+        let asset = RemoteManagerSource::release_asset(Some(info("backend-1"))).unwrap();
+
+        assert!(RemoteManagerSource::check_downloaded_release(&asset, Some(info("backend-1"))).is_ok());
+
+        for downloaded in [Some(info("backend-2")), None] {
+            let e = RemoteManagerSource::check_downloaded_release(&asset, downloaded).unwrap_err();
+            assert!(matches!(e.current_context(), UpdateError::SoftwareDownloadFailedNoMatchingFile));
+        }
+    }
+}
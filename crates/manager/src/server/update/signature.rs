@@ -0,0 +1,178 @@
+use std::path::PathBuf;
+
+use base64::Engine;
+use error_stack::{Result, ResultExt};
+use manager_config::file::ReleaseSigningKeyConfig;
+use ring::signature::{UnparsedPublicKey, ED25519};
+use simple_backend_utils::ContextExt;
+
+use super::UpdateError;
+
+/// Verify detached Ed25519 signature of the file.
+///
+/// The signature file contains base64 encoded signature.
+/// Returns name of the key which signature matched.
+pub async fn verify_release_signature(
+    keys: Vec<ReleaseSigningKeyConfig>,
+    file: PathBuf,
+    signature_file: PathBuf,
+) -> Result<String, UpdateError> {
+    if keys.is_empty() {
+        return Err(UpdateError::ReleaseSigningKeyMissing.report());
+    }
+
+    if !signature_file.exists() {
+        return Err(UpdateError::ReleaseSignatureMissing.report());
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let data = std::fs::read(file).change_context(UpdateError::FileReadingFailed)?;
+        let signature =
+            std::fs::read_to_string(signature_file).change_context(UpdateError::FileReadingFailed)?;
+        let signature = base64::engine::general_purpose::STANDARD
+            .decode(signature.trim())
+            .change_context(UpdateError::InvalidReleaseSignature)?;
+
+        for key in keys {
+            let public_key = base64::engine::general_purpose::STANDARD
+                .decode(key.public_key.trim())
+                .change_context(UpdateError::InvalidReleaseSigningKey)
+                .attach_printable(key.name.clone())?;
+            if UnparsedPublicKey::new(&ED25519, public_key)
+                .verify(&data, &signature)
+                .is_ok()
+            {
+                return Ok(key.name);
+            }
+        }
+
+        Err(UpdateError::ReleaseSignatureMismatch.report())
+    })
+    .await
+    .change_context(UpdateError::BlockingTaskFailed)?
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use manager_config::file::ReleaseSigningKeyConfig;
+
+    use super::verify_release_signature;
+    use crate::{
+        server::update::UpdateError,
+        test_utils::{TestDir, TestSigningKey},
+    };
+
+    const RELEASE: &[u8] = b"release file";
+
+    struct TestRelease {
+        dir: TestDir,
+        key: TestSigningKey,
+    }
+
+    impl TestRelease {
+        fn new(test_name: &str) -> Self {
+            let dir = TestDir::new("signature", test_name);
+            dir.write("release", RELEASE);
+            Self {
+                dir,
+                key: TestSigningKey::generate(),
+            }
+        }
+
+        fn file(&self) -> PathBuf {
+            self.dir.path("release")
+        }
+
+        fn signature_file(&self) -> PathBuf {
+            self.dir.path("release.sig")
+        }
+
+        fn write_signature(&self, data: &[u8]) {
+            self.dir.write("release.sig", self.key.signature(data));
+        }
+
+        fn key(&self, name: &str) -> ReleaseSigningKeyConfig {
+            self.key.config(name)
+        }
+    }
+
+    #[tokio::test]
+    async fn valid_signature_returns_key_name() {
+        let r = TestRelease::new("valid");
+        r.write_signature(RELEASE);
+        let other = TestSigningKey::generate().config("other");
+
+        let name = verify_release_signature(
+            vec![other, r.key("release")],
+            r.file(),
+            r.signature_file(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(name, "release");
+    }
+
+    #[tokio::test]
+    async fn tampered_file_is_rejected() {
+        let r = TestRelease::new("tampered");
+        r.write_signature(RELEASE);
+        r.dir.write("release", b"tampered release file");
+
+        let e = verify_release_signature(vec![r.key("release")], r.file(), r.signature_file())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), UpdateError::ReleaseSignatureMismatch));
+    }
+
+    #[tokio::test]
+    async fn signature_from_unknown_key_is_rejected() {
+        let r = TestRelease::new("unknown-key");
+        r.write_signature(RELEASE);
+        let other = TestSigningKey::generate().config("other");
+
+        let e = verify_release_signature(vec![other], r.file(), r.signature_file())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), UpdateError::ReleaseSignatureMismatch));
+    }
+
+    #[tokio::test]
+    async fn missing_signature_is_rejected() {
+        let r = TestRelease::new("missing");
+
+        let e = verify_release_signature(vec![r.key("release")], r.file(), r.signature_file())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), UpdateError::ReleaseSignatureMissing));
+    }
+
+    #[tokio::test]
+    async fn invalid_signature_encoding_is_rejected() {
+        let r = TestRelease::new("invalid-encoding");
+        r.dir.write("release.sig", "not base64!");
+
+        let e = verify_release_signature(vec![r.key("release")], r.file(), r.signature_file())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), UpdateError::InvalidReleaseSignature));
+    }
+
+    #[tokio::test]
+    async fn missing_signing_keys_is_error() {
+        let r = TestRelease::new("no-keys");
+        r.write_signature(RELEASE);
+
+        let e = verify_release_signature(vec![], r.file(), r.signature_file())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(e.current_context(), UpdateError::ReleaseSigningKeyMissing));
+    }
+}
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+
+use error_stack::{report, Result, ResultExt};
+use manager_config::{file::SoftwareUpdateSourceConfig, Config};
+use reqwest::{Client, Url};
+
+use super::{
+    github::GitHubApi,
+    https_directory::HttpsDirectoryApi,
+    local_directory::LocalDirectorySource,
+    remote_manager::RemoteManagerSource,
+    UpdateError,
+};
+
+pub struct ReleaseAsset {
+    /// Name which is used to detect new releases.
+    pub name: String,
+    pub location: AssetLocation,
+}
+
+pub enum AssetLocation {
+    GitHub {
+        id: i64,
+        /// Asset ID of the detached signature asset `<name>.sig`.
+        signature_id: Option<i64>,
+    },
+    Url {
+        file: Url,
+        signature: Url,
+    },
+    File {
+        file: PathBuf,
+        signature: PathBuf,
+    },
+    /// Installed backend of the remote manager.
+    RemoteManager,
+}
+
+pub enum UpdateSource<'a> {
+    GitHub(GitHubApi<'a>),
+    HttpsDirectory(HttpsDirectoryApi<'a>),
+    LocalDirectory(LocalDirectorySource<'a>),
+    RemoteManager(RemoteManagerSource<'a>),
+}
+
+impl<'a> UpdateSource<'a> {
+    pub fn new(
+        source: SoftwareUpdateSourceConfig<'a>,
+        manager_config: &'a Config,
+        client: &'a Client,
+        user_agent: &'a str,
+    ) -> Self {
+        match source {
+            SoftwareUpdateSourceConfig::GitHub(config) => Self::GitHub(GitHubApi {
+                config,
+                client,
+                user_agent,
+            }),
+            SoftwareUpdateSourceConfig::HttpsDirectory(config) => Self::HttpsDirectory(HttpsDirectoryApi {
+                config,
+                client,
+                user_agent,
+            }),
+            SoftwareUpdateSourceConfig::LocalDirectory(config) => Self::LocalDirectory(LocalDirectorySource {
+                config,
+            }),
+            SoftwareUpdateSourceConfig::RemoteManager(config) => Self::RemoteManager(RemoteManagerSource {
+                config,
+                manager_config,
+            }),
+        }
+    }
+
+    pub async fn get_latest_release_asset(&self) -> Result<Option<ReleaseAsset>, UpdateError> {
+        match self {
+            Self::GitHub(s) => s.get_latest_release_asset().await,
+            Self::HttpsDirectory(s) => s.get_latest_release_asset().await,
+            Self::LocalDirectory(s) => s.get_latest_release_asset().await,
+            Self::RemoteManager(s) => s.get_latest_release_asset().await,
+        }
+    }
+
+    /// Download release asset and its signature.
+    ///
+    /// Missing signature is not an error here as signature
+    /// verification handles that. The signature is missing when
+    /// the source does not have the signature file or HTTPS directory
+    /// responds with 404 Not Found.
+    pub async fn download(
+        &self,
+        asset: &ReleaseAsset,
+        download_location: &Path,
+        signature_location: &Path,
+    ) -> Result<(), UpdateError> {
+        match (self, &asset.location) {
+            (Self::GitHub(s), AssetLocation::GitHub { id, signature_id }) => {
+                s.download_asset(*id, download_location).await?;
+                if let Some(signature_id) = signature_id {
+                    s.download_asset(*signature_id, signature_location).await?;
+                }
+                Ok(())
+            }
+            (Self::HttpsDirectory(s), AssetLocation::Url { file, signature }) => {
+                s.download(file.clone(), download_location).await?;
+                s.download_if_exists(signature.clone(), signature_location).await?;
+                Ok(())
+            }
+            (Self::LocalDirectory(_), AssetLocation::File { file, signature }) => {
+                LocalDirectorySource::copy(file, download_location).await?;
+                if signature.exists() {
+                    LocalDirectorySource::copy(signature, signature_location).await?;
+                }
+                Ok(())
+            }
+            (Self::RemoteManager(s), AssetLocation::RemoteManager) =>
+                s.download(asset, download_location, signature_location).await,
+            _ => Err(report!(UpdateError::SoftwareDownloadFailed))
+                .attach_printable("Asset location does not match the update source"),
+        }
+    }
+}
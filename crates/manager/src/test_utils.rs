@@ -0,0 +1,155 @@
+//! Utilities for manager unit tests
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use base64::Engine;
+use manager_config::file::ReleaseSigningKeyConfig;
+use ring::{
+    rand::SystemRandom,
+    signature::{Ed25519KeyPair, KeyPair},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+/// Empty directory for a test. The directory is removed when
+/// this is dropped.
+pub struct TestDir {
+    dir: PathBuf,
+}
+
+impl TestDir {
+    /// Create directory `manager-{name}-test-{test_name}-{process_id}` to
+    /// temporary directory. Possible previous directory is removed.
+    pub fn new(name: &str, test_name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "manager-{}-test-{}-{}",
+            name,
+            test_name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Self { dir }
+    }
+
+    pub fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.dir.join(path)
+    }
+
+    /// Write file and create missing parent directories.
+    pub fn write(&self, path: impl AsRef<Path>, data: impl AsRef<[u8]>) {
+        let path = self.path(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(path, data).unwrap();
+    }
+
+    pub fn read(&self, path: impl AsRef<Path>) -> Option<String> {
+        std::fs::read_to_string(self.path(path)).ok()
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Ed25519 key for signing test releases.
+pub struct TestSigningKey {
+    key_pair: Ed25519KeyPair,
+}
+
+impl TestSigningKey {
+    pub fn generate() -> Self {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        Self {
+            key_pair: Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap(),
+        }
+    }
+
+    pub fn config(&self, name: &str) -> ReleaseSigningKeyConfig {
+        ReleaseSigningKeyConfig {
+            name: name.to_string(),
+            public_key: base64::engine::general_purpose::STANDARD
+                .encode(self.key_pair.public_key().as_ref()),
+        }
+    }
+
+    /// Signature file content for the data.
+    pub fn signature(&self, data: &[u8]) -> String {
+        let signature = base64::engine::general_purpose::STANDARD
+            .encode(self.key_pair.sign(data).as_ref());
+        format!("{}\n", signature)
+    }
+}
+
+/// Local HTTP server which responds to GET requests with
+/// the configured files. Other paths are responded with
+/// 404 Not Found.
+///
+/// The server stops when this is dropped.
+pub struct FakeReleaseServer {
+    url: String,
+    task: JoinHandle<()>,
+}
+
+impl FakeReleaseServer {
+    pub async fn start(files: HashMap<String, Vec<u8>>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let files = Arc::new(files);
+        let task = tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    return;
+                };
+                tokio::spawn(Self::respond(stream, files.clone()));
+            }
+        });
+        Self { url, task }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    async fn respond(mut stream: TcpStream, files: Arc<HashMap<String, Vec<u8>>>) {
+        let mut request = vec![];
+        let mut buffer = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            match stream.read(&mut buffer).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => request.extend_from_slice(&buffer[..n]),
+            }
+        }
+
+        let request = String::from_utf8_lossy(&request);
+        let path = request.split(' ').nth(1).unwrap_or_default();
+        let (status, body) = match files.get(path) {
+            Some(data) => ("200 OK", data.as_slice()),
+            None => ("404 Not Found", [].as_slice()),
+        };
+        let header = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            status,
+            body.len()
+        );
+        let _ = stream.write_all(header.as_bytes()).await;
+        let _ = stream.write_all(body).await;
+    }
+}
+
+impl Drop for FakeReleaseServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
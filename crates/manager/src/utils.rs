@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use error_stack::{Context, Report, Result, ResultExt};
 use tokio::sync::{oneshot, Mutex, OwnedMutexGuard};
@@ -124,6 +124,17 @@ impl<T> InProgressSender<T> {
 
         Ok(())
     }
+
+    /// Wait until the previously sent message is handled.
+    pub async fn wait_message_handled(&self) {
+        loop {
+            if self.message_storage.lock().await.is_none() {
+                return;
+            }
+            // Receiver has not yet started handling the message.
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -11,7 +11,7 @@ use std::{future::Future, path::Path, sync::Arc};
 
 use error_stack::{report, ResultExt};
 use futures::FutureExt;
-use manager_model::{JsonRpcRequest, JsonRpcResponse, ManagerInstanceName, ManagerProtocolMode, ManagerProtocolVersion, ServerEvent};
+use manager_model::{JsonRpcRequest, JsonRpcResponse, LogEntry, LogFilter, ManagerInstanceName, ManagerProtocolMode, ManagerProtocolVersion, ServerEvent, SoftwareInfo};
 use protocol::{ClientConnectionReadWrite, ConnectionUtilsRead, ConnectionUtilsWrite};
 use tokio::net::TcpStream;
 use tokio_rustls::{rustls::pki_types::{pem::PemObject, CertificateDer, ServerName}, TlsConnector};
@@ -63,6 +63,11 @@ pub enum ClientError {
 
     #[error("Missing configuration")]
     MissingConfiguration,
+
+    #[error("File reading error")]
+    FileRead,
+    #[error("File writing error")]
+    FileWrite,
 }
 
 
@@ -183,12 +188,56 @@ impl ManagerClient {
     pub async fn listen_events(
         mut self,
     ) -> Result<ServerEventListerner, ClientError> {
-        self.stream.send_u8(ManagerProtocolMode::ListenServerEvents as u8)
+        self.stream.send_u8(ManagerProtocolMode::ListenAllServerEvents as u8)
             .await
             .change_context(ClientError::Write)?;
         Ok(ServerEventListerner { stream: self.stream })
     }
 
+    /// Follow new log entries of the connected manager.
+    pub async fn follow_logs(
+        mut self,
+        filter: LogFilter,
+    ) -> Result<LogListener, ClientError> {
+        self.stream.send_u8(ManagerProtocolMode::FollowLogs as u8)
+            .await
+            .change_context(ClientError::Write)?;
+        self.stream.send_log_filter(&filter)
+            .await
+            .change_context(ClientError::Write)?;
+        Ok(LogListener { stream: self.stream })
+    }
+
+    /// Download release file and signature file of the installed backend.
+    ///
+    /// Returns `None` if the remote manager does not have installed
+    /// backend. The signature file is not created if the remote manager
+    /// does not have it.
+    pub async fn download_installed_backend(
+        mut self,
+        release_file: &Path,
+        signature_file: &Path,
+    ) -> Result<Option<SoftwareInfo>, ClientError> {
+        self.stream.send_u8(ManagerProtocolMode::DownloadInstalledBackend as u8)
+            .await
+            .change_context(ClientError::Write)?;
+        let info = self.stream.receive_string_with_u32_len()
+            .await
+            .change_context(ClientError::Read)?;
+        let info: Option<SoftwareInfo> = serde_json::from_str(&info)
+            .change_context(ClientError::Parse)?;
+        if info.is_none() {
+            return Ok(None);
+        }
+        self.stream.receive_file_with_u64_len(release_file)
+            .await?;
+        if self.stream.receive_u8().await? == 1 {
+            self.stream.receive_file_with_u64_len(signature_file)
+                .await?;
+        }
+        Ok(info)
+    }
+
     pub fn request_to(
         self,
         request_receiver: ManagerInstanceName
@@ -212,6 +261,18 @@ impl ServerEventListerner {
     }
 }
 
+pub struct LogListener {
+    stream: Box<dyn ClientConnectionReadWrite>,
+}
+
+impl LogListener {
+    pub async fn next_entry(&mut self) -> Result<LogEntry, ClientError> {
+        self.stream.receive_log_entry()
+            .await
+            .change_context(ClientError::Read)
+    }
+}
+
 pub trait RequestSendingSupport {
     fn send_request(
         &mut self,
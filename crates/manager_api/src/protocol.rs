@@ -1,6 +1,8 @@
+use std::path::Path;
+
 use error_stack::{report, Result, ResultExt};
-use manager_model::{ManualTaskType, NotifyBackend, ScheduledTaskStatus, ScheduledTaskType, SoftwareUpdateTaskType};
-use manager_model::{JsonRpcRequest, JsonRpcRequestType, JsonRpcResponse, JsonRpcResponseType, ManagerInstanceName, ManagerInstanceNameList, ManagerProtocolMode, ManagerProtocolVersion, SecureStorageEncryptionKey, ServerEvent, SoftwareUpdateStatus, SystemInfo};
+use manager_model::{BackendDataSnapshotList, BackendDataSnapshotName, ClusterStatus, LogEntry, LogFilter, LogPage, LogQuery, ManualTaskType, NotifyBackend, ScheduledTaskStatus, ScheduledTaskType, SecureStorageAuditLog, SecureStorageEncryptionKeyInfo, SecureStorageExpansion, SoftwareUpdateTaskType};
+use manager_model::{JsonRpcRequest, JsonRpcRequestType, JsonRpcResponse, JsonRpcResponseType, ManagerInstanceName, ManagerInstanceNameList, ManagerProtocolMode, ManagerProtocolVersion, SecureStorageEncryptionKey, SecureStorageEncryptionKeyCommit, ServerEvent, SoftwareUpdateStatus, SystemInfo};
 
 use tokio::io::AsyncWriteExt;
 use tokio::io::AsyncReadExt;
@@ -61,6 +63,37 @@ pub trait ConnectionUtilsRead: tokio::io::AsyncRead + Unpin  {
         serde_json::from_str(&s)
             .change_context(ClientError::Parse)
     }
+
+    async fn receive_log_filter(&mut self) -> Result<LogFilter, ClientError> {
+        let s = self.receive_string_with_u32_len().await
+            .change_context(ClientError::Read)?;
+        serde_json::from_str(&s)
+            .change_context(ClientError::Parse)
+    }
+
+    async fn receive_log_entry(&mut self) -> Result<LogEntry, ClientError> {
+        let s = self.receive_string_with_u32_len().await
+            .change_context(ClientError::Read)?;
+        serde_json::from_str(&s)
+            .change_context(ClientError::Parse)
+    }
+
+    async fn receive_file_with_u64_len(&mut self, target: &Path) -> Result<(), ClientError> {
+        let len = self.read_u64_le().await.change_context(ClientError::Read)?;
+        let mut file = tokio::fs::File::create(target)
+            .await
+            .change_context(ClientError::FileWrite)?;
+        let mut reader = (&mut *self).take(len);
+        let received = tokio::io::copy(&mut reader, &mut file)
+            .await
+            .change_context(ClientError::Read)?;
+        if received != len {
+            return Err(report!(ClientError::Read))
+                .attach_printable("File transfer ended too early");
+        }
+        file.flush().await.change_context(ClientError::FileWrite)?;
+        Ok(())
+    }
 }
 
 impl <T: tokio::io::AsyncRead + Unpin> ConnectionUtilsRead for T {}
@@ -107,6 +140,26 @@ pub trait ConnectionUtilsWrite: tokio::io::AsyncWrite + Unpin  {
         Ok(())
     }
 
+    async fn send_file_with_u64_len(&mut self, source: &Path) -> Result<(), ClientError> {
+        let mut file = tokio::fs::File::open(source)
+            .await
+            .change_context(ClientError::FileRead)?;
+        let len = file.metadata()
+            .await
+            .change_context(ClientError::FileRead)?
+            .len();
+        self.write_u64_le(len).await.change_context(ClientError::Write)?;
+        let sent = tokio::io::copy(&mut (&mut file).take(len), self)
+            .await
+            .change_context(ClientError::Write)?;
+        if sent != len {
+            return Err(report!(ClientError::FileRead))
+                .attach_printable("File size changed during file transfer");
+        }
+        self.flush().await.change_context(ClientError::Flush)?;
+        Ok(())
+    }
+
     async fn send_server_event(
         &mut self,
         server_event: &ServerEvent,
@@ -118,6 +171,30 @@ pub trait ConnectionUtilsWrite: tokio::io::AsyncWrite + Unpin  {
         self.flush().await.change_context(ClientError::Flush)?;
         Ok(())
     }
+
+    async fn send_log_filter(
+        &mut self,
+        filter: &LogFilter,
+    ) -> Result<(), ClientError> {
+        let text = serde_json::to_string(filter)
+            .change_context(ClientError::Serialize)?;
+        self.send_string_with_u32_len(text).await
+            .change_context(ClientError::Write)?;
+        self.flush().await.change_context(ClientError::Flush)?;
+        Ok(())
+    }
+
+    async fn send_log_entry(
+        &mut self,
+        entry: &LogEntry,
+    ) -> Result<(), ClientError> {
+        let text = serde_json::to_string(entry)
+            .change_context(ClientError::Serialize)?;
+        self.send_string_with_u32_len(text).await
+            .change_context(ClientError::Write)?;
+        self.flush().await.change_context(ClientError::Flush)?;
+        Ok(())
+    }
 }
 
 impl <T: tokio::io::AsyncWrite + Unpin> ConnectionUtilsWrite for T {}
@@ -219,6 +296,32 @@ pub trait RequestSenderCmds: Sized {
         self.send_request(request).await?.require_successful()
     }
 
+    async fn get_backend_data_snapshot_list(
+        self,
+    ) -> Result<BackendDataSnapshotList, ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::GetBackendDataSnapshotList,
+        );
+        let response = self.send_request(request).await?;
+        if let JsonRpcResponseType::BackendDataSnapshotList(list) = response.into_response() {
+            Ok(list)
+        } else {
+            Err(report!(ClientError::InvalidResponse))
+        }
+    }
+
+    async fn restore_backend_data_snapshot(
+        self,
+        snapshot: BackendDataSnapshotName,
+    ) -> Result<(), ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::RestoreBackendDataSnapshot(snapshot),
+        );
+        self.send_request(request).await?.require_successful()
+    }
+
     async fn get_scheduled_tasks_status(
         self,
     ) -> Result<ScheduledTaskStatus, ClientError> {
@@ -256,6 +359,116 @@ pub trait RequestSenderCmds: Sized {
         );
         self.send_request(request).await?.require_successful()
     }
+
+    async fn get_cluster_status(
+        self,
+    ) -> Result<ClusterStatus, ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::GetClusterStatus,
+        );
+        let response = self.send_request(request).await?;
+        if let JsonRpcResponseType::ClusterStatus(status) = response.into_response() {
+            Ok(status)
+        } else {
+            Err(report!(ClientError::InvalidResponse))
+        }
+    }
+
+    async fn get_logs(
+        self,
+        query: LogQuery,
+    ) -> Result<LogPage, ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::GetLogs(query),
+        );
+        let response = self.send_request(request).await?;
+        if let JsonRpcResponseType::Logs(page) = response.into_response() {
+            Ok(page)
+        } else {
+            Err(report!(ClientError::InvalidResponse))
+        }
+    }
+
+    async fn get_secure_storage_encryption_key_info(
+        self,
+        key: ManagerInstanceName,
+    ) -> Result<SecureStorageEncryptionKeyInfo, ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::GetSecureStorageEncryptionKeyInfo(key),
+        );
+        let response = self.send_request(request).await?;
+        if let JsonRpcResponseType::SecureStorageEncryptionKeyInfo(info) = response.into_response() {
+            Ok(info)
+        } else {
+            Err(report!(ClientError::InvalidResponse))
+        }
+    }
+
+    async fn create_pending_secure_storage_encryption_key(
+        self,
+        key: ManagerInstanceName,
+    ) -> Result<SecureStorageEncryptionKey, ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::CreatePendingSecureStorageEncryptionKey(key),
+        );
+        let response = self.send_request(request).await?;
+        if let JsonRpcResponseType::SecureStorageEncryptionKey(key) = response.into_response() {
+            Ok(key)
+        } else {
+            Err(report!(ClientError::InvalidResponse))
+        }
+    }
+
+    async fn commit_pending_secure_storage_encryption_key(
+        self,
+        key: SecureStorageEncryptionKeyCommit,
+    ) -> Result<(), ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::CommitPendingSecureStorageEncryptionKey(key),
+        );
+        self.send_request(request).await?.require_successful()
+    }
+
+    async fn rotate_secure_storage_encryption_key(
+        self,
+    ) -> Result<(), ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::RotateSecureStorageEncryptionKey,
+        );
+        self.send_request(request).await?.require_successful()
+    }
+
+    async fn expand_secure_storage(
+        self,
+        expansion: SecureStorageExpansion,
+    ) -> Result<(), ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::ExpandSecureStorage(expansion),
+        );
+        self.send_request(request).await?.require_successful()
+    }
+
+    async fn get_secure_storage_audit_log(
+        self,
+    ) -> Result<SecureStorageAuditLog, ClientError> {
+        let request = JsonRpcRequest::new(
+            self.request_receiver_name(),
+            JsonRpcRequestType::GetSecureStorageAuditLog,
+        );
+        let response = self.send_request(request).await?;
+        if let JsonRpcResponseType::SecureStorageAuditLog(log) = response.into_response() {
+            Ok(log)
+        } else {
+            Err(report!(ClientError::InvalidResponse))
+        }
+    }
 }
 
 trait RpcResponseExtensions: Sized {
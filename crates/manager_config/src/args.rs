@@ -5,7 +5,7 @@ use std::path::PathBuf;
 use clap::{arg, command, Args, Parser};
 use error_stack::{Result, ResultExt};
 use manager_api::ManagerClient;
-use manager_model::ManagerInstanceName;
+use manager_model::{LogFilter, LogLevel, LogSource, ManagerInstanceName};
 use tokio_rustls::rustls::RootCertStore;
 use url::Url;
 
@@ -126,4 +126,89 @@ pub enum ApiCommand {
         name: String,
         sha256: String,
     },
+    ClusterStatus,
+    /// Search logs. Log entries are printed from oldest to newest.
+    Logs {
+        #[command(flatten)]
+        filter: LogFilterArgs,
+        /// Unix time
+        #[arg(long)]
+        since: Option<i64>,
+        /// Unix time
+        #[arg(long)]
+        until: Option<i64>,
+        /// Continue from the cursor printed after the previous page
+        #[arg(long)]
+        cursor: Option<String>,
+        #[arg(long, default_value_t = 100)]
+        limit: u32,
+    },
+    /// Print age of the secure storage encryption key. Send this
+    /// request to the key storage manager.
+    EncryptionKeyInfo {
+        encryption_key_name: String,
+    },
+    /// Change secure storage to use a new encryption key
+    RotateEncryptionKey,
+    ExpandSecureStorage {
+        size_increase_mib: u32,
+    },
+    SecureStorageAuditLog,
+    /// List backend data snapshots. Backend data reset creates
+    /// the snapshots.
+    BackendDataSnapshots,
+    /// Replace backend data with the snapshot and restart the backend
+    RestoreBackendDataSnapshot {
+        name: String,
+    },
+    /// Print new log entries until the command is stopped.
+    /// Only logs of the connected manager are available.
+    FollowLogs {
+        #[command(flatten)]
+        filter: LogFilterArgs,
+    },
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct LogFilterArgs {
+    /// Read backend log files instead of systemd journal
+    #[arg(long)]
+    backend_log_file: bool,
+    /// systemd service. If not present, all services from the
+    /// manager config are selected.
+    #[arg(long = "service", value_name = "SERVICE")]
+    services: Vec<String>,
+    /// Minimum log level: error, warn, info, debug or trace
+    #[arg(long, value_parser = parse_log_level)]
+    min_level: Option<LogLevel>,
+    /// Case insensitive text which the log message must contain
+    #[arg(long)]
+    text: Option<String>,
+}
+
+impl LogFilterArgs {
+    pub fn to_log_filter(&self) -> LogFilter {
+        let source = if self.backend_log_file {
+            LogSource::BackendLogFile
+        } else {
+            LogSource::Journal { services: self.services.clone() }
+        };
+
+        LogFilter {
+            source,
+            min_level: self.min_level,
+            text: self.text.clone(),
+        }
+    }
+}
+
+fn parse_log_level(value: &str) -> std::result::Result<LogLevel, String> {
+    match value {
+        "error" => Ok(LogLevel::Error),
+        "warn" => Ok(LogLevel::Warn),
+        "info" => Ok(LogLevel::Info),
+        "debug" => Ok(LogLevel::Debug),
+        "trace" => Ok(LogLevel::Trace),
+        _ => Err(format!("Unknown log level: {value}")),
+    }
 }
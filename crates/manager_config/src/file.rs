@@ -12,8 +12,6 @@ use url::Url;
 
 use super::GetConfigError;
 
-// TODO(prod): Add secure storage expanding support
-
 pub const CONFIG_FILE_NAME: &str = "manager_config.toml";
 
 pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
@@ -53,31 +51,112 @@ public_api = "127.0.0.1:4000"
 # [software_update]
 # backend_install_location = "/home/afrodite/afrodite-backend"
 
+# Configure one of the update sources: github, https_directory,
+# local_directory or remote_manager.
+
 # [software_update.github]
 # owner = "TODO"
 # repository = "TODO"
 # file_name_ending = "TODO"
 # uploader = "TODO" # Optional
 # archive_backend_binary_path = "TODO" # Optional
+# api_url = "https://api.github.com" # Optional
+
+# [software_update.https_directory]
+# manifest_url = "https://example.com/releases/manifest.json"
+# token = "TODO" # Optional
+# archive_backend_binary_path = "TODO" # Optional
+
+# [software_update.local_directory]
+# dir = "/path/to/releases"
+# file_name_ending = "TODO"
+# archive_backend_binary_path = "TODO" # Optional
+
+# [software_update.remote_manager]
+# manager_name = "TODO"
+# api_key = "TODO" # Optional
+# archive_backend_binary_path = "TODO" # Optional
+
+# Releases must have a signature asset "<release asset name>.sig" which
+# contains base64 encoded Ed25519 signature of the release asset.
+# [[software_update.release_signing_key]]
+# name = "TODO"
+# public_key = "TODO" # Base64 encoded Ed25519 public key
+
+# Restore previous backend if the backend does not respond after update
+# [software_update.health_check]
+# url = "http://localhost:3000/common_api/version"
+# timeout_seconds = 60 # Optional
 
 # [manual_tasks]
 # allow_backend_restart = true
+# allow_backend_rollback = true
 # allow_system_reboot = true
 
 # [manual_tasks.allow_backend_data_reset]
 # backend_data_dir = "/path/to/backend/data"
+#
+# [manual_tasks.allow_backend_data_reset.snapshot_retention]
+# max_count = 10 # Optional
+# max_age_days = 30 # Optional
 
 # [scheduled_tasks]
 # daily_start_time = "12:00"
 # allow_backend_restart = true
 # allow_system_reboot = true
+# allow_software_update = true
+#
+# Scheduled software update installs only releases which version
+# (for example "1.2.3") is found from the release name and is in
+# one of the allowed version ranges.
+# [[scheduled_tasks.software_update_allowed_version]]
+# min = "1.0.0"
+# max_exclusive = "2.0.0"
 
 # [automatic_system_reboot]
 # scheduling_time = "11:00"
 # notify_backend = true
 
+# Download the latest release and schedule software update if the
+# release is not installed and its version is allowed.
+# [automatic_software_update]
+# scheduling_time = "11:00"
+# notify_backend = true
+
 # [system_info]
 # log_services = ["afrodite-manager", "afrodite-backend"]
+# include_command_output = true
+# backend_log_dir = "/home/afrodite/backend/logs" # optional
+
+# Send alerts to all configured alert sinks (email, webhook and file).
+# Active alert is sent again after the quiet period if it is still active.
+# [alerting]
+# check_interval_seconds = 60 # Optional
+# quiet_period_minutes = 1440 # Optional
+#
+# [alerting.rules]
+# scheduled_task_failed = true
+# update_available = true
+# secure_storage_unmounted = true
+# disk_usage_percent = 90
+# [alerting.rules.backend_health_check]
+# url = "http://localhost:3000/common_api/version"
+# timeout_seconds = 60 # Optional
+#
+# [alerting.email]
+# smtp_server_address = "smtp.example.com"
+# use_starttls_instead_of_smtps = false # Optional
+# username = "username"
+# password = "password"
+# email_from_header = "Manager <no-reply@example.com>"
+# email_to = ["admin@example.com"]
+#
+# [alerting.webhook]
+# url = "https://example.com/alert"
+# token = "TODO" # Optional, sent as bearer token
+#
+# [alerting.file]
+# path = "alerts.jsonl"
 
 # [tls]
 # public_api_cert = "manager-tls/server.crt"
@@ -114,8 +193,10 @@ pub struct ConfigFile {
     pub manual_tasks: Option<ManualTasksConfig>,
     pub scheduled_tasks: Option<ScheduledTasksConfig>,
     pub automatic_system_reboot: Option<AutomaticSystemRebootConfig>,
+    pub automatic_software_update: Option<AutomaticSoftwareUpdateConfig>,
     pub software_update: Option<SoftwareUpdateConfig>,
     pub system_info: Option<SystemInfoConfig>,
+    pub alerting: Option<AlertingConfig>,
     /// TLS is required if debug setting is false.
     pub tls: Option<TlsConfig>,
 }
@@ -161,6 +242,26 @@ impl ConfigFile {
                 .attach_printable("Automatic system reboot requires enabling scheduled tasks with system reboot")
         }
 
+        let software_update_scheduled_tasks_enabled = file.scheduled_tasks
+            .as_ref()
+            .map(|v| v.allow_software_update)
+            .unwrap_or_default();
+        if file.automatic_software_update.is_some() && (!software_update_scheduled_tasks_enabled || file.software_update.is_none()) {
+            return Err(ConfigFileError::InvalidConfig.report())
+                .attach_printable("Automatic software update requires software update config and enabling scheduled tasks with software update")
+        }
+
+        if let Some(alerting) = &file.alerting {
+            if alerting.email.is_none() && alerting.webhook.is_none() && alerting.file.is_none() {
+                return Err(ConfigFileError::InvalidConfig.report())
+                    .attach_printable("Alerting requires at least one alert sink")
+            }
+            if alerting.rules.update_available && file.software_update.is_none() {
+                return Err(ConfigFileError::InvalidConfig.report())
+                    .attach_printable("Update available alert requires software update config")
+            }
+        }
+
         Ok(file)
     }
 
@@ -264,7 +365,86 @@ pub struct SecureStorageConfig {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SoftwareUpdateConfig {
     pub backend_install_location: PathBuf,
-    pub github: SoftwareUpdateGitHubConfig,
+    /// Update source. Only one source can be configured.
+    pub github: Option<SoftwareUpdateGitHubConfig>,
+    /// Update source. Only one source can be configured.
+    pub https_directory: Option<SoftwareUpdateHttpsDirectoryConfig>,
+    /// Update source. Only one source can be configured.
+    pub local_directory: Option<SoftwareUpdateLocalDirectoryConfig>,
+    /// Update source. Only one source can be configured.
+    pub remote_manager: Option<SoftwareUpdateRemoteManagerConfig>,
+    /// Keys which are accepted for release signature verification.
+    /// Unsigned releases are not downloaded or installed, so
+    /// at least one key is required.
+    #[serde(default)]
+    pub release_signing_key: Vec<ReleaseSigningKeyConfig>,
+    /// Check backend health after a new backend is installed and
+    /// the backend is started. If the health check fails, the previous
+    /// backend is restored automatically.
+    pub health_check: Option<BackendHealthCheckConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BackendHealthCheckConfig {
+    /// Backend URL which returns HTTP status 200 when the backend
+    /// is running. For example "http://localhost:3000/common_api/version".
+    pub url: String,
+    /// Time limit for getting the successful response.
+    #[serde(default = "default_health_check_timeout_seconds")]
+    pub timeout_seconds: u32,
+}
+
+fn default_health_check_timeout_seconds() -> u32 {
+    60
+}
+
+impl SoftwareUpdateConfig {
+    pub fn sources(&self) -> Vec<SoftwareUpdateSourceConfig<'_>> {
+        let mut sources = vec![];
+        if let Some(c) = &self.github {
+            sources.push(SoftwareUpdateSourceConfig::GitHub(c));
+        }
+        if let Some(c) = &self.https_directory {
+            sources.push(SoftwareUpdateSourceConfig::HttpsDirectory(c));
+        }
+        if let Some(c) = &self.local_directory {
+            sources.push(SoftwareUpdateSourceConfig::LocalDirectory(c));
+        }
+        if let Some(c) = &self.remote_manager {
+            sources.push(SoftwareUpdateSourceConfig::RemoteManager(c));
+        }
+        sources
+    }
+
+    /// Config loading checks that there is only one source.
+    pub fn source(&self) -> Option<SoftwareUpdateSourceConfig<'_>> {
+        self.sources().first().copied()
+    }
+
+    pub fn archive_backend_binary_path(&self) -> Option<&SimplePatternPath> {
+        match self.source()? {
+            SoftwareUpdateSourceConfig::GitHub(c) => c.archive_backend_binary_path.as_ref(),
+            SoftwareUpdateSourceConfig::HttpsDirectory(c) => c.archive_backend_binary_path.as_ref(),
+            SoftwareUpdateSourceConfig::LocalDirectory(c) => c.archive_backend_binary_path.as_ref(),
+            SoftwareUpdateSourceConfig::RemoteManager(c) => c.archive_backend_binary_path.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SoftwareUpdateSourceConfig<'a> {
+    GitHub(&'a SoftwareUpdateGitHubConfig),
+    HttpsDirectory(&'a SoftwareUpdateHttpsDirectoryConfig),
+    LocalDirectory(&'a SoftwareUpdateLocalDirectoryConfig),
+    RemoteManager(&'a SoftwareUpdateRemoteManagerConfig),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReleaseSigningKeyConfig {
+    /// Signer name which is displayed in software update status.
+    pub name: String,
+    /// Base64 encoded Ed25519 public key.
+    pub public_key: String,
 }
 
 /// Download latest release from GitHub repository.
@@ -286,6 +466,49 @@ pub struct SoftwareUpdateGitHubConfig {
     /// If the archive has multiple matching files the extracting
     /// will fail.
     pub archive_backend_binary_path: Option<SimplePatternPath>,
+    /// GitHub API URL. Default is `https://api.github.com`.
+    /// Can be changed for testing with a local fake release server.
+    pub api_url: Option<String>,
+}
+
+/// Download latest release using JSON manifest from HTTPS server.
+///
+/// The manifest has format
+/// `{"name": "backend.tar.gz", "file": "backend.tar.gz", "signature": "backend.tar.gz.sig"}`.
+/// The `file` and `signature` are URLs relative to the manifest URL.
+/// The `name` is used to detect new releases.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SoftwareUpdateHttpsDirectoryConfig {
+    pub manifest_url: Url,
+    /// Bearer token for HTTP Authorization header.
+    pub token: Option<String>,
+    /// See [SoftwareUpdateGitHubConfig::archive_backend_binary_path].
+    pub archive_backend_binary_path: Option<SimplePatternPath>,
+}
+
+/// Use release from local directory. Useful for air-gapped installs.
+///
+/// Signature file must be in the same directory and
+/// have name `<release file name>.sig`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SoftwareUpdateLocalDirectoryConfig {
+    pub dir: PathBuf,
+    /// Select file which name ends with this text.
+    pub file_name_ending: String,
+    /// See [SoftwareUpdateGitHubConfig::archive_backend_binary_path].
+    pub archive_backend_binary_path: Option<SimplePatternPath>,
+}
+
+/// Download installed release from other manager instance.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SoftwareUpdateRemoteManagerConfig {
+    /// Manager instance name from remote manager config.
+    pub manager_name: ManagerInstanceName,
+    /// API key for the remote manager. Default is the API key of
+    /// this manager.
+    pub api_key: Option<String>,
+    /// See [SoftwareUpdateGitHubConfig::archive_backend_binary_path].
+    pub archive_backend_binary_path: Option<SimplePatternPath>,
 }
 
 /// File path where path components might be equal with `*` string value.
@@ -299,16 +522,34 @@ pub struct ManualTasksConfig {
     pub allow_backend_data_reset: Option<BackendDataResetConfig>,
     /// Allow manual backend restart
     pub allow_backend_restart: bool,
+    /// Allow manual rollback to previously installed backend
+    #[serde(default)]
+    pub allow_backend_rollback: bool,
     /// Allow manaual system reboot
     pub allow_system_reboot: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BackendDataResetConfig {
-    /// This directory will be moved next to the original dir with postfix
-    /// "-old" when backend is updated. If there is already a directory
-    /// with that name, it will be deleted.
+    /// This directory will be deleted when backend data is reset.
+    ///
+    /// Before that, a snapshot of the directory is saved to
+    /// `backend_data_snapshots` directory in the manager storage
+    /// directory. Enabling the data reset also enables restoring
+    /// the snapshots.
     pub backend_data_dir: PathBuf,
+    /// Old snapshots are removed after a new snapshot is created.
+    /// By default, snapshots are not removed.
+    #[serde(default)]
+    pub snapshot_retention: BackendDataSnapshotRetentionConfig,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct BackendDataSnapshotRetentionConfig {
+    /// Remove the oldest snapshots when there are more snapshots than this.
+    pub max_count: Option<u32>,
+    /// Remove snapshots which are older than this.
+    pub max_age_days: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -322,6 +563,22 @@ pub struct ScheduledTasksConfig {
     pub allow_backend_restart: bool,
     /// Allow scheduled backend restart
     pub allow_system_reboot: bool,
+    /// Allow scheduled software update
+    #[serde(default)]
+    pub allow_software_update: bool,
+    /// Scheduled software update installs only releases which version
+    /// is in one of these ranges. The version is parsed from the
+    /// release name.
+    #[serde(default)]
+    pub software_update_allowed_version: Vec<SoftwareVersionRange>,
+}
+
+impl ScheduledTasksConfig {
+    pub fn is_software_update_version_allowed(&self, version: SoftwareVersion) -> bool {
+        self.software_update_allowed_version
+            .iter()
+            .any(|v| v.contains(version))
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -330,9 +587,103 @@ pub struct AutomaticSystemRebootConfig {
     pub notify_backend: bool,
 }
 
+/// Check daily is there a new release available and schedule
+/// software update if the release version is allowed.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AutomaticSoftwareUpdateConfig {
+    pub scheduling_time: UtcTimeValue,
+    pub notify_backend: bool,
+}
+
+/// Version range `min <= version < max_exclusive`.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct SoftwareVersionRange {
+    pub min: SoftwareVersion,
+    pub max_exclusive: SoftwareVersion,
+}
+
+impl SoftwareVersionRange {
+    pub fn contains(&self, version: SoftwareVersion) -> bool {
+        self.min <= version && version < self.max_exclusive
+    }
+}
+
+/// Version with format "major.minor.patch". For example "1.2.3".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "String")]
+pub struct SoftwareVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl SoftwareVersion {
+    /// Find the first "major.minor.patch" version from text.
+    /// For example "backend-v1.2.3.tar.gz" contains version "1.2.3".
+    pub fn find_from_text(text: &str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        for (i, c) in bytes.iter().enumerate() {
+            let previous_is_digit = i > 0 && bytes[i - 1].is_ascii_digit();
+            if !c.is_ascii_digit() || previous_is_digit {
+                continue;
+            }
+            let mut parts = text[i..].splitn(4, '.');
+            let (Some(major), Some(minor), Some(patch)) = (parts.next(), parts.next(), parts.next()) else {
+                continue;
+            };
+            let patch_len = patch.bytes().take_while(|v| v.is_ascii_digit()).count();
+            let version = format!("{}.{}.{}", major, minor, &patch[..patch_len]);
+            if let Ok(version) = Self::try_from(version) {
+                return Some(version);
+            }
+        }
+        None
+    }
+}
+
+impl TryFrom<String> for SoftwareVersion {
+    type Error = String;
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        let values: Vec<&str> = value.trim().split('.').collect();
+        match values[..] {
+            [major, minor, patch] => {
+                let parse = |v: &str| {
+                    if !v.is_empty() && v.bytes().all(|v| v.is_ascii_digit()) {
+                        v.parse::<u32>().map_err(|e| e.to_string())
+                    } else {
+                        Err(format!("Invalid version number: {v}"))
+                    }
+                };
+                Ok(Self {
+                    major: parse(major)?,
+                    minor: parse(minor)?,
+                    patch: parse(patch)?,
+                })
+            }
+            _ => Err(format!("Unknown version format: {value}")),
+        }
+    }
+}
+
+impl std::fmt::Display for SoftwareVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SystemInfoConfig {
     pub log_services: Vec<String>,
+    /// Include raw command output in addition to the natively
+    /// collected statistics.
+    #[serde(default = "default_include_command_output")]
+    pub include_command_output: bool,
+    /// Backend log file directory for the log API.
+    pub backend_log_dir: Option<PathBuf>,
+}
+
+fn default_include_command_output() -> bool {
+    true
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -340,3 +691,102 @@ pub struct ManagerInstance {
     pub name: ManagerInstanceName,
     pub url: Url,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlertingConfig {
+    #[serde(default = "default_alert_check_interval_seconds")]
+    pub check_interval_seconds: u32,
+    /// Active alert is sent again after this time.
+    #[serde(default = "default_alert_quiet_period_minutes")]
+    pub quiet_period_minutes: u32,
+    #[serde(default)]
+    pub rules: AlertRulesConfig,
+    pub email: Option<AlertEmailConfig>,
+    pub webhook: Option<AlertWebhookConfig>,
+    pub file: Option<AlertFileConfig>,
+}
+
+fn default_alert_check_interval_seconds() -> u32 {
+    60
+}
+
+fn default_alert_quiet_period_minutes() -> u32 {
+    60 * 24
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AlertRulesConfig {
+    /// Alert when the backend does not respond to the health check.
+    pub backend_health_check: Option<BackendHealthCheckConfig>,
+    /// Alert when the latest scheduled software update failed.
+    #[serde(default)]
+    pub scheduled_task_failed: bool,
+    /// Alert when disk space or inode usage percentage is equal
+    /// or above this value.
+    pub disk_usage_percent: Option<u8>,
+    /// Alert when the latest release from the update source is
+    /// not installed. The update source is checked once per hour.
+    #[serde(default)]
+    pub update_available: bool,
+    /// Alert when the secure storage is not mounted.
+    #[serde(default)]
+    pub secure_storage_unmounted: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlertEmailConfig {
+    /// The SMTP server must have port 465 open for sending emails using
+    /// implicit TLS.
+    pub smtp_server_address: String,
+    /// Use STARTTLS to start TLS connection on port 587 instead of implicit
+    /// TLS.
+    #[serde(default)]
+    pub use_starttls_instead_of_smtps: bool,
+    pub username: String,
+    pub password: String,
+    /// Email `From` header, for example `Manager <no-reply@example.com>`
+    pub email_from_header: String,
+    pub email_to: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlertWebhookConfig {
+    /// Alert JSON is sent to this URL using HTTP POST.
+    pub url: Url,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlertFileConfig {
+    /// Alert JSONs are appended to this file.
+    pub path: PathBuf,
+}
+
+#[cfg(test)]
+mod test {
+    use super::SoftwareVersion;
+
+    fn version(major: u32, minor: u32, patch: u32) -> Option<SoftwareVersion> {
+        Some(SoftwareVersion { major, minor, patch })
+    }
+
+    #[test]
+    fn version_is_found_from_release_name() {
+        assert_eq!(SoftwareVersion::find_from_text("1.2.3"), version(1, 2, 3));
+        assert_eq!(SoftwareVersion::find_from_text("backend-v1.2.3.tar.gz"), version(1, 2, 3));
+        assert_eq!(SoftwareVersion::find_from_text("backend-10.20.30-linux"), version(10, 20, 30));
+    }
+
+    #[test]
+    fn first_valid_version_is_selected() {
+        assert_eq!(SoftwareVersion::find_from_text("build-2024-backend-1.2.3"), version(1, 2, 3));
+        assert_eq!(SoftwareVersion::find_from_text("v1.2.3-rc.4.5.6"), version(1, 2, 3));
+    }
+
+    #[test]
+    fn incomplete_version_is_not_found() {
+        assert_eq!(SoftwareVersion::find_from_text("backend"), None);
+        assert_eq!(SoftwareVersion::find_from_text("backend-1.2.tar.gz"), None);
+        assert_eq!(SoftwareVersion::find_from_text("backend-1..3"), None);
+    }
+}
@@ -11,7 +11,7 @@ use std::{
 };
 
 use error_stack::{Result, ResultExt};
-use file::{AutomaticSystemRebootConfig, ManagerInstance, ScheduledTasksConfig};
+use file::{AlertingConfig, AutomaticSoftwareUpdateConfig, AutomaticSystemRebootConfig, ManagerInstance, ScheduledTasksConfig, SoftwareUpdateSourceConfig};
 use manager_model::ManagerInstanceName;
 use rustls_pemfile::certs;
 use tokio_rustls::rustls::{RootCertStore, ServerConfig};
@@ -54,6 +54,9 @@ pub enum GetConfigError {
     InvalidConstant,
     #[error("Certificate file reading failed")]
     ReadCertificateError,
+
+    #[error("Invalid software update config")]
+    InvalidSoftwareUpdateConfig,
 }
 
 #[derive(Debug)]
@@ -141,6 +144,14 @@ impl Config {
         self.file.automatic_system_reboot.as_ref()
     }
 
+    pub fn automatic_software_update(&self) -> Option<&AutomaticSoftwareUpdateConfig> {
+        self.file.automatic_software_update.as_ref()
+    }
+
+    pub fn alerting(&self) -> Option<&AlertingConfig> {
+        self.file.alerting.as_ref()
+    }
+
     pub fn log_timestamp(&self) -> bool {
         self.file.general.log_timestamp()
     }
@@ -205,6 +216,10 @@ pub fn get_config(
             .attach_printable("TLS must be configured when debug mode is false");
     }
 
+    if let Some(config) = &file_config.software_update {
+        check_software_update_config(config, &file_config.remote_manager)?;
+    }
+
     let script_locations = check_script_locations(
         &file_config.dir.scripts,
         file_config.general.debug(),
@@ -221,6 +236,25 @@ pub fn get_config(
     })
 }
 
+fn check_software_update_config(
+    config: &SoftwareUpdateConfig,
+    remote_managers: &[ManagerInstance],
+) -> Result<(), GetConfigError> {
+    if config.sources().len() != 1 {
+        return Err(GetConfigError::InvalidSoftwareUpdateConfig)
+            .attach_printable("Software update config must have exactly one update source");
+    }
+
+    if let Some(SoftwareUpdateSourceConfig::RemoteManager(c)) = config.source() {
+        if !remote_managers.iter().any(|m| m.name == c.manager_name) {
+            return Err(GetConfigError::InvalidSoftwareUpdateConfig)
+                .attach_printable(format!("Remote manager {} is not configured", c.manager_name));
+        }
+    }
+
+    Ok(())
+}
+
 fn check_script_locations(
     script_dir: &Path,
     is_debug: bool,
@@ -232,6 +266,11 @@ fn check_script_locations(
     let start_backend = script_dir.join("start-backend.sh");
     let stop_backend = script_dir.join("stop-backend.sh");
     let print_logs = script_dir.join("print-logs.sh");
+    // Optional scripts. Related secure storage operations fail
+    // if the script is missing.
+    let add_encryption_key = script_dir.join("add-encryption-key.sh");
+    let remove_encryption_key = script_dir.join("remove-encryption-key.sh");
+    let expand_encryption = script_dir.join("expand-encryption.sh");
 
     let mut errors = vec![];
 
@@ -277,6 +316,9 @@ fn check_script_locations(
             start_backend,
             stop_backend,
             print_logs,
+            add_encryption_key,
+            remove_encryption_key,
+            expand_encryption,
         })
     } else {
         Err(GetConfigError::ScriptLocationError).attach_printable(errors.join("\n"))
@@ -342,6 +384,9 @@ pub struct ScriptLocations {
     pub start_backend: PathBuf,
     pub stop_backend: PathBuf,
     pub print_logs: PathBuf,
+    pub add_encryption_key: PathBuf,
+    pub remove_encryption_key: PathBuf,
+    pub expand_encryption: PathBuf,
 }
 
 impl ScriptLocations {
@@ -372,4 +417,21 @@ impl ScriptLocations {
     pub fn print_logs(&self) -> &Path {
         &self.print_logs
     }
+
+    /// Script reads current key and new key from stdin. Keys are
+    /// separated with newline.
+    pub fn add_encryption_key(&self) -> &Path {
+        &self.add_encryption_key
+    }
+
+    /// Script reads the removed key from stdin.
+    pub fn remove_encryption_key(&self) -> &Path {
+        &self.remove_encryption_key
+    }
+
+    /// Script reads current key from stdin. Size increase in MiB is
+    /// the first argument.
+    pub fn expand_encryption(&self) -> &Path {
+        &self.expand_encryption
+    }
 }
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{ManagerInstanceName, ScheduledTaskStatus, SoftwareInfo, SystemInfo};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ClusterStatus {
+    /// Status of the manager which handled the request and
+    /// status of its remote managers.
+    pub managers: Vec<ManagerStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ManagerStatus {
+    pub name: ManagerInstanceName,
+    /// True if at least one status request was successful.
+    pub reachable: bool,
+    /// Errors from failed status requests.
+    pub errors: Vec<String>,
+    pub system_info: Option<SystemInfo>,
+    pub installed_software: Option<SoftwareInfo>,
+    pub scheduled_tasks: Option<ScheduledTaskStatus>,
+}
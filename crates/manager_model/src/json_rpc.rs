@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use simple_backend_model::UnixTime;
 use utoipa::{IntoParams, ToSchema};
 
-use crate::{ManualTaskType, NotifyBackend, ScheduledTaskStatus, ScheduledTaskType, SecureStorageEncryptionKey, SoftwareUpdateStatus, SoftwareUpdateTaskType, SystemInfo};
+use crate::{BackendDataSnapshotList, BackendDataSnapshotName, ClusterStatus, LogPage, LogQuery, ManualTaskType, NotifyBackend, ScheduledTaskStatus, ScheduledTaskType, SecureStorageAuditLog, SecureStorageEncryptionKey, SecureStorageEncryptionKeyCommit, SecureStorageEncryptionKeyInfo, SecureStorageExpansion, SoftwareRollback, SoftwareUpdateStatus, SoftwareUpdateTaskType, SystemInfo};
 
 #[derive(Debug, Clone, Copy, PartialEq, num_enum::TryFromPrimitive)]
 #[repr(u8)]
@@ -14,7 +14,18 @@ pub enum ManagerProtocolVersion {
 #[repr(u8)]
 pub enum ManagerProtocolMode {
     JsonRpc = 0,
+    /// Listen server events which existed in the first protocol
+    /// version. Older backends match [ServerEventType] exhaustively,
+    /// so new event types would break their event listening.
     ListenServerEvents = 1,
+    DownloadInstalledBackend = 2,
+    /// Log entries are not sent as [ServerEventType] events as backends
+    /// match [ServerEventType] exhaustively, so a new event type would
+    /// break event listening of older backends. Also only the clients
+    /// which follow logs should receive the log entries.
+    FollowLogs = 3,
+    /// Listen all [ServerEventType] events.
+    ListenAllServerEvents = 4,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -54,6 +65,42 @@ pub enum JsonRpcRequestType {
     ScheduleTask(ScheduledTaskType, NotifyBackend),
     /// Response [JsonRpcResponseType::Successful]
     UnscheduleTask(ScheduledTaskType),
+    /// Get status of the receiver and its remote managers.
+    ///
+    /// Response [JsonRpcResponseType::ClusterStatus]
+    GetClusterStatus,
+    /// Response [JsonRpcResponseType::Logs]
+    GetLogs(LogQuery),
+    /// Handled by the key storage manager.
+    ///
+    /// Response [JsonRpcResponseType::SecureStorageEncryptionKeyInfo]
+    GetSecureStorageEncryptionKeyInfo(ManagerInstanceName),
+    /// Handled by the key storage manager. Replaces possible
+    /// previous pending key.
+    ///
+    /// Response [JsonRpcResponseType::SecureStorageEncryptionKey]
+    CreatePendingSecureStorageEncryptionKey(ManagerInstanceName),
+    /// Handled by the key storage manager.
+    ///
+    /// Response [JsonRpcResponseType::Successful]
+    CommitPendingSecureStorageEncryptionKey(SecureStorageEncryptionKeyCommit),
+    /// Change secure storage to use a new key from the key
+    /// storage manager.
+    ///
+    /// Response [JsonRpcResponseType::Successful]
+    RotateSecureStorageEncryptionKey,
+    /// Response [JsonRpcResponseType::Successful]
+    ExpandSecureStorage(SecureStorageExpansion),
+    /// Response [JsonRpcResponseType::SecureStorageAuditLog]
+    GetSecureStorageAuditLog,
+    /// Response [JsonRpcResponseType::BackendDataSnapshotList]
+    GetBackendDataSnapshotList,
+    /// Replace backend data with the snapshot and restart the backend.
+    /// Snapshot of the current backend data is created before
+    /// restoring.
+    ///
+    /// Response [JsonRpcResponseType::Successful]
+    RestoreBackendDataSnapshot(BackendDataSnapshotName),
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -120,6 +167,48 @@ impl JsonRpcResponse {
         }
     }
 
+    pub fn cluster_status(
+        status: ClusterStatus,
+    ) -> Self {
+        Self {
+            response: JsonRpcResponseType::ClusterStatus(
+                status
+            ),
+        }
+    }
+
+    pub fn logs(
+        page: LogPage,
+    ) -> Self {
+        Self {
+            response: JsonRpcResponseType::Logs(page),
+        }
+    }
+
+    pub fn secure_storage_encryption_key_info(
+        info: SecureStorageEncryptionKeyInfo,
+    ) -> Self {
+        Self {
+            response: JsonRpcResponseType::SecureStorageEncryptionKeyInfo(info),
+        }
+    }
+
+    pub fn secure_storage_audit_log(
+        log: SecureStorageAuditLog,
+    ) -> Self {
+        Self {
+            response: JsonRpcResponseType::SecureStorageAuditLog(log),
+        }
+    }
+
+    pub fn backend_data_snapshot_list(
+        list: BackendDataSnapshotList,
+    ) -> Self {
+        Self {
+            response: JsonRpcResponseType::BackendDataSnapshotList(list),
+        }
+    }
+
     pub fn into_response(self) -> JsonRpcResponseType {
         self.response
     }
@@ -143,6 +232,11 @@ pub enum JsonRpcResponseType {
     SystemInfo(SystemInfo),
     SoftwareUpdateStatus(SoftwareUpdateStatus),
     ScheduledTasksStatus(ScheduledTaskStatus),
+    ClusterStatus(ClusterStatus),
+    Logs(LogPage),
+    SecureStorageEncryptionKeyInfo(SecureStorageEncryptionKeyInfo),
+    SecureStorageAuditLog(SecureStorageAuditLog),
+    BackendDataSnapshotList(BackendDataSnapshotList),
     Successful,
     RequestReceiverNotFound,
 }
@@ -175,6 +269,19 @@ pub struct ManagerInstanceNameValue {
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum ServerEventType {
     MaintenanceSchedulingStatus(Option<MaintenanceTime>),
+    /// Latest automatic or manual backend rollback.
+    SoftwareRollback(SoftwareRollback),
+}
+
+impl ServerEventType {
+    /// Event is sent to [ManagerProtocolMode::ListenServerEvents]
+    /// listeners.
+    pub fn supported_by_listen_server_events_mode(&self) -> bool {
+        match self {
+            Self::MaintenanceSchedulingStatus(_) => true,
+            Self::SoftwareRollback(_) => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
@@ -3,13 +3,17 @@
 #![deny(unused_features)]
 #![warn(unused_crate_dependencies)]
 
+pub mod cluster;
 pub mod json_rpc;
+pub mod log;
 pub mod software;
 pub mod secure_storage;
 pub mod system_info;
 pub mod task;
 
+pub use cluster::*;
 pub use json_rpc::*;
+pub use log::*;
 pub use software::*;
 pub use secure_storage::*;
 pub use system_info::*;
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use simple_backend_model::UnixTime;
+
+/// Backend log files are named `backend.YYYY-MM-DD.log`.
+pub const BACKEND_LOG_FILE_NAME_PREFIX: &str = "backend";
+pub const BACKEND_LOG_FILE_NAME_SUFFIX: &str = "log";
+
+/// Maximum value for [LogQuery::limit].
+pub const LOG_QUERY_MAX_LIMIT: u32 = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum LogSource {
+    /// systemd journal of the services. Only services listed in the
+    /// manager config can be selected. Empty list selects all
+    /// configured services.
+    Journal { services: Vec<String> },
+    /// Backend log files. The backend writes the files when
+    /// log file is enabled from the backend config.
+    BackendLogFile,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Returns true if `self` is at least as severe as `min_level`.
+    pub fn is_enabled(&self, min_level: LogLevel) -> bool {
+        *self <= min_level
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogFilter {
+    pub source: LogSource,
+    /// Minimum level of the log entries. Default is all levels.
+    pub min_level: Option<LogLevel>,
+    /// Case insensitive text which the log message must contain.
+    pub text: Option<String>,
+}
+
+impl LogFilter {
+    pub fn is_match(&self, entry: &LogEntry) -> bool {
+        if let Some(min_level) = self.min_level {
+            if !entry.level.is_enabled(min_level) {
+                return false;
+            }
+        }
+
+        if let Some(text) = &self.text {
+            if !entry.message.to_lowercase().contains(&text.to_lowercase()) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Log search. Log entries are returned from oldest to newest.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogQuery {
+    pub filter: LogFilter,
+    /// Return only log entries which are newer or equal to this time.
+    pub since: Option<UnixTime>,
+    /// Return only log entries which are older than this time.
+    pub until: Option<UnixTime>,
+    /// Continue from [LogPage::next_cursor].
+    pub cursor: Option<String>,
+    /// Max count of log entries in the page.
+    /// Values larger than [LOG_QUERY_MAX_LIMIT] are clamped.
+    pub limit: u32,
+}
+
+impl LogQuery {
+    pub fn page_size(&self) -> usize {
+        self.limit.min(LOG_QUERY_MAX_LIMIT) as usize
+    }
+
+    pub fn is_in_time_range(&self, time: UnixTime) -> bool {
+        self.since.map(|v| time.ut >= v.ut).unwrap_or(true) &&
+            self.until.map(|v| time.ut < v.ut).unwrap_or(true)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogEntry {
+    pub time: UnixTime,
+    pub level: LogLevel,
+    /// Service name or [BACKEND_LOG_FILE_NAME_PREFIX].
+    pub service: String,
+    pub message: String,
+    /// Position of the entry in the log source.
+    pub cursor: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LogPage {
+    pub entries: Vec<LogEntry>,
+    /// Cursor for the next page if there are more log entries.
+    pub next_cursor: Option<String>,
+}
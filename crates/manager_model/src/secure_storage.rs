@@ -1,8 +1,63 @@
 use serde::{Deserialize, Serialize};
+use simple_backend_model::UnixTime;
 use utoipa::ToSchema;
 
+use crate::ManagerInstanceName;
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
 pub struct SecureStorageEncryptionKey {
     /// Base64 key
     pub key: String,
 }
+
+/// Key storage manager replaces the current key with the pending key
+/// only if the pending key matches.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct SecureStorageEncryptionKeyCommit {
+    /// Manager instance which uses the key.
+    pub key_owner: ManagerInstanceName,
+    /// Lowercase hex encoded SHA-256 of the pending key.
+    pub pending_key_sha256: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SecureStorageEncryptionKeyInfo {
+    /// Time when the current key was created.
+    pub created: UnixTime,
+    pub age_seconds: i64,
+    /// New key is created but the secure storage is not yet
+    /// changed to use it.
+    pub rotation_pending: bool,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SecureStorageExpansion {
+    pub size_increase_mib: u32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ToSchema)]
+pub enum SecureStorageOperation {
+    /// Key storage manager created a new pending key.
+    PendingKeyCreation,
+    /// Key storage manager replaced the current key with the pending key.
+    PendingKeyCommit,
+    /// Secure storage was changed to use a new key.
+    KeyRotation,
+    Expansion,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SecureStorageAuditEntry {
+    pub time: UnixTime,
+    pub operation: SecureStorageOperation,
+    /// Manager instance which uses the key.
+    pub key_owner: ManagerInstanceName,
+    pub successful: bool,
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SecureStorageAuditLog {
+    /// Latest entries from oldest to newest.
+    pub entries: Vec<SecureStorageAuditEntry>,
+}
@@ -1,11 +1,18 @@
 use serde::{Deserialize, Serialize};
+use simple_backend_model::UnixTime;
 use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
 pub struct SoftwareUpdateStatus {
     pub state: SoftwareUpdateState,
     pub downloaded: Option<SoftwareInfo>,
+    /// Release signing key name which signature
+    /// the downloaded software has.
+    pub downloaded_signer: Option<String>,
     pub installed: Option<SoftwareInfo>,
+    /// Release signing key name which signature
+    /// the installed software has.
+    pub installed_signer: Option<String>,
 }
 
 impl SoftwareUpdateStatus {
@@ -13,7 +20,9 @@ impl SoftwareUpdateStatus {
         Self {
             state: SoftwareUpdateState::Idle,
             downloaded: None,
+            downloaded_signer: None,
             installed: None,
+            installed_signer: None,
         }
     }
 }
@@ -31,6 +40,17 @@ pub struct SoftwareInfo {
     pub sha256: String,
 }
 
+/// Installed backend was replaced with the previously installed backend.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SoftwareRollback {
+    /// Backend which was removed.
+    pub removed: Option<SoftwareInfo>,
+    /// Backend which is now installed.
+    pub restored: Option<SoftwareInfo>,
+    pub reason: String,
+    pub time: UnixTime,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub enum SoftwareUpdateTaskType {
     Download,
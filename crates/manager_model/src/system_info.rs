@@ -1,9 +1,13 @@
-
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub struct SystemInfo {
+    /// Natively collected system statistics. Empty if
+    /// collecting the statistics failed.
+    pub stats: Option<SystemStats>,
+    /// Raw command outputs. Empty if command output is disabled
+    /// from config.
     pub info: Vec<CommandOutput>,
 }
 
@@ -12,3 +16,49 @@ pub struct CommandOutput {
     pub name: String,
     pub output: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct SystemStats {
+    pub uptime_seconds: u64,
+    pub load_average: LoadAverage,
+    pub memory: MemoryUsage,
+    pub swap: MemoryUsage,
+    pub disks: Vec<DiskUsage>,
+    /// Manager and backend processes. Backend process is
+    /// missing if it is not running.
+    pub processes: Vec<ProcessUsage>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct MemoryUsage {
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct DiskUsage {
+    pub mount_point: String,
+    pub device: String,
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    /// Available space for unprivileged users.
+    pub available_bytes: u64,
+    pub inodes_total: u64,
+    pub inodes_used: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ProcessUsage {
+    pub name: String,
+    pub pid: u32,
+    /// CPU usage percentage. Value 100 means one fully used CPU core.
+    pub cpu_usage: f32,
+    pub memory_bytes: u64,
+}
@@ -2,10 +2,40 @@ use serde::{Deserialize, Serialize};
 use simple_backend_model::UnixTime;
 use utoipa::{IntoParams, ToSchema};
 
+use crate::SoftwareInfo;
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
 pub struct ScheduledTaskStatus {
     pub system_reboot: Option<MaintenanceTask>,
     pub backend_restart: Option<MaintenanceTask>,
+    /// Download and install the latest release and restart the backend.
+    pub software_update: Option<MaintenanceTask>,
+    /// Results of previous scheduled software updates.
+    /// The latest result is the last item.
+    pub software_update_history: Vec<ScheduledSoftwareUpdateResult>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct ScheduledSoftwareUpdateResult {
+    pub time: UnixTime,
+    pub outcome: ScheduledSoftwareUpdateOutcome,
+    /// Latest release from the update source if it was found.
+    pub release: Option<SoftwareInfo>,
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ToSchema)]
+pub enum ScheduledSoftwareUpdateOutcome {
+    /// The latest release was installed.
+    Installed,
+    /// The latest release was already installed.
+    AlreadyInstalled,
+    /// The latest release version is not in the allowed version ranges.
+    VersionNotAllowed,
+    /// The latest release was rolled back earlier, so it is not
+    /// installed again.
+    RolledBack,
+    Failed,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, ToSchema)]
@@ -23,13 +53,38 @@ pub struct NotifyBackend {
 pub enum ManualTaskType {
     BackendDataReset,
     BackendRestart,
+    /// Replace installed backend with the previously installed backend
+    /// and restart the backend.
+    BackendRollback,
     SystemReboot,
 }
 
+/// Archive of the backend data directory. Backend data reset
+/// creates a snapshot before the data is removed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BackendDataSnapshot {
+    pub name: BackendDataSnapshotName,
+    pub created: UnixTime,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BackendDataSnapshotList {
+    /// The oldest snapshot is the first item.
+    pub snapshots: Vec<BackendDataSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BackendDataSnapshotName {
+    pub name: String,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
 pub enum ScheduledTaskType {
     BackendRestart,
     SystemReboot,
+    /// Download and install the latest release and restart the backend.
+    SoftwareUpdate,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema, IntoParams)]
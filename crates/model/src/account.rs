@@ -36,6 +36,7 @@ impl Account {
                 initial_setup_completed: shared_state.account_state_initial_setup_completed,
                 banned: shared_state.account_state_banned,
                 pending_deletion: shared_state.account_state_pending_deletion,
+                warning_pending: shared_state.account_state_warning_pending,
             },
             permissions,
             visibility: shared_state.profile_visibility(),
@@ -84,6 +85,10 @@ pub enum AccountState {
     Normal,
     Banned,
     PendingDeletion,
+    /// Account has received a warning which it has not acknowledged yet.
+    /// Routes which interact with other accounts return
+    /// HTTP status 403 in this state.
+    WarningPending,
 }
 
 #[derive(
@@ -107,6 +112,9 @@ pub struct AccountStateContainer {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     #[schema(default = false)]
     pub(crate) pending_deletion: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    pub(crate) warning_pending: bool,
 }
 
 fn value_is_true(v: &bool) -> bool {
@@ -121,6 +129,8 @@ impl AccountStateContainer {
             AccountState::Banned
         } else if !self.initial_setup_completed {
             AccountState::InitialSetup
+        } else if self.warning_pending {
+            AccountState::WarningPending
         } else {
             AccountState::Normal
         }
@@ -137,6 +147,10 @@ impl AccountStateContainer {
     pub fn set_banned(&mut self, value: bool) {
         self.banned = value;
     }
+
+    pub fn set_warning_pending(&mut self, value: bool) {
+        self.warning_pending = value;
+    }
 }
 
 macro_rules! define_permissions {
@@ -162,6 +176,36 @@ macro_rules! define_permissions {
                     )*
                 }
             }
+
+            /// Enable permissions which are enabled in `other`.
+            pub fn merge(&mut self, other: &Self) {
+                $(
+                    self.$name |= other.$name;
+                )*
+            }
+        }
+
+        /// Marker types for requiring a specific permission at type level.
+        pub mod permission {
+            pub trait RequiredPermission: Send + Sync + 'static {
+                /// Permission field name. If any of multiple permissions
+                /// is enough, the field names are separated with `" | "`.
+                const NAME: &'static str;
+                fn is_granted(permissions: &super::$struct_name) -> bool;
+            }
+
+            $(
+                $(#[doc = $text])?
+                #[allow(non_camel_case_types)]
+                pub struct $name;
+
+                impl RequiredPermission for $name {
+                    const NAME: &'static str = stringify!($name);
+                    fn is_granted(permissions: &super::$struct_name) -> bool {
+                        permissions.$name
+                    }
+                }
+            )*
         }
 
     };
@@ -196,12 +240,84 @@ define_permissions!(
     admin_news_create,
     admin_news_edit_all,
     admin_profile_statistics,
+    /// View audit log of admin actions.
+    admin_view_audit_log,
 );
 
 impl Permissions {
     pub fn some_admin_news_permissions_granted(&self) -> bool {
         self.admin_news_create || self.admin_news_edit_all
     }
+
+    pub fn some_admin_moderation_permissions_granted(&self) -> bool {
+        self.admin_moderate_media_content
+            || self.admin_moderate_profile_names
+            || self.admin_moderate_profile_texts
+            || self.admin_process_reports
+    }
+
+    pub fn some_admin_server_maintenance_permissions_granted(&self) -> bool {
+        self.admin_server_maintenance_view_info
+            || self.admin_server_maintenance_update_software
+            || self.admin_server_maintenance_reset_data
+            || self.admin_server_maintenance_reboot_backend
+    }
+}
+
+/// Marker types for requiring any of multiple permissions at type level.
+pub mod permission_any {
+    use super::{permission::RequiredPermission, Permissions};
+
+    /// [Permissions::admin_news_create] or [Permissions::admin_news_edit_all]
+    #[allow(non_camel_case_types)]
+    pub struct some_admin_news_permission;
+
+    impl RequiredPermission for some_admin_news_permission {
+        const NAME: &'static str = "admin_news_create | admin_news_edit_all";
+        fn is_granted(permissions: &Permissions) -> bool {
+            permissions.some_admin_news_permissions_granted()
+        }
+    }
+
+    /// Permission for moderating media content, profile names,
+    /// profile texts or reports.
+    #[allow(non_camel_case_types)]
+    pub struct some_admin_moderation_permission;
+
+    impl RequiredPermission for some_admin_moderation_permission {
+        const NAME: &'static str = "admin_moderate_media_content | admin_moderate_profile_names | admin_moderate_profile_texts | admin_process_reports";
+        fn is_granted(permissions: &Permissions) -> bool {
+            permissions.some_admin_moderation_permissions_granted()
+        }
+    }
+
+    /// Permission for viewing server info, updating software,
+    /// resetting data or rebooting backend.
+    #[allow(non_camel_case_types)]
+    pub struct some_admin_server_maintenance_permission;
+
+    impl RequiredPermission for some_admin_server_maintenance_permission {
+        const NAME: &'static str = "admin_server_maintenance_view_info | admin_server_maintenance_update_software | admin_server_maintenance_reset_data | admin_server_maintenance_reboot_backend";
+        fn is_granted(permissions: &Permissions) -> bool {
+            permissions.some_admin_server_maintenance_permissions_granted()
+        }
+    }
+
+    /// Permission for finding accounts, viewing permissions or moderating
+    /// media content, profile names or profile texts.
+    #[allow(non_camel_case_types)]
+    pub struct some_admin_profile_data_permission;
+
+    impl RequiredPermission for some_admin_profile_data_permission {
+        const NAME: &'static str = "admin_find_account_by_email | admin_view_permissions | admin_moderate_media_content | admin_moderate_profile_names | admin_moderate_profile_texts";
+        fn is_granted(permissions: &Permissions) -> bool {
+            permissions.admin_find_account_by_email
+                || permissions.admin_view_permissions
+                || permissions.admin_moderate_media_content
+                || permissions.admin_moderate_profile_names
+                || permissions.admin_moderate_profile_texts
+        }
+    }
 }
 
 #[derive(
@@ -58,6 +58,8 @@ pub enum EventType {
     InitialContentModerationCompleted,
     MediaContentChanged,
     ScheduledMaintenanceStatus,
+    /// Some report which the account made was processed.
+    ReportProcessed,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
@@ -117,6 +119,7 @@ pub enum EventToClientInternal {
     InitialContentModerationCompleted,
     MediaContentChanged,
     ScheduledMaintenanceStatus(ScheduledMaintenanceStatus),
+    ReportProcessed,
 }
 
 impl From<&EventToClientInternal> for EventType {
@@ -138,6 +141,7 @@ impl From<&EventToClientInternal> for EventType {
             InitialContentModerationCompleted => Self::InitialContentModerationCompleted,
             MediaContentChanged => Self::MediaContentChanged,
             ScheduledMaintenanceStatus(_) => Self::ScheduledMaintenanceStatus,
+            ReportProcessed => Self::ReportProcessed,
         }
     }
 }
@@ -168,7 +172,8 @@ impl From<EventToClientInternal> for EventToClient {
             | ProfileChanged
             | NewsChanged
             | InitialContentModerationCompleted
-            | MediaContentChanged => (),
+            | MediaContentChanged
+            | ReportProcessed => (),
         }
 
         value
@@ -181,6 +186,7 @@ pub enum NotificationEvent {
     ReceivedLikesChanged,
     InitialContentModerationCompleted,
     NewsChanged,
+    ReportProcessed,
 }
 
 impl From<NotificationEvent> for EventToClientInternal {
@@ -192,6 +198,7 @@ impl From<NotificationEvent> for EventToClientInternal {
                 EventToClientInternal::InitialContentModerationCompleted
             }
             NotificationEvent::NewsChanged => EventToClientInternal::NewsChanged,
+            NotificationEvent::ReportProcessed => EventToClientInternal::ReportProcessed,
         }
     }
 }
@@ -448,12 +455,15 @@ pub struct SharedStateRaw {
     pub account_state_initial_setup_completed: bool,
     pub account_state_banned: bool,
     pub account_state_pending_deletion: bool,
+    pub account_state_warning_pending: bool,
     pub profile_visibility_state_number: ProfileVisibility,
     pub sync_version: AccountSyncVersion,
     pub unlimited_likes: bool,
     pub birthdate: Option<NaiveDate>,
     pub is_bot_account: bool,
     pub initial_setup_completed_unix_time: InitialSetupCompletedTime,
+    pub profile_verified: bool,
+    pub profile_hidden_pending_review: bool,
 }
 
 #[derive(Debug, Clone, Default, Queryable, Selectable, AsChangeset)]
@@ -464,6 +474,7 @@ pub struct AccountStateRelatedSharedState {
     pub account_state_initial_setup_completed: bool,
     pub account_state_banned: bool,
     pub account_state_pending_deletion: bool,
+    pub account_state_warning_pending: bool,
     pub sync_version: AccountSyncVersion,
 }
 
@@ -475,6 +486,8 @@ pub struct OtherSharedState {
     pub birthdate: Option<NaiveDate>,
     pub is_bot_account: bool,
     pub initial_setup_completed_unix_time: InitialSetupCompletedTime,
+    pub profile_verified: bool,
+    pub profile_hidden_pending_review: bool,
 }
 
 impl AccountStateRelatedSharedState {
@@ -487,6 +500,7 @@ impl AccountStateRelatedSharedState {
             initial_setup_completed: self.account_state_initial_setup_completed,
             banned: self.account_state_banned,
             pending_deletion: self.account_state_pending_deletion,
+            warning_pending: self.account_state_warning_pending,
         }
     }
 }
@@ -498,6 +512,7 @@ impl From<Account> for AccountStateRelatedSharedState {
             account_state_initial_setup_completed: account.state_container().initial_setup_completed,
             account_state_banned: account.state_container().banned,
             account_state_pending_deletion: account.state_container().pending_deletion,
+            account_state_warning_pending: account.state_container().warning_pending,
             sync_version: account.sync_version(),
         }
     }
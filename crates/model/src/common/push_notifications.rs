@@ -19,6 +19,7 @@ use crate::{
 /// - const RECEIVED_LIKES_CHANGED = 0x2;
 /// - const INITIAL_CONTENT_MODERATION_COMPLETED = 0x4;
 /// - const NEWS_CHANGED = 0x8;
+/// - const REPORT_PROCESSED = 0x10;
 ///
 #[derive(
     Debug,
@@ -58,6 +59,7 @@ bitflags::bitflags! {
         const RECEIVED_LIKES_CHANGED = 0x2;
         const INITIAL_CONTENT_MODERATION_COMPLETED = 0x4;
         const NEWS_CHANGED = 0x8;
+        const REPORT_PROCESSED = 0x10;
     }
 }
 
@@ -76,6 +78,7 @@ impl From<NotificationEvent> for PendingNotificationFlags {
                 Self::INITIAL_CONTENT_MODERATION_COMPLETED
             }
             NotificationEvent::NewsChanged => Self::NEWS_CHANGED,
+            NotificationEvent::ReportProcessed => Self::REPORT_PROCESSED,
         }
     }
 }
@@ -48,6 +48,9 @@ pub enum ReportTypeNumber {
     ProfileText = 1,
     ProfileContent = 2,
     ChatMessage = 3,
+    /// Banned account appeals its ban. Creator and target
+    /// are the same account.
+    BanAppeal = 4,
 }
 
 impl ReportTypeNumber {
@@ -85,6 +88,76 @@ impl Default for ReportProcessingState {
 
 diesel_i64_try_from!(ReportProcessingState);
 
+/// Moderator decision when processing a report.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    PartialEq,
+    Eq,
+    TryFromPrimitive,
+    diesel::FromSqlRow,
+    diesel::AsExpression,
+)]
+#[diesel(sql_type = Integer)]
+#[repr(i64)]
+pub enum ReportOutcome {
+    Dismissed = 0,
+    /// Reported profile name, profile text or profile content
+    /// was rejected.
+    ContentRemoved = 1,
+    Warned = 2,
+    Banned = 3,
+}
+
+impl Default for ReportOutcome {
+    fn default() -> Self {
+        Self::Dismissed
+    }
+}
+
+diesel_i64_try_from!(ReportOutcome);
+
+/// Admin decision when processing a ban appeal.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    PartialEq,
+    Eq,
+    TryFromPrimitive,
+    diesel::FromSqlRow,
+    diesel::AsExpression,
+)]
+#[diesel(sql_type = Integer)]
+#[repr(i64)]
+pub enum BanAppealDecision {
+    /// Ban stays as is.
+    Uphold = 0,
+    /// Ban end time is moved earlier.
+    Shorten = 1,
+    /// Ban is removed.
+    Lift = 2,
+}
+
+impl BanAppealDecision {
+    /// Report outcome which is saved to the appeal report.
+    pub fn report_outcome(&self) -> ReportOutcome {
+        match self {
+            Self::Uphold | Self::Shorten => ReportOutcome::Banned,
+            Self::Lift => ReportOutcome::Dismissed,
+        }
+    }
+}
+
+diesel_i64_try_from!(BanAppealDecision);
+
 #[derive(Debug, Clone, Deserialize, Serialize, IntoParams, PartialEq)]
 pub struct ReportQueryParams {
     /// Report target
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+mod audit_log;
+pub use audit_log::*;
+mod moderation_claim;
+pub use moderation_claim::*;
 mod report;
 pub use report::*;
 
@@ -0,0 +1,93 @@
+use diesel::{prelude::*, Selectable};
+use num_enum::TryFromPrimitive;
+use serde::{Deserialize, Serialize};
+use simple_backend_model::{diesel_i64_try_from, UnixTime};
+use utoipa::ToSchema;
+
+use crate::{schema_sqlite_types::Integer, AccountId};
+
+/// Admin action type for audit log.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    PartialEq,
+    Eq,
+    TryFromPrimitive,
+    diesel::FromSqlRow,
+    diesel::AsExpression,
+)]
+#[diesel(sql_type = Integer)]
+#[repr(i64)]
+pub enum AdminActionType {
+    // Account
+    SetBanState = 0,
+    SetPermissions = 1,
+    DeleteAccount = 2,
+    ProcessAccountReport = 3,
+    CreateNewsItem = 4,
+    DeleteNewsItem = 5,
+    UpdateNewsTranslation = 6,
+    DeleteNewsTranslation = 7,
+    SetNewsPublicity = 8,
+    WarnAccount = 9,
+    ProcessBanAppeal = 10,
+    SetAdminRole = 11,
+    DeleteAdminRole = 12,
+    AssignAdminRole = 13,
+    RemoveAdminRole = 14,
+    // Media
+    ModerateProfileContent = 20,
+    SetProfileVerification = 21,
+    // Profile
+    ModerateProfileName = 40,
+    ModerateProfileText = 41,
+    // Common
+    ProcessReport = 60,
+    EditMaintenanceNotification = 61,
+    SaveBackendConfig = 62,
+    TriggerSoftwareUpdateDownload = 63,
+    TriggerSoftwareUpdateInstall = 64,
+    TriggerBackendDataReset = 65,
+    TriggerBackendRestart = 66,
+    TriggerSystemReboot = 67,
+    ScheduleManagerTask = 68,
+    UnscheduleManagerTask = 69,
+    TriggerBackendRollback = 70,
+}
+
+diesel_i64_try_from!(AdminActionType);
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, Queryable, Selectable)]
+#[diesel(table_name = crate::schema::history_admin_action)]
+#[diesel(check_for_backend(crate::Db))]
+pub struct AdminAction {
+    pub unix_time: UnixTime,
+    /// Admin which did the action.
+    pub admin_account_id: AccountId,
+    pub action: AdminActionType,
+    /// Account which the action targeted.
+    pub target_account_id: Option<AccountId>,
+    /// Action parameters as JSON.
+    pub parameters: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct GetAdminActionList {
+    pub values: Vec<AdminAction>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AdminActionIteratorQuery {
+    pub start_position: UnixTime,
+    pub page: i64,
+    /// Optional filter for admin account.
+    pub admin: Option<AccountId>,
+    /// Optional filter for target account.
+    pub target: Option<AccountId>,
+    /// Optional filter for action type.
+    pub action: Option<AdminActionType>,
+}
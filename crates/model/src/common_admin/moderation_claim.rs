@@ -0,0 +1,52 @@
+use num_enum::TryFromPrimitive;
+use serde::{Deserialize, Serialize};
+use simple_backend_model::{diesel_i64_try_from, UnixTime};
+use utoipa::ToSchema;
+
+use crate::{schema_sqlite_types::Integer, AccountId};
+
+/// Moderation queue which items moderators can claim.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Deserialize,
+    Serialize,
+    ToSchema,
+    PartialEq,
+    Eq,
+    Hash,
+    TryFromPrimitive,
+    diesel::FromSqlRow,
+    diesel::AsExpression,
+)]
+#[diesel(sql_type = Integer)]
+#[repr(i64)]
+pub enum ModerationClaimQueue {
+    MediaContent = 0,
+    ProfileText = 1,
+    ProfileName = 2,
+    Report = 3,
+    BanAppeal = 4,
+}
+
+diesel_i64_try_from!(ModerationClaimQueue);
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ModeratorThroughputQuery {
+    pub start_time: UnixTime,
+    pub end_time: UnixTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ModeratorThroughput {
+    pub moderator: AccountId,
+    pub queue: ModerationClaimQueue,
+    /// Count of moderated items.
+    pub completed: i64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct GetModeratorThroughputList {
+    pub values: Vec<ModeratorThroughput>,
+}
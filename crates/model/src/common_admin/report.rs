@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use simple_backend_model::UnixTime;
 use utoipa::ToSchema;
 
-use crate::{AccountId, AccountIdDb, AccountIdInternal, ContentId, ProfileAge, ReportIdDb, ReportProcessingState, ReportTypeNumber};
+use crate::{AccountId, AccountIdDb, AccountIdInternal, ContentId, ProfileAge, ReportIdDb, ReportOutcome, ReportProcessingState, ReportTypeNumber};
 
 #[derive(Debug, Clone)]
 pub struct ReportInternal {
@@ -24,6 +24,9 @@ pub struct ReportDetailedInfo {
     pub target: AccountId,
     pub processing_state: ReportProcessingState,
     pub report_type: ReportTypeNumber,
+    /// Available when the report is processed.
+    pub outcome: Option<ReportOutcome>,
+    pub moderator_note: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
@@ -93,6 +96,7 @@ pub struct ReportContent {
     pub profile_text: Option<String>,
     pub profile_content: Option<ContentId>,
     pub chat_message: Option<String>,
+    pub ban_appeal: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
@@ -106,6 +110,18 @@ pub struct ProcessReport {
     pub target: AccountId,
     pub report_type: ReportTypeNumber,
     pub content: ReportContent,
+    #[serde(default)]
+    pub outcome: ReportOutcome,
+    /// Moderator written note about the outcome.
+    /// Max length is [Self::MODERATOR_NOTE_MAX_LEN] bytes.
+    pub moderator_note: Option<String>,
+    /// Ban end time. Required when outcome is
+    /// [ReportOutcome::Banned].
+    pub ban_until: Option<UnixTime>,
+}
+
+impl ProcessReport {
+    pub const MODERATOR_NOTE_MAX_LEN: usize = 1000;
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
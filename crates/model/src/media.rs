@@ -28,6 +28,59 @@ impl ContentIdDb {
 
 diesel_i64_wrapper!(ContentIdDb);
 
+/// Perceptual hash of an image. Near-duplicate images have hashes
+/// with small Hamming distance.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, FromSqlRow, AsExpression,
+)]
+#[diesel(sql_type = Integer)]
+#[serde(transparent)]
+pub struct PerceptualHash(i64);
+
+impl PerceptualHash {
+    pub fn new(hash: i64) -> Self {
+        Self(hash)
+    }
+
+    pub fn from_u64(hash: u64) -> Self {
+        Self(i64::from_ne_bytes(hash.to_ne_bytes()))
+    }
+
+    pub fn as_i64(&self) -> &i64 {
+        &self.0
+    }
+
+    /// Hamming distance between the hashes
+    pub fn distance(&self, other: PerceptualHash) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
+    /// The hash is split to this many 16 bit bands for finding
+    /// near-duplicate candidates from database.
+    pub const BAND_COUNT: usize = 4;
+
+    pub fn bands(&self) -> [i64; Self::BAND_COUNT] {
+        let hash = self.0 as u64;
+        std::array::from_fn(|i| ((hash >> (i * 16)) & 0xFFFF) as i64)
+    }
+
+    /// Band values which near-duplicates might have.
+    ///
+    /// If Hamming distance between two hashes is `max_distance` or less,
+    /// at least one band pair has distance `max_distance / BAND_COUNT`
+    /// or less.
+    pub fn candidate_band_values(&self, max_distance: u8) -> [Vec<i64>; Self::BAND_COUNT] {
+        let max_band_distance = u32::from(max_distance) / Self::BAND_COUNT as u32;
+        self.bands().map(|band| {
+            (0..=i64::from(u16::MAX))
+                .filter(|v| (v ^ band).count_ones() <= max_band_distance)
+                .collect()
+        })
+    }
+}
+
+diesel_i64_wrapper!(PerceptualHash);
+
 /// Content ID for media content.
 ///
 /// Uniqueness is guaranteed for one account so other account might
@@ -82,6 +135,13 @@ impl ContentId {
     }
 }
 
+/// Content which was compared when verifying a profile.
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileVerificationContent {
+    pub security_content_id: ContentId,
+    pub profile_content_id: ContentId,
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct ContentIdInternal {
     aid: AccountIdInternal,
@@ -183,6 +243,8 @@ pub enum ContentProcessingStateType {
     Completed = 3,
     /// Content processing failed.
     Failed = 4,
+    /// Content processing failed because NSFW content was detected.
+    NsfwDetected = 5,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
@@ -238,6 +300,13 @@ impl ContentProcessingState {
         self.cid = None;
         self.fd = None;
     }
+
+    pub fn change_to_nsfw_detected(&mut self) {
+        self.state = ContentProcessingStateType::NsfwDetected;
+        self.wait_queue_position = None;
+        self.cid = None;
+        self.fd = None;
+    }
 }
 
 /// Version UUID for public profile content.
@@ -286,3 +355,50 @@ diesel_uuid_wrapper!(ProfileContentVersion);
 pub struct InitialContentModerationCompletedResult {
     pub accepted: bool,
 }
+
+#[cfg(test)]
+mod test {
+    use super::PerceptualHash;
+
+    #[test]
+    fn perceptual_hash_distance_to_itself_is_zero() {
+        let hash = PerceptualHash::from_u64(0xF0F0_1234_5678_9ABC);
+        assert_eq!(hash.distance(hash), 0);
+    }
+
+    #[test]
+    fn perceptual_hash_candidate_bands_contain_near_duplicate() {
+        let hash = PerceptualHash::from_u64(0xF0F0_1234_5678_9ABC);
+        // Lowest band has two different bits and other bands one.
+        let near_duplicate = PerceptualHash::from_u64(0xF0F0_1234_5678_9ABC ^ 0x0001_0001_0001_0003);
+        assert_eq!(hash.distance(near_duplicate), 5);
+        let candidates = hash.candidate_band_values(5);
+        let bands = near_duplicate.bands();
+        assert!(!candidates[0].contains(&bands[0]));
+        assert!(candidates[1].contains(&bands[1]));
+        assert!(candidates[2].contains(&bands[2]));
+        assert!(candidates[3].contains(&bands[3]));
+    }
+
+    #[test]
+    fn perceptual_hash_candidate_bands_with_zero_band_distance() {
+        let hash = PerceptualHash::from_u64(0xF0F0_1234_5678_9ABC);
+        let candidates = hash.candidate_band_values(3);
+        assert_eq!(candidates, hash.bands().map(|v| vec![v]));
+    }
+
+    #[test]
+    fn perceptual_hash_distance_counts_different_bits() {
+        let a = PerceptualHash::from_u64(0b1011);
+        let b = PerceptualHash::from_u64(0b0110);
+        assert_eq!(a.distance(b), 3);
+        assert_eq!(b.distance(a), 3);
+    }
+
+    #[test]
+    fn perceptual_hash_distance_handles_sign_bit() {
+        let a = PerceptualHash::from_u64(u64::MAX);
+        let b = PerceptualHash::from_u64(0);
+        assert_eq!(a.distance(b), 64);
+    }
+}
@@ -18,6 +18,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    account_admin_role (account_id, role_id) {
+        account_id -> Integer,
+        role_id -> Integer,
+        expires_unix_time -> Nullable<Integer>,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -108,6 +118,8 @@ diesel::table! {
         admin_news_create -> Bool,
         admin_news_edit_all -> Bool,
         admin_profile_statistics -> Bool,
+        admin_view_audit_log -> Bool,
+        individual_permissions -> Nullable<Text>,
     }
 }
 
@@ -122,6 +134,7 @@ diesel::table! {
         moderator_account_id -> Nullable<Integer>,
         processing_state -> Integer,
         processing_state_change_unix_time -> Integer,
+        prioritized -> Bool,
         is_bot -> Bool,
         is_scammer -> Bool,
         is_spammer -> Bool,
@@ -130,6 +143,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    account_report_ban_appeal (report_id) {
+        report_id -> Integer,
+        ban_state_change_unix_time -> Integer,
+        appeal_text -> Nullable<Text>,
+        decision -> Nullable<Integer>,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -160,6 +184,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    account_warning (id) {
+        id -> Integer,
+        account_id -> Integer,
+        admin_account_id -> Nullable<Integer>,
+        creation_unix_time -> Integer,
+        reason_category -> Nullable<Integer>,
+        reason_details -> Nullable<Text>,
+        acknowledged_unix_time -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    admin_role (id) {
+        id -> Integer,
+        name -> Text,
+        permissions -> Text,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -210,6 +258,9 @@ diesel::table! {
         moderator_account_id -> Nullable<Integer>,
         processing_state -> Integer,
         processing_state_change_unix_time -> Integer,
+        processing_outcome -> Nullable<Integer>,
+        moderator_note -> Nullable<Text>,
+        prioritized -> Bool,
     }
 }
 
@@ -252,6 +303,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    history_admin_action (id) {
+        id -> Integer,
+        unix_time -> Integer,
+        admin_account_id -> Binary,
+        action -> Integer,
+        target_account_id -> Nullable<Binary>,
+        parameters -> Text,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -356,6 +420,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    history_profile_statistics_count_changes_profile_visits (save_time_id) {
+        save_time_id -> Integer,
+        count -> Integer,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -393,6 +466,34 @@ diesel::table! {
         moderation_moderator_account_id -> Nullable<Integer>,
         usage_start_unix_time -> Nullable<Integer>,
         usage_end_unix_time -> Nullable<Integer>,
+        perceptual_hash -> Nullable<Integer>,
+        human_moderation_required -> Bool,
+        nsfw_score_drawings -> Nullable<Double>,
+        nsfw_score_hentai -> Nullable<Double>,
+        nsfw_score_neutral -> Nullable<Double>,
+        nsfw_score_porn -> Nullable<Double>,
+        nsfw_score_sexy -> Nullable<Double>,
+    }
+}
+
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    media_content_duplicate (content_id, duplicate_content_id) {
+        content_id -> Integer,
+        duplicate_content_id -> Integer,
+        hash_distance -> Integer,
+        detection_unix_time -> Integer,
+    }
+}
+
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    media_content_hash_band (band_index, band_value, content_id) {
+        band_index -> Integer,
+        band_value -> Integer,
+        content_id -> Integer,
     }
 }
 
@@ -416,6 +517,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    moderation_claim (id) {
+        id -> Integer,
+        queue_type -> Integer,
+        item_id -> Integer,
+        moderator_account_id -> Integer,
+        claim_unix_time -> Integer,
+        lease_expiration_unix_time -> Integer,
+        completed_unix_time -> Nullable<Integer>,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -453,6 +568,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    passed_profile (account_id, passed_account_id) {
+        account_id -> Integer,
+        passed_account_id -> Integer,
+        unix_time -> Integer,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -564,10 +689,14 @@ diesel::table! {
         search_group_flags -> Integer,
         last_seen_time_filter -> Nullable<Integer>,
         unlimited_likes_filter -> Nullable<Bool>,
+        verified_filter -> Nullable<Bool>,
         max_distance_km_filter -> Nullable<Integer>,
         profile_created_time_filter -> Nullable<Integer>,
         profile_edited_time_filter -> Nullable<Integer>,
         random_profile_order -> Bool,
+        mutual_interest_profile_order -> Bool,
+        hide_seen_profiles -> Bool,
+        profile_visit_tracking -> Bool,
         latitude -> Double,
         longitude -> Double,
         profile_attributes_sync_version -> Integer,
@@ -584,6 +713,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    profile_visit (id) {
+        id -> Integer,
+        target_account_id -> Integer,
+        visitor_account_id -> Integer,
+        visit_day -> Integer,
+        unix_time -> Integer,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -614,6 +755,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use crate::schema_sqlite_types::*;
+
+    seen_profile (account_id, seen_account_id) {
+        account_id -> Integer,
+        seen_account_id -> Integer,
+        expiration_unix_time -> Integer,
+    }
+}
+
 diesel::table! {
     use crate::schema_sqlite_types::*;
 
@@ -622,12 +773,15 @@ diesel::table! {
         account_state_initial_setup_completed -> Bool,
         account_state_banned -> Bool,
         account_state_pending_deletion -> Bool,
+        account_state_warning_pending -> Bool,
         profile_visibility_state_number -> Integer,
         sync_version -> Integer,
         unlimited_likes -> Bool,
         birthdate -> Nullable<Date>,
         is_bot_account -> Bool,
         initial_setup_completed_unix_time -> Integer,
+        profile_verified -> Bool,
+        profile_hidden_pending_review -> Bool,
     }
 }
 
@@ -660,6 +814,8 @@ diesel::table! {
 
 diesel::joinable!(access_token -> account_id (account_id));
 diesel::joinable!(account -> account_id (account_id));
+diesel::joinable!(account_admin_role -> account_id (account_id));
+diesel::joinable!(account_admin_role -> admin_role (role_id));
 diesel::joinable!(account_email_sending_state -> account_id (account_id));
 diesel::joinable!(account_interaction_index -> account_interaction (interaction_id));
 diesel::joinable!(account_permissions -> account_id (account_id));
@@ -677,10 +833,13 @@ diesel::joinable!(history_profile_statistics_count_changes_account -> history_pr
 diesel::joinable!(history_profile_statistics_count_changes_all_genders -> history_profile_statistics_save_time (save_time_id));
 diesel::joinable!(history_profile_statistics_count_changes_man -> history_profile_statistics_save_time (save_time_id));
 diesel::joinable!(history_profile_statistics_count_changes_non_binary -> history_profile_statistics_save_time (save_time_id));
+diesel::joinable!(history_profile_statistics_count_changes_profile_visits -> history_profile_statistics_save_time (save_time_id));
 diesel::joinable!(history_profile_statistics_count_changes_woman -> history_profile_statistics_save_time (save_time_id));
 diesel::joinable!(media_content -> account_id (account_id));
+diesel::joinable!(media_content_hash_band -> media_content (content_id));
 diesel::joinable!(media_report_profile_content -> common_report (report_id));
 diesel::joinable!(media_state -> account_id (account_id));
+diesel::joinable!(moderation_claim -> account_id (moderator_account_id));
 diesel::joinable!(news -> account_id (account_id_creator));
 diesel::joinable!(news_translations -> news (news_id));
 diesel::joinable!(profile -> account_id (account_id));
@@ -699,6 +858,7 @@ diesel::joinable!(used_content_ids -> account_id (account_id));
 diesel::allow_tables_to_appear_in_same_query!(
     access_token,
     account,
+    account_admin_role,
     account_email_sending_state,
     account_global_state,
     account_id,
@@ -706,8 +866,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     account_interaction_index,
     account_permissions,
     account_report,
+    account_report_ban_appeal,
     account_setup,
     account_state,
+    account_warning,
+    admin_role,
     chat_global_state,
     chat_report_chat_message,
     chat_state,
@@ -715,6 +878,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     current_account_media,
     demo_mode_account_ids,
     favorite_profile,
+    history_admin_action,
     history_performance_statistics_metric_name,
     history_performance_statistics_metric_value,
     history_performance_statistics_save_time,
@@ -726,14 +890,19 @@ diesel::allow_tables_to_appear_in_same_query!(
     history_profile_statistics_count_changes_all_genders,
     history_profile_statistics_count_changes_man,
     history_profile_statistics_count_changes_non_binary,
+    history_profile_statistics_count_changes_profile_visits,
     history_profile_statistics_count_changes_woman,
     history_profile_statistics_save_time,
     media_content,
+    media_content_duplicate,
+    media_content_hash_band,
     media_report_profile_content,
     media_state,
+    moderation_claim,
     news,
     news_translations,
     next_queue_number,
+    passed_profile,
     pending_messages,
     profile,
     profile_attributes,
@@ -744,9 +913,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     profile_report_profile_name,
     profile_report_profile_text,
     profile_state,
+    profile_visit,
     public_key,
     queue_entry,
     refresh_token,
+    seen_profile,
     shared_state,
     sign_in_with_info,
     used_account_ids,
@@ -21,6 +21,9 @@ pub use ban::*;
 mod report;
 pub use report::*;
 
+mod warning;
+pub use warning::*;
+
 // TODO(prod): Also add info what sign in with service is used?
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Clone, PartialEq)]
@@ -70,3 +70,13 @@ impl AccountBanReasonDetails {
 }
 
 diesel_string_wrapper!(AccountBanReasonDetails);
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone, PartialEq)]
+pub struct BanAppeal {
+    /// Max length is [Self::TEXT_MAX_LEN] bytes.
+    pub text: String,
+}
+
+impl BanAppeal {
+    pub const TEXT_MAX_LEN: usize = 2000;
+}
@@ -0,0 +1,23 @@
+use diesel::prelude::*;
+use model::UnixTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{AccountBanReasonCategory, AccountBanReasonDetails};
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq, Queryable, Selectable)]
+#[diesel(table_name = crate::schema::account_warning)]
+#[diesel(check_for_backend(crate::Db))]
+pub struct AccountWarning {
+    pub creation_unix_time: UnixTime,
+    pub reason_category: Option<AccountBanReasonCategory>,
+    pub reason_details: Option<AccountBanReasonDetails>,
+    /// `None` if the account has not acknowledged the warning.
+    pub acknowledged_unix_time: Option<UnixTime>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct GetAccountWarningList {
+    /// Warnings which have not expired. Latest warning is first.
+    pub values: Vec<AccountWarning>,
+}
@@ -1,4 +1,4 @@
-use model::{AccountId, UnixTime};
+use model::{AccountId, BanAppealDecision, UnixTime};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -6,11 +6,13 @@ mod news;
 mod search;
 mod permissions;
 mod report;
+mod role;
 
 pub use news::*;
 pub use search::*;
 pub use permissions::*;
 pub use report::*;
+pub use role::*;
 
 use crate::{AccountBanReasonCategory, AccountBanReasonDetails};
 
@@ -27,3 +29,22 @@ pub struct SetAccountBanState {
     pub reason_category: Option<AccountBanReasonCategory>,
     pub reason_details: Option<AccountBanReasonDetails>,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct ProcessBanAppeal {
+    /// Banned account which created the appeal.
+    pub account: AccountId,
+    pub decision: BanAppealDecision,
+    /// New ban end time. Required when decision is
+    /// [BanAppealDecision::Shorten].
+    pub ban_until: Option<UnixTime>,
+    /// Max length is [model::ProcessReport::MODERATOR_NOTE_MAX_LEN] bytes.
+    pub moderator_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct WarnAccount {
+    pub account: AccountId,
+    pub reason_category: Option<AccountBanReasonCategory>,
+    pub reason_details: Option<AccountBanReasonDetails>,
+}
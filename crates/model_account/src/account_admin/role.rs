@@ -0,0 +1,57 @@
+use model::{AccountId, Permissions, UnixTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Named set of admin permissions.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct AdminRole {
+    /// Max length is [Self::NAME_MAX_LEN] bytes.
+    pub name: String,
+    pub permissions: Permissions,
+}
+
+impl AdminRole {
+    pub const NAME_MAX_LEN: usize = 100;
+
+    pub fn name_is_valid(name: &str) -> bool {
+        !name.trim().is_empty() && name.len() <= Self::NAME_MAX_LEN
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct GetAdminRoleList {
+    pub roles: Vec<AdminRole>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct DeleteAdminRole {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct AdminRoleAssignment {
+    pub name: String,
+    /// `None` if the role does not expire.
+    pub expires: Option<UnixTime>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct GetAccountAdminRoles {
+    pub roles: Vec<AdminRoleAssignment>,
+    /// Permissions which are granted to the account without roles.
+    pub individual_permissions: Permissions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct AssignAdminRole {
+    pub account: AccountId,
+    pub name: String,
+    /// `None` if the role does not expire.
+    pub expires: Option<UnixTime>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct RemoveAdminRole {
+    pub account: AccountId,
+    pub name: String,
+}
@@ -1,5 +1,5 @@
 use diesel::{prelude::*, sql_types::BigInt, AsExpression, FromSqlRow};
-use model::{sync_version_wrappers, ContentId, ContentIdDb, ContentSlot, ProfileContentVersion, UnixTime};
+use model::{sync_version_wrappers, ContentId, ContentIdDb, ContentSlot, PerceptualHash, ProfileContentVersion, UnixTime};
 use model_server_data::{MediaContentType, ProfileContentEditedTime};
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
@@ -218,9 +218,20 @@ pub struct MediaContentRaw {
     pub moderation_moderator_account_id: Option<AccountIdDb>,
     pub usage_start_unix_time: Option<UnixTime>,
     pub usage_end_unix_time: Option<UnixTime>,
+    pub perceptual_hash: Option<PerceptualHash>,
+    pub human_moderation_required: bool,
 }
 
 impl MediaContentRaw {
+    /// Moderation state for content which moves from slot to moderation.
+    pub fn waiting_moderation_state(&self) -> ContentModerationState {
+        if self.human_moderation_required {
+            ContentModerationState::WaitingHumanModeration
+        } else {
+            ContentModerationState::WaitingBotOrHumanModeration
+        }
+    }
+
     pub fn slot_number(&self) -> Option<ContentSlot> {
         if self.moderation_state == ContentModerationState::InSlot {
             Some(self.slot_number)
@@ -348,6 +359,15 @@ impl CurrentAccountMediaInternal {
         .flatten()
     }
 
+    /// Profile verification is valid only for the primary profile content
+    /// and security content which were compared when verifying.
+    pub fn profile_verification_content_changed(&self, previous: &Self) -> bool {
+        let primary_content = |v: &Self| v.profile_content_id_0.as_ref().map(|c| c.content_id());
+        let security_content = |v: &Self| v.security_content_id.as_ref().map(|c| c.content_id());
+        primary_content(self) != primary_content(previous)
+            || security_content(self) != security_content(previous)
+    }
+
     pub fn iter_current_profile_content_info(&self) -> impl Iterator<Item = ContentInfo> + '_ {
         self.iter_current_profile_content().enumerate().map(|(i, v)| ContentInfo {
             cid: v.content_id(),
@@ -1,11 +1,12 @@
 use diesel::prelude::*;
-use model::{AccountId, ContentId};
+use model::{AccountId, ContentId, UnixTime};
 use model_server_data::MediaContentType;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 use crate::{
-    ProfileContentModerationRejectedReasonCategory, ProfileContentModerationRejectedReasonDetails,
+    ContentModerationState, ProfileContentModerationRejectedReasonCategory,
+    ProfileContentModerationRejectedReasonDetails,
 };
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, ToSchema)]
@@ -26,10 +27,42 @@ pub struct GetProfileContentPendingModerationList {
     pub values: Vec<ProfileContentPendingModeration>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Queryable)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProfileContentPendingModeration {
     pub account_id: AccountId,
     pub content_id: ContentId,
+    /// NSFW detection scores. Null if NSFW detection was not run.
+    pub nsfw_scores: Option<NsfwScores>,
+}
+
+/// NSFW detection scores. Scores are between 0.0 and 1.0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct NsfwScores {
+    pub drawings: f64,
+    pub hentai: f64,
+    pub neutral: f64,
+    pub porn: f64,
+    pub sexy: f64,
+}
+
+impl NsfwScores {
+    /// Scores from nullable database columns. Returns `None` if
+    /// some value is missing.
+    pub fn from_db_values(
+        drawings: Option<f64>,
+        hentai: Option<f64>,
+        neutral: Option<f64>,
+        porn: Option<f64>,
+        sexy: Option<f64>,
+    ) -> Option<Self> {
+        Some(Self {
+            drawings: drawings?,
+            hentai: hentai?,
+            neutral: neutral?,
+            porn: porn?,
+            sexy: sexy?,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Queryable)]
@@ -43,3 +76,44 @@ pub struct PostModerateProfileContent {
     /// the content to waiting for human moderation state.
     pub move_to_human: Option<bool>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PostProfileVerification {
+    pub account_id: AccountId,
+    /// Security content which was compared with the primary
+    /// profile content. Checked only when `verified` is true.
+    pub security_content_id: ContentId,
+    /// Primary profile content which was compared with the
+    /// security content. Checked only when `verified` is true.
+    pub profile_content_id: ContentId,
+    pub verified: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MediaContentDuplicateIteratorQuery {
+    pub start_position: UnixTime,
+    pub page: i64,
+}
+
+/// Near-duplicate image detected using perceptual hashes.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MediaContentDuplicate {
+    /// Owner of the new content
+    pub account_id: AccountId,
+    /// The new content
+    pub content_id: ContentId,
+    /// Owner of the older content which the new content matches
+    pub duplicate_account_id: AccountId,
+    pub duplicate_content_id: ContentId,
+    /// Moderation state of the older content when the page was loaded.
+    pub duplicate_state: ContentModerationState,
+    /// Hamming distance between the perceptual hashes.
+    /// Zero means that the images are most likely the same.
+    pub hash_distance: i64,
+    pub detection_time: UnixTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct GetMediaContentDuplicateList {
+    pub values: Vec<MediaContentDuplicate>,
+}
@@ -35,6 +35,9 @@ pub use text::*;
 mod report;
 pub use report::*;
 
+mod visit;
+pub use visit::*;
+
 const NUMBER_LIST_ATTRIBUTE_MAX_VALUES: usize = 8;
 
 /// Public profile info
@@ -53,6 +56,11 @@ pub struct Profile {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     #[schema(default = false)]
     unlimited_likes: bool,
+    /// Admin has verified that the primary profile content matches
+    /// the account's security content.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    verified: bool,
     /// The name has been accepted using allowlist or manual moderation.
     #[serde(default = "name_accepted_default", skip_serializing_if = "is_true")]
     #[schema(default = true)]
@@ -82,6 +90,7 @@ impl Profile {
         profile_text_moderation_state: ProfileTextModerationState,
         attributes: Vec<ProfileAttributeValue>,
         unlimited_likes: bool,
+        verified: bool,
     ) -> Self {
         Self {
             name: value.name,
@@ -89,6 +98,7 @@ impl Profile {
             age: value.age,
             attributes,
             unlimited_likes,
+            verified,
             name_accepted: profile_name_moderation_state.is_accepted(),
             ptext_accepted: profile_text_moderation_state.is_accepted(),
         }
@@ -117,10 +127,14 @@ pub struct ProfileStateInternal {
     pub search_group_flags: SearchGroupFlags,
     pub last_seen_time_filter: Option<LastSeenTimeFilter>,
     pub unlimited_likes_filter: Option<bool>,
+    pub verified_filter: Option<bool>,
     pub max_distance_km_filter: Option<MaxDistanceKm>,
     pub profile_created_time_filter: Option<ProfileCreatedTimeFilter>,
     pub profile_edited_time_filter: Option<ProfileEditedTimeFilter>,
     pub random_profile_order: bool,
+    pub mutual_interest_profile_order: bool,
+    pub hide_seen_profiles: bool,
+    pub profile_visit_tracking: bool,
     pub profile_attributes_sync_version: ProfileAttributesSyncVersion,
     pub profile_sync_version: ProfileSyncVersion,
     pub profile_name_moderation_state: ProfileNameModerationState,
@@ -141,10 +155,14 @@ impl From<ProfileStateInternal> for ProfileStateCached {
             search_group_flags: value.search_group_flags,
             last_seen_time_filter: value.last_seen_time_filter,
             unlimited_likes_filter: value.unlimited_likes_filter,
+            verified_filter: value.verified_filter,
             max_distance_km_filter: value.max_distance_km_filter,
             profile_created_time_filter: value.profile_created_time_filter,
             profile_edited_time_filter: value.profile_edited_time_filter,
             random_profile_order: value.random_profile_order,
+            mutual_interest_profile_order: value.mutual_interest_profile_order,
+            hide_seen_profiles: value.hide_seen_profiles,
+            profile_visit_tracking: value.profile_visit_tracking,
             profile_name_moderation_state: value.profile_name_moderation_state,
             profile_text_moderation_state: value.profile_text_moderation_state,
             profile_edited_time: value.profile_edited_unix_time,
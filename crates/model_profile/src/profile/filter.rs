@@ -12,12 +12,24 @@ pub struct ProfileFilteringSettingsUpdate {
     filters: Vec<ProfileAttributeFilterValueUpdate>,
     last_seen_time_filter: Option<LastSeenTimeFilter>,
     unlimited_likes_filter: Option<bool>,
+    verified_filter: Option<bool>,
     max_distance_km_filter: Option<MaxDistanceKm>,
     profile_created_filter: Option<ProfileCreatedTimeFilter>,
     profile_edited_filter: Option<ProfileEditedTimeFilter>,
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     #[schema(default = false)]
     random_profile_order: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    mutual_interest_profile_order: bool,
+    /// Hide profiles which profile iterator has returned recently.
+    /// Passed profiles are always hidden.
+    ///
+    /// Seen profiles are only recorded when this is enabled.
+    /// Disabling this removes the recorded seen profiles.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    hide_seen_profiles: bool,
 }
 
 impl ProfileFilteringSettingsUpdate {
@@ -51,6 +63,13 @@ impl ProfileFilteringSettingsUpdate {
             }
         }
 
+        if self.random_profile_order && self.mutual_interest_profile_order {
+            return Err(
+                "Random profile order and mutual interest profile order can't be enabled at the same time"
+                    .to_string(),
+            );
+        }
+
         if let Some(value) = self.last_seen_time_filter {
             if value.value < LastSeenTimeFilter::MIN_VALUE {
                 return Err("Invalid LastSeenTimeFilter value".to_string());
@@ -79,10 +98,13 @@ impl ProfileFilteringSettingsUpdate {
             filters: self.filters,
             last_seen_time_filter: self.last_seen_time_filter,
             unlimited_likes_filter: self.unlimited_likes_filter,
+            verified_filter: self.verified_filter,
             max_distance_km_filter: self.max_distance_km_filter,
             profile_created_filter: self.profile_created_filter,
             profile_edited_filter: self.profile_edited_filter,
             random_profile_order: self.random_profile_order,
+            mutual_interest_profile_order: self.mutual_interest_profile_order,
+            hide_seen_profiles: self.hide_seen_profiles,
         })
     }
 }
@@ -92,10 +114,13 @@ pub struct ProfileFilteringSettingsUpdateValidated {
     pub filters: Vec<ProfileAttributeFilterValueUpdate>,
     pub last_seen_time_filter: Option<LastSeenTimeFilter>,
     pub unlimited_likes_filter: Option<bool>,
+    pub verified_filter: Option<bool>,
     pub max_distance_km_filter: Option<MaxDistanceKm>,
     pub profile_created_filter: Option<ProfileCreatedTimeFilter>,
     pub profile_edited_filter: Option<ProfileEditedTimeFilter>,
     pub random_profile_order: bool,
+    pub mutual_interest_profile_order: bool,
+    pub hide_seen_profiles: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq, Eq)]
@@ -117,6 +142,9 @@ pub struct GetProfileFilteringSettings {
     pub filters: Vec<ProfileAttributeFilterValue>,
     pub last_seen_time_filter: Option<LastSeenTimeFilter>,
     pub unlimited_likes_filter: Option<bool>,
+    /// Show only verified (`true`) or only not verified (`false`)
+    /// profiles.
+    pub verified_filter: Option<bool>,
     /// Show profiles until this far from current location. The value
     /// is in kilometers.
     ///
@@ -129,4 +157,19 @@ pub struct GetProfileFilteringSettings {
     /// Randomize iterator starting position within the profile index area which
     /// current position and [Self::max_distance_km] defines.
     pub random_profile_order: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    /// Return profiles in mutual interest score order instead of location
+    /// order. Profiles within the profile index area which current
+    /// position and [Self::max_distance_km] defines are scored using
+    /// attribute overlap, recent activity and profile completeness.
+    pub mutual_interest_profile_order: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    /// Hide profiles which profile iterator has returned recently.
+    /// Passed profiles are always hidden.
+    ///
+    /// Seen profiles are only recorded when this is enabled.
+    /// Disabling this removes the recorded seen profiles.
+    pub hide_seen_profiles: bool,
 }
@@ -0,0 +1,30 @@
+use model_server_data::ProfileVisitorsIteratorSessionId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{AccountId, UnixTime};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, ToSchema, PartialEq, Default)]
+pub struct ProfileVisitTrackingSetting {
+    pub value: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResetProfileVisitorsIteratorResult {
+    pub s: ProfileVisitorsIteratorSessionId,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct ProfileVisitor {
+    pub aid: AccountId,
+    /// Time of the first visit during the visit day.
+    pub ut: UnixTime,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq, Default)]
+pub struct ProfileVisitorsPage {
+    pub p: Vec<ProfileVisitor>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[schema(default = false)]
+    pub error_invalid_iterator_session_id: bool,
+}
@@ -51,6 +51,8 @@ pub enum ProfileStatisticsHistoryValueType {
     AgeChangeMan,
     AgeChangeWoman,
     AgeChangeNonBinary,
+    /// Recorded profile visits during the last 24 hours
+    ProfileVisits,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Queryable)]
@@ -69,6 +71,7 @@ pub enum ProfileStatisticsHistoryValueTypeInternal {
         gender: Option<StatisticsGender>,
         age: i64,
     },
+    ProfileVisits,
 }
 
 impl TryFrom<GetProfileStatisticsHistoryParams> for ProfileStatisticsHistoryValueTypeInternal {
@@ -87,6 +90,7 @@ impl TryFrom<GetProfileStatisticsHistoryParams> for ProfileStatisticsHistoryValu
             (V::PublicNonBinary, _) => Self::Public {
                 gender: Some(StatisticsGender::NonBinary),
             },
+            (V::ProfileVisits, _) => Self::ProfileVisits,
             (V::AgeChange, Some(age)) => Self::AgeChange { gender: None, age },
             (V::AgeChangeMan, Some(age)) => Self::AgeChange {
                 gender: Some(StatisticsGender::Man),
@@ -46,6 +46,12 @@ pub use location::*;
 mod statistics;
 pub use statistics::*;
 
+mod ranking;
+pub use ranking::*;
+
+mod visit;
+pub use visit::*;
+
 /// Profile's database data
 #[derive(Debug, Clone, Queryable, Selectable)]
 #[diesel(table_name = crate::schema::profile)]
@@ -107,10 +113,14 @@ pub struct ProfileStateCached {
     pub search_group_flags: SearchGroupFlags,
     pub last_seen_time_filter: Option<LastSeenTimeFilter>,
     pub unlimited_likes_filter: Option<bool>,
+    pub verified_filter: Option<bool>,
     pub profile_created_time_filter: Option<ProfileCreatedTimeFilter>,
     pub profile_edited_time_filter: Option<ProfileEditedTimeFilter>,
     pub max_distance_km_filter: Option<MaxDistanceKm>,
     pub random_profile_order: bool,
+    pub mutual_interest_profile_order: bool,
+    pub hide_seen_profiles: bool,
+    pub profile_visit_tracking: bool,
     pub profile_name_moderation_state: ProfileNameModerationState,
     pub profile_text_moderation_state: ProfileTextModerationState,
     pub profile_edited_time: ProfileEditedTime,
@@ -181,6 +181,74 @@ impl SortedProfileAttributes {
             .and_then(|i| self.attributes.get(i))
     }
 
+    /// Attribute value is returned only if the possible attribute
+    /// condition is met.
+    pub fn find_id_with_met_condition(
+        &self,
+        id: AttributeId,
+        all_attributes: &ProfileAttributesInternal,
+    ) -> Option<&ProfileAttributeValue> {
+        self.find_id(id)
+            .filter(|_| all_attributes.is_condition_met(id, self))
+    }
+
+    /// Get attributes without values which have unmet attribute condition.
+    pub fn attributes_with_met_conditions(
+        &self,
+        all_attributes: Option<&ProfileAttributesInternal>,
+    ) -> Vec<ProfileAttributeValue> {
+        if let Some(all_attributes) = all_attributes {
+            self.attributes
+                .iter()
+                .filter(|a| all_attributes.is_condition_met(a.id, self))
+                .cloned()
+                .collect()
+        } else {
+            self.attributes.clone()
+        }
+    }
+
+    /// Count attributes which have at least one common value with
+    /// the other profile's attributes.
+    pub fn overlapping_attributes_count(
+        &self,
+        other: &SortedProfileAttributes,
+        all_attributes: &ProfileAttributesInternal,
+    ) -> usize {
+        self.attributes
+            .iter()
+            .filter(|a| {
+                let info = if let Some(info) = all_attributes.get_attribute(a.id) {
+                    info
+                } else {
+                    return false;
+                };
+
+                if !all_attributes.is_condition_met(a.id, self) {
+                    return false;
+                }
+
+                let other_value = if let Some(value) =
+                    other.find_id_with_met_condition(a.id, all_attributes)
+                {
+                    value
+                } else {
+                    return false;
+                };
+
+                if info.mode.is_bitflag_mode() {
+                    a.as_bitflags() & other_value.as_bitflags() != 0
+                } else if info.mode.is_number_list() {
+                    a.as_number_list()
+                        .iter()
+                        .any(|v| other_value.as_number_list().contains(v))
+                } else {
+                    a.as_top_level_id() == other_value.as_top_level_id()
+                }
+            })
+            .count()
+    }
+
     pub fn set_attributes(&mut self, value: Vec<ProfileAttributeValue>) {
         self.attributes = value;
     }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use utoipa::ToSchema;
 
-use super::AttributeId;
+use super::{AttributeId, ProfileAttributeValue, SortedProfileAttributes, TopLevelAttributeValueId};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttributesFileInternal {
@@ -81,6 +81,17 @@ pub struct AttributeInternal {
     pub group_values: Vec<GroupValuesInternal>,
     #[serde(default = "value_empty_vec")]
     pub translations: Vec<Language>,
+    pub condition: Option<AttributeConditionInternal>,
+}
+
+/// Attribute is visible only if other attribute has some of the
+/// listed top level values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttributeConditionInternal {
+    /// Key of the other attribute.
+    pub attribute: String,
+    /// Top level value keys of the other attribute.
+    pub values: Vec<String>,
 }
 
 fn value_bool_true() -> bool {
@@ -646,13 +657,28 @@ impl ProfileAttributesInternal {
         self.attributes.get(id.to_usize()).map(|v| &v.0)
     }
 
+    pub fn attribute_count(&self) -> usize {
+        self.attributes.len()
+    }
+
     pub fn from_file(file: AttributesFileInternal) -> Result<Self, String> {
         let (attribute_order, internal_attributes) = file.validate_attributes()?;
 
-        let mut attributes = vec![];
-        let mut attributes_for_info = vec![];
+        let mut validated_attributes = vec![];
         for a in internal_attributes {
             let info = a.validate()?;
+            validated_attributes.push((a, info));
+        }
+
+        let mut conditions = vec![];
+        for (a, _) in &validated_attributes {
+            conditions.push(Self::resolve_condition(a, &validated_attributes)?);
+        }
+        Self::validate_no_condition_cycles(&conditions)?;
+
+        let mut attributes = vec![];
+        let mut attributes_for_info = vec![];
+        for ((a, info), condition) in validated_attributes.into_iter().zip(conditions) {
             let a = Attribute {
                 key: a.key,
                 name: a.name,
@@ -666,6 +692,7 @@ impl ProfileAttributesInternal {
                 value_order: a.value_order,
                 values: info.values,
                 translations: info.translations,
+                condition,
             };
             let hash = ProfileAttributeHash::hash_attribute(&a)?;
             let id_and_hash = AttributeIdAndHash {
@@ -684,6 +711,120 @@ impl ProfileAttributesInternal {
         })
     }
 
+    /// The `attributes` must be sorted by attribute ID.
+    fn resolve_condition(
+        attribute: &AttributeInternal,
+        attributes: &[(AttributeInternal, AttributeInfoValidated)],
+    ) -> Result<Option<AttributeCondition>, String> {
+        let condition = if let Some(condition) = &attribute.condition {
+            condition
+        } else {
+            return Ok(None);
+        };
+
+        if condition.attribute == attribute.key {
+            return Err(format!(
+                "Attribute {} condition must not refer to the attribute itself",
+                attribute.key
+            ));
+        }
+
+        let (other, other_info) = attributes
+            .iter()
+            .find(|(a, _)| a.key == condition.attribute)
+            .ok_or_else(|| {
+                format!(
+                    "Attribute {} condition refers to unknown attribute {}",
+                    attribute.key, condition.attribute
+                )
+            })?;
+
+        if condition.values.is_empty() {
+            return Err(format!(
+                "Attribute {} condition must have at least one value",
+                attribute.key
+            ));
+        }
+
+        let mut values = vec![];
+        for key in &condition.values {
+            let value = other_info
+                .values
+                .iter()
+                .find(|v| v.key == *key)
+                .ok_or_else(|| {
+                    format!(
+                        "Attribute {} condition refers to unknown value {} of attribute {}",
+                        attribute.key, key, other.key
+                    )
+                })?;
+            values.push(value.id);
+        }
+        values.sort();
+        values.dedup();
+
+        Ok(Some(AttributeCondition {
+            attribute: other.id,
+            values,
+        }))
+    }
+
+    /// The `conditions` list index is the attribute ID.
+    fn validate_no_condition_cycles(
+        conditions: &[Option<AttributeCondition>],
+    ) -> Result<(), String> {
+        for start in 0..conditions.len() {
+            let mut visited = HashSet::new();
+            let mut current = start;
+            while let Some(Some(condition)) = conditions.get(current) {
+                if !visited.insert(current) {
+                    return Err(format!(
+                        "Attribute condition cycle detected for attribute ID {}",
+                        start
+                    ));
+                }
+                current = condition.attribute.to_usize();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the attribute does not have a condition or
+    /// the condition and conditions of the other attributes in
+    /// the condition chain are met.
+    pub fn is_condition_met(
+        &self,
+        id: AttributeId,
+        attributes: &SortedProfileAttributes,
+    ) -> bool {
+        let attribute = if let Some(attribute) = self.get_attribute(id) {
+            attribute
+        } else {
+            return false;
+        };
+
+        let condition = if let Some(condition) = &attribute.condition {
+            condition
+        } else {
+            return true;
+        };
+
+        let other = if let Some(other) = self.get_attribute(condition.attribute) {
+            other
+        } else {
+            return false;
+        };
+
+        match attributes.find_id(condition.attribute) {
+            Some(value) => {
+                condition.is_match(value, other.mode)
+                    && self.is_condition_met(condition.attribute, attributes)
+            }
+            None => false,
+        }
+    }
+
     pub fn info_for_client(&self) -> &ProfileAttributeInfo {
         &self.info
     }
@@ -761,4 +902,104 @@ pub struct Attribute {
     #[serde(default = "value_empty_vec", skip_serializing_if = "value_is_empty")]
     #[schema(default = json!([]))]
     pub translations: Vec<Language>,
+    /// Client should show and edit this attribute only when
+    /// the condition is met. Server ignores the attribute value
+    /// if the condition is not met.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<AttributeCondition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttributeCondition {
+    /// Attribute ID of the other attribute.
+    pub attribute: AttributeId,
+    /// The condition is met when the other attribute has at least one
+    /// of these top level values. The values are bitflags if the
+    /// other attribute is in bitflag mode.
+    pub values: Vec<u16>,
+}
+
+impl AttributeCondition {
+    /// Check the condition against the other attribute's value.
+    pub fn is_match(&self, value: &ProfileAttributeValue, mode: AttributeMode) -> bool {
+        if self.attribute != value.id() {
+            return false;
+        }
+
+        if mode.is_bitflag_mode() {
+            self.values.iter().any(|v| value.as_bitflags() & v != 0)
+        } else if mode.is_number_list() {
+            value
+                .as_number_list()
+                .iter()
+                .any(|v| self.values.contains(v))
+        } else {
+            self.values
+                .iter()
+                .any(|v| value.as_top_level_id() == TopLevelAttributeValueId::new(*v))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AttributesFileInternal;
+
+    fn attributes_file(relationship_condition: &str) -> String {
+        format!(
+            r#"
+attribute_order = "OrderNumber"
+
+[[attribute]]
+key = "relationship_type"
+name = "Relationship type"
+mode = "SelectSingleFilterSingle"
+id = 0
+order_number = 0
+value_order = "OrderNumber"
+values = ["Long-term", "Short-term"]
+{}
+
+[[attribute]]
+key = "children_wanted"
+name = "Children wanted"
+mode = "SelectSingleFilterSingle"
+id = 1
+order_number = 1
+value_order = "OrderNumber"
+values = ["Yes", "No"]
+condition = {{ attribute = "relationship_type", values = ["long-term"] }}
+"#,
+            relationship_condition
+        )
+    }
+
+    fn validate(file: &str) -> Result<(), String> {
+        let file: AttributesFileInternal = toml::from_str(file).map_err(|e| e.to_string())?;
+        file.validate().map(|_| ())
+    }
+
+    #[test]
+    fn condition_is_resolved() {
+        let file: AttributesFileInternal = toml::from_str(&attributes_file("")).unwrap();
+        let attributes = file.validate().unwrap();
+        let condition = attributes
+            .get_attribute(super::AttributeId::new(1))
+            .and_then(|a| a.condition.clone())
+            .unwrap();
+        assert_eq!(condition.attribute, super::AttributeId::new(0));
+        assert_eq!(condition.values, vec![0]);
+    }
+
+    #[test]
+    fn condition_cycle_is_rejected() {
+        let cycle = r#"condition = { attribute = "children_wanted", values = ["yes"] }"#;
+        assert!(validate(&attributes_file(cycle)).is_err());
+    }
+
+    #[test]
+    fn condition_to_itself_is_rejected() {
+        let itself = r#"condition = { attribute = "relationship_type", values = ["short-term"] }"#;
+        assert!(validate(&attributes_file(itself)).is_err());
+    }
 }
@@ -7,7 +7,10 @@ use simple_backend_model::UnixTime;
 use super::{
     ProfileCreatedTimeFilter, LastSeenTimeFilter, ProfileAttributeFilterValue, ProfileAttributesInternal, ProfileEditedTime, ProfileEditedTimeFilter, ProfileInternal, ProfileSearchAgeRangeValidated, ProfileStateCached, SearchGroupFlags, SearchGroupFlagsFilter, SortedProfileAttributes
 };
-use crate::{LastSeenTime, ProfileContentEditedTime, ProfileLink};
+use crate::{
+    LastSeenTime, MutualInterestRankingCandidate, MutualInterestRankingInfo,
+    ProfileContentEditedTime, ProfileLink,
+};
 
 #[derive(Debug)]
 pub struct ProfileQueryMakerDetails {
@@ -17,6 +20,7 @@ pub struct ProfileQueryMakerDetails {
     pub attribute_filters: Vec<ProfileAttributeFilterValue>,
     pub last_seen_time_filter: Option<LastSeenTimeFilter>,
     pub unlimited_likes_filter: Option<bool>,
+    pub verified_filter: Option<bool>,
     pub profile_created_time_filter: Option<ProfileCreatedTimeFilter>,
     pub profile_edited_time_filter: Option<ProfileEditedTimeFilter>,
 }
@@ -37,6 +41,7 @@ impl ProfileQueryMakerDetails {
             attribute_filters,
             last_seen_time_filter: state.last_seen_time_filter,
             unlimited_likes_filter: state.unlimited_likes_filter,
+            verified_filter: state.verified_filter,
             profile_created_time_filter: state.profile_created_time_filter,
             profile_edited_time_filter: state.profile_edited_time_filter,
         }
@@ -54,6 +59,9 @@ pub struct LocationIndexProfileData {
     search_groups: SearchGroupFlags,
     attributes: SortedProfileAttributes,
     unlimited_likes: bool,
+    profile_verified: bool,
+    /// Automatic report triage has hidden the profile.
+    hidden_pending_review: bool,
     /// Possible values:
     /// - Unix timestamp
     /// - Value -1 is currently online
@@ -63,6 +71,7 @@ pub struct LocationIndexProfileData {
     profile_edited_time: ProfileEditedTime,
     /// Option because media component might not be enabled
     profile_content_edited_time: Option<ProfileContentEditedTime>,
+    profile_text_exists: bool,
 }
 
 impl LocationIndexProfileData {
@@ -74,6 +83,8 @@ impl LocationIndexProfileData {
         attributes: SortedProfileAttributes,
         profile_content_version: Option<ProfileContentVersion>,
         unlimited_likes: bool,
+        profile_verified: bool,
+        hidden_pending_review: bool,
         last_seen_value: Option<LastSeenTime>,
         profile_created_time: InitialSetupCompletedTime,
         profile_content_edited_time: Option<ProfileContentEditedTime>,
@@ -88,6 +99,8 @@ impl LocationIndexProfileData {
             search_groups: state.search_group_flags,
             attributes,
             unlimited_likes,
+            profile_verified,
+            hidden_pending_review,
             last_seen_time: if let Some(last_seen_time) = last_seen_value {
                 AtomicI64::new(last_seen_time.raw())
             } else {
@@ -96,6 +109,7 @@ impl LocationIndexProfileData {
             profile_created_time,
             profile_edited_time: state.profile_edited_time,
             profile_content_edited_time,
+            profile_text_exists: !profile.profile_text.is_empty(),
         }
     }
 
@@ -112,12 +126,31 @@ impl LocationIndexProfileData {
         self.last_seen_time.store(value.raw(), Ordering::Relaxed);
     }
 
+    pub fn mutual_interest_score(
+        &self,
+        ranking_info: &MutualInterestRankingInfo,
+        attribute_info: Option<&ProfileAttributesInternal>,
+        current_time: &UnixTime,
+    ) -> u32 {
+        let candidate = MutualInterestRankingCandidate {
+            id: self.profile_link.id(),
+            attributes: &self.attributes,
+            last_seen_time: self.to_profile_link_value().last_seen_time(),
+            profile_text_exists: self.profile_text_exists,
+        };
+        ranking_info.score(candidate, attribute_info, current_time)
+    }
+
     pub fn is_match(
         &self,
         query_maker_details: &ProfileQueryMakerDetails,
         attribute_info: Option<&ProfileAttributesInternal>,
         current_time: &UnixTime,
     ) -> bool {
+        if self.hidden_pending_review {
+            return false;
+        }
+
         let mut is_match = self.search_age_range.is_match(query_maker_details.age)
             && query_maker_details.search_age_range.is_match(self.age)
             && query_maker_details
@@ -136,6 +169,12 @@ impl LocationIndexProfileData {
             }
         }
 
+        if is_match {
+            if let Some(verified_filter) = query_maker_details.verified_filter {
+                is_match &= verified_filter == self.profile_verified;
+            }
+        }
+
         if is_match {
             if let Some(profile_created_time_filter) = query_maker_details.profile_created_time_filter {
                 is_match &= profile_created_time_filter.is_match(self.profile_created_time, current_time);
@@ -178,15 +217,18 @@ impl LocationIndexProfileData {
         attribute_info: &ProfileAttributesInternal,
     ) -> bool {
         for filter in &query_maker_details.attribute_filters {
-            let attribute_info =
+            let attribute =
                 if let Some(info) = attribute_info.get_attribute(filter.id()) {
                     info
                 } else {
                     return false;
                 };
 
-            if let Some(value) = self.attributes.find_id(filter.id()) {
-                if !filter.is_match_with_attribute_value(value, attribute_info) {
+            if let Some(value) = self
+                .attributes
+                .find_id_with_met_condition(filter.id(), attribute_info)
+            {
+                if !filter.is_match_with_attribute_value(value, attribute) {
                     return false;
                 }
             } else {
@@ -62,6 +62,10 @@ impl ProfileLink {
         }
     }
 
+    pub fn id(&self) -> AccountId {
+        self.id
+    }
+
     pub fn last_seen_time(&self) -> Option<LastSeenTime> {
         self.last_seen_time
     }
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use model::AccountId;
+use simple_backend_model::UnixTime;
+
+use super::{LastSeenTime, ProfileAttributesInternal, SortedProfileAttributes};
+
+const SCORE_ATTRIBUTE_OVERLAP: u32 = 10;
+const SCORE_ONLINE: u32 = 30;
+const SCORE_SEEN_DAY: u32 = 20;
+const SCORE_SEEN_WEEK: u32 = 10;
+const SCORE_SEEN_MONTH: u32 = 5;
+const SCORE_COMPLETENESS_MAX: u32 = 20;
+const SCORE_RECEIVED_LIKE: u32 = 25;
+
+const SECONDS_DAY: i64 = 60 * 60 * 24;
+const SECONDS_WEEK: i64 = SECONDS_DAY * 7;
+const SECONDS_MONTH: i64 = SECONDS_DAY * 30;
+
+/// Viewer related data for mutual interest profile ranking.
+#[derive(Debug, Clone)]
+pub struct MutualInterestRankingInfo {
+    pub attributes: SortedProfileAttributes,
+    /// Accounts which have liked the viewer. This only affects the
+    /// ranking and it must not be revealed to the viewer.
+    pub received_likes: HashSet<AccountId>,
+}
+
+/// Candidate profile data for calculating the mutual interest score.
+pub struct MutualInterestRankingCandidate<'a> {
+    pub id: AccountId,
+    pub attributes: &'a SortedProfileAttributes,
+    pub last_seen_time: Option<LastSeenTime>,
+    pub profile_text_exists: bool,
+}
+
+impl MutualInterestRankingInfo {
+    /// Higher score is better.
+    pub fn score(
+        &self,
+        candidate: MutualInterestRankingCandidate<'_>,
+        attribute_info: Option<&ProfileAttributesInternal>,
+        current_time: &UnixTime,
+    ) -> u32 {
+        let mut score = 0;
+
+        if let Some(attribute_info) = attribute_info {
+            let overlap = self
+                .attributes
+                .overlapping_attributes_count(candidate.attributes, attribute_info);
+            score += SCORE_ATTRIBUTE_OVERLAP.saturating_mul(overlap as u32);
+        }
+
+        score += Self::activity_score(candidate.last_seen_time, current_time);
+        score += Self::completeness_score(&candidate, attribute_info);
+
+        if self.received_likes.contains(&candidate.id) {
+            score += SCORE_RECEIVED_LIKE;
+        }
+
+        score
+    }
+
+    fn activity_score(last_seen_time: Option<LastSeenTime>, current_time: &UnixTime) -> u32 {
+        let last_seen_time = if let Some(last_seen_time) = last_seen_time {
+            last_seen_time
+        } else {
+            return 0;
+        };
+
+        if last_seen_time == LastSeenTime::ONLINE {
+            return SCORE_ONLINE;
+        }
+
+        let seconds_since = current_time.ut.saturating_sub(last_seen_time.raw());
+        if seconds_since <= SECONDS_DAY {
+            SCORE_SEEN_DAY
+        } else if seconds_since <= SECONDS_WEEK {
+            SCORE_SEEN_WEEK
+        } else if seconds_since <= SECONDS_MONTH {
+            SCORE_SEEN_MONTH
+        } else {
+            0
+        }
+    }
+
+    fn completeness_score(
+        candidate: &MutualInterestRankingCandidate<'_>,
+        attribute_info: Option<&ProfileAttributesInternal>,
+    ) -> u32 {
+        // Profile text and attributes have equal weights. Profile content
+        // is not counted as public profiles always have content.
+        let part = SCORE_COMPLETENESS_MAX / 2;
+        let mut score = 0;
+
+        if candidate.profile_text_exists {
+            score += part;
+        }
+
+        if let Some(attribute_info) = attribute_info {
+            let all = attribute_info.attribute_count();
+            if all > 0 {
+                let set = candidate.attributes.attributes().len().min(all);
+                score += (part as usize * set / all) as u32;
+            }
+        }
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use model::AccountId;
+    use simple_backend_model::UnixTime;
+
+    use super::{
+        MutualInterestRankingCandidate, MutualInterestRankingInfo, SCORE_COMPLETENESS_MAX,
+        SCORE_ONLINE, SCORE_RECEIVED_LIKE, SCORE_SEEN_DAY, SCORE_SEEN_MONTH, SCORE_SEEN_WEEK,
+        SECONDS_DAY,
+    };
+    use crate::{LastSeenTime, SortedProfileAttributes};
+
+    const CURRENT_TIME: i64 = 1_000_000_000;
+
+    fn empty_attributes() -> SortedProfileAttributes {
+        SortedProfileAttributes::new(vec![], None)
+    }
+
+    fn score(
+        info: &MutualInterestRankingInfo,
+        id: AccountId,
+        last_seen_time: Option<LastSeenTime>,
+        profile_text_exists: bool,
+    ) -> u32 {
+        let attributes = empty_attributes();
+        let candidate = MutualInterestRankingCandidate {
+            id,
+            attributes: &attributes,
+            last_seen_time,
+            profile_text_exists,
+        };
+        info.score(candidate, None, &UnixTime::new(CURRENT_TIME))
+    }
+
+    fn info(received_likes: HashSet<AccountId>) -> MutualInterestRankingInfo {
+        MutualInterestRankingInfo {
+            attributes: empty_attributes(),
+            received_likes,
+        }
+    }
+
+    fn seen_seconds_ago(seconds: i64) -> Option<LastSeenTime> {
+        Some(LastSeenTime::new(CURRENT_TIME - seconds))
+    }
+
+    #[test]
+    fn empty_candidate_has_zero_score() {
+        assert_eq!(score(&info(HashSet::new()), AccountId::new_random(), None, false), 0);
+    }
+
+    #[test]
+    fn online_candidate_has_highest_activity_score() {
+        let info = info(HashSet::new());
+        let id = AccountId::new_random();
+        assert_eq!(score(&info, id, Some(LastSeenTime::ONLINE), false), SCORE_ONLINE);
+        assert_eq!(score(&info, id, seen_seconds_ago(SECONDS_DAY), false), SCORE_SEEN_DAY);
+        assert_eq!(score(&info, id, seen_seconds_ago(SECONDS_DAY * 7), false), SCORE_SEEN_WEEK);
+        assert_eq!(score(&info, id, seen_seconds_ago(SECONDS_DAY * 30), false), SCORE_SEEN_MONTH);
+        assert_eq!(score(&info, id, seen_seconds_ago(SECONDS_DAY * 31), false), 0);
+    }
+
+    #[test]
+    fn profile_text_increases_score() {
+        let info = info(HashSet::new());
+        let id = AccountId::new_random();
+        assert_eq!(score(&info, id, None, true), SCORE_COMPLETENESS_MAX / 2);
+    }
+
+    #[test]
+    fn received_like_increases_score_only_for_liker() {
+        let liker = AccountId::new_random();
+        let other = AccountId::new_random();
+        let info = info(HashSet::from([liker]));
+        assert_eq!(score(&info, liker, None, false), SCORE_RECEIVED_LIKE);
+        assert_eq!(score(&info, other, None, false), 0);
+    }
+}
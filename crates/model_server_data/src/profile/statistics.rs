@@ -77,6 +77,8 @@ pub struct ProfileStatisticsInternal {
     pub age_counts: ProfileAgeCounts,
     pub account_count: i64,
     pub public_profile_counts: PublicProfileCounts,
+    /// Recorded profile visits during the last 24 hours.
+    pub profile_visit_count: i64,
 }
 
 impl ProfileStatisticsInternal {
@@ -85,12 +87,14 @@ impl ProfileStatisticsInternal {
         age_counts: ProfileAgeCounts,
         account_count: i64,
         public_profile_counts: PublicProfileCounts,
+        profile_visit_count: i64,
     ) -> Self {
         Self {
             generation_time,
             age_counts,
             account_count,
             public_profile_counts,
+            profile_visit_count,
         }
     }
 }
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use diesel::{deserialize::FromSqlRow, expression::AsExpression, sql_types::BigInt};
+use model::{AccountId, NextNumberStorage, UnixTime};
+use serde::{Deserialize, Serialize};
+use simple_backend_model::diesel_i64_wrapper;
+use utoipa::ToSchema;
+
+/// Session ID type for profile visitors iterator so that client can detect
+/// server restarts and ask user to refresh profile visitors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfileVisitorsIteratorSessionIdInternal {
+    id: i64,
+}
+
+impl ProfileVisitorsIteratorSessionIdInternal {
+    /// Current implementation uses i64. Only requirement for this
+    /// type is that next one should be different than the previous.
+    pub fn create(storage: &mut NextNumberStorage) -> Self {
+        Self {
+            id: storage.get_and_increment(),
+        }
+    }
+}
+
+/// Session ID type for profile visitors iterator so that client can detect
+/// server restarts and ask user to refresh profile visitors.
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, PartialEq)]
+pub struct ProfileVisitorsIteratorSessionId {
+    id: i64,
+}
+
+impl From<ProfileVisitorsIteratorSessionIdInternal> for ProfileVisitorsIteratorSessionId {
+    fn from(value: ProfileVisitorsIteratorSessionIdInternal) -> Self {
+        Self { id: value.id }
+    }
+}
+
+impl From<ProfileVisitorsIteratorSessionId> for ProfileVisitorsIteratorSessionIdInternal {
+    fn from(value: ProfileVisitorsIteratorSessionId) -> Self {
+        Self { id: value.id }
+    }
+}
+
+/// Profile visit ID
+#[derive(
+    Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Default, FromSqlRow, AsExpression,
+)]
+#[diesel(sql_type = BigInt)]
+pub struct ProfileVisitId {
+    pub id: i64,
+}
+
+impl ProfileVisitId {
+    /// The value is the same as [model::MatchId::next_id_to_latest_used_id]
+    /// returns if there is no items.
+    pub const NO_PROFILE_VISIT_ID: ProfileVisitId = ProfileVisitId { id: -1 };
+
+    pub fn new(id: i64) -> Self {
+        Self { id }
+    }
+
+    pub fn as_i64(&self) -> &i64 {
+        &self.id
+    }
+}
+
+diesel_i64_wrapper!(ProfileVisitId);
+
+impl From<ProfileVisitId> for i64 {
+    fn from(value: ProfileVisitId) -> Self {
+        value.id
+    }
+}
+
+/// Profile visits are deduplicated using the day number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfileVisitDay {
+    pub day: i64,
+}
+
+impl ProfileVisitDay {
+    const SECONDS_IN_DAY: i64 = 60 * 60 * 24;
+
+    pub fn from_unix_time(time: UnixTime) -> Self {
+        Self {
+            day: time.ut.div_euclid(Self::SECONDS_IN_DAY),
+        }
+    }
+}
+
+/// Visited profiles which visit is already stored to the database
+/// during the current day. This is used for skipping unnecessary
+/// database writes.
+#[derive(Debug, Default)]
+pub struct RecordedProfileVisits {
+    day: Option<ProfileVisitDay>,
+    targets: HashSet<AccountId>,
+}
+
+impl RecordedProfileVisits {
+    /// Returns true if the visit was not yet recorded during the day.
+    pub fn mark_recorded(&mut self, target: AccountId, day: ProfileVisitDay) -> bool {
+        if self.day != Some(day) {
+            self.day = Some(day);
+            self.targets.clear();
+        }
+        self.targets.insert(target)
+    }
+
+    /// Forget the visit, so that the next visit is recorded again.
+    pub fn remove(&mut self, target: AccountId) {
+        self.targets.remove(&target);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use model::{AccountId, UnixTime};
+
+    use super::{ProfileVisitDay, RecordedProfileVisits};
+
+    const DAY: i64 = 60 * 60 * 24;
+
+    #[test]
+    fn visit_day_changes_at_midnight() {
+        let day = ProfileVisitDay::from_unix_time(UnixTime::new(DAY * 10));
+        assert_eq!(day.day, 10);
+        assert_eq!(ProfileVisitDay::from_unix_time(UnixTime::new(DAY * 11 - 1)), day);
+        assert_ne!(ProfileVisitDay::from_unix_time(UnixTime::new(DAY * 11)), day);
+    }
+
+    #[test]
+    fn visit_is_recorded_once_per_day() {
+        let mut visits = RecordedProfileVisits::default();
+        let target = AccountId::new_random();
+        let day = ProfileVisitDay::from_unix_time(UnixTime::new(DAY));
+        let next_day = ProfileVisitDay::from_unix_time(UnixTime::new(DAY * 2));
+        assert!(visits.mark_recorded(target, day));
+        assert!(!visits.mark_recorded(target, day));
+        assert!(visits.mark_recorded(AccountId::new_random(), day));
+        assert!(visits.mark_recorded(target, next_day));
+    }
+
+    #[test]
+    fn removed_visit_is_recorded_again() {
+        let mut visits = RecordedProfileVisits::default();
+        let target = AccountId::new_random();
+        let day = ProfileVisitDay::from_unix_time(UnixTime::new(DAY));
+        assert!(visits.mark_recorded(target, day));
+        visits.remove(target);
+        assert!(visits.mark_recorded(target, day));
+    }
+}
@@ -33,6 +33,8 @@ impl ApiDoc {
             .merge_from(server_api::common_admin::router_maintenance(state.clone()).into_openapi())
             .merge_from(server_api::common_admin::router_manager(state.clone()).into_openapi())
             .merge_from(server_api::common_admin::router_report(state.clone()).into_openapi())
+            .merge_from(server_api::common_admin::router_audit_log(state.clone()).into_openapi())
+            .merge_from(server_api::common_admin::router_moderation_claim(state.clone()).into_openapi())
             .tag_routes("common_admin");
         doc.merge(common_admin);
         // Account
@@ -47,6 +49,7 @@ impl ApiDoc {
             .merge_from(server_api_account::account::router_settings(state.clone()).into_openapi())
             .merge_from(server_api_account::account::router_state(state.clone()).into_openapi())
             .merge_from(server_api_account::account::router_account_report(state.clone()).into_openapi())
+            .merge_from(server_api_account::account::router_warning(state.clone()).into_openapi())
             .tag_routes("account");
         doc.merge(account);
         let account_admin = ApiDoc::openapi()
@@ -65,12 +68,18 @@ impl ApiDoc {
             .merge_from(
                 server_api_account::account_admin::router_admin_permissions(state.clone()).into_openapi(),
             )
+            .merge_from(
+                server_api_account::account_admin::router_admin_role(state.clone()).into_openapi(),
+            )
             .merge_from(
                 server_api_account::account_admin::router_admin_state(state.clone()).into_openapi(),
             )
             .merge_from(
                 server_api_account::account_admin::router_admin_account_report(state.clone()).into_openapi(),
             )
+            .merge_from(
+                server_api_account::account_admin::router_admin_warning(state.clone()).into_openapi(),
+            )
             .tag_routes("account_admin");
         doc.merge(account_admin);
         // Media
@@ -95,6 +104,14 @@ impl ApiDoc {
                 server_api_media::media_admin::router_admin_moderation(state.clone())
                     .into_openapi(),
             )
+            .merge_from(
+                server_api_media::media_admin::router_admin_profile_verification(state.clone())
+                    .into_openapi(),
+            )
+            .merge_from(
+                server_api_media::media_admin::router_admin_duplicate(state.clone())
+                    .into_openapi(),
+            )
             .tag_routes("media_admin");
         doc.merge(media_admin);
         // Profile
@@ -109,6 +126,7 @@ impl ApiDoc {
                 server_api_profile::profile::router_iterate_profiles(state.clone()).into_openapi(),
             )
             .merge_from(server_api_profile::profile::router_location(state.clone()).into_openapi())
+            .merge_from(server_api_profile::profile::router_pass(state.clone()).into_openapi())
             .merge_from(
                 server_api_profile::profile::router_profile_data(state.clone()).into_openapi(),
             )
@@ -118,6 +136,9 @@ impl ApiDoc {
             .merge_from(
                 server_api_profile::profile::router_statistics(state.clone()).into_openapi(),
             )
+            .merge_from(
+                server_api_profile::profile::router_profile_visit(state.clone()).into_openapi(),
+            )
             .tag_routes("profile");
         doc.merge(profile);
         let profile_admin = ApiDoc::openapi()
@@ -167,7 +188,7 @@ impl ApiDoc {
             )
             .tag_routes("chat");
         doc.merge(chat);
-        doc
+        doc.document_required_permissions()
     }
 
     pub async fn open_api_json_string() -> Result<String, serde_json::Error> {
@@ -226,6 +247,7 @@ impl ApiDoc {
 
 trait OpenApiExtensions: Sized {
     fn tag_routes(self, tag: &str) -> Self;
+    fn document_required_permissions(self) -> Self;
 }
 
 impl OpenApiExtensions for utoipa::openapi::OpenApi {
@@ -251,4 +273,72 @@ impl OpenApiExtensions for utoipa::openapi::OpenApi {
         }
         self
     }
+
+    /// Add description text and 403 response for routes which have
+    /// permission names as "access_token" security scopes.
+    fn document_required_permissions(mut self) -> Self {
+        let handle_operation = |operation: Option<&mut utoipa::openapi::path::Operation>| {
+            let operation = if let Some(operation) = operation {
+                operation
+            } else {
+                return;
+            };
+
+            let permissions: Vec<String> = operation
+                .security
+                .iter()
+                .flatten()
+                .filter_map(|v| serde_json::to_value(v).ok())
+                .filter_map(|mut v| v.get_mut("access_token").map(|v| v.take()))
+                .filter_map(|v| serde_json::from_value::<Vec<String>>(v).ok())
+                .flatten()
+                .collect();
+
+            if permissions.is_empty() {
+                return;
+            }
+
+            let permissions = permissions
+                .iter()
+                .map(|v| format!("`{}`", v))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let text = format!("Required permissions: {}", permissions);
+            operation.description = match operation.description.take() {
+                Some(description) => Some(format!("{}\n\n{}", description, text)),
+                None => Some(text),
+            };
+
+            operation
+                .responses
+                .responses
+                .entry("403".to_string())
+                .or_insert_with(|| {
+                    utoipa::openapi::ResponseBuilder::new()
+                        .description("Permission denied.")
+                        .content(
+                            "application/json",
+                            utoipa::openapi::ContentBuilder::new()
+                                .schema(Some(utoipa::openapi::Ref::from_schema_name(
+                                    "PermissionDenied",
+                                )))
+                                .build(),
+                        )
+                        .build()
+                        .into()
+                });
+        };
+
+        for (_, item) in self.paths.paths.iter_mut() {
+            handle_operation(item.get.as_mut());
+            handle_operation(item.put.as_mut());
+            handle_operation(item.post.as_mut());
+            handle_operation(item.delete.as_mut());
+            handle_operation(item.options.as_mut());
+            handle_operation(item.head.as_mut());
+            handle_operation(item.patch.as_mut());
+            handle_operation(item.trace.as_mut());
+        }
+        self
+    }
 }
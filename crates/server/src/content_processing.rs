@@ -1,6 +1,6 @@
 use config::Config;
-use model::ContentId;
-use model_media::MediaContentType;
+use model::{ContentId, PerceptualHash};
+use model_media::{MediaContentType, NsfwScores};
 use server_api::{
     app::{ContentProcessingProvider, EventManagerProvider, WriteData},
     db_write_raw,
@@ -12,9 +12,9 @@ use server_data::{
 };
 use server_data_media::write::GetWriteCommandsMedia;
 use server_state::S;
-use simple_backend::{image::ImageProcess, ServerQuitWatcher};
+use simple_backend::{create_counters, image::ImageProcess, ServerQuitWatcher};
 use simple_backend_config::args::InputFileType;
-use simple_backend_image_process::ImageProcessingInfo;
+use simple_backend_image_process::{ImageProcessingInfo, NsfwDetectionInfo, NsfwDetectionResult};
 use tokio::task::JoinHandle;
 use tracing::{error, warn};
 
@@ -98,18 +98,20 @@ impl ContentProcessingManager {
             .await
             .change_context(ContentProcessingError::ContentProcessingFailed),
         };
-
         let mut write = self.state.content_processing().data().write().await;
         if let Some(state) = write.processing_states_mut().get_mut(&content.to_key()) {
             let result = self
                 .if_successful_save_to_database(self.state.config(), result, state)
                 .await;
             match result {
-                Ok(ImgInfo { face_detected, content_id }) => {
+                Ok(Some(ImgInfo { face_detected, content_id })) => {
                     state
                         .processing_state
                         .change_to_completed(content_id, face_detected);
                 }
+                Ok(None) => {
+                    state.processing_state.change_to_nsfw_detected();
+                }
                 Err(e) => {
                     state.processing_state.change_to_failed();
                     error!("Content processing error: {:?}", e);
@@ -132,13 +134,32 @@ impl ContentProcessingManager {
         }
     }
 
+    /// Returns `None` if NSFW content was detected.
     async fn if_successful_save_to_database(
         &self,
         config: &Config,
         result: Result<ImageProcessingInfo, ContentProcessingError>,
         state: &mut ProcessingState,
-    ) -> Result<ImgInfo, ContentProcessingError> {
+    ) -> Result<Option<ImgInfo>, ContentProcessingError> {
         let info = result?;
+        let nsfw = match (info.nsfw_detection, &config.simple_backend().image_processing().nsfw_detection) {
+            (NsfwDetectionInfo::Detected(scores), Some(nsfw_config)) => {
+                NsfwDetection::Detected(NsfwDetectionResult::new(scores, nsfw_config))
+            }
+            (NsfwDetectionInfo::Failed, _) => {
+                CONTENT_PROCESSING.nsfw_detection_failed.incr();
+                NsfwDetection::Failed
+            }
+            _ => NsfwDetection::Disabled,
+        };
+        if let NsfwDetection::Detected(NsfwDetectionResult { rejected: true, .. }) = nsfw {
+            CONTENT_PROCESSING.nsfw_rejected.incr();
+            if let Err(e) = state.tmp_img.clone().overwrite_and_remove_if_exists().await {
+                warn!("content.tmp_img removing failed {:?}", e)
+            }
+            return Ok(None);
+        }
+
         let face_detected =
             if let Some(face_detected) = config.simple_backend().override_face_detection_result() {
                 face_detected
@@ -146,6 +167,23 @@ impl ContentProcessingManager {
                 info.face_detected
             };
 
+        let perceptual_hash = PerceptualHash::from_u64(info.perceptual_hash);
+        let (nsfw_scores, nsfw_move_to_human) = match nsfw {
+            NsfwDetection::Disabled => (None, false),
+            // Let human moderators check the image
+            NsfwDetection::Failed => (None, true),
+            NsfwDetection::Detected(result) => {
+                let scores = NsfwScores {
+                    drawings: result.scores.drawings.into(),
+                    hentai: result.scores.hentai.into(),
+                    neutral: result.scores.neutral.into(),
+                    porn: result.scores.porn.into(),
+                    sexy: result.scores.sexy.into(),
+                };
+                (Some(scores), result.move_to_human)
+            }
+        };
+
         let state_copy = state.clone();
         let content_id = db_write_raw!(self.state, move |cmds| {
             cmds.media()
@@ -155,16 +193,19 @@ impl ContentProcessingManager {
                     state_copy.slot,
                     state_copy.new_content_params,
                     face_detected,
+                    perceptual_hash,
+                    nsfw_scores,
+                    nsfw_move_to_human,
                 )
                 .await
         })
         .await
         .change_context(ContentProcessingError::DatabaseError)?;
 
-        Ok(ImgInfo {
+        Ok(Some(ImgInfo {
             face_detected,
             content_id,
-        })
+        }))
     }
 }
 
@@ -172,3 +213,18 @@ struct ImgInfo {
     face_detected: bool,
     content_id: ContentId,
 }
+
+enum NsfwDetection {
+    Disabled,
+    Detected(NsfwDetectionResult),
+    /// Detection failed, so human moderators should check the image.
+    Failed,
+}
+
+create_counters!(
+    ContentProcessingCounters,
+    CONTENT_PROCESSING,
+    CONTENT_PROCESSING_COUNTERS_LIST,
+    nsfw_rejected,
+    nsfw_detection_failed,
+);
@@ -6,6 +6,7 @@ use server_api::{
 };
 use server_common::result::{Result, WrappedResultExt};
 use server_data::write::GetWriteCommandsCommon;
+use server_data_account::write::GetWriteCommandsAccount;
 use server_state::S;
 use simple_backend::{app::PerfCounterDataProvider, ServerQuitWatcher};
 
@@ -86,6 +87,7 @@ impl HourlyTaskManager {
         &self,
     ) -> Result<(), HourlyTaskError> {
         self.save_profile_statistics().await?;
+        self.remove_expired_admin_roles().await?;
         Ok(())
     }
 
@@ -106,4 +108,17 @@ impl HourlyTaskManager {
 
         Ok(())
     }
+
+    pub async fn remove_expired_admin_roles(&self) -> Result<(), HourlyTaskError> {
+        db_write_raw!(self.state, move |cmds| {
+            cmds.account_admin()
+                .role()
+                .remove_expired_roles()
+                .await
+        })
+        .await
+        .change_context(HourlyTaskError::DatabaseError)?;
+
+        Ok(())
+    }
 }
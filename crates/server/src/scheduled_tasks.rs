@@ -122,6 +122,9 @@ impl ScheduledTaskManager {
             .await?;
         self.save_profile_statistics().await?;
         self.delete_processed_reports_which_have_user_data().await?;
+        self.delete_old_profile_visits().await?;
+        self.delete_expired_seen_profiles().await?;
+        self.delete_old_moderation_claims().await?;
         // TODO(prod): SQLite database backups
         Ok(())
     }
@@ -165,6 +168,45 @@ impl ScheduledTaskManager {
         Ok(())
     }
 
+    pub async fn delete_old_profile_visits(&self) -> Result<(), ScheduledTaskError> {
+        let retention_time = self.state.config().limits_profile().profile_visit_retention_duration;
+        let deletion_time = UnixTime::current_time().subtract_seconds(retention_time.seconds);
+
+        db_write_raw!(self.state, move |cmds| {
+            cmds.profile()
+                .visit()
+                .delete_profile_visits_older_than(deletion_time)
+                .await
+        })
+        .await
+        .change_context(ScheduledTaskError::DatabaseError)?;
+
+        Ok(())
+    }
+
+    pub async fn delete_expired_seen_profiles(&self) -> Result<(), ScheduledTaskError> {
+        db_write_raw!(self.state, move |cmds| {
+            cmds.profile().delete_expired_seen_profiles().await
+        })
+        .await
+        .change_context(ScheduledTaskError::DatabaseError)?;
+
+        Ok(())
+    }
+
+    pub async fn delete_old_moderation_claims(&self) -> Result<(), ScheduledTaskError> {
+        db_write_raw!(self.state, move |cmds| {
+            cmds.common_admin()
+                .moderation_claim()
+                .delete_old_claims()
+                .await
+        })
+        .await
+        .change_context(ScheduledTaskError::DatabaseError)?;
+
+        Ok(())
+    }
+
     pub async fn run_tasks_for_individual_accounts(
         &self,
         quit_notification: &mut ServerQuitWatcher,
@@ -204,6 +246,8 @@ impl ScheduledTaskManager {
             } else if account_state == AccountState::Banned {
                 self.unban_account_if_needed(id).await?;
             }
+
+            self.end_report_triage_if_needed(id).await?;
         }
 
         if age_updated != 0 {
@@ -302,7 +346,7 @@ impl ScheduledTaskManager {
                 db_write_raw!(self.state, move |cmds| {
                     cmds.account()
                         .delete()
-                        .delete_account(id)
+                        .delete_account(id, None)
                         .await
                     })
                 .await
@@ -332,7 +376,7 @@ impl ScheduledTaskManager {
                 db_write_raw!(self.state, move |cmds| {
                     cmds.account_admin()
                         .ban()
-                        .set_account_ban_state(id, None, None, None, None)
+                        .set_account_ban_state(id, None, None, None, None, None)
                         .await
                     })
                 .await
@@ -342,4 +386,18 @@ impl ScheduledTaskManager {
 
         Ok(())
     }
+
+    pub async fn end_report_triage_if_needed(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<(), ScheduledTaskError> {
+        db_write_raw!(self.state, move |cmds| {
+            cmds.common_admin()
+                .report()
+                .end_report_triage_if_reviewed(id)
+                .await
+            })
+        .await
+        .change_context(ScheduledTaskError::DatabaseError)
+    }
 }
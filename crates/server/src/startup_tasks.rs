@@ -5,7 +5,7 @@ use server_api::{
 };
 use server_common::{data::DataError, result::Result};
 use server_data::{read::GetReadCommandsCommon, write::GetWriteCommandsCommon};
-use server_data_account::read::GetReadCommandsAccount;
+use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use server_data_profile::write::GetWriteCommandsProfile;
 use server_state::S;
 
@@ -23,6 +23,7 @@ impl StartupTasks {
         email_sender: EmailSenderImpl,
     ) -> Result<(), DataError> {
         Self::handle_profile_attribute_file_changes(&self.state).await?;
+        Self::handle_admin_role_config(&self.state).await?;
         Self::handle_account_specific_tasks(&self.state, email_sender).await
     }
 
@@ -41,6 +42,16 @@ impl StartupTasks {
         .await
     }
 
+    async fn handle_admin_role_config(state: &S) -> Result<(), DataError> {
+        db_write_raw!(state, move |cmds| {
+            cmds.account_admin()
+                .role()
+                .create_roles_from_config()
+                .await
+        })
+        .await
+    }
+
     async fn handle_account_specific_tasks(
         state: &S,
         email_sender: EmailSenderImpl,
@@ -1,12 +1,16 @@
 //! Common routes related to admin features
 
+pub mod audit_log;
 pub mod config;
 pub mod manager;
+pub mod moderation_claim;
 pub mod perf;
 pub mod report;
 pub mod maintenance;
 
+pub use audit_log::*;
 pub use manager::*;
+pub use moderation_claim::*;
 pub use perf::*;
 pub use config::*;
 pub use report::*;
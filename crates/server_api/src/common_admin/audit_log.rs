@@ -0,0 +1,93 @@
+use axum::extract::State;
+use model::{permission, AdminActionIteratorQuery, GetAdminActionList, UnixTime};
+use server_data::read::GetReadCommandsCommon;
+use simple_backend::create_counters;
+
+use crate::{
+    app::ReadData,
+    create_open_api_router,
+    utils::{Json, RequirePermission, StatusCode},
+    S,
+};
+
+const PATH_GET_LATEST_ADMIN_ACTION_ITERATOR_START_POSITION: &str =
+    "/common_api/admin/latest_admin_action_iterator_start_position";
+
+/// # Access
+/// - Permission [model::Permissions::admin_view_audit_log]
+#[utoipa::path(
+    get,
+    path = PATH_GET_LATEST_ADMIN_ACTION_ITERATOR_START_POSITION,
+    responses(
+        (status = 200, description = "Successful", body = UnixTime),
+        (status = 401, description = "Unauthorized"),
+        (
+            status = 500,
+            description = "Internal server error",
+        ),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_latest_admin_action_iterator_start_position(
+    State(_state): State<S>,
+    _: RequirePermission<permission::admin_view_audit_log>,
+) -> Result<Json<UnixTime>, StatusCode> {
+    COMMON_ADMIN.get_latest_admin_action_iterator_start_position.incr();
+
+    let previous_time = UnixTime::current_time().decrement();
+    Ok(previous_time.into())
+}
+
+const PATH_POST_GET_ADMIN_ACTION_ITERATOR_PAGE: &str =
+    "/common_api/admin/admin_action_iterator_page";
+
+/// Get audit log page. Latest admin actions are first.
+///
+/// The HTTP method is POST because HTTP GET does not allow request body.
+///
+/// # Access
+/// - Permission [model::Permissions::admin_view_audit_log]
+#[utoipa::path(
+    post,
+    path = PATH_POST_GET_ADMIN_ACTION_ITERATOR_PAGE,
+    request_body = AdminActionIteratorQuery,
+    responses(
+        (status = 200, description = "Successful", body = GetAdminActionList),
+        (status = 401, description = "Unauthorized"),
+        (
+            status = 500,
+            description = "Internal server error",
+        ),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_get_admin_action_iterator_page(
+    State(state): State<S>,
+    _: RequirePermission<permission::admin_view_audit_log>,
+    Json(query): Json<AdminActionIteratorQuery>,
+) -> Result<Json<GetAdminActionList>, StatusCode> {
+    COMMON_ADMIN.post_get_admin_action_iterator_page.incr();
+
+    let r = state
+        .read()
+        .common_admin()
+        .audit_log()
+        .admin_action_iterator_page(query)
+        .await?;
+
+    Ok(r.into())
+}
+
+create_open_api_router!(
+        fn router_audit_log,
+        get_latest_admin_action_iterator_start_position,
+        post_get_admin_action_iterator_page,
+);
+
+create_counters!(
+    CommonAdminCounters,
+    COMMON_ADMIN,
+    COMMON_ADMIN_AUDIT_LOG_COUNTERS_LIST,
+    get_latest_admin_action_iterator_start_position,
+    post_get_admin_action_iterator_page,
+);
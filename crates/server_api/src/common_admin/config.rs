@@ -1,12 +1,13 @@
 use axum::{extract::State, Extension};
-use model::{AccountIdInternal, BackendConfig, Permissions};
+use model::{permission, AccountIdInternal, AdminActionType, BackendConfig};
+use server_data::write::GetWriteCommandsCommon;
 use simple_backend::create_counters;
 use tracing::info;
 
 use crate::{
-    app::{ReadDynamicConfig, WriteDynamicConfig},
-    create_open_api_router,
-    utils::{Json, StatusCode},
+    app::{ReadDynamicConfig, WriteData, WriteDynamicConfig},
+    create_open_api_router, db_write,
+    utils::{Json, RequirePermission, StatusCode},
     S,
 };
 
@@ -29,16 +30,12 @@ const PATH_GET_BACKEND_CONFIG: &str = "/common_api/backend_config";
 )]
 pub async fn get_backend_config(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_server_maintenance_view_backend_config>,
 ) -> Result<Json<BackendConfig>, StatusCode> {
     COMMON_ADMIN.get_backend_config.incr();
 
-    if api_caller_permissions.admin_server_maintenance_view_backend_config {
-        let config = state.read_config().await?;
-        Ok(config.into())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let config = state.read_config().await?;
+    Ok(config.into())
 }
 
 const PATH_POST_BACKEND_CONFIG: &str = "/common_api/backend_config";
@@ -62,23 +59,25 @@ const PATH_POST_BACKEND_CONFIG: &str = "/common_api/backend_config";
 pub async fn post_backend_config(
     State(state): State<S>,
     Extension(api_caller_account_id): Extension<AccountIdInternal>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_server_maintenance_save_backend_config>,
     Json(backend_config): Json<BackendConfig>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_backend_config.incr();
 
-    if api_caller_permissions.admin_server_maintenance_save_backend_config {
-        info!(
-            "Saving dynamic backend config, account: {}, settings: {:#?}",
-            api_caller_account_id.as_id(),
-            backend_config
-        );
-        state.write_config(backend_config).await?;
+    info!(
+        "Saving dynamic backend config, account: {}, settings: {:#?}",
+        api_caller_account_id.as_id(),
+        backend_config
+    );
+    state.write_config(backend_config.clone()).await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::SaveBackendConfig,
+        None,
+        backend_config
+    ))?;
 
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    Ok(())
 }
 
 create_open_api_router!(fn router_config, get_backend_config, post_backend_config,);
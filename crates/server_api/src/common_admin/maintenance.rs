@@ -1,13 +1,14 @@
 use axum::{extract::State, Extension};
-use model::{EventToClientInternal, Permissions, ScheduledMaintenanceStatus};
-use server_data::app::EventManagerProvider;
+use model::{permission, AccountIdInternal, AdminActionType, EventToClientInternal, ScheduledMaintenanceStatus};
+use server_data::{app::EventManagerProvider, write::GetWriteCommandsCommon};
 use simple_backend::create_counters;
 
 use simple_backend::app::GetManagerApi;
 
 use crate::{
-    create_open_api_router,
-    utils::{Json, StatusCode},
+    app::WriteData,
+    create_open_api_router, db_write,
+    utils::{Json, RequirePermission, StatusCode},
     S,
 };
 
@@ -29,18 +30,14 @@ const PATH_GET_MAINTENANCE_NOTIFICATION: &str = "/common_api/maintenance_notific
 )]
 pub async fn get_maintenance_notification(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_server_maintenance_edit_notification>,
 ) -> Result<Json<ScheduledMaintenanceStatus>, StatusCode> {
     COMMON_ADMIN.get_maintenance_notification.incr();
 
-    if api_caller_permissions.admin_server_maintenance_edit_notification {
-        let status = ScheduledMaintenanceStatus {
-            scheduled_maintenance: state.manager_api_client().latest_scheduled_reboot(),
-        };
-        Ok(status.into())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let status = ScheduledMaintenanceStatus {
+        scheduled_maintenance: state.manager_api_client().latest_scheduled_reboot(),
+    };
+    Ok(status.into())
 }
 
 const PATH_POST_EDIT_MAINTENANCE_NOTIFICATION: &str = "/common_api/edit_maintenance_notification";
@@ -62,20 +59,23 @@ const PATH_POST_EDIT_MAINTENANCE_NOTIFICATION: &str = "/common_api/edit_maintena
 )]
 pub async fn post_edit_maintenance_notification(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_edit_notification>,
     Json(status): Json<ScheduledMaintenanceStatus>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_edit_maintenance_notification.incr();
 
-    if api_caller_permissions.admin_server_maintenance_edit_notification {
-        state.manager_api_client().set_latest_scheduled_reboot(status.scheduled_maintenance);
-        state.event_manager().send_connected_event_to_logged_in_clients(
-            EventToClientInternal::ScheduledMaintenanceStatus(status),
-        ).await;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    state.manager_api_client().set_latest_scheduled_reboot(status.scheduled_maintenance);
+    state.event_manager().send_connected_event_to_logged_in_clients(
+        EventToClientInternal::ScheduledMaintenanceStatus(status.clone()),
+    ).await;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::EditMaintenanceNotification,
+        None,
+        status
+    ))?;
+    Ok(())
 }
 
 create_open_api_router!(
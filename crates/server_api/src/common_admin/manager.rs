@@ -3,15 +3,17 @@ use axum::{
     Extension,
 };
 use manager_model::{
-    ManagerInstanceNameValue, ManagerInstanceNameList, ManualTaskType, NotifyBackend, ScheduledTaskStatus, ScheduledTaskTypeValue, SoftwareInfo, SoftwareUpdateStatus, SoftwareUpdateTaskType, SystemInfo
+    ClusterStatus, ManagerInstanceNameValue, ManagerInstanceNameList, ManualTaskType, NotifyBackend, ScheduledTaskStatus, ScheduledTaskTypeValue, SoftwareInfo, SoftwareUpdateStatus, SoftwareUpdateTaskType, SystemInfo
 };
-use model::Permissions;
+use model::{permission, permission_any, AccountIdInternal, AdminActionType};
+use server_data::write::GetWriteCommandsCommon;
 use simple_backend::{app::GetManagerApi, create_counters};
 use manager_api::RequestSenderCmds;
 
 use crate::{
-    create_open_api_router,
-    utils::{Json, StatusCode},
+    app::WriteData,
+    create_open_api_router, db_write,
+    utils::{Json, RequirePermission, StatusCode},
     S,
 };
 
@@ -34,26 +36,19 @@ const PATH_GET_MANAGER_INSTANCE_NAMES: &str = "/common_api/manager_instance_name
     responses(
         (status = 200, description = "Successful.", body = ManagerInstanceNameList),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
 )]
 pub async fn get_manager_instance_names(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission_any::some_admin_server_maintenance_permission>,
 ) -> Result<Json<ManagerInstanceNameList>, StatusCode> {
     COMMON_ADMIN.get_manager_instance_names.incr();
 
-    if api_caller_permissions.admin_server_maintenance_view_info ||
-        api_caller_permissions.admin_server_maintenance_update_software ||
-        api_caller_permissions.admin_server_maintenance_reset_data ||
-        api_caller_permissions.admin_server_maintenance_reboot_backend
-    {
-        let info = state.manager_request().await?.get_available_instances().await?;
-        Ok(info.into())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let info = state.manager_request().await?.get_available_instances().await?;
+    Ok(info.into())
 }
 
 const PATH_GET_SYSTEM_INFO: &str = "/common_api/system_info";
@@ -75,17 +70,40 @@ const PATH_GET_SYSTEM_INFO: &str = "/common_api/system_info";
 )]
 pub async fn get_system_info(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_server_maintenance_view_info>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<Json<SystemInfo>, StatusCode> {
     COMMON_ADMIN.get_system_info.incr();
 
-    if api_caller_permissions.admin_server_maintenance_view_info {
-        let info = state.manager_request_to(manager).await?.get_system_info().await?;
-        Ok(info.into())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let info = state.manager_request_to(manager).await?.get_system_info().await?;
+    Ok(info.into())
+}
+
+const PATH_GET_CLUSTER_STATUS: &str = "/common_api/cluster_status";
+
+/// Get status of the manager instance which backend uses and status
+/// of its remote manager instances.
+///
+/// # Access
+/// * Permission [model::Permissions::admin_server_maintenance_view_info]
+#[utoipa::path(
+    get,
+    path = PATH_GET_CLUSTER_STATUS,
+    responses(
+        (status = 200, description = "Successful.", body = ClusterStatus),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_cluster_status(
+    State(state): State<S>,
+    _: RequirePermission<permission::admin_server_maintenance_view_info>,
+) -> Result<Json<ClusterStatus>, StatusCode> {
+    COMMON_ADMIN.get_cluster_status.incr();
+
+    let info = state.manager_request().await?.get_cluster_status().await?;
+    Ok(info.into())
 }
 
 const PATH_GET_SOFTWARE_INFO: &str = "/common_api/software_info";
@@ -107,17 +125,13 @@ const PATH_GET_SOFTWARE_INFO: &str = "/common_api/software_info";
 )]
 pub async fn get_software_update_status(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_server_maintenance_view_info>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<Json<SoftwareUpdateStatus>, StatusCode> {
     COMMON_ADMIN.get_software_update_status.incr();
 
-    if api_caller_permissions.admin_server_maintenance_view_info {
-        let info = state.manager_request_to(manager).await?.get_software_update_status().await?;
-        Ok(info.into())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let info = state.manager_request_to(manager).await?.get_software_update_status().await?;
+    Ok(info.into())
 }
 
 const PATH_POST_TRIGGER_SOFTWARE_UPDATE_DOWNLOAD: &str = "/common_api/trigger_software_update_download";
@@ -139,20 +153,24 @@ const PATH_POST_TRIGGER_SOFTWARE_UPDATE_DOWNLOAD: &str = "/common_api/trigger_so
 )]
 pub async fn post_trigger_software_update_download(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_update_software>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_software_update_download.incr();
 
-    if api_caller_permissions.admin_server_maintenance_update_software {
-        state.manager_request_to(manager)
-            .await?
-            .trigger_software_update_task(SoftwareUpdateTaskType::Download)
-            .await?;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let parameters = manager.clone();
+    state.manager_request_to(manager)
+        .await?
+        .trigger_software_update_task(SoftwareUpdateTaskType::Download)
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::TriggerSoftwareUpdateDownload,
+        None,
+        parameters
+    ))?;
+    Ok(())
 }
 
 const PATH_POST_TRIGGER_SOFTWARE_UPDATE_INSTALL: &str = "/common_api/trigger_software_update_install";
@@ -174,21 +192,25 @@ const PATH_POST_TRIGGER_SOFTWARE_UPDATE_INSTALL: &str = "/common_api/trigger_sof
 )]
 pub async fn post_trigger_software_update_install(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_update_software>,
     Query(manager): Query<ManagerInstanceNameValue>,
     Query(info): Query<SoftwareInfo>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_software_update_install.incr();
 
-    if api_caller_permissions.admin_server_maintenance_update_software {
-        state.manager_request_to(manager)
-            .await?
-            .trigger_software_update_task(SoftwareUpdateTaskType::Install(info))
-            .await?;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let parameters = (manager.clone(), info.clone());
+    state.manager_request_to(manager)
+        .await?
+        .trigger_software_update_task(SoftwareUpdateTaskType::Install(info))
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::TriggerSoftwareUpdateInstall,
+        None,
+        parameters
+    ))?;
+    Ok(())
 }
 
 const PATH_POST_TRIGGER_BACKEND_DATA_RESET: &str = "/common_api/trigger_backend_data_reset";
@@ -210,20 +232,24 @@ const PATH_POST_TRIGGER_BACKEND_DATA_RESET: &str = "/common_api/trigger_backend_
 )]
 pub async fn post_trigger_backend_data_reset(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_reset_data>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_backend_data_reset.incr();
 
-    if api_caller_permissions.admin_server_maintenance_reset_data {
-        state.manager_request_to(manager)
-            .await?
-            .trigger_manual_task(ManualTaskType::BackendDataReset)
-            .await?;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let parameters = manager.clone();
+    state.manager_request_to(manager)
+        .await?
+        .trigger_manual_task(ManualTaskType::BackendDataReset)
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::TriggerBackendDataReset,
+        None,
+        parameters
+    ))?;
+    Ok(())
 }
 
 const PATH_POST_TRIGGER_BACKEND_RESTART: &str = "/common_api/trigger_backend_restart";
@@ -245,20 +271,65 @@ const PATH_POST_TRIGGER_BACKEND_RESTART: &str = "/common_api/trigger_backend_res
 )]
 pub async fn post_trigger_backend_restart(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_reboot_backend>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_backend_restart.incr();
 
-    if api_caller_permissions.admin_server_maintenance_reboot_backend {
-        state.manager_request_to(manager)
-            .await?
-            .trigger_manual_task(ManualTaskType::BackendRestart)
-            .await?;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let parameters = manager.clone();
+    state.manager_request_to(manager)
+        .await?
+        .trigger_manual_task(ManualTaskType::BackendRestart)
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::TriggerBackendRestart,
+        None,
+        parameters
+    ))?;
+    Ok(())
+}
+
+const PATH_POST_TRIGGER_BACKEND_ROLLBACK: &str = "/common_api/trigger_backend_rollback";
+
+/// Trigger backend rollback to previously installed backend.
+///
+/// The backend is restarted.
+///
+/// # Access
+/// * Permission [model::Permissions::admin_server_maintenance_update_software]
+#[utoipa::path(
+    post,
+    path = PATH_POST_TRIGGER_BACKEND_ROLLBACK,
+    params(ManagerInstanceNameValue),
+    responses(
+        (status = 200, description = "Successful."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_trigger_backend_rollback(
+    State(state): State<S>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_update_software>,
+    Query(manager): Query<ManagerInstanceNameValue>,
+) -> Result<(), StatusCode> {
+    COMMON_ADMIN.post_trigger_backend_rollback.incr();
+
+    let parameters = manager.clone();
+    state.manager_request_to(manager)
+        .await?
+        .trigger_manual_task(ManualTaskType::BackendRollback)
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::TriggerBackendRollback,
+        None,
+        parameters
+    ))?;
+    Ok(())
 }
 
 const PATH_POST_TRIGGER_SYSTEM_REBOOT: &str = "/common_api/trigger_system_reboot";
@@ -280,20 +351,24 @@ const PATH_POST_TRIGGER_SYSTEM_REBOOT: &str = "/common_api/trigger_system_reboot
 )]
 pub async fn post_trigger_system_reboot(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_reboot_backend>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_trigger_system_reboot.incr();
 
-    if api_caller_permissions.admin_server_maintenance_reboot_backend {
-        state.manager_request_to(manager)
-            .await?
-            .trigger_manual_task(ManualTaskType::SystemReboot)
-            .await?;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let parameters = manager.clone();
+    state.manager_request_to(manager)
+        .await?
+        .trigger_manual_task(ManualTaskType::SystemReboot)
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::TriggerSystemReboot,
+        None,
+        parameters
+    ))?;
+    Ok(())
 }
 
 const PATH_GET_SCHEDULED_TASKS_STATUS: &str = "/common_api/scheduled_tasks_status";
@@ -315,17 +390,13 @@ const PATH_GET_SCHEDULED_TASKS_STATUS: &str = "/common_api/scheduled_tasks_statu
 )]
 pub async fn get_scheduled_tasks_status(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_server_maintenance_reboot_backend>,
     Query(manager): Query<ManagerInstanceNameValue>,
 ) -> Result<Json<ScheduledTaskStatus>, StatusCode> {
     COMMON_ADMIN.get_software_update_status.incr();
 
-    if api_caller_permissions.admin_server_maintenance_reboot_backend {
-        let info = state.manager_request_to(manager).await?.get_scheduled_tasks_status().await?;
-        Ok(info.into())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let info = state.manager_request_to(manager).await?.get_scheduled_tasks_status().await?;
+    Ok(info.into())
 }
 
 const PATH_POST_SCHEDULE_TASK: &str = "/common_api/schedule_task";
@@ -347,22 +418,26 @@ const PATH_POST_SCHEDULE_TASK: &str = "/common_api/schedule_task";
 )]
 pub async fn post_schedule_task(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_reboot_backend>,
     Query(manager): Query<ManagerInstanceNameValue>,
     Query(task): Query<ScheduledTaskTypeValue>,
     Query(notify_backend): Query<NotifyBackend>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_schedule_task.incr();
 
-    if api_caller_permissions.admin_server_maintenance_reboot_backend {
-        state.manager_request_to(manager)
-            .await?
-            .schedule_task(task.scheduled_task_type, notify_backend)
-            .await?;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let parameters = (manager.clone(), task, notify_backend.clone());
+    state.manager_request_to(manager)
+        .await?
+        .schedule_task(task.scheduled_task_type, notify_backend)
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::ScheduleManagerTask,
+        None,
+        parameters
+    ))?;
+    Ok(())
 }
 
 const PATH_POST_UNSCHEDULE_TASK: &str = "/common_api/unschedule_task";
@@ -384,32 +459,38 @@ const PATH_POST_UNSCHEDULE_TASK: &str = "/common_api/unschedule_task";
 )]
 pub async fn post_unschedule_task(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_server_maintenance_reboot_backend>,
     Query(manager): Query<ManagerInstanceNameValue>,
     Query(task): Query<ScheduledTaskTypeValue>,
 ) -> Result<(), StatusCode> {
     COMMON_ADMIN.post_unschedule_task.incr();
 
-    if api_caller_permissions.admin_server_maintenance_reboot_backend {
-        state.manager_request_to(manager)
-            .await?
-            .unschedule_task(task.scheduled_task_type)
-            .await?;
-        Ok(())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let parameters = (manager.clone(), task);
+    state.manager_request_to(manager)
+        .await?
+        .unschedule_task(task.scheduled_task_type)
+        .await?;
+    db_write!(state, move |cmds| cmds.common_history().save_admin_action(
+        api_caller_account_id,
+        AdminActionType::UnscheduleManagerTask,
+        None,
+        parameters
+    ))?;
+    Ok(())
 }
 
 create_open_api_router!(
         fn router_manager,
         get_manager_instance_names,
         get_system_info,
+        get_cluster_status,
         get_software_update_status,
         post_trigger_software_update_download,
         post_trigger_software_update_install,
         post_trigger_backend_data_reset,
         post_trigger_backend_restart,
+        post_trigger_backend_rollback,
         post_trigger_system_reboot,
         get_scheduled_tasks_status,
         post_schedule_task,
@@ -422,6 +503,7 @@ create_counters!(
     COMMON_ADMIN_MANAGER_COUNTERS_LIST,
     get_manager_instance_names,
     get_system_info,
+    get_cluster_status,
     get_software_update_status,
     get_latest_build_info,
     post_request_build_software,
@@ -429,6 +511,7 @@ create_counters!(
     post_trigger_software_update_install,
     post_trigger_backend_data_reset,
     post_trigger_backend_restart,
+    post_trigger_backend_rollback,
     post_trigger_system_reboot,
     get_scheduled_tasks_status,
     post_schedule_task,
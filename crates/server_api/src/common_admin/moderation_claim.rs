@@ -0,0 +1,71 @@
+use axum::extract::State;
+use model::{permission_any, GetModeratorThroughputList, ModeratorThroughputQuery};
+use server_data::read::GetReadCommandsCommon;
+use simple_backend::create_counters;
+
+use crate::{
+    app::ReadData,
+    create_open_api_router,
+    utils::{Json, RequirePermission, StatusCode},
+    S,
+};
+
+const PATH_POST_GET_MODERATOR_THROUGHPUT: &str =
+    "/common_api/admin/moderator_throughput";
+
+/// Get moderated item counts for every moderator and moderation queue.
+///
+/// Only items moderated between [ModeratorThroughputQuery::start_time]
+/// and [ModeratorThroughputQuery::end_time] are counted. Old data is
+/// deleted automatically, so the available time range is limited.
+///
+/// The HTTP method is POST because HTTP GET does not allow request body.
+///
+/// # Access
+/// - Permission [model::Permissions::admin_moderate_media_content]
+/// - Permission [model::Permissions::admin_moderate_profile_names]
+/// - Permission [model::Permissions::admin_moderate_profile_texts]
+/// - Permission [model::Permissions::admin_process_reports]
+#[utoipa::path(
+    post,
+    path = PATH_POST_GET_MODERATOR_THROUGHPUT,
+    request_body = ModeratorThroughputQuery,
+    responses(
+        (status = 200, description = "Successful", body = GetModeratorThroughputList),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Permission denied"),
+        (
+            status = 500,
+            description = "Internal server error",
+        ),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_get_moderator_throughput(
+    State(state): State<S>,
+    _: RequirePermission<permission_any::some_admin_moderation_permission>,
+    Json(query): Json<ModeratorThroughputQuery>,
+) -> Result<Json<GetModeratorThroughputList>, StatusCode> {
+    COMMON_ADMIN.post_get_moderator_throughput.incr();
+
+    let r = state
+        .read()
+        .common_admin()
+        .moderation_claim()
+        .moderator_throughput(query)
+        .await?;
+
+    Ok(r.into())
+}
+
+create_open_api_router!(
+        fn router_moderation_claim,
+        post_get_moderator_throughput,
+);
+
+create_counters!(
+    CommonAdminCounters,
+    COMMON_ADMIN,
+    COMMON_ADMIN_MODERATION_CLAIM_COUNTERS_LIST,
+    post_get_moderator_throughput,
+);
@@ -1,14 +1,11 @@
-use axum::{
-    extract::{Query, State},
-    Extension,
-};
-use model::Permissions;
+use axum::extract::{Query, State};
+use model::permission;
 use simple_backend::{app::PerfCounterDataProvider, create_counters};
 use simple_backend_model::{PerfMetricQuery, PerfMetricQueryResult};
 
 use crate::{
     create_open_api_router,
-    utils::{Json, StatusCode},
+    utils::{Json, RequirePermission, StatusCode},
     S,
 };
 
@@ -31,16 +28,12 @@ const PATH_GET_PERF_DATA: &str = "/common_api/perf_data";
 )]
 pub async fn get_perf_data(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_server_maintenance_view_info>,
     Query(_query): Query<PerfMetricQuery>,
 ) -> Result<Json<PerfMetricQueryResult>, StatusCode> {
     COMMON_ADMIN.get_perf_data.incr();
-    if api_caller_permissions.admin_server_maintenance_view_info {
-        let data = state.perf_counter_data().get_history(false).await;
-        Ok(data.into())
-    } else {
-        Err(StatusCode::UNAUTHORIZED)
-    }
+    let data = state.perf_counter_data().get_history(false).await;
+    Ok(data.into())
 }
 
 create_open_api_router!(fn router_perf, get_perf_data,);
@@ -1,22 +1,26 @@
 use axum::{extract::State, Extension};
-use model::{
-    AccountIdInternal, GetReportList, Permissions, ProcessReport, ReportIteratorQuery, ReportIteratorQueryInternal, UnixTime
-};
-use server_data::{read::GetReadCommandsCommon, write::GetWriteCommandsCommon};
+use model::{permission, AccountIdInternal, AdminActionType, GetReportList, ProcessReport, ReportIteratorQuery, ReportIteratorQueryInternal, ReportOutcome, ReportTypeNumber, UnixTime};
+use server_data::{read::GetReadCommandsCommon, write::{common_history::AdminAction, GetWriteCommandsCommon}};
 use crate::{
     app::{GetAccounts, WriteData},
-    create_open_api_router, db_write_multiple, S,
+    create_open_api_router, db_write, S,
 };
 use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{permission_denied, Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_WAITING_REPORT_PAGE: &str =
     "/common_api/admin/waiting_report_page";
 
+/// Get waiting reports. Reports which automatic report triage
+/// has prioritized are first.
+///
+/// The returned reports are claimed for the moderator, so other moderators
+/// do not get the same reports until the claim lease expires. Reports
+/// which other moderators have claimed are not returned.
 #[utoipa::path(
     get,
     path = PATH_GET_WAITING_REPORT_PAGE,
@@ -32,26 +36,45 @@ const PATH_GET_WAITING_REPORT_PAGE: &str =
 )]
 pub async fn get_waiting_report_page(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_process_reports>,
+    Extension(moderator_id): Extension<AccountIdInternal>,
 ) -> Result<Json<GetReportList>, StatusCode> {
     COMMON.get_waiting_report_page.incr();
 
-    if !permissions.admin_process_reports {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
-    let r = state
-        .read()
+    let r = db_write!(state, move |cmds| cmds
         .common_admin()
         .report()
-        .get_waiting_report_list()
-        .await?;
+        .claim_waiting_report_list(moderator_id))?;
 
     Ok(r.into())
 }
 
 const PATH_POST_PROCESS_REPORT: &str = "/common_api/admin/process_report";
 
+/// Process report.
+///
+/// The report outcome and moderator note are saved to the report.
+/// The outcome related action is done at the same time:
+///
+/// - [ReportOutcome::ContentRemoved] rejects the reported profile name,
+///   profile text or profile content. Chat message reports can't have
+///   this outcome.
+/// - [ReportOutcome::Warned] gives a warning to the reported account.
+/// - [ReportOutcome::Banned] bans the reported account until
+///   [ProcessReport::ban_until].
+///
+/// The report creator gets a notification which does not tell the outcome.
+///
+/// Ban appeals are processed using a separate route.
+///
+/// # Access
+/// - Permission [model::Permissions::admin_process_reports]
+/// - Permission [model::Permissions::admin_ban_account] when the outcome is
+///   [ReportOutcome::Warned] or [ReportOutcome::Banned]
+/// - Permission [model::Permissions::admin_moderate_profile_names],
+///   [model::Permissions::admin_moderate_profile_texts] or
+///   [model::Permissions::admin_moderate_media_content] depending on
+///   report type when the outcome is [ReportOutcome::ContentRemoved]
 #[utoipa::path(
     post,
     path = PATH_POST_PROCESS_REPORT,
@@ -68,26 +91,62 @@ const PATH_POST_PROCESS_REPORT: &str = "/common_api/admin/process_report";
 )]
 pub async fn post_process_report(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_process_reports>,
+    ban_permission: Option<RequirePermission<permission::admin_ban_account>>,
+    name_permission: Option<RequirePermission<permission::admin_moderate_profile_names>>,
+    text_permission: Option<RequirePermission<permission::admin_moderate_profile_texts>>,
+    media_permission: Option<RequirePermission<permission::admin_moderate_media_content>>,
     Extension(moderator_id): Extension<AccountIdInternal>,
     Json(data): Json<ProcessReport>,
 ) -> Result<(), StatusCode> {
     COMMON.post_process_report.incr();
 
-    if !permissions.admin_process_reports {
+    if data.report_type == ReportTypeNumber::BanAppeal {
+        // Ban appeals have separate processing route
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    match data.outcome {
+        ReportOutcome::Dismissed => (),
+        ReportOutcome::ContentRemoved => match data.report_type {
+            ReportTypeNumber::ProfileName => if name_permission.is_none() {
+                return Err(permission_denied::<permission::admin_moderate_profile_names>());
+            },
+            ReportTypeNumber::ProfileText => if text_permission.is_none() {
+                return Err(permission_denied::<permission::admin_moderate_profile_texts>());
+            },
+            ReportTypeNumber::ProfileContent => if media_permission.is_none() {
+                return Err(permission_denied::<permission::admin_moderate_media_content>());
+            },
+            ReportTypeNumber::ChatMessage | ReportTypeNumber::BanAppeal => {
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        },
+        ReportOutcome::Warned | ReportOutcome::Banned => if ban_permission.is_none() {
+            return Err(permission_denied::<permission::admin_ban_account>());
+        },
+    }
+
+    if (data.outcome == ReportOutcome::Banned) != data.ban_until.is_some() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    if data
+        .moderator_note
+        .as_ref()
+        .is_some_and(|v| v.len() > ProcessReport::MODERATOR_NOTE_MAX_LEN)
+    {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
     let creator = state.get_internal_id(data.creator).await?;
     let target = state.get_internal_id(data.target).await?;
+    let admin_action = AdminAction::new(moderator_id, AdminActionType::ProcessReport, Some(target), &data)?;
 
-    db_write_multiple!(state, move |cmds| {
-        cmds.common_admin()
-            .report()
-            .process_report(moderator_id, creator, target, data.report_type, data.content)
-            .await?;
-        Ok(())
-    })?;
+    state
+        .data_all_access()
+        .process_report(moderator_id, creator, target, data, admin_action)
+        .await?;
 
     Ok(())
 }
@@ -110,14 +169,10 @@ const PATH_GET_LATEST_REPORT_ITERATOR_START_POSITION: &str =
 )]
 pub async fn get_latest_report_iterator_start_position(
     State(_state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_process_reports>,
 ) -> Result<Json<UnixTime>, StatusCode> {
     COMMON.get_latest_report_iterator_start_position.incr();
 
-    if !permissions.admin_process_reports {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let previous_time = UnixTime::current_time().decrement();
     Ok(previous_time.into())
 }
@@ -144,15 +199,11 @@ const PATH_POST_GET_REPORT_ITERATOR_PAGE: &str =
 )]
 pub async fn post_get_report_iterator_page(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_process_reports>,
     Json(query): Json<ReportIteratorQuery>,
 ) -> Result<Json<GetReportList>, StatusCode> {
     COMMON.post_get_report_iterator_page.incr();
 
-    if !permissions.admin_process_reports {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let query_account = state.get_internal_id(query.aid).await?;
 
     let query_internal = ReportIteratorQueryInternal {
@@ -38,6 +38,7 @@ pub mod app {
         // Common
         model::common::EventToClient,
         model::common_admin::ReportIteratorMode,
+        utils::PermissionDenied,
         // Manager
         manager_model::ScheduledTaskTypeValue,
     )),
@@ -1,17 +1,21 @@
-use std::net::SocketAddr;
+use std::{marker::PhantomData, net::SocketAddr};
 
 use axum::{
     body::Body,
-    extract::{rejection::JsonRejection, ConnectInfo, FromRequest, State},
+    extract::{
+        rejection::JsonRejection, ConnectInfo, FromRequest, FromRequestParts,
+        OptionalFromRequestParts, State,
+    },
+    http::request::Parts,
     middleware::Next,
     response::{IntoResponse, Response},
 };
 use headers::{Header, HeaderValue};
 use hyper::{header, Request};
-use model::AccessToken;
+use model::{permission::RequiredPermission, AccessToken, AccountState, Permissions};
 use serde::Serialize;
-pub use server_state::utils::StatusCode;
-use server_state::{app::GetAccessTokens, S};
+pub use server_state::utils::{PermissionDenied, StatusCode};
+use server_state::{app::GetAccessTokens, route_permission::RouteArgumentPermission, S};
 use simple_backend::create_counters;
 use simple_backend_config::RUNNING_IN_DEBUG_MODE;
 pub use utils::api::ACCESS_TOKEN_HEADER_STR;
@@ -63,12 +67,103 @@ pub async fn authenticate_with_access_token(
     }
 }
 
+/// Middleware for rejecting requests from accounts which have not
+/// acknowledged their warnings yet.
+///
+/// The middleware must be used with routes which are behind
+/// [authenticate_with_access_token] middleware. Use this with routes
+/// which interact with other accounts.
+pub async fn reject_if_warning_pending(
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if req.extensions().get::<AccountState>() == Some(&AccountState::WarningPending) {
+        API.warning_pending_rejected.incr();
+        Err(StatusCode::FORBIDDEN)
+    } else {
+        Ok(next.run(req).await)
+    }
+}
+
+/// Extractor which requires that the API caller has permission `P`.
+///
+/// The extractor must be used with routes which are behind
+/// [authenticate_with_access_token] middleware. If the permission is
+/// not granted, the request is rejected with
+/// [hyper::StatusCode::FORBIDDEN] and [PermissionDenied] JSON body.
+///
+/// The permission is added to the route's API doc as "access_token"
+/// security scope when the route is created with
+/// [server_state::create_open_api_router] macro, so write only
+/// `security(("access_token" = []))` to the route's API doc.
+///
+/// Use `Option<RequirePermission<P>>` if the permission is required only
+/// in some cases, for example when the API caller is not the account owner.
+/// Then the route must return [permission_denied] itself and document
+/// the 403 response.
+pub struct RequirePermission<P>(PhantomData<P>);
+
+impl<P: RequiredPermission> RouteArgumentPermission for RequirePermission<P> {
+    const REQUIRED_PERMISSION: Option<&'static str> = Some(P::NAME);
+}
+
+impl<P: RequiredPermission> RequirePermission<P> {
+    fn granted(parts: &Parts) -> bool {
+        parts
+            .extensions
+            .get::<Permissions>()
+            .map(P::is_granted)
+            .unwrap_or_default()
+    }
+}
+
+impl<P: RequiredPermission, S: Send + Sync> FromRequestParts<S> for RequirePermission<P> {
+    type Rejection = PermissionDenied;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if Self::granted(parts) {
+            Ok(Self(PhantomData))
+        } else {
+            API.permission_denied.incr();
+            Err(PermissionDenied {
+                required_permission: P::NAME.to_string(),
+            })
+        }
+    }
+}
+
+impl<P: RequiredPermission, S: Send + Sync> OptionalFromRequestParts<S> for RequirePermission<P> {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if Self::granted(parts) {
+            Ok(Some(Self(PhantomData)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Reject the request with [PermissionDenied] response.
+///
+/// Use this when the route uses `Option<RequirePermission<P>>` or
+/// [Permissions] extension and the permission turns out to be required.
+pub fn permission_denied<P: RequiredPermission>() -> StatusCode {
+    API.permission_denied.incr();
+    StatusCode::PERMISSION_DENIED(P::NAME)
+}
+
 create_counters!(
     ApiCounters,
     API,
     API_COUNTERS_LIST,
     access_token_found,
     access_token_not_found,
+    permission_denied,
+    warning_pending_rejected,
 );
 
 pub struct AccessTokenHeader(AccessToken);
@@ -123,6 +218,8 @@ impl Modify for SecurityApiAccessTokenDefault {
 #[from_request(via(axum::Json), rejection(ApiError))]
 pub struct Json<T>(pub T);
 
+impl<T> RouteArgumentPermission for Json<T> {}
+
 impl<T> From<T> for Json<T> {
     fn from(value: T) -> Self {
         Self(value)
@@ -8,6 +8,7 @@ pub mod register;
 pub mod settings;
 pub mod state;
 pub mod report;
+pub mod warning;
 
 pub use ban::*;
 pub use delete::*;
@@ -19,3 +20,4 @@ pub use register::*;
 pub use settings::*;
 pub use state::*;
 pub use report::*;
+pub use warning::*;
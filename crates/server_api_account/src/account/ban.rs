@@ -1,11 +1,11 @@
 use axum::{extract::{Path, State}, Extension};
-use model::{AccountId, AccountIdInternal, Permissions};
-use model_account::GetAccountBanTimeResult;
-use server_api::{app::{ReadData, GetAccounts}, create_open_api_router, S};
-use server_data_account::read::GetReadCommandsAccount;
+use model::{permission, AccountId, AccountIdInternal, UpdateReportResult};
+use model_account::{BanAppeal, GetAccountBanTimeResult};
+use server_api::{app::{ReadData, GetAccounts, WriteData}, create_open_api_router, db_write, S};
+use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
-use crate::utils::{Json, StatusCode};
+use crate::utils::{permission_denied, Json, RequirePermission, StatusCode};
 
 const PATH_GET_ACCOUNT_BAN_TIME: &str = "/account_api/account_ban_time/{aid}";
 
@@ -21,6 +21,7 @@ const PATH_GET_ACCOUNT_BAN_TIME: &str = "/account_api/account_ban_time/{aid}";
     responses(
         (status = 200, description = "Successfull.", body = GetAccountBanTimeResult),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
@@ -28,13 +29,13 @@ const PATH_GET_ACCOUNT_BAN_TIME: &str = "/account_api/account_ban_time/{aid}";
 pub async fn get_account_ban_time(
     State(state): State<S>,
     Extension(api_caller): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    admin_permission: Option<RequirePermission<permission::admin_ban_account>>,
     Path(account): Path<AccountId>,
 ) -> Result<Json<GetAccountBanTimeResult>, StatusCode> {
     ACCOUNT.get_account_ban_time.incr();
 
-    if account != api_caller.as_id() && !permissions.admin_ban_account {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    if account != api_caller.as_id() && admin_permission.is_none() {
+        return Err(permission_denied::<permission::admin_ban_account>());
     }
 
     let internal_id = state.get_internal_id(account).await?;
@@ -44,11 +45,52 @@ pub async fn get_account_ban_time(
     Ok(result.into())
 }
 
-create_open_api_router!(fn router_ban, get_account_ban_time,);
+const PATH_POST_BAN_APPEAL: &str = "/account_api/ban_appeal";
+
+/// Appeal the current ban
+///
+/// Only one appeal is allowed for one ban. If the ban is already
+/// appealed, [UpdateReportResult::error_too_many_reports] is `true`.
+///
+/// Max appeal text length is [BanAppeal::TEXT_MAX_LEN] bytes.
+///
+/// # Access
+/// - Banned account
+#[utoipa::path(
+    post,
+    path = PATH_POST_BAN_APPEAL,
+    request_body = BanAppeal,
+    responses(
+        (status = 200, description = "Successfull.", body = UpdateReportResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_ban_appeal(
+    State(state): State<S>,
+    Extension(api_caller): Extension<AccountIdInternal>,
+    Json(appeal): Json<BanAppeal>,
+) -> Result<Json<UpdateReportResult>, StatusCode> {
+    ACCOUNT.post_ban_appeal.incr();
+
+    if appeal.text.len() > BanAppeal::TEXT_MAX_LEN {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    let result = db_write!(state, move |cmds| {
+        cmds.account().report().appeal_ban(api_caller, appeal.text)
+    })?;
+
+    Ok(result.into())
+}
+
+create_open_api_router!(fn router_ban, get_account_ban_time, post_ban_appeal,);
 
 create_counters!(
     AccountCounters,
     ACCOUNT,
     ACCOUNT_BAN_COUNTERS_LIST,
     get_account_ban_time,
+    post_ban_appeal,
 );
@@ -1,11 +1,11 @@
 use axum::{extract::{Path, State}, Extension};
-use model::{AccountId, AccountIdInternal, EventToClientInternal, Permissions};
+use model::{permission, AccountId, AccountIdInternal, EventToClientInternal};
 use model_account::{BooleanSetting, GetAccountDeletionRequestResult};
 use server_api::{app::{GetAccounts, WriteData, ReadData}, create_open_api_router, db_write_multiple, S};
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
-use crate::utils::{Json, StatusCode};
+use crate::utils::{permission_denied, Json, RequirePermission, StatusCode};
 
 const PATH_POST_SET_ACCOUNT_DELETION_REQUEST_STATE: &str = "/account_api/set_account_deletion_request_state/{aid}";
 
@@ -22,6 +22,7 @@ const PATH_POST_SET_ACCOUNT_DELETION_REQUEST_STATE: &str = "/account_api/set_acc
     responses(
         (status = 200, description = "Successfull."),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
@@ -29,14 +30,14 @@ const PATH_POST_SET_ACCOUNT_DELETION_REQUEST_STATE: &str = "/account_api/set_acc
 pub async fn post_set_account_deletion_request_state(
     State(state): State<S>,
     Extension(api_caller): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    admin_permission: Option<RequirePermission<permission::admin_request_account_deletion>>,
     Path(account): Path<AccountId>,
     Json(value): Json<BooleanSetting>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.post_set_account_deletion_request_state.incr();
 
-    if account != api_caller.as_id() && !permissions.admin_request_account_deletion {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    if account != api_caller.as_id() && admin_permission.is_none() {
+        return Err(permission_denied::<permission::admin_request_account_deletion>());
     }
 
     let internal_id = state.get_internal_id(account).await?;
@@ -73,6 +74,7 @@ const PATH_GET_ACCOUNT_DELETION_REQUEST_STATE: &str = "/account_api/get_account_
     responses(
         (status = 200, description = "Successfull.", body = GetAccountDeletionRequestResult),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
@@ -80,13 +82,13 @@ const PATH_GET_ACCOUNT_DELETION_REQUEST_STATE: &str = "/account_api/get_account_
 pub async fn get_account_deletion_request_state(
     State(state): State<S>,
     Extension(api_caller): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    admin_permission: Option<RequirePermission<permission::admin_request_account_deletion>>,
     Path(account): Path<AccountId>,
 ) -> Result<Json<GetAccountDeletionRequestResult>, StatusCode> {
     ACCOUNT.get_account_deletion_request_state.incr();
 
-    if account != api_caller.as_id() && !permissions.admin_request_account_deletion {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    if account != api_caller.as_id() && admin_permission.is_none() {
+        return Err(permission_denied::<permission::admin_request_account_deletion>());
     }
 
     let internal_id = state.get_internal_id(account).await?;
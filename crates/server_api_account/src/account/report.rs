@@ -1,7 +1,7 @@
 use axum::{extract::{Query, State}, Extension};
-use model::{AccountIdInternal, ReportQueryParams};
+use model::{AccountIdInternal, PendingNotificationFlags, ReportQueryParams};
 use model_account::{AccountReport, UpdateAccountReport};
-use server_api::{create_open_api_router, S};
+use server_api::{app::EventManagerProvider, create_open_api_router, S};
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
@@ -73,10 +73,46 @@ pub async fn post_account_report(
     Ok(())
 }
 
+const PATH_POST_MARK_REPORT_PROCESSED_NOTIFICATION_SEEN: &str =
+    "/account_api/mark_report_processed_notification_seen";
+
+/// Mark report processed notification as seen.
+///
+/// The notification does not contain any info about the processed
+/// report, so the client can only show a generic
+/// "your report was handled" message.
+#[utoipa::path(
+    post,
+    path = PATH_POST_MARK_REPORT_PROCESSED_NOTIFICATION_SEEN,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_mark_report_processed_notification_seen(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+) -> Result<(), StatusCode> {
+    ACCOUNT.post_mark_report_processed_notification_seen.incr();
+
+    state
+        .event_manager()
+        .remove_specific_pending_notification_flags_from_cache(
+            account_id,
+            PendingNotificationFlags::REPORT_PROCESSED,
+        )
+        .await;
+
+    Ok(())
+}
+
 create_open_api_router!(
         fn router_account_report,
         get_account_report,
         post_account_report,
+        post_mark_report_processed_notification_seen,
 );
 
 create_counters!(
@@ -85,4 +121,5 @@ create_counters!(
     ACCOUNT_REPORT_COUNTERS_LIST,
     get_account_report,
     post_account_report,
+    post_mark_report_processed_notification_seen,
 );
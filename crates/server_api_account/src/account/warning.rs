@@ -0,0 +1,93 @@
+use axum::{extract::{Path, State}, Extension};
+use model::{permission, AccountId, AccountIdInternal, EventToClientInternal};
+use model_account::GetAccountWarningList;
+use server_api::{app::{GetAccounts, ReadData, WriteData}, create_open_api_router, db_write_multiple, S};
+use server_data::write::GetWriteCommandsCommon;
+use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
+use simple_backend::create_counters;
+
+use crate::utils::{permission_denied, Json, RequirePermission, StatusCode};
+
+const PATH_GET_ACCOUNT_WARNINGS: &str = "/account_api/account_warnings/{aid}";
+
+/// Get account warnings which have not expired.
+///
+/// # Access
+/// - Account owner
+/// - Permission [model::Permissions::admin_ban_account]
+#[utoipa::path(
+    get,
+    path = PATH_GET_ACCOUNT_WARNINGS,
+    params(AccountId),
+    responses(
+        (status = 200, description = "Successfull.", body = GetAccountWarningList),
+        (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_account_warnings(
+    State(state): State<S>,
+    Extension(api_caller): Extension<AccountIdInternal>,
+    admin_permission: Option<RequirePermission<permission::admin_ban_account>>,
+    Path(account): Path<AccountId>,
+) -> Result<Json<GetAccountWarningList>, StatusCode> {
+    ACCOUNT.get_account_warnings.incr();
+
+    if account != api_caller.as_id() && admin_permission.is_none() {
+        return Err(permission_denied::<permission::admin_ban_account>());
+    }
+
+    let internal_id = state.get_internal_id(account).await?;
+
+    let result = state.read().account().warning().active_warnings(internal_id).await?;
+
+    Ok(result.into())
+}
+
+const PATH_POST_ACKNOWLEDGE_ACCOUNT_WARNINGS: &str = "/account_api/acknowledge_account_warnings";
+
+/// Acknowledge all account warnings. This changes account state
+/// from [model::AccountState::WarningPending] to the next state.
+#[utoipa::path(
+    post,
+    path = PATH_POST_ACKNOWLEDGE_ACCOUNT_WARNINGS,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_acknowledge_account_warnings(
+    State(state): State<S>,
+    Extension(api_caller): Extension<AccountIdInternal>,
+) -> Result<(), StatusCode> {
+    ACCOUNT.post_acknowledge_account_warnings.incr();
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account().warning().acknowledge_warnings(api_caller).await?;
+
+        cmds.events()
+            .send_connected_event(
+                api_caller.uuid,
+                EventToClientInternal::AccountStateChanged,
+            )
+            .await?;
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+create_open_api_router!(fn router_warning, get_account_warnings, post_acknowledge_account_warnings,);
+
+create_counters!(
+    AccountCounters,
+    ACCOUNT,
+    ACCOUNT_WARNING_COUNTERS_LIST,
+    get_account_warnings,
+    post_acknowledge_account_warnings,
+);
@@ -5,6 +5,8 @@ pub mod search;
 pub mod permissions;
 pub mod state;
 pub mod report;
+pub mod role;
+pub mod warning;
 
 pub use ban::*;
 pub use delete::*;
@@ -13,3 +15,5 @@ pub use search::*;
 pub use permissions::*;
 pub use state::*;
 pub use report::*;
+pub use role::*;
+pub use warning::*;
@@ -1,11 +1,12 @@
 use axum::{extract::State, Extension};
-use model::{AccountIdInternal, EventToClientInternal, Permissions};
-use model_account::SetAccountBanState;
-use server_api::{app::{GetAccounts, WriteData}, create_open_api_router, db_write_multiple, S};
+use model::{permission, AccountIdInternal, AdminActionType, BanAppealDecision, EventToClientInternal, GetReportList, NotificationEvent, ProcessReport};
+use model_account::{ProcessBanAppeal, SetAccountBanState};
+use server_api::{app::{GetAccounts, WriteData}, create_open_api_router, db_write, db_write_multiple, S};
+use server_data::write::{common_history::AdminAction, GetWriteCommandsCommon};
 use server_data_account::write::GetWriteCommandsAccount;
 use simple_backend::create_counters;
 
-use crate::utils::{Json, StatusCode};
+use crate::utils::{Json, RequirePermission, StatusCode};
 
 const PATH_POST_SET_BAN_STATE: &str = "/account_api/set_ban_state";
 
@@ -28,16 +29,13 @@ const PATH_POST_SET_BAN_STATE: &str = "/account_api/set_ban_state";
 pub async fn post_set_ban_state(
     State(state): State<S>,
     Extension(api_caller_id): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_ban_account>,
     Json(ban_info): Json<SetAccountBanState>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_set_ban_state.incr();
 
-    if !permissions.admin_ban_account {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let internal_id = state.get_internal_id(ban_info.account).await?;
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::SetBanState, Some(internal_id), &ban_info)?;
 
     db_write_multiple!(state, move |cmds| {
         let new_account = cmds.account_admin().ban().set_account_ban_state(
@@ -45,7 +43,118 @@ pub async fn post_set_ban_state(
             Some(api_caller_id),
             ban_info.ban_until,
             ban_info.reason_category,
-            ban_info.reason_details
+            ban_info.reason_details,
+            Some(admin_action),
+        ).await?;
+
+        if new_account.is_some() {
+            cmds.events()
+                .send_connected_event(
+                    internal_id.uuid,
+                    EventToClientInternal::AccountStateChanged,
+                )
+                .await?;
+        }
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+const PATH_GET_WAITING_BAN_APPEAL_PAGE: &str = "/account_api/waiting_ban_appeal_page";
+
+/// Get waiting ban appeals. Oldest appeal is first.
+///
+/// The returned ban appeals are claimed for the moderator, so other
+/// moderators do not get the same ban appeals until the claim lease
+/// expires.
+///
+/// # Access
+///
+/// Permission [model_account::Permissions::admin_ban_account] is required.
+#[utoipa::path(
+    get,
+    path = PATH_GET_WAITING_BAN_APPEAL_PAGE,
+    responses(
+        (status = 200, description = "Successfull.", body = GetReportList),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_waiting_ban_appeal_page(
+    State(state): State<S>,
+    _: RequirePermission<permission::admin_ban_account>,
+    Extension(moderator_id): Extension<AccountIdInternal>,
+) -> Result<Json<GetReportList>, StatusCode> {
+    ACCOUNT_ADMIN.get_waiting_ban_appeal_page.incr();
+
+    let r = db_write!(state, move |cmds| cmds
+        .common_admin()
+        .report()
+        .claim_waiting_ban_appeal_list(moderator_id))?;
+
+    Ok(r.into())
+}
+
+const PATH_POST_PROCESS_BAN_APPEAL: &str = "/account_api/process_ban_appeal";
+
+/// Process ban appeal
+///
+/// - [BanAppealDecision::Uphold] keeps the current ban.
+/// - [BanAppealDecision::Shorten] changes the ban end time to
+///   [ProcessBanAppeal::ban_until] which must be earlier than the current
+///   ban end time.
+/// - [BanAppealDecision::Lift] unbans the account.
+///
+/// The appeal creator gets a notification which does not tell the decision.
+///
+/// # Access
+///
+/// Permission [model_account::Permissions::admin_ban_account] is required.
+#[utoipa::path(
+    post,
+    path = PATH_POST_PROCESS_BAN_APPEAL,
+    request_body = ProcessBanAppeal,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_process_ban_appeal(
+    State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_ban_account>,
+    Json(data): Json<ProcessBanAppeal>,
+) -> Result<(), StatusCode> {
+    ACCOUNT_ADMIN.post_process_ban_appeal.incr();
+
+    if (data.decision == BanAppealDecision::Shorten) != data.ban_until.is_some() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    if data
+        .moderator_note
+        .as_ref()
+        .is_some_and(|v| v.len() > ProcessReport::MODERATOR_NOTE_MAX_LEN)
+    {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    let internal_id = state.get_internal_id(data.account).await?;
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::ProcessBanAppeal, Some(internal_id), &data)?;
+
+    db_write_multiple!(state, move |cmds| {
+        let new_account = cmds.account_admin().ban().process_ban_appeal(
+            api_caller_id,
+            internal_id,
+            data.decision,
+            data.ban_until,
+            data.moderator_note,
+            admin_action,
         ).await?;
 
         if new_account.is_some() {
@@ -57,17 +166,28 @@ pub async fn post_set_ban_state(
                 .await?;
         }
 
+        cmds.events()
+            .send_notification(internal_id, NotificationEvent::ReportProcessed)
+            .await?;
+
         Ok(())
     })?;
 
     Ok(())
 }
 
-create_open_api_router!(fn router_admin_ban, post_set_ban_state,);
+create_open_api_router!(
+    fn router_admin_ban,
+    post_set_ban_state,
+    get_waiting_ban_appeal_page,
+    post_process_ban_appeal,
+);
 
 create_counters!(
     AccountCounters,
     ACCOUNT_ADMIN,
     ACCOUNT_ADMIN_BAN_COUNTERS_LIST,
     post_set_ban_state,
+    get_waiting_ban_appeal_page,
+    post_process_ban_appeal,
 );
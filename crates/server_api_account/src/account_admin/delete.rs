@@ -1,10 +1,11 @@
 use axum::{extract::{Path, State}, Extension};
-use model::{AccountId, Permissions};
+use model::{permission, AccountId, AccountIdInternal, AdminActionType};
 use server_api::{app::{GetAccounts, WriteData}, create_open_api_router, db_write_multiple, S};
+use server_data::write::common_history::AdminAction;
 use server_data_account::write::GetWriteCommandsAccount;
 use simple_backend::create_counters;
 
-use crate::utils::StatusCode;
+use crate::utils::{RequirePermission, StatusCode};
 
 const PATH_POST_DELETE_ACCOUNT: &str = "/account_api/delete_account/{aid}";
 
@@ -26,19 +27,17 @@ const PATH_POST_DELETE_ACCOUNT: &str = "/account_api/delete_account/{aid}";
 )]
 pub async fn post_delete_account(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_delete_account>,
     Path(account): Path<AccountId>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_delete_account.incr();
 
-    if !permissions.admin_delete_account {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let internal_id = state.get_internal_id(account).await?;
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::DeleteAccount, Some(internal_id), ())?;
 
     db_write_multiple!(state, move |cmds| {
-        cmds.account().delete().delete_account(internal_id).await?;
+        cmds.account().delete().delete_account(internal_id, Some(admin_action)).await?;
         Ok(())
     })?;
 
@@ -2,17 +2,17 @@ use axum::{
     extract::{Path, State},
     Extension,
 };
+use model::{permission, permission_any};
 use model_account::{
-    AccountIdInternal, BooleanSetting, NewsId, NewsLocale, NotificationEvent, Permissions,
+    AccountIdInternal, AdminActionType, BooleanSetting, NewsId, NewsLocale, NotificationEvent, Permissions,
     UpdateNewsTranslation, UpdateNewsTranslationResult,
 };
-use server_api::{
-    create_open_api_router, db_write, db_write_multiple, result::WrappedContextExt, DataError, S,
-};
+use server_api::{create_open_api_router, db_write_multiple, S};
+use server_data::write::common_history::AdminAction;
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
-use super::super::utils::{Json, StatusCode};
+use super::super::utils::{permission_denied, Json, RequirePermission, StatusCode};
 use crate::app::{ReadData, WriteData};
 
 const PATH_POST_CREATE_NEWS_ITEM: &str = "/account_api/admin/create_news_item";
@@ -30,18 +30,18 @@ const PATH_POST_CREATE_NEWS_ITEM: &str = "/account_api/admin/create_news_item";
 pub async fn post_create_news_item(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_news_create>,
 ) -> Result<Json<NewsId>, StatusCode> {
     ACCOUNT.post_create_news_item.incr();
 
-    if !permissions.admin_news_create {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
+    let admin_action = AdminAction::new(account_id, AdminActionType::CreateNewsItem, None, ())?;
 
-    let news_id = db_write!(state, move |cmds| cmds
-        .account_admin()
-        .news()
-        .create_news_item(account_id))?;
+    let news_id = db_write_multiple!(state, move |cmds| {
+        cmds.account_admin()
+            .news()
+            .create_news_item(account_id, admin_action)
+            .await
+    })?;
     Ok(news_id.into())
 }
 
@@ -54,6 +54,7 @@ const PATH_DELETE_NEWS_ITEM: &str = "/account_api/admin/delete_news/{nid}";
     responses(
         (status = 200, description = "Success."),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
@@ -61,15 +62,12 @@ const PATH_DELETE_NEWS_ITEM: &str = "/account_api/admin/delete_news/{nid}";
 pub async fn delete_news_item(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission_any::some_admin_news_permission>,
     Extension(permissions): Extension<Permissions>,
     Path(nid): Path<NewsId>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.delete_news_item.incr();
 
-    if !permissions.some_admin_news_permissions_granted() {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let item = state
         .read()
         .account_admin()
@@ -78,13 +76,17 @@ pub async fn delete_news_item(
         .await?;
 
     if !permissions.admin_news_edit_all && item.aid_creator != Some(account_id.uuid) {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        return Err(permission_denied::<permission::admin_news_edit_all>());
     }
 
-    db_write!(state, move |cmds| cmds
-        .account_admin()
-        .news()
-        .delete_news_item(nid))?;
+    let admin_action = AdminAction::new(account_id, AdminActionType::DeleteNewsItem, None, nid)?;
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account_admin()
+            .news()
+            .delete_news_item(nid, admin_action)
+            .await
+    })?;
 
     Ok(())
 }
@@ -100,6 +102,7 @@ const PATH_POST_UPDATE_NEWS_TRANSLATION: &str =
     responses(
         (status = 200, description = "Success.", body = UpdateNewsTranslationResult),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
@@ -107,6 +110,7 @@ const PATH_POST_UPDATE_NEWS_TRANSLATION: &str =
 pub async fn post_update_news_translation(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission_any::some_admin_news_permission>,
     Extension(permissions): Extension<Permissions>,
     Path(nid): Path<NewsId>,
     Path(locale): Path<NewsLocale>,
@@ -114,10 +118,23 @@ pub async fn post_update_news_translation(
 ) -> Result<Json<UpdateNewsTranslationResult>, StatusCode> {
     ACCOUNT.post_update_news_translation.incr();
 
-    if !permissions.some_admin_news_permissions_granted() || !locale.is_supported_locale() {
+    if !locale.is_supported_locale() {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    let item = state
+        .read()
+        .account_admin()
+        .news()
+        .news_translations(nid)
+        .await?;
+
+    // News item creator does not change, so checking it outside the
+    // write transaction is enough.
+    if !permissions.admin_news_edit_all && item.aid_creator != Some(account_id.uuid) {
+        return Err(permission_denied::<permission::admin_news_edit_all>());
+    }
+
     let result = db_write_multiple!(state, move |cmds| {
         let item = cmds
             .read()
@@ -126,10 +143,6 @@ pub async fn post_update_news_translation(
             .news_translations(nid)
             .await?;
 
-        if !permissions.admin_news_edit_all && item.aid_creator != Some(account_id.uuid) {
-            return Err(DataError::NotAllowed.report());
-        }
-
         let current_version = item
             .translations
             .into_iter()
@@ -139,9 +152,15 @@ pub async fn post_update_news_translation(
             return Ok(UpdateNewsTranslationResult::error_already_changed());
         }
 
+        let admin_action = AdminAction::new(
+            account_id,
+            AdminActionType::UpdateNewsTranslation,
+            None,
+            (nid, &locale, &news_translation),
+        )?;
         cmds.account_admin()
             .news()
-            .upsert_news_translation(account_id, nid, locale, news_translation)
+            .upsert_news_translation(account_id, nid, locale, news_translation, admin_action)
             .await?;
 
         Ok(UpdateNewsTranslationResult::success())
@@ -160,6 +179,7 @@ const PATH_DELETE_NEWS_TRANSLATION: &str =
     responses(
         (status = 200, description = "Success."),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
@@ -167,16 +187,13 @@ const PATH_DELETE_NEWS_TRANSLATION: &str =
 pub async fn delete_news_translation(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission_any::some_admin_news_permission>,
     Extension(permissions): Extension<Permissions>,
     Path(nid): Path<NewsId>,
     Path(locale): Path<NewsLocale>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.delete_news_translation.incr();
 
-    if !permissions.some_admin_news_permissions_granted() {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let item = state
         .read()
         .account_admin()
@@ -185,13 +202,17 @@ pub async fn delete_news_translation(
         .await?;
 
     if !permissions.admin_news_edit_all && item.aid_creator != Some(account_id.uuid) {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        return Err(permission_denied::<permission::admin_news_edit_all>());
     }
 
-    db_write!(state, move |cmds| cmds
-        .account_admin()
-        .news()
-        .delete_news_translation(nid, locale))?;
+    let admin_action = AdminAction::new(account_id, AdminActionType::DeleteNewsTranslation, None, (nid, &locale))?;
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account_admin()
+            .news()
+            .delete_news_translation(nid, locale, admin_action)
+            .await
+    })?;
 
     Ok(())
 }
@@ -206,6 +227,7 @@ const PATH_POST_SET_NEWS_PUBLICITY: &str = "/account_api/admin/set_news_publicit
     responses(
         (status = 200, description = "Success."),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500, description = "Internal server error."),
     ),
     security(("access_token" = [])),
@@ -213,14 +235,22 @@ const PATH_POST_SET_NEWS_PUBLICITY: &str = "/account_api/admin/set_news_publicit
 pub async fn post_set_news_publicity(
     State(state): State<S>,
     Extension(account_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission_any::some_admin_news_permission>,
     Extension(permissions): Extension<Permissions>,
     Path(nid): Path<NewsId>,
     Json(publicity): Json<BooleanSetting>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.post_set_news_publicity.incr();
 
-    if !permissions.some_admin_news_permissions_granted() {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    let item = state
+        .read()
+        .account_admin()
+        .news()
+        .news_translations(nid)
+        .await?;
+
+    if !permissions.admin_news_edit_all && item.aid_creator != Some(account_id.uuid) {
+        return Err(permission_denied::<permission::admin_news_edit_all>());
     }
 
     db_write_multiple!(state, move |cmds| {
@@ -231,17 +261,14 @@ pub async fn post_set_news_publicity(
             .news_translations(nid)
             .await?;
 
-        if !permissions.admin_news_edit_all && item.aid_creator != Some(account_id.uuid) {
-            return Err(DataError::NotAllowed.report());
-        }
-
         if item.public == publicity.value {
             return Ok(());
         }
 
+        let admin_action = AdminAction::new(account_id, AdminActionType::SetNewsPublicity, None, (nid, &publicity))?;
         cmds.account_admin()
             .news()
-            .set_news_publicity(nid, publicity.value)
+            .set_news_publicity(nid, publicity.value, admin_action)
             .await?;
 
         cmds.events()
@@ -1,12 +1,12 @@
 use axum::{extract::{Path, State}, Extension};
-use model::{AccountId, EventToClientInternal, Permissions};
+use model::{permission, AccountId, AccountIdInternal, AdminActionType, EventToClientInternal, Permissions};
 use model_account::GetAllAdminsResult;
 use server_api::{app::{GetAccounts, WriteData, ReadData}, create_open_api_router, db_write_multiple, S};
-use server_data::read::GetReadCommandsCommon;
+use server_data::{read::GetReadCommandsCommon, write::common_history::AdminAction};
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
-use crate::utils::{Json, StatusCode};
+use crate::utils::{Json, RequirePermission, StatusCode};
 
 const PATH_GET_PERMISSIONS: &str = "/account_api/get_permissions/{aid}";
 
@@ -28,15 +28,11 @@ const PATH_GET_PERMISSIONS: &str = "/account_api/get_permissions/{aid}";
 )]
 pub async fn get_permissions(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_view_permissions>,
     Path(account_id): Path<AccountId>,
 ) -> Result<Json<Permissions>, StatusCode> {
     ACCOUNT_ADMIN.get_permissions.incr();
 
-    if !permissions.admin_view_permissions {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let internal_id = state.get_internal_id(account_id).await?;
 
     let permissions = state.read().common().account(internal_id).await?;
@@ -63,14 +59,10 @@ const PATH_GET_ALL_ADMINS: &str = "/account_api/get_all_admins";
 )]
 pub async fn get_all_admins(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_view_permissions>,
 ) -> Result<Json<GetAllAdminsResult>, StatusCode> {
     ACCOUNT_ADMIN.get_all_admins.incr();
 
-    if !permissions.admin_view_permissions {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let admins = state.read().account_admin().permissions().all_admins().await?;
 
     Ok(admins.into())
@@ -80,6 +72,9 @@ const PATH_POST_SET_PERMISSIONS: &str = "/account_api/set_permissions/{aid}";
 
 /// Set permissions for account
 ///
+/// The permissions are individual permissions. Permissions from
+/// assigned admin roles are added to the effective permissions.
+///
 /// # Access
 ///
 /// Permission [model_account::Permissions::admin_modify_permissions] is required.
@@ -97,22 +92,21 @@ const PATH_POST_SET_PERMISSIONS: &str = "/account_api/set_permissions/{aid}";
 )]
 pub async fn post_set_permissions(
     State(state): State<S>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_modify_permissions>,
     Path(account): Path<AccountId>,
     Json(new_permissions): Json<Permissions>,
 ) -> Result<(), StatusCode> {
     ACCOUNT_ADMIN.post_set_permissions.incr();
 
-    if !api_caller_permissions.admin_modify_permissions {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let internal_id = state.get_internal_id(account).await?;
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::SetPermissions, Some(internal_id), &new_permissions)?;
 
     db_write_multiple!(state, move |cmds| {
         cmds.account_admin().permissions().set_permissions(
             internal_id,
             new_permissions,
+            admin_action,
         ).await?;
 
         cmds.events()
@@ -1,17 +1,19 @@
 use axum::{extract::State, Extension};
+use model::permission;
 use model_account::{
-    AccountIdInternal, GetAccountReportList, Permissions, ProcessAccountReport
+    AccountIdInternal, AdminActionType, GetAccountReportList, ProcessAccountReport
 };
 use server_api::{
     app::{GetAccounts, WriteData},
     create_open_api_router, db_write_multiple, S,
 };
+use server_data::write::common_history::AdminAction;
 use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
 use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_ACCOUNT_REPORT_PENDING_PROCESSING_LIST: &str =
@@ -32,14 +34,10 @@ const PATH_GET_ACCOUNT_REPORT_PENDING_PROCESSING_LIST: &str =
 )]
 pub async fn get_account_report_pending_processing_list(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_process_reports>,
 ) -> Result<Json<GetAccountReportList>, StatusCode> {
     ACCOUNT.get_account_report_pending_processing_list.incr();
 
-    if !permissions.admin_process_reports {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let r = state
         .read()
         .account_admin()
@@ -68,23 +66,20 @@ const PATH_POST_PROCESS_ACCOUNT_REPORT: &str = "/account_api/admin/process_accou
 )]
 pub async fn post_process_account_report(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_process_reports>,
     Extension(moderator_id): Extension<AccountIdInternal>,
     Json(data): Json<ProcessAccountReport>,
 ) -> Result<(), StatusCode> {
     ACCOUNT.post_process_account_report.incr();
 
-    if !permissions.admin_process_reports {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let creator = state.get_internal_id(data.creator).await?;
     let target = state.get_internal_id(data.target).await?;
+    let admin_action = AdminAction::new(moderator_id, AdminActionType::ProcessAccountReport, Some(target), &data)?;
 
     db_write_multiple!(state, move |cmds| {
         cmds.account_admin()
             .report()
-            .process_report(moderator_id, creator, target, data.content)
+            .process_report(moderator_id, creator, target, data.content, admin_action)
             .await?;
         Ok(())
     })?;
@@ -0,0 +1,263 @@
+use axum::{extract::{Path, State}, Extension};
+use model::{permission, AccountId, AccountIdInternal, AdminActionType, EventToClientInternal};
+use model_account::{AdminRole, AssignAdminRole, DeleteAdminRole, GetAccountAdminRoles, GetAdminRoleList, RemoveAdminRole};
+use server_api::{app::{GetAccounts, ReadData, WriteData}, create_open_api_router, db_write_multiple, S};
+use server_data::write::common_history::AdminAction;
+use server_data_account::{read::GetReadCommandsAccount, write::GetWriteCommandsAccount};
+use simple_backend::create_counters;
+
+use crate::utils::{Json, RequirePermission, StatusCode};
+
+const PATH_GET_ADMIN_ROLES: &str = "/account_api/admin_roles";
+
+/// Get all admin roles.
+///
+/// # Access
+///
+/// Permission [model::Permissions::admin_view_permissions] is required.
+#[utoipa::path(
+    get,
+    path = PATH_GET_ADMIN_ROLES,
+    responses(
+        (status = 200, description = "Successfull.", body = GetAdminRoleList),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_admin_roles(
+    State(state): State<S>,
+    _: RequirePermission<permission::admin_view_permissions>,
+) -> Result<Json<GetAdminRoleList>, StatusCode> {
+    ACCOUNT_ADMIN.get_admin_roles.incr();
+
+    let roles = state.read().account_admin().role().all_roles().await?;
+
+    Ok(roles.into())
+}
+
+const PATH_POST_SET_ADMIN_ROLE: &str = "/account_api/set_admin_role";
+
+/// Create a new admin role or replace permissions of an existing role.
+///
+/// Permissions of accounts which have the role are updated.
+///
+/// # Access
+///
+/// Permission [model::Permissions::admin_modify_permissions] is required.
+#[utoipa::path(
+    post,
+    path = PATH_POST_SET_ADMIN_ROLE,
+    request_body = AdminRole,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_set_admin_role(
+    State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_modify_permissions>,
+    Json(role): Json<AdminRole>,
+) -> Result<(), StatusCode> {
+    ACCOUNT_ADMIN.post_set_admin_role.incr();
+
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::SetAdminRole, None, &role)?;
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account_admin().role().set_role(role, admin_action).await?;
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+const PATH_POST_DELETE_ADMIN_ROLE: &str = "/account_api/delete_admin_role";
+
+/// Delete admin role. The role is removed from all accounts.
+///
+/// # Access
+///
+/// Permission [model::Permissions::admin_modify_permissions] is required.
+#[utoipa::path(
+    post,
+    path = PATH_POST_DELETE_ADMIN_ROLE,
+    request_body = DeleteAdminRole,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_delete_admin_role(
+    State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_modify_permissions>,
+    Json(role): Json<DeleteAdminRole>,
+) -> Result<(), StatusCode> {
+    ACCOUNT_ADMIN.post_delete_admin_role.incr();
+
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::DeleteAdminRole, None, &role)?;
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account_admin().role().delete_role(role.name, admin_action).await?;
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+const PATH_GET_ACCOUNT_ADMIN_ROLES: &str = "/account_api/account_admin_roles/{aid}";
+
+/// Get admin roles and individual permissions of specific account.
+///
+/// # Access
+///
+/// Permission [model::Permissions::admin_view_permissions] is required.
+#[utoipa::path(
+    get,
+    path = PATH_GET_ACCOUNT_ADMIN_ROLES,
+    params(AccountId),
+    responses(
+        (status = 200, description = "Successfull.", body = GetAccountAdminRoles),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_account_admin_roles(
+    State(state): State<S>,
+    _: RequirePermission<permission::admin_view_permissions>,
+    Path(account): Path<AccountId>,
+) -> Result<Json<GetAccountAdminRoles>, StatusCode> {
+    ACCOUNT_ADMIN.get_account_admin_roles.incr();
+
+    let internal_id = state.get_internal_id(account).await?;
+    let roles = state.read().account_admin().role().account_roles(internal_id).await?;
+
+    Ok(roles.into())
+}
+
+const PATH_POST_ASSIGN_ADMIN_ROLE: &str = "/account_api/assign_admin_role";
+
+/// Assign admin role to an account. If the account already has the role,
+/// the expiration time is updated.
+///
+/// # Access
+///
+/// Permission [model::Permissions::admin_modify_permissions] is required.
+#[utoipa::path(
+    post,
+    path = PATH_POST_ASSIGN_ADMIN_ROLE,
+    request_body = AssignAdminRole,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_assign_admin_role(
+    State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_modify_permissions>,
+    Json(assignment): Json<AssignAdminRole>,
+) -> Result<(), StatusCode> {
+    ACCOUNT_ADMIN.post_assign_admin_role.incr();
+
+    let internal_id = state.get_internal_id(assignment.account).await?;
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::AssignAdminRole, Some(internal_id), &assignment)?;
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account_admin()
+            .role()
+            .assign_role(internal_id, assignment.name, assignment.expires, admin_action)
+            .await?;
+
+        cmds.events()
+            .send_connected_event(
+                internal_id.uuid,
+                EventToClientInternal::AccountStateChanged,
+            )
+            .await?;
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+const PATH_POST_REMOVE_ADMIN_ROLE: &str = "/account_api/remove_admin_role";
+
+/// Remove admin role from an account.
+///
+/// # Access
+///
+/// Permission [model::Permissions::admin_modify_permissions] is required.
+#[utoipa::path(
+    post,
+    path = PATH_POST_REMOVE_ADMIN_ROLE,
+    request_body = RemoveAdminRole,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_remove_admin_role(
+    State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_modify_permissions>,
+    Json(removal): Json<RemoveAdminRole>,
+) -> Result<(), StatusCode> {
+    ACCOUNT_ADMIN.post_remove_admin_role.incr();
+
+    let internal_id = state.get_internal_id(removal.account).await?;
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::RemoveAdminRole, Some(internal_id), &removal)?;
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account_admin()
+            .role()
+            .remove_role(internal_id, removal.name, admin_action)
+            .await?;
+
+        cmds.events()
+            .send_connected_event(
+                internal_id.uuid,
+                EventToClientInternal::AccountStateChanged,
+            )
+            .await?;
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+create_open_api_router!(
+    fn router_admin_role,
+    get_admin_roles,
+    post_set_admin_role,
+    post_delete_admin_role,
+    get_account_admin_roles,
+    post_assign_admin_role,
+    post_remove_admin_role,
+);
+
+create_counters!(
+    AccountCounters,
+    ACCOUNT_ADMIN,
+    ACCOUNT_ADMIN_ROLE_COUNTERS_LIST,
+    get_admin_roles,
+    post_set_admin_role,
+    post_delete_admin_role,
+    get_account_admin_roles,
+    post_assign_admin_role,
+    post_remove_admin_role,
+);
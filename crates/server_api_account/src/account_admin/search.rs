@@ -1,11 +1,11 @@
-use axum::{extract::{Path, State}, Extension};
-use model::Permissions;
+use axum::extract::{Path, State};
+use model::permission;
 use model_account::{GetAccountIdFromEmailParams, GetAccountIdFromEmailResult};
 use server_api::{app::ReadData, create_open_api_router, S};
 use server_data_account::read::GetReadCommandsAccount;
 use simple_backend::create_counters;
 
-use crate::utils::{Json, StatusCode};
+use crate::utils::{Json, RequirePermission, StatusCode};
 
 const PATH_GET_ACCOUNT_ID_FROM_EMAIL: &str = "/account_api/get_account_id_from_email/{email}";
 
@@ -27,15 +27,11 @@ const PATH_GET_ACCOUNT_ID_FROM_EMAIL: &str = "/account_api/get_account_id_from_e
 )]
 pub async fn get_account_id_from_email(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_find_account_by_email>,
     Path(email): Path<GetAccountIdFromEmailParams>,
 ) -> Result<Json<GetAccountIdFromEmailResult>, StatusCode> {
     ACCOUNT_ADMIN.get_account_id_from_email.incr();
 
-    if !permissions.admin_find_account_by_email {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let r = state.read().account_admin().search().account_id_from_email(email.email).await?;
     Ok(r.into())
 }
@@ -1,10 +1,10 @@
-use axum::{extract::{Path, State}, Extension};
-use model::{Account, AccountId, Permissions};
+use axum::extract::{Path, State};
+use model::{permission, Account, AccountId};
 use server_api::{app::{GetAccounts, ReadData}, create_open_api_router, S};
 use server_data::read::GetReadCommandsCommon;
 use simple_backend::create_counters;
 
-use crate::utils::{Json, StatusCode};
+use crate::utils::{Json, RequirePermission, StatusCode};
 
 const PATH_GET_ACCOUNT_STATE_ADMIN: &str = "/account_api/get_account_state_admin/{aid}";
 
@@ -26,15 +26,11 @@ const PATH_GET_ACCOUNT_STATE_ADMIN: &str = "/account_api/get_account_state_admin
 )]
 pub async fn get_account_state_admin(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_view_private_info>,
     Path(account_id): Path<AccountId>,
 ) -> Result<Json<Account>, StatusCode> {
     ACCOUNT_ADMIN.get_account_state_admin.incr();
 
-    if !permissions.admin_view_private_info {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let internal_id = state.get_internal_id(account_id).await?;
 
     let permissions = state.read().common().account(internal_id).await?;
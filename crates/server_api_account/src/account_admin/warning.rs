@@ -0,0 +1,74 @@
+use axum::{extract::State, Extension};
+use model::{permission, AccountIdInternal, AdminActionType, EventToClientInternal};
+use model_account::WarnAccount;
+use server_api::{app::{GetAccounts, WriteData}, create_open_api_router, db_write_multiple, S};
+use server_data::write::common_history::AdminAction;
+use server_data_account::write::GetWriteCommandsAccount;
+use simple_backend::create_counters;
+
+use crate::utils::{Json, RequirePermission, StatusCode};
+
+const PATH_POST_WARN_ACCOUNT: &str = "/account_api/warn_account";
+
+/// Give a warning to an account
+///
+/// The account state changes to [model::AccountState::WarningPending]
+/// until the account acknowledges the warning. If the account has
+/// received too many warnings recently, the account is also banned
+/// temporarily.
+///
+/// # Access
+///
+/// Permission [model_account::Permissions::admin_ban_account] is required.
+#[utoipa::path(
+    post,
+    path = PATH_POST_WARN_ACCOUNT,
+    request_body = WarnAccount,
+    responses(
+        (status = 200, description = "Successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_warn_account(
+    State(state): State<S>,
+    Extension(api_caller_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_ban_account>,
+    Json(warning): Json<WarnAccount>,
+) -> Result<(), StatusCode> {
+    ACCOUNT_ADMIN.post_warn_account.incr();
+
+    let internal_id = state.get_internal_id(warning.account).await?;
+    let admin_action = AdminAction::new(api_caller_id, AdminActionType::WarnAccount, Some(internal_id), &warning)?;
+
+    db_write_multiple!(state, move |cmds| {
+        cmds.account_admin().warning().warn_account(
+            internal_id,
+            Some(api_caller_id),
+            warning.reason_category,
+            warning.reason_details,
+            Some(admin_action),
+        ).await?;
+
+        cmds.events()
+            .send_connected_event(
+                internal_id.uuid,
+                EventToClientInternal::AccountStateChanged,
+            )
+            .await?;
+
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+create_open_api_router!(fn router_admin_warning, post_warn_account,);
+
+create_counters!(
+    AccountCounters,
+    ACCOUNT_ADMIN,
+    ACCOUNT_ADMIN_WARNING_COUNTERS_LIST,
+    post_warn_account,
+);
@@ -5,7 +5,7 @@ use axum::{
 };
 use axum_extra::TypedHeader;
 use headers::{ContentLength, ContentType};
-use model::EventToClientInternal;
+use model::{permission, EventToClientInternal};
 use model_media::{
     AccountContent, AccountId, AccountIdInternal, AccountState, ContentId, ContentProcessingId,
     ContentProcessingState, ContentSlot, GetContentQueryParams, NewContentParams, Permissions,
@@ -22,7 +22,7 @@ use simple_backend::create_counters;
 
 use crate::{
     app::{ContentProcessingProvider, GetAccounts, ReadData, WriteData},
-    utils::{Json, StatusCode},
+    utils::{permission_denied, Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_CONTENT: &str = "/media_api/content/{aid}/{cid}";
@@ -145,6 +145,7 @@ const PATH_GET_ALL_ACCOUNT_MEDIA_CONTENT: &str = "/media_api/all_account_media_c
     responses(
         (status = 200, description = "Successful.", body = AccountContent),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500),
     ),
     security(("access_token" = [])),
@@ -153,16 +154,16 @@ pub async fn get_all_account_media_content(
     State(state): State<S>,
     Path(account_id): Path<AccountId>,
     Extension(api_caller_account_id): Extension<AccountIdInternal>,
-    Extension(api_caller_permissions): Extension<Permissions>,
+    admin_permission: Option<RequirePermission<permission::admin_moderate_media_content>>,
 ) -> Result<Json<AccountContent>, StatusCode> {
     MEDIA.get_all_account_media_content.incr();
 
     let internal_id = state.get_internal_id(account_id).await?;
 
     let access_allowed = api_caller_account_id == internal_id ||
-        api_caller_permissions.admin_moderate_media_content;
+        admin_permission.is_some();
     if !access_allowed {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        return Err(permission_denied::<permission::admin_moderate_media_content>());
     }
 
     let internal_current_media = state
@@ -311,6 +312,7 @@ const PATH_DELETE_CONTENT: &str = "/media_api/content/{aid}/{cid}";
     responses(
         (status = 200, description = "Content data deleted."),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500),
     ),
     security(("access_token" = [])),
@@ -318,7 +320,7 @@ const PATH_DELETE_CONTENT: &str = "/media_api/content/{aid}/{cid}";
 pub async fn delete_content(
     State(state): State<S>,
     Extension(api_caller_account_id): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    admin_permission: Option<RequirePermission<permission::admin_delete_media_content>>,
     Path(content_owner_account_id): Path<AccountId>,
     Path(content_id): Path<ContentId>,
 ) -> Result<(), StatusCode> {
@@ -333,18 +335,18 @@ pub async fn delete_content(
     }
 
     let owner_deleting_content = content_owner_account_id == api_caller_account_id;
-    let admin_access = permissions.admin_delete_media_content;
+    let admin_access = admin_permission.is_some();
     let route_access_allowed = owner_deleting_content || admin_access;
 
     if !route_access_allowed {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        return Err(permission_denied::<permission::admin_delete_media_content>());
     }
 
     if owner_deleting_content && !admin_access && !content.removable_by_user(state.config().limits_media().unused_content_wait_duration.seconds) {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    db_write_multiple!(state, move |cmds| {
+    let profile_verification_revoke_needed = db_write_multiple!(state, move |cmds| {
         let r = cmds
             .media()
             .delete_content(content_id)
@@ -359,8 +361,17 @@ pub async fn delete_content(
                 .await?;
         }
 
-        Ok(())
-    })
+        Ok(r.profile_verification_revoke_needed)
+    })?;
+
+    if profile_verification_revoke_needed {
+        state
+            .data_all_access()
+            .update_profile_verified(content_owner_account_id, false, None, None)
+            .await?;
+    }
+
+    Ok(())
 }
 
 create_open_api_router!(
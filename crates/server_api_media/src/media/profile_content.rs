@@ -123,6 +123,8 @@ const PATH_PUT_PROFILE_CONTENT: &str = "/media_api/profile_content";
 /// Also profile visibility moves from pending to normal when
 /// all profile content is moderated as accepted.
 ///
+/// Profile verification is revoked if the first content changes.
+///
 /// # Restrictions
 /// - All content must be owned by the account.
 /// - All content must be images.
@@ -145,12 +147,12 @@ pub async fn put_profile_content(
 ) -> Result<(), StatusCode> {
     MEDIA.put_profile_content.incr();
 
-    db_write_multiple!(state, move |cmds| {
+    let profile_verification_revoke_needed = db_write_multiple!(state, move |cmds| {
         let info = cmds
             .media()
             .update_profile_content(api_caller_account_id, new).await?;
 
-        match info {
+        match info.initial_content_moderation {
             InitialContentModerationResult::AllAccepted { .. } => {
                 if cmds.config().components().account {
                     cmds.events()
@@ -178,9 +180,16 @@ pub async fn put_profile_content(
             InitialContentModerationResult::NoChange => (),
         }
 
-        Ok(())
+        Ok(info.profile_verification_revoke_needed)
     })?;
 
+    if profile_verification_revoke_needed {
+        state
+            .data_all_access()
+            .update_profile_verified(api_caller_account_id, false, None, None)
+            .await?;
+    }
+
     // TODO(microservice): Add profile visibility change notification
     // to account internal API.
 
@@ -2,7 +2,7 @@ use axum::{
     extract::{Path, State},
     Extension,
 };
-use model::Permissions;
+use model::permission;
 use model_media::{
     AccountId, AccountIdInternal, ContentId, SecurityContent,
 };
@@ -12,7 +12,7 @@ use simple_backend::create_counters;
 
 use crate::{
     app::{GetAccounts, ReadData, WriteData},
-    utils::{Json, StatusCode},
+    utils::{permission_denied, Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_SECURITY_CONTENT_INFO: &str = "/media_api/security_content_info/{aid}";
@@ -30,6 +30,7 @@ const PATH_GET_SECURITY_CONTENT_INFO: &str = "/media_api/security_content_info/{
     responses(
         (status = 200, description = "Successful.", body = SecurityContent),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (status = 500),
     ),
     security(("access_token" = [])),
@@ -38,17 +39,17 @@ pub async fn get_security_content_info(
     State(state): State<S>,
     Path(requested_account_id): Path<AccountId>,
     Extension(api_caller_account_id): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    admin_permission: Option<RequirePermission<permission::admin_moderate_media_content>>,
 ) -> Result<Json<SecurityContent>, StatusCode> {
     MEDIA.get_security_content_info.incr();
 
     let internal_id = state.get_internal_id(requested_account_id).await?;
 
     let access_allowed = internal_id == api_caller_account_id ||
-        permissions.admin_moderate_media_content;
+        admin_permission.is_some();
 
     if !access_allowed {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        return Err(permission_denied::<permission::admin_moderate_media_content>());
     }
 
     let internal_current_media = state
@@ -68,6 +69,8 @@ const PATH_PUT_SECURITY_CONTENT_INFO: &str = "/media_api/security_content_info";
 /// This also moves the content to moderation if it is not already
 /// in moderation or moderated.
 ///
+/// Profile verification is revoked if the security content changes.
+///
 /// # Restrictions
 /// - The content must be owned by the account.
 /// - The content must be an image.
@@ -91,7 +94,7 @@ pub async fn put_security_content_info(
 ) -> Result<(), StatusCode> {
     MEDIA.put_security_content_info.incr();
 
-    db_write_multiple!(state, move |cmds| {
+    let r = db_write_multiple!(state, move |cmds| {
         let content_id = cmds
             .read()
             .media()
@@ -100,7 +103,16 @@ pub async fn put_security_content_info(
         cmds
             .media()
             .update_security_content(content_id).await
-    })
+    })?;
+
+    if r.profile_verification_revoke_needed {
+        state
+            .data_all_access()
+            .update_profile_verified(api_caller_account_id, false, None, None)
+            .await?;
+    }
+
+    Ok(())
 }
 
 create_open_api_router!(
@@ -1,3 +1,7 @@
+pub mod duplicate;
 pub mod moderation;
+pub mod verification;
 
+pub use duplicate::*;
 pub use moderation::*;
+pub use verification::*;
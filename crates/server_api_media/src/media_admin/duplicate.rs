@@ -0,0 +1,66 @@
+use axum::extract::State;
+use model::permission;
+use model_media::{GetMediaContentDuplicateList, MediaContentDuplicateIteratorQuery};
+use server_api::{create_open_api_router, S};
+use server_data_media::read::GetReadMediaCommands;
+use simple_backend::create_counters;
+
+use crate::{
+    app::ReadData,
+    utils::{Json, RequirePermission, StatusCode},
+};
+
+const PATH_POST_GET_MEDIA_CONTENT_DUPLICATE_PAGE: &str =
+    "/media_api/admin/media_content_duplicate_page";
+
+/// Get near-duplicate images. Latest detections are first.
+///
+/// Uploaded images are compared to images of other accounts and
+/// rejected images of the uploader using perceptual hashes.
+///
+/// The HTTP method is POST because HTTP GET does not allow request body.
+///
+/// # Access
+/// - Permission [model::Permissions::admin_moderate_media_content]
+#[utoipa::path(
+    post,
+    path = PATH_POST_GET_MEDIA_CONTENT_DUPLICATE_PAGE,
+    request_body = MediaContentDuplicateIteratorQuery,
+    responses(
+        (status = 200, description = "Successful", body = GetMediaContentDuplicateList),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Permission denied"),
+        (
+            status = 500,
+            description = "Internal server error",
+        ),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_get_media_content_duplicate_page(
+    State(state): State<S>,
+    _: RequirePermission<permission::admin_moderate_media_content>,
+    Json(query): Json<MediaContentDuplicateIteratorQuery>,
+) -> Result<Json<GetMediaContentDuplicateList>, StatusCode> {
+    MEDIA_ADMIN.post_get_media_content_duplicate_page.incr();
+
+    let r = state
+        .read()
+        .media_admin()
+        .media_content_duplicate_page(query)
+        .await?;
+
+    Ok(r.into())
+}
+
+create_open_api_router!(
+        fn router_admin_duplicate,
+        post_get_media_content_duplicate_page,
+);
+
+create_counters!(
+    MediaAdminCounters,
+    MEDIA_ADMIN,
+    MEDIA_ADMIN_DUPLICATE_COUNTERS_LIST,
+    post_get_media_content_duplicate_page,
+);
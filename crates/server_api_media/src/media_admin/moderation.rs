@@ -2,18 +2,19 @@ use axum::{
     extract::{Query, State},
     Extension,
 };
+use model::permission;
 use model_media::{
-    AccountIdInternal, EventToClientInternal, GetProfileContentPendingModerationList, GetProfileContentPendingModerationParams, NotificationEvent, Permissions, PostModerateProfileContent
+    AccountIdInternal, AdminActionType, EventToClientInternal, GetProfileContentPendingModerationList, GetProfileContentPendingModerationParams, NotificationEvent, PostModerateProfileContent
 };
 use server_api::{create_open_api_router, S, app::GetAccounts};
+use server_data::write::common_history::AdminAction;
 use server_data_media::{read::GetReadMediaCommands, write::{media::InitialContentModerationResult, media_admin::content::ContentModerationMode, GetWriteCommandsMedia}};
 use simple_backend::create_counters;
-use server_api::app::ReadData;
 
 use crate::{
     app::WriteData,
-    db_write_multiple,
-    utils::{Json, StatusCode},
+    db_write, db_write_multiple,
+    utils::{Json, RequirePermission, StatusCode},
 };
 
 // TODO(prod): Change moderation related API naming from
@@ -23,6 +24,10 @@ const PATH_GET_PROFILE_CONTENT_PENDING_MODERATION_LIST: &str =
     "/media_api/admin/profile_content_pending_moderation";
 
 /// Get first page of pending profile content moderations. Oldest item is first and count 25.
+///
+/// The returned content is claimed for the moderator, so other moderators
+/// do not get the same content until the claim lease expires. Content
+/// which other moderators have claimed is not returned.
 #[utoipa::path(
     get,
     path = PATH_GET_PROFILE_CONTENT_PENDING_MODERATION_LIST,
@@ -40,20 +45,15 @@ const PATH_GET_PROFILE_CONTENT_PENDING_MODERATION_LIST: &str =
 pub async fn get_profile_content_pending_moderation_list(
     State(state): State<S>,
     Extension(moderator_id): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_moderate_media_content>,
     Query(params): Query<GetProfileContentPendingModerationParams>,
 ) -> Result<Json<GetProfileContentPendingModerationList>, StatusCode> {
     MEDIA_ADMIN.get_profile_content_pending_moderation_list.incr();
 
-    if !permissions.admin_moderate_media_content {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
-    let r = state
-        .read()
+    let r = db_write!(state, move |cmds| cmds
         .media_admin()
-        .profile_content_pending_moderation_list(moderator_id, params)
-        .await?;
+        .content()
+        .claim_profile_content_pending_moderation_list(moderator_id, params))?;
 
     Ok(r.into())
 }
@@ -66,6 +66,9 @@ const PATH_POST_MODERATE_PROFILE_CONTENT: &str = "/media_api/admin/moderate_prof
 ///
 /// Also profile visibility moves from pending to normal when
 /// all profile content is moderated as accepted.
+///
+/// Profile verification is revoked if the primary profile content or
+/// the security content is rejected.
 #[utoipa::path(
     post,
     path = PATH_POST_MODERATE_PROFILE_CONTENT,
@@ -82,21 +85,18 @@ const PATH_POST_MODERATE_PROFILE_CONTENT: &str = "/media_api/admin/moderate_prof
 )]
 pub async fn post_moderate_profile_content(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_moderate_media_content>,
     Extension(moderator_id): Extension<AccountIdInternal>,
     Json(data): Json<PostModerateProfileContent>,
 ) -> Result<(), StatusCode> {
     MEDIA_ADMIN.post_moderate_profile_content.incr();
 
-    if !permissions.admin_moderate_media_content {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     if data.accept && (data.rejected_category.is_some() || data.rejected_details.is_some()) {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
     let content_owner = state.get_internal_id(data.account_id).await?;
+    let admin_action = AdminAction::new(moderator_id, AdminActionType::ModerateProfileContent, Some(content_owner), &data)?;
 
     let mode = if data.move_to_human.unwrap_or_default() {
         ContentModerationMode::MoveToHumanModeration
@@ -109,7 +109,7 @@ pub async fn post_moderate_profile_content(
         }
     };
 
-    db_write_multiple!(state, move |cmds| {
+    let profile_verification_revoke_needed = db_write_multiple!(state, move |cmds| {
         let content_id = cmds
             .read()
             .media()
@@ -119,6 +119,7 @@ pub async fn post_moderate_profile_content(
             .moderate_profile_content(
                 mode,
                 content_id,
+                Some(admin_action),
             )
             .await?;
 
@@ -158,9 +159,16 @@ pub async fn post_moderate_profile_content(
             )
             .await?;
 
-        Ok(())
+        Ok(info.profile_verification_revoke_needed)
     })?;
 
+    if profile_verification_revoke_needed {
+        state
+            .data_all_access()
+            .update_profile_verified(content_owner, false, None, None)
+            .await?;
+    }
+
     // TODO(microservice): Add profile visibility change notification
     // to account internal API.
 
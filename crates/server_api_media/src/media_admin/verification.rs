@@ -0,0 +1,83 @@
+use axum::{extract::State, Extension};
+use model::{permission, ProfileVerificationContent};
+use model_media::{AccountIdInternal, AdminActionType, PostProfileVerification};
+use server_api::{app::GetAccounts, create_open_api_router, S};
+use server_data::write::common_history::AdminAction;
+use simple_backend::create_counters;
+
+use crate::utils::{Json, RequirePermission, StatusCode};
+
+const PATH_POST_PROFILE_VERIFICATION: &str = "/media_api/admin/profile_verification";
+
+/// Set profile verification state.
+///
+/// Verify the profile after comparing the security content with the
+/// primary profile content. Verification is revoked automatically
+/// when the primary profile content or the security content changes
+/// or is rejected in moderation.
+///
+/// Automatic verification by an admin bot is not supported. The image
+/// process face detection only detects if an image contains a face, so
+/// it can not compare faces between two images.
+///
+/// # Restrictions
+/// When verifying
+/// - The security content and the primary profile content must be
+///   the current content of the account.
+/// - Both content must be moderated as accepted.
+///
+/// # Access
+/// - Permission [model::Permissions::admin_moderate_media_content]
+#[utoipa::path(
+    post,
+    path = PATH_POST_PROFILE_VERIFICATION,
+    request_body = PostProfileVerification,
+    responses(
+        (status = 200, description = "Successful"),
+        (status = 401, description = "Unauthorized"),
+        (
+            status = 500,
+            description = "Internal server error",
+        ),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_profile_verification(
+    State(state): State<S>,
+    Extension(moderator_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_moderate_media_content>,
+    Json(data): Json<PostProfileVerification>,
+) -> Result<(), StatusCode> {
+    MEDIA_ADMIN.post_profile_verification.incr();
+
+    let account = state.get_internal_id(data.account_id).await?;
+
+    let admin_action = AdminAction::new(moderator_id, AdminActionType::SetProfileVerification, Some(account), &data)?;
+
+    state
+        .data_all_access()
+        .update_profile_verified(
+            account,
+            data.verified,
+            data.verified.then_some(ProfileVerificationContent {
+                security_content_id: data.security_content_id,
+                profile_content_id: data.profile_content_id,
+            }),
+            Some(admin_action),
+        )
+        .await?;
+
+    Ok(())
+}
+
+create_open_api_router!(
+        fn router_admin_profile_verification,
+        post_profile_verification,
+);
+
+create_counters!(
+    MediaAdminCounters,
+    MEDIA_ADMIN,
+    MEDIA_ADMIN_PROFILE_VERIFICATION_COUNTERS_LIST,
+    post_profile_verification,
+);
@@ -3,15 +3,19 @@ pub mod benchmark;
 pub mod favorite;
 pub mod iterate_profiles;
 pub mod location;
+pub mod pass;
 pub mod profile_data;
 pub mod statistics;
 pub mod report;
+pub mod visit;
 
 pub use filters::*;
 pub use benchmark::*;
 pub use favorite::*;
 pub use iterate_profiles::*;
 pub use location::*;
+pub use pass::*;
 pub use profile_data::*;
 pub use statistics::*;
 pub use report::*;
+pub use visit::*;
@@ -1,10 +1,12 @@
 use axum::{extract::State, Extension};
 use model_profile::{AccountIdInternal, ProfileIteratorSessionId, ProfilePage};
 use server_api::{create_open_api_router, S};
+use server_data_profile::{read::GetReadProfileCommands, write::GetWriteCommandsProfile};
 use simple_backend::create_counters;
 
 use crate::{
-    app::WriteData,
+    app::{ReadData, WriteData},
+    db_write,
     utils::{Json, StatusCode},
 };
 
@@ -37,6 +39,10 @@ pub async fn post_get_next_profile_page(
 
     if let Some(data) = data {
         // Profile iterator session ID was valid
+        let seen = data.iter().map(|v| v.id()).collect::<Vec<_>>();
+        db_write!(state, move |cmds| cmds
+            .profile()
+            .insert_seen_profiles(account_id, seen))?;
         Ok(ProfilePage {
             profiles: data,
             error_invalid_iterator_session_id: false,
@@ -60,7 +66,8 @@ const PATH_POST_RESET_PROFILE_PAGING: &str = "/profile_api/page/reset";
 /// Reset profile paging.
 ///
 /// After this request getting next profiles will continue from the nearest
-/// profiles.
+/// profiles. If mutual interest profile order is enabled, profiles
+/// are ranked when this request is made.
 #[utoipa::path(
     post,
     path = PATH_POST_RESET_PROFILE_PAGING,
@@ -76,9 +83,14 @@ pub async fn post_reset_profile_paging(
     Extension(account_id): Extension<AccountIdInternal>,
 ) -> Result<Json<ProfileIteratorSessionId>, StatusCode> {
     PROFILE.post_reset_profile_paging.incr();
+    let received_likes = state
+        .read()
+        .profile()
+        .mutual_interest_ranking_received_likes(account_id)
+        .await?;
     let iterator_session_id: ProfileIteratorSessionId = state
         .concurrent_write_profile_blocking(account_id.as_id(), move |cmds| {
-            cmds.reset_profile_iterator(account_id)
+            cmds.reset_profile_iterator(account_id, received_likes)
         })
         .await??
         .into();
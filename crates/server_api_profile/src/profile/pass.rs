@@ -0,0 +1,90 @@
+use axum::{extract::State, Extension};
+use model_profile::{AccountId, AccountIdInternal};
+use server_api::{create_open_api_router, S};
+use server_data_profile::write::GetWriteCommandsProfile;
+use simple_backend::create_counters;
+
+use crate::{
+    app::{GetAccounts, WriteData},
+    db_write,
+    utils::{Json, StatusCode},
+};
+
+const PATH_POST_PASS_PROFILE: &str = "/profile_api/pass_profile";
+
+/// Pass profile.
+///
+/// Profile iterator will not return passed profiles. The change
+/// is visible immediately.
+///
+/// Passing own profile is not possible.
+#[utoipa::path(
+    post,
+    path = PATH_POST_PASS_PROFILE,
+    request_body(content = AccountId),
+    responses(
+        (status = 200, description = "Request successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 406, description = "Own profile can not be passed."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_pass_profile(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(passed): Json<AccountId>,
+) -> Result<(), StatusCode> {
+    PROFILE.post_pass_profile.incr();
+
+    if account_id.as_id() == passed {
+        return Err(StatusCode::NOT_ACCEPTABLE);
+    }
+
+    let passed_account_id = state.get_internal_id(passed).await?;
+    db_write!(state, move |cmds| cmds
+        .profile()
+        .insert_passed_profile(account_id, passed_account_id))?;
+
+    Ok(())
+}
+
+const PATH_DELETE_PASSED_PROFILES: &str = "/profile_api/passed_profiles";
+
+/// Remove all passed profiles
+#[utoipa::path(
+    delete,
+    path = PATH_DELETE_PASSED_PROFILES,
+    responses(
+        (status = 200, description = "Request successfull."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn delete_passed_profiles(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+) -> Result<(), StatusCode> {
+    PROFILE.delete_passed_profiles.incr();
+
+    db_write!(state, move |cmds| cmds
+        .profile()
+        .remove_all_passed_profiles(account_id))?;
+
+    Ok(())
+}
+
+create_open_api_router!(
+        fn router_pass,
+        post_pass_profile,
+        delete_passed_profiles,
+);
+
+create_counters!(
+    ProfileCounters,
+    PROFILE,
+    PROFILE_PASS_COUNTERS_LIST,
+    post_pass_profile,
+    delete_passed_profiles,
+);
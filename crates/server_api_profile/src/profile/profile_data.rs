@@ -44,6 +44,11 @@ const PATH_GET_PROFILE: &str = "/profile_api/profile/{aid}";
 /// If the profile is not a match, then permission `admin_view_all_profiles`
 /// is required.
 ///
+/// # Profile visits
+/// Profile visit is recorded if both accounts have enabled profile visit
+/// tracking. Visits are not recorded when the profile is accessed only
+/// because of permission `admin_view_all_profiles`.
+///
 /// # Microservice notes
 /// If account feature is set as external service then cached permission
 /// information from account service is used for access checks.
@@ -104,13 +109,32 @@ pub async fn get_profile(
         .is_currently_public();
 
     if visibility
-        || permissions.admin_view_all_profiles
         || (params.allow_get_profile_if_match()
             && state
                 .data_all_access()
                 .is_match(account_id, requested_profile)
                 .await?)
     {
+        let visitor_tracking = state
+            .read()
+            .profile()
+            .visit()
+            .profile_visit_tracking(account_id)
+            .await?;
+        let target_tracking = state
+            .read()
+            .profile()
+            .visit()
+            .profile_visit_tracking(requested_profile)
+            .await?;
+        if visitor_tracking && target_tracking && account_id.as_id() != requested_profile.as_id() {
+            db_write!(state, move |cmds| cmds
+                .profile()
+                .visit()
+                .insert_profile_visit(requested_profile, account_id))?;
+        }
+        read_profile_action().await
+    } else if permissions.admin_view_all_profiles {
         read_profile_action().await
     } else {
         Ok(GetProfileResult::empty().into())
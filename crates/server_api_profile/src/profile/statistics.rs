@@ -1,15 +1,13 @@
-use axum::{
-    extract::{Query, State},
-    Extension,
-};
-use model_profile::{GetProfileStatisticsParams, GetProfileStatisticsResult, Permissions};
+use axum::extract::{Query, State};
+use model::permission;
+use model_profile::{GetProfileStatisticsParams, GetProfileStatisticsResult};
 use server_api::{app::ProfileStatisticsCacheProvider, create_open_api_router, S};
 use server_data_profile::{read::GetReadProfileCommands, statistics::ProfileStatisticsCacheUtils};
 use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{permission_denied, Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_PROFILE_STATISTICS: &str = "/profile_api/profile_statistics";
@@ -23,6 +21,7 @@ const PATH_GET_PROFILE_STATISTICS: &str = "/profile_api/profile_statistics";
     responses(
         (status = 200, description = "Successful", body = GetProfileStatisticsResult),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Permission denied"),
         (
             status = 500,
             description = "Internal server error",
@@ -32,13 +31,13 @@ const PATH_GET_PROFILE_STATISTICS: &str = "/profile_api/profile_statistics";
 )]
 pub async fn get_profile_statistics(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    admin_permission: Option<RequirePermission<permission::admin_profile_statistics>>,
     Query(params): Query<GetProfileStatisticsParams>,
 ) -> Result<Json<GetProfileStatisticsResult>, StatusCode> {
     PROFILE.get_profile_statistics.incr();
 
-    if !permissions.admin_profile_statistics && params.contains_admin_settings() {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    if admin_permission.is_none() && params.contains_admin_settings() {
+        return Err(permission_denied::<permission::admin_profile_statistics>());
     }
 
     let r: GetProfileStatisticsResult = if params.contains_admin_settings() {
@@ -0,0 +1,177 @@
+//! Profile visit related routes
+
+use axum::{extract::State, Extension};
+use model_profile::{
+    AccountIdInternal, ProfileVisitTrackingSetting, ProfileVisitorsIteratorSessionId,
+    ProfileVisitorsPage, ResetProfileVisitorsIteratorResult,
+};
+use server_api::{create_open_api_router, S};
+use server_data_profile::{read::GetReadProfileCommands, write::GetWriteCommandsProfile};
+use simple_backend::create_counters;
+
+use crate::{
+    app::{ReadData, WriteData},
+    db_write,
+    utils::{Json, StatusCode},
+};
+
+const PATH_GET_PROFILE_VISIT_TRACKING: &str = "/profile_api/settings/profile_visit_tracking";
+
+/// Get profile visit tracking setting.
+#[utoipa::path(
+    get,
+    path = PATH_GET_PROFILE_VISIT_TRACKING,
+    responses(
+        (status = 200, description = "Success.", body = ProfileVisitTrackingSetting),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn get_profile_visit_tracking(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+) -> Result<Json<ProfileVisitTrackingSetting>, StatusCode> {
+    PROFILE.get_profile_visit_tracking.incr();
+    let value = state
+        .read()
+        .profile()
+        .visit()
+        .profile_visit_tracking(account_id)
+        .await?;
+    Ok(ProfileVisitTrackingSetting { value }.into())
+}
+
+const PATH_PUT_PROFILE_VISIT_TRACKING: &str = "/profile_api/settings/profile_visit_tracking";
+
+/// Update profile visit tracking setting.
+///
+/// When enabled, visits to the account's profile are recorded
+/// and visits from the account to other profiles are recorded.
+/// A visit is recorded only if both accounts have enabled the setting.
+///
+/// Disabling the setting does not remove already recorded visits.
+#[utoipa::path(
+    put,
+    path = PATH_PUT_PROFILE_VISIT_TRACKING,
+    request_body(content = ProfileVisitTrackingSetting),
+    responses(
+        (status = 200, description = "Success."),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn put_profile_visit_tracking(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(setting): Json<ProfileVisitTrackingSetting>,
+) -> Result<(), StatusCode> {
+    PROFILE.put_profile_visit_tracking.incr();
+    db_write!(state, move |cmds| cmds
+        .profile()
+        .update_profile_visit_tracking(account_id, setting.value))?;
+    Ok(())
+}
+
+const PATH_POST_RESET_PROFILE_VISITORS_PAGING: &str = "/profile_api/profile_visitors/reset";
+
+#[utoipa::path(
+    post,
+    path = PATH_POST_RESET_PROFILE_VISITORS_PAGING,
+    responses(
+        (status = 200, description = "Successfull.", body = ResetProfileVisitorsIteratorResult),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_reset_profile_visitors_paging(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+) -> Result<Json<ResetProfileVisitorsIteratorResult>, StatusCode> {
+    PROFILE.post_reset_profile_visitors_paging.incr();
+    let iterator_session_id = db_write!(state, move |cmds| {
+        cmds.profile()
+            .visit()
+            .handle_reset_profile_visitors_iterator(account_id)
+    })?;
+    let r = ResetProfileVisitorsIteratorResult {
+        s: iterator_session_id.into(),
+    };
+
+    Ok(r.into())
+}
+
+const PATH_POST_GET_NEXT_PROFILE_VISITORS_PAGE: &str = "/profile_api/profile_visitors_page";
+
+/// Update profile visitors iterator and get next page
+/// of profile visitors. If the page is empty there is no more
+/// profile visitors available.
+///
+/// The visitors are ordered from recent to older. Visits are
+/// deduplicated per day, so the same visitor can be in the
+/// list multiple times.
+#[utoipa::path(
+    post,
+    path = PATH_POST_GET_NEXT_PROFILE_VISITORS_PAGE,
+    request_body(content = ProfileVisitorsIteratorSessionId),
+    responses(
+        (status = 200, description = "Success.", body = ProfileVisitorsPage),
+        (status = 401, description = "Unauthorized."),
+        (status = 500, description = "Internal server error."),
+    ),
+    security(("access_token" = [])),
+)]
+pub async fn post_get_next_profile_visitors_page(
+    State(state): State<S>,
+    Extension(account_id): Extension<AccountIdInternal>,
+    Json(iterator_session_id): Json<ProfileVisitorsIteratorSessionId>,
+) -> Result<Json<ProfileVisitorsPage>, StatusCode> {
+    PROFILE.post_get_next_profile_visitors_page.incr();
+
+    let data = state
+        .concurrent_write_profile_blocking(account_id.as_id(), move |cmds| {
+            cmds.next_profile_visitors_iterator_state(account_id, iterator_session_id)
+        })
+        .await??;
+
+    if let Some(data) = data {
+        // Profile visitors iterator session ID was valid
+        let visitors = state
+            .read()
+            .profile()
+            .visit()
+            .profile_visitors_page(account_id, data)
+            .await?;
+        Ok(ProfileVisitorsPage {
+            p: visitors,
+            error_invalid_iterator_session_id: false,
+        }
+        .into())
+    } else {
+        Ok(ProfileVisitorsPage {
+            p: vec![],
+            error_invalid_iterator_session_id: true,
+        }
+        .into())
+    }
+}
+
+create_open_api_router!(
+        fn router_profile_visit,
+        get_profile_visit_tracking,
+        put_profile_visit_tracking,
+        post_reset_profile_visitors_paging,
+        post_get_next_profile_visitors_page,
+);
+
+create_counters!(
+    ProfileCounters,
+    PROFILE,
+    PROFILE_VISIT_COUNTERS_LIST,
+    get_profile_visit_tracking,
+    put_profile_visit_tracking,
+    post_reset_profile_visitors_paging,
+    post_get_next_profile_visitors_page,
+);
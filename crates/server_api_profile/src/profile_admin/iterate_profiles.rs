@@ -1,9 +1,7 @@
-use axum::{
-    extract::{Query, State},
-    Extension,
-};
+use axum::extract::{Query, State};
+use model::permission;
 use model_profile::{
-    AccountIdDbValue, Permissions, ProfileIteratorPage, ProfileIteratorSettings
+    AccountIdDbValue, ProfileIteratorPage, ProfileIteratorSettings
 };
 use server_api::{
     create_open_api_router, S,
@@ -13,7 +11,7 @@ use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_LATEST_CREATED_ACCOUNT_ID_DB: &str = "/profile_api/get_latest_created_account_id_db";
@@ -37,14 +35,10 @@ const PATH_GET_LATEST_CREATED_ACCOUNT_ID_DB: &str = "/profile_api/get_latest_cre
 )]
 pub async fn get_latest_created_account_id_db(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_view_all_profiles>,
 ) -> Result<Json<AccountIdDbValue>, StatusCode> {
     PROFILE.get_latest_created_account_id_db.incr();
 
-    if !permissions.admin_view_all_profiles {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let r = state.read().profile_admin().iterator().get_latest_created_account_id_db().await?;
 
     Ok(r.into())
@@ -72,15 +66,11 @@ const PATH_GET_ADMIN_PROFILE_ITERATOR_PAGE: &str = "/profile_api/get_admin_profi
 )]
 pub async fn get_admin_profile_iterator_page(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_view_all_profiles>,
     Query(settings): Query<ProfileIteratorSettings>,
 ) -> Result<Json<ProfileIteratorPage>, StatusCode> {
     PROFILE.get_admin_profile_iterator_page.incr();
 
-    if !permissions.admin_view_all_profiles {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let r = state.read().profile_admin().iterator().get_profile_page(settings).await?;
 
     Ok(r.into())
@@ -1,11 +1,6 @@
-use axum::{
-    extract::{Path, State},
-    Extension,
-};
-use model::AccountId;
-use model_profile::{
-    GetProfileAgeAndName, Permissions,
-};
+use axum::extract::{Path, State};
+use model::{permission_any, AccountId};
+use model_profile::GetProfileAgeAndName;
 use server_api::{
     app::GetAccounts,
     create_open_api_router, S,
@@ -15,7 +10,7 @@ use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_PROFILE_AGE_AND_NAME: &str = "/profile_api/get_profile_age_and_name/{aid}";
@@ -35,6 +30,7 @@ const PATH_GET_PROFILE_AGE_AND_NAME: &str = "/profile_api/get_profile_age_and_na
     responses(
         (status = 200, description = "Successful.", body = GetProfileAgeAndName),
         (status = 401, description = "Unauthorized."),
+        (status = 403, description = "Permission denied."),
         (
             status = 500,
             description = "Internal server error.",
@@ -44,22 +40,11 @@ const PATH_GET_PROFILE_AGE_AND_NAME: &str = "/profile_api/get_profile_age_and_na
 )]
 pub async fn get_profile_age_and_name(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission_any::some_admin_profile_data_permission>,
     Path(account_id): Path<AccountId>,
 ) -> Result<Json<GetProfileAgeAndName>, StatusCode> {
     PROFILE.get_profile_age_and_name.incr();
 
-    let access_allowed =
-        permissions.admin_find_account_by_email ||
-        permissions.admin_view_permissions ||
-        permissions.admin_moderate_media_content ||
-        permissions.admin_moderate_profile_names ||
-        permissions.admin_moderate_profile_texts;
-
-    if !access_allowed {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let profile_owner_id = state.get_internal_id(account_id).await?;
 
     let r = state.read().profile().profile(profile_owner_id).await?;
@@ -1,23 +1,28 @@
 use axum::{extract::{Path, State}, Extension};
+use model::permission;
 use model_profile::{
-    AccountIdInternal, EventToClientInternal, GetProfileNamePendingModerationList, GetProfileNameState, Permissions, PostModerateProfileName
+    AccountIdInternal, AdminActionType, EventToClientInternal, GetProfileNamePendingModerationList, GetProfileNameState, PostModerateProfileName
 };
 use server_api::{
     app::{GetAccounts, WriteData},
-    create_open_api_router, db_write_multiple, S,
+    create_open_api_router, db_write, db_write_multiple, S,
 };
 use model_profile::AccountId;
+use server_data::write::common_history::AdminAction;
 use server_data_profile::{read::GetReadProfileCommands, write::GetWriteCommandsProfile};
 use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_PROFILE_NAME_PENDING_MODERATION_LIST: &str =
     "/profile_api/admin/profile_name_pending_moderation";
 
+/// The returned names are claimed for the moderator, so other moderators
+/// do not get the same names until the claim lease expires. Names
+/// which other moderators have claimed are not returned.
 #[utoipa::path(
     get,
     path = PATH_GET_PROFILE_NAME_PENDING_MODERATION_LIST,
@@ -33,20 +38,15 @@ const PATH_GET_PROFILE_NAME_PENDING_MODERATION_LIST: &str =
 )]
 pub async fn get_profile_name_pending_moderation_list(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    Extension(moderator_id): Extension<AccountIdInternal>,
+    _: RequirePermission<permission::admin_moderate_profile_names>,
 ) -> Result<Json<GetProfileNamePendingModerationList>, StatusCode> {
     PROFILE.get_profile_name_pending_moderation_list.incr();
 
-    if !permissions.admin_moderate_profile_names {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
-    let r = state
-        .read()
+    let r = db_write!(state, move |cmds| cmds
         .profile_admin()
         .profile_name_allowlist()
-        .profile_name_pending_moderation_list()
-        .await?;
+        .claim_profile_name_pending_moderation_list(moderator_id))?;
 
     Ok(r.into())
 }
@@ -69,22 +69,19 @@ const PATH_POST_MODERATE_PROFILE_NAME: &str = "/profile_api/admin/moderate_profi
 )]
 pub async fn post_moderate_profile_name(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_moderate_profile_names>,
     Extension(moderator_id): Extension<AccountIdInternal>,
     Json(data): Json<PostModerateProfileName>,
 ) -> Result<(), StatusCode> {
     PROFILE.post_moderate_profile_name.incr();
 
-    if !permissions.admin_moderate_profile_names {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let name_owner_id = state.get_internal_id(data.id).await?;
+    let admin_action = AdminAction::new(moderator_id, AdminActionType::ModerateProfileName, Some(name_owner_id), &data)?;
 
     db_write_multiple!(state, move |cmds| {
         cmds.profile_admin()
             .profile_name_allowlist()
-            .moderate_profile_name(moderator_id, name_owner_id, data.name, data.accept)
+            .moderate_profile_name(moderator_id, name_owner_id, data.name, data.accept, Some(admin_action))
             .await?;
 
         cmds.events()
@@ -119,15 +116,11 @@ const PATH_GET_PROFILE_NAME_STATE: &str = "/profile_api/get_profile_name_state/{
 )]
 pub async fn get_profile_name_state(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_moderate_profile_names>,
     Path(account_id): Path<AccountId>,
 ) -> Result<Json<GetProfileNameState>, StatusCode> {
     PROFILE.get_profile_name_state.incr();
 
-    if !permissions.admin_moderate_profile_names {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let name_owner_id = state.get_internal_id(account_id).await?;
 
     let r = state.read().profile().my_profile(name_owner_id).await?;
@@ -2,26 +2,31 @@ use axum::{
     extract::{Path, Query, State},
     Extension,
 };
-use model::AccountId;
+use model::{permission, AccountId};
 use model_profile::{
-    AccountIdInternal, EventToClientInternal, GetProfileTextPendingModerationList, GetProfileTextPendingModerationParams, GetProfileTextState, Permissions, PostModerateProfileText
+    AccountIdInternal, AdminActionType, EventToClientInternal, GetProfileTextPendingModerationList, GetProfileTextPendingModerationParams, GetProfileTextState, PostModerateProfileText
 };
 use server_api::{
     app::{GetAccounts, WriteData},
-    create_open_api_router, db_write_multiple, S,
+    create_open_api_router, db_write, db_write_multiple, S,
 };
+use server_data::write::common_history::AdminAction;
 use server_data_profile::{read::GetReadProfileCommands, write::{profile_admin::profile_text::ModerateProfileTextMode, GetWriteCommandsProfile}};
 use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_PROFILE_TEXT_PENDING_MODERATION_LIST: &str =
     "/profile_api/admin/profile_text_pending_moderation";
 
 /// Get first page of pending profile text moderations. Oldest item is first and count 25.
+///
+/// The returned texts are claimed for the moderator, so other moderators
+/// do not get the same texts until the claim lease expires. Texts
+/// which other moderators have claimed are not returned.
 #[utoipa::path(
     get,
     path = PATH_GET_PROFILE_TEXT_PENDING_MODERATION_LIST,
@@ -39,21 +44,15 @@ const PATH_GET_PROFILE_TEXT_PENDING_MODERATION_LIST: &str =
 pub async fn get_profile_text_pending_moderation_list(
     State(state): State<S>,
     Extension(moderator_id): Extension<AccountIdInternal>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_moderate_profile_texts>,
     Query(params): Query<GetProfileTextPendingModerationParams>,
 ) -> Result<Json<GetProfileTextPendingModerationList>, StatusCode> {
     PROFILE.get_profile_text_pending_moderation_list.incr();
 
-    if !permissions.admin_moderate_profile_texts {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
-    let r = state
-        .read()
+    let r = db_write!(state, move |cmds| cmds
         .profile_admin()
         .profile_text()
-        .profile_text_pending_moderation_list(moderator_id, params)
-        .await?;
+        .claim_profile_text_pending_moderation_list(moderator_id, params))?;
 
     Ok(r.into())
 }
@@ -80,21 +79,18 @@ const PATH_POST_MODERATE_PROFILE_TEXT: &str = "/profile_api/admin/moderate_profi
 )]
 pub async fn post_moderate_profile_text(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_moderate_profile_texts>,
     Extension(moderator_id): Extension<AccountIdInternal>,
     Json(data): Json<PostModerateProfileText>,
 ) -> Result<(), StatusCode> {
     PROFILE.post_moderate_profile_text.incr();
 
-    if !permissions.admin_moderate_profile_texts {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     if data.accept && (data.rejected_category.is_some() || data.rejected_details.is_some()) {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
     let text_owner_id = state.get_internal_id(data.id).await?;
+    let admin_action = AdminAction::new(moderator_id, AdminActionType::ModerateProfileText, Some(text_owner_id), &data)?;
 
     let mode = if data.move_to_human.unwrap_or_default() {
         ModerateProfileTextMode::MoveToHumanModeration
@@ -114,6 +110,7 @@ pub async fn post_moderate_profile_text(
                 mode,
                 text_owner_id,
                 data.text,
+                Some(admin_action),
             )
             .await?;
 
@@ -149,15 +146,11 @@ const PATH_GET_PROFILE_TEXT_STATE: &str = "/profile_api/get_profile_text_state/{
 )]
 pub async fn get_profile_text_state(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_moderate_profile_texts>,
     Path(account_id): Path<AccountId>,
 ) -> Result<Json<GetProfileTextState>, StatusCode> {
     PROFILE.get_profile_text_state.incr();
 
-    if !permissions.admin_moderate_profile_texts {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let text_owner_id = state.get_internal_id(account_id).await?;
 
     let r = state.read().profile().my_profile(text_owner_id).await?;
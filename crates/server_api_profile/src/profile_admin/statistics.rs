@@ -1,10 +1,7 @@
-use axum::{
-    extract::{Query, State},
-    Extension,
-};
+use axum::extract::{Query, State};
+use model::permission;
 use model_profile::{
-    GetProfileStatisticsHistoryParams, GetProfileStatisticsHistoryResult, Permissions,
-    ProfileStatisticsHistoryValueTypeInternal,
+    GetProfileStatisticsHistoryParams, GetProfileStatisticsHistoryResult, ProfileStatisticsHistoryValueTypeInternal,
 };
 use server_api::{create_open_api_router, S};
 use server_data_profile::read::GetReadProfileCommands;
@@ -12,7 +9,7 @@ use simple_backend::create_counters;
 
 use crate::{
     app::ReadData,
-    utils::{Json, StatusCode},
+    utils::{Json, RequirePermission, StatusCode},
 };
 
 const PATH_GET_PROFILE_STATISTICS_HISTORY: &str = "/profile_api/profile_statistics_history";
@@ -33,15 +30,11 @@ const PATH_GET_PROFILE_STATISTICS_HISTORY: &str = "/profile_api/profile_statisti
 )]
 pub async fn get_profile_statistics_history(
     State(state): State<S>,
-    Extension(permissions): Extension<Permissions>,
+    _: RequirePermission<permission::admin_profile_statistics>,
     Query(params): Query<GetProfileStatisticsHistoryParams>,
 ) -> Result<Json<GetProfileStatisticsHistoryResult>, StatusCode> {
     PROFILE.get_profile_statistics_history.incr();
 
-    if !permissions.admin_profile_statistics {
-        return Err(StatusCode::INTERNAL_SERVER_ERROR);
-    }
-
     let p: ProfileStatisticsHistoryValueTypeInternal = params
         .try_into()
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
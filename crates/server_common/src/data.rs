@@ -54,6 +54,8 @@ pub enum DataError {
     MissingValue,
     #[error("Server closing in progress")]
     ServerClosingInProgress,
+    #[error("Serializing error")]
+    Serialize,
 
     #[error("Command runner quit too early")]
     CommandRunnerQuit,
@@ -5,7 +5,7 @@ use config::{file::EmailAddress, Config};
 use futures::future::BoxFuture;
 use model::{
     Account, AccountId, AccountIdInternal, PendingNotification, PendingNotificationWithData,
-    SyncDataVersionFromClient,
+    ProcessReport, ProfileVerificationContent, SyncDataVersionFromClient,
 };
 use model_server_data::SignInWithInfo;
 pub use server_common::app::*;
@@ -16,6 +16,7 @@ use crate::{
     db_manager::{InternalWriting, RouterDatabaseReadHandle},
     event::EventManagerWithCacheReference,
     statistics::ProfileStatisticsCache,
+    write::common_history::AdminAction,
     write_commands::{WriteCmds, WriteCommandRunnerHandle},
     write_concurrent::{
         ConcurrentWriteAction, ConcurrentWriteProfileHandleBlocking, ConcurrentWriteSelectorHandle,
@@ -108,6 +109,15 @@ pub trait DataAllUtils: Send + Sync + 'static {
         unlimited_likes: bool,
     ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
 
+    fn update_profile_verified<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        id: AccountIdInternal,
+        profile_verified: bool,
+        verified_content: Option<ProfileVerificationContent>,
+        admin_action: Option<AdminAction>,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
+
     fn register_impl<'a>(
         &self,
         write_command_runner: &'a WriteCommandRunnerHandle,
@@ -115,6 +125,16 @@ pub trait DataAllUtils: Send + Sync + 'static {
         email: Option<EmailAddress>,
     ) -> BoxFuture<'a, server_common::result::Result<AccountIdInternal, DataError>>;
 
+    fn process_report<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        moderator_id: AccountIdInternal,
+        creator: AccountIdInternal,
+        target: AccountIdInternal,
+        data: ProcessReport,
+        admin_action: AdminAction,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>>;
+
     #[allow(clippy::too_many_arguments)]
     fn handle_new_websocket_connection<'a>(
         &self,
@@ -209,7 +209,7 @@ impl DatabaseCache {
             {
                 Some((
                     entry.account_id_internal,
-                    r.common.permissions.clone(),
+                    r.common.current_permissions(UnixTime::current_time()),
                     r.common
                         .account_state_related_shared_state
                         .state_container()
@@ -512,9 +512,51 @@ pub struct ConnectionInfo {
     pub event_sender: EventSender,
 }
 
+/// Permissions granted with admin roles which have an expiration time.
+///
+/// Expired roles are removed from the database with hourly tasks, so
+/// this is used for removing the expired permissions before that.
+#[derive(Debug, Default)]
+pub struct ExpiringPermissions {
+    /// Individual permissions and permissions of roles which
+    /// do not expire.
+    pub permanent: Permissions,
+    pub expiring: Vec<(Permissions, UnixTime)>,
+}
+
+impl ExpiringPermissions {
+    pub fn new(individual: Permissions, roles: Vec<(Permissions, Option<UnixTime>)>) -> Self {
+        let mut permanent = individual;
+        let mut expiring = vec![];
+        for (permissions, expires) in roles {
+            if let Some(expires) = expires {
+                expiring.push((permissions, expires));
+            } else {
+                permanent.merge(&permissions);
+            }
+        }
+        Self {
+            permanent,
+            expiring,
+        }
+    }
+
+    /// Permissions which are granted at `time`.
+    pub fn permissions_at(&self, time: UnixTime) -> Permissions {
+        let mut permissions = self.permanent.clone();
+        for (role, expires) in &self.expiring {
+            if time.ut < expires.ut {
+                permissions.merge(role);
+            }
+        }
+        permissions
+    }
+}
+
 #[derive(Debug)]
 pub struct CacheEntryCommon {
     pub permissions: Permissions,
+    pub expiring_permissions: ExpiringPermissions,
     pub account_state_related_shared_state: AccountStateRelatedSharedState,
     pub other_shared_state: OtherSharedState,
     pub current_connection: Option<ConnectionInfo>,
@@ -525,6 +567,21 @@ pub struct CacheEntryCommon {
 }
 
 impl CacheEntryCommon {
+    /// Returns [Self::permissions] without permissions from admin
+    /// roles which have expired at `time`.
+    pub fn current_permissions(&self, time: UnixTime) -> Permissions {
+        let expired = self
+            .expiring_permissions
+            .expiring
+            .iter()
+            .any(|(_, expires)| time.ut >= expires.ut);
+        if expired {
+            self.expiring_permissions.permissions_at(time)
+        } else {
+            self.permissions.clone()
+        }
+    }
+
     pub fn connection_event_sender(&self) -> Option<&EventSender> {
         self.current_connection
             .as_ref()
@@ -550,6 +607,7 @@ impl CacheEntry {
             chat: None,
             common: CacheEntryCommon {
                 permissions: Permissions::default(),
+                expiring_permissions: ExpiringPermissions::default(),
                 account_state_related_shared_state: AccountStateRelatedSharedState::default(),
                 other_shared_state: OtherSharedState::default(),
                 current_connection: None,
@@ -627,6 +685,8 @@ impl CacheEntry {
             profile.attributes.clone(),
             self.media.as_ref().map(|m| m.profile_content_version),
             self.common.other_shared_state.unlimited_likes,
+            self.common.other_shared_state.profile_verified,
+            self.common.other_shared_state.profile_hidden_pending_review,
             profile.last_seen_time(&self.common),
             self.common.other_shared_state.initial_setup_completed_unix_time,
             self.media.as_ref().map(|m| m.profile_content_edited_time),
@@ -639,3 +699,48 @@ impl Default for CacheEntry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use model::Permissions;
+    use simple_backend_model::UnixTime;
+
+    use super::{CacheEntry, ExpiringPermissions};
+
+    fn entry_with_expiring_role(expires: i64) -> CacheEntry {
+        let individual = Permissions {
+            admin_news_create: true,
+            ..Permissions::default()
+        };
+        let role = Permissions {
+            admin_view_audit_log: true,
+            ..Permissions::default()
+        };
+        let expiring_permissions =
+            ExpiringPermissions::new(individual, vec![(role, Some(UnixTime::new(expires)))]);
+        let mut entry = CacheEntry::new();
+        entry.common.permissions = Permissions {
+            admin_news_create: true,
+            admin_view_audit_log: true,
+            ..Permissions::default()
+        };
+        entry.common.expiring_permissions = expiring_permissions;
+        entry
+    }
+
+    #[test]
+    fn role_permissions_are_granted_before_expiration() {
+        let entry = entry_with_expiring_role(10);
+        let p = entry.common.current_permissions(UnixTime::new(9));
+        assert!(p.admin_news_create);
+        assert!(p.admin_view_audit_log);
+    }
+
+    #[test]
+    fn role_permissions_are_removed_after_expiration() {
+        let entry = entry_with_expiring_role(10);
+        let p = entry.common.current_permissions(UnixTime::new(10));
+        assert!(p.admin_news_create);
+        assert!(!p.admin_view_audit_log);
+    }
+}
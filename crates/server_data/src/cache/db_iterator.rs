@@ -1,7 +1,7 @@
 use model::{MatchId, NextNumberStorage, ReceivedLikeId};
 use model_server_data::{
-    MatchesIteratorSessionIdInternal, NewsIteratorSessionIdInternal, PublicationId,
-    ReceivedLikesIteratorSessionIdInternal,
+    MatchesIteratorSessionIdInternal, NewsIteratorSessionIdInternal, ProfileVisitId,
+    ProfileVisitorsIteratorSessionIdInternal, PublicationId, ReceivedLikesIteratorSessionIdInternal,
 };
 
 pub mod new_count;
@@ -114,6 +114,16 @@ impl IteratorSessionIdTrait for NewsIteratorSessionIdInternal {
 
 impl IteratorStartPoint for PublicationId {}
 
+// Profile
+
+impl IteratorSessionIdTrait for ProfileVisitorsIteratorSessionIdInternal {
+    fn create(storage: &mut NextNumberStorage) -> Self {
+        ProfileVisitorsIteratorSessionIdInternal::create(storage)
+    }
+}
+
+impl IteratorStartPoint for ProfileVisitId {}
+
 // Chat
 
 impl IteratorSessionIdTrait for ReceivedLikesIteratorSessionIdInternal {
@@ -1,13 +1,17 @@
+use std::collections::{HashMap, HashSet};
+
 use config::Config;
 use error_stack::{Result, ResultExt};
 use model::{AccountId, AccountIdInternal, NextNumberStorage, UnixTime};
 use model_server_data::{
     LastSeenTime, ProfileAttributeFilterValue, ProfileAttributeValue,
-    ProfileInternal, ProfileIteratorSessionIdInternal, ProfileQueryMakerDetails,
-    ProfileStateCached, SortedProfileAttributes,
+    ProfileInternal, ProfileIteratorSessionIdInternal, ProfileLink, ProfileQueryMakerDetails,
+    ProfileStateCached, ProfileVisitId, ProfileVisitorsIteratorSessionIdInternal,
+    RecordedProfileVisits, SortedProfileAttributes,
 };
 use server_common::data::{cache::CacheError, DataError};
 
+use super::db_iterator::DbIterator;
 use crate::{
     cache::CacheEntryCommon, db_manager::InternalWriting,
     index::{area::LocationIndexArea, location::LocationIndexIteratorState},
@@ -24,6 +28,20 @@ pub struct CachedProfile {
     pub last_seen_time: Option<UnixTime>,
     pub profile_iterator_session_id: Option<ProfileIteratorSessionIdInternal>,
     pub profile_iterator_session_id_storage: NextNumberStorage,
+    /// Remaining profiles for current profile iterator session if
+    /// mutual interest profile order is enabled. The profiles are in
+    /// reverse order.
+    pub mutual_interest_ranked_profiles: Option<Vec<ProfileLink>>,
+    pub profile_visitors_iterator:
+        DbIterator<ProfileVisitorsIteratorSessionIdInternal, ProfileVisitId>,
+    /// Profiles which this account has visited during the current day.
+    pub recorded_profile_visits: RecordedProfileVisits,
+    /// Profile iterator never returns passed profiles.
+    pub passed_profiles: HashSet<AccountId>,
+    /// Profiles which profile iterator has returned when hiding seen
+    /// profiles is enabled. The value is the time when the profile is
+    /// not considered seen anymore.
+    pub seen_profiles: HashMap<AccountId, UnixTime>,
 }
 
 impl CachedProfile {
@@ -35,6 +53,8 @@ impl CachedProfile {
         filters: Vec<ProfileAttributeFilterValue>,
         config: &Config,
         last_seen_time: Option<UnixTime>,
+        passed_profiles: HashSet<AccountId>,
+        seen_profiles: HashMap<AccountId, UnixTime>,
     ) -> Self {
         Self {
             account_id,
@@ -49,9 +69,37 @@ impl CachedProfile {
             last_seen_time,
             profile_iterator_session_id: None,
             profile_iterator_session_id_storage: NextNumberStorage::default(),
+            mutual_interest_ranked_profiles: None,
+            profile_visitors_iterator: DbIterator::default(),
+            recorded_profile_visits: RecordedProfileVisits::default(),
+            passed_profiles,
+            seen_profiles,
+        }
+    }
+
+    pub fn is_hidden_from_profile_iterator(&self, id: AccountId, current_time: UnixTime) -> bool {
+        if self.passed_profiles.contains(&id) {
+            return true;
+        }
+
+        self.state.hide_seen_profiles
+            && self
+                .seen_profiles
+                .get(&id)
+                .is_some_and(|expiration_time| expiration_time.ut > current_time.ut)
+    }
+
+    pub fn mark_profiles_seen(&mut self, profiles: &[AccountId], expiration_time: UnixTime) {
+        for p in profiles {
+            self.seen_profiles.insert(*p, expiration_time);
         }
     }
 
+    pub fn remove_expired_seen_profiles(&mut self, current_time: UnixTime) {
+        self.seen_profiles
+            .retain(|_, expiration_time| expiration_time.ut > current_time.ut);
+    }
+
     pub fn filters(&self) -> ProfileQueryMakerDetails {
         ProfileQueryMakerDetails::new(&self.data, &self.state, self.filters.clone())
     }
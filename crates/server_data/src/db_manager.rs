@@ -201,6 +201,7 @@ impl RouterDatabaseWriteHandle {
             &self.read.cache,
             &self.read.root.file_dir,
             LocationIndexIteratorHandle::new(&self.location),
+            &self.config,
         )
     }
 
@@ -10,7 +10,7 @@ use config::{file::LocationConfig, Config};
 use error_stack::ResultExt;
 use model::{AccountId, UnixTime};
 use model_server_data::{
-    CellData, Location, LocationIndexKey, LocationIndexProfileData, LocationInternal, MaxDistanceKm, ProfileLink, ProfileQueryMakerDetails
+    CellData, Location, LocationIndexKey, LocationIndexProfileData, LocationInternal, MaxDistanceKm, MutualInterestRankingInfo, ProfileLink, ProfileQueryMakerDetails
 };
 use server_common::data::index::IndexError;
 use tokio::sync::RwLock;
@@ -157,6 +157,8 @@ fn format_size_in_bytes(size: usize) -> String {
     format!("{:.2} {}", size, unit)
 }
 
+const MUTUAL_INTEREST_RANKING_MAX_PROFILES: usize = 1000;
+
 enum IteratorResultInternal {
     NoProfiles,
     TryAgain,
@@ -258,6 +260,47 @@ impl<'a> LocationIndexIteratorHandle<'a> {
     ) -> LocationIndexIteratorState {
         LocationIndexIteratorState::new(area, random, &self.index)
     }
+
+    /// Get matching profiles from the area in mutual interest score order.
+    ///
+    /// Only [MUTUAL_INTEREST_RANKING_MAX_PROFILES] nearest matching profiles
+    /// are ranked. Profiles with equal score are in location order.
+    pub fn mutual_interest_ranked_profiles(
+        &self,
+        area: &LocationIndexArea,
+        query_maker_details: &ProfileQueryMakerDetails,
+        ranking_info: &MutualInterestRankingInfo,
+    ) -> Vec<ProfileLink> {
+        let current_time = UnixTime::current_time();
+        let attribute_info = self.config.profile_attributes();
+        let iterator = self
+            .new_iterator_state(area, false)
+            .into_iterator(self.index.clone());
+        let mut scored_profiles: Vec<(u32, ProfileLink)> = vec![];
+
+        for key in iterator {
+            if scored_profiles.len() >= MUTUAL_INTEREST_RANKING_MAX_PROFILES {
+                break;
+            }
+
+            if let Some(profiles) = self.profiles.blocking_read().get(&key) {
+                let matches = profiles
+                    .profiles
+                    .values()
+                    .filter(|p| p.is_match(query_maker_details, attribute_info, &current_time))
+                    .map(|p| {
+                        (
+                            p.mutual_interest_score(ranking_info, attribute_info, &current_time),
+                            p.to_profile_link_value(),
+                        )
+                    });
+                scored_profiles.extend(matches);
+            }
+        }
+
+        scored_profiles.sort_by(|a, b| b.0.cmp(&a.0));
+        scored_profiles.into_iter().map(|(_, p)| p).collect()
+    }
 }
 
 #[derive(Debug)]
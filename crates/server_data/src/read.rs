@@ -49,3 +49,33 @@ impl<I: InternalReading> DbRead for I {
         self.db_read_raw(cmd).await
     }
 }
+
+pub trait DbReadHistory {
+    async fn db_read_history<
+        T: FnOnce(
+                database::DbReadModeHistory<'_>,
+            ) -> error_stack::Result<R, database::DieselDatabaseError>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    >(
+        &self,
+        cmd: T,
+    ) -> error_stack::Result<R, database::DieselDatabaseError>;
+}
+
+impl<I: InternalReading> DbReadHistory for I {
+    async fn db_read_history<
+        T: FnOnce(
+                database::DbReadModeHistory<'_>,
+            ) -> error_stack::Result<R, database::DieselDatabaseError>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    >(
+        &self,
+        cmd: T,
+    ) -> error_stack::Result<R, database::DieselDatabaseError> {
+        self.db_read_history_raw(cmd).await
+    }
+}
@@ -1,10 +1,20 @@
 use crate::define_cmd_wrapper_read;
 
+mod audit_log;
+mod moderation_claim;
 mod report;
 
 define_cmd_wrapper_read!(ReadCommandsCommonAdmin);
 
 impl<'a> ReadCommandsCommonAdmin<'a> {
+    pub fn audit_log(self) -> audit_log::ReadCommandsCommonAdminAuditLog<'a> {
+        audit_log::ReadCommandsCommonAdminAuditLog::new(self.0)
+    }
+
+    pub fn moderation_claim(self) -> moderation_claim::ReadCommandsCommonAdminModerationClaim<'a> {
+        moderation_claim::ReadCommandsCommonAdminModerationClaim::new(self.0)
+    }
+
     pub fn report(self) -> report::ReadCommandsCommonAdminReport<'a> {
         report::ReadCommandsCommonAdminReport::new(self.0)
     }
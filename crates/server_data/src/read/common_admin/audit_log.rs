@@ -0,0 +1,20 @@
+use database::history::read::GetDbHistoryReadCommandsCommon;
+use model::{AdminActionIteratorQuery, GetAdminActionList};
+
+use crate::{define_cmd_wrapper_read, read::DbReadHistory, result::Result, DataError, IntoDataError};
+
+define_cmd_wrapper_read!(ReadCommandsCommonAdminAuditLog);
+
+impl ReadCommandsCommonAdminAuditLog<'_> {
+    pub async fn admin_action_iterator_page(
+        &self,
+        query: AdminActionIteratorQuery,
+    ) -> Result<GetAdminActionList, DataError> {
+        self.db_read_history(move |mut cmds| {
+            cmds.common_admin_history()
+                .admin_action_iterator_page(query)
+        })
+        .await
+        .into_error()
+    }
+}
@@ -0,0 +1,21 @@
+use database::current::read::GetDbReadCommandsCommon;
+use model::{GetModeratorThroughputList, ModeratorThroughputQuery};
+
+use crate::{define_cmd_wrapper_read, read::DbRead, result::Result, DataError, IntoDataError};
+
+define_cmd_wrapper_read!(ReadCommandsCommonAdminModerationClaim);
+
+impl ReadCommandsCommonAdminModerationClaim<'_> {
+    pub async fn moderator_throughput(
+        &self,
+        query: ModeratorThroughputQuery,
+    ) -> Result<GetModeratorThroughputList, DataError> {
+        self.db_read(move |mut cmds| {
+            cmds.common_admin()
+                .moderation_claim()
+                .moderator_throughput(query)
+        })
+        .await
+        .into_error()
+    }
+}
@@ -9,19 +9,6 @@ use crate::{
 define_cmd_wrapper_read!(ReadCommandsCommonAdminReport);
 
 impl ReadCommandsCommonAdminReport<'_> {
-    pub async fn get_waiting_report_list(
-        &self,
-    ) -> Result<GetReportList, DataError> {
-        let components = self.config().components();
-        self.db_read(move |mut cmds| {
-            cmds.common_admin()
-                .report()
-                .get_reports_page(components)
-        })
-        .await
-        .into_error()
-    }
-
     pub async fn get_report_iterator_page(
         &self,
         query: ReportIteratorQueryInternal,
@@ -147,3 +147,55 @@ impl<I: InternalWriting> DbTransactionHistory for I {
         self.db_transaction_history_raw(cmd).await
     }
 }
+
+pub trait DbTransactionWithAdminAction {
+    /// Write to current database with transaction and save the admin
+    /// action to the audit log. The current database transaction is
+    /// rolled back if saving the admin action fails.
+    ///
+    /// Write commands which have `admin_action` parameter use this, so
+    /// the admin action is saved to the audit log in the same
+    /// transaction as the database modifications of the command.
+    async fn db_transaction_with_admin_action<
+        T: FnOnce(
+                database::DbWriteMode<'_>,
+            ) -> error_stack::Result<R, database::DieselDatabaseError>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    >(
+        &self,
+        admin_action: Option<common_history::AdminAction>,
+        cmd: T,
+    ) -> crate::result::Result<R, crate::DataError>;
+}
+
+impl<I: InternalWriting> DbTransactionWithAdminAction for I {
+    async fn db_transaction_with_admin_action<
+        T: FnOnce(
+                database::DbWriteMode<'_>,
+            ) -> error_stack::Result<R, database::DieselDatabaseError>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    >(
+        &self,
+        admin_action: Option<common_history::AdminAction>,
+        cmd: T,
+    ) -> crate::result::Result<R, crate::DataError> {
+        let r = self
+            .db_transaction_with_history(move |conn, mut history| {
+                let mut conn = conn.into_conn();
+                let r = cmd(database::DbWriteMode(&mut *conn.0))?;
+                if let Some(mut admin_action) = admin_action {
+                    if let Some(report) = admin_action.processed_report.take() {
+                        report.save(&mut conn)?;
+                    }
+                    admin_action.save(&mut history)?;
+                }
+                Ok(r)
+            })
+            .await;
+        crate::IntoDataError::into_error(r)
+    }
+}
@@ -1,10 +1,15 @@
 use crate::define_cmd_wrapper_write;
 
-mod report;
+mod moderation_claim;
+pub mod report;
 
 define_cmd_wrapper_write!(WriteCommandsCommonAdmin);
 
 impl<'a> WriteCommandsCommonAdmin<'a> {
+    pub fn moderation_claim(self) -> moderation_claim::WriteCommandsCommonAdminModerationClaim<'a> {
+        moderation_claim::WriteCommandsCommonAdminModerationClaim::new(self.0)
+    }
+
     pub fn report(self) -> report::WriteCommandsCommonAdminReport<'a> {
         report::WriteCommandsCommonAdminReport::new(self.0)
     }
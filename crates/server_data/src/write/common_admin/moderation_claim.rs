@@ -0,0 +1,31 @@
+use database::current::write::GetDbWriteCommandsCommon;
+use model::UnixTime;
+
+use crate::{
+    app::GetConfig, define_cmd_wrapper_write, result::Result, write::db_transaction, DataError,
+};
+
+use crate::write::DbTransaction;
+
+define_cmd_wrapper_write!(WriteCommandsCommonAdminModerationClaim);
+
+impl WriteCommandsCommonAdminModerationClaim<'_> {
+    /// Delete expired claims and completed claims which are older than
+    /// the configured retention duration.
+    pub async fn delete_old_claims(&self) -> Result<(), DataError> {
+        let retention = self
+            .config()
+            .limits_common()
+            .moderation_claim
+            .completed_claim_retention_duration;
+        let completed_before = UnixTime::current_time().subtract_seconds(retention.seconds);
+
+        db_transaction!(self, move |mut cmds| {
+            cmds.common_admin()
+                .moderation_claim()
+                .delete_old_claims(completed_before)
+        })?;
+
+        Ok(())
+    }
+}
@@ -1,41 +1,208 @@
 
-use database::current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon};
-use model::{AccountIdInternal, ReportContent, ReportTypeNumber};
+use database::{
+    current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon},
+    DbWriteMode, DieselDatabaseError,
+};
+use model::{AccountIdInternal, GetReportList, ModerationClaimQueue, ReportContent, ReportIdDb, ReportOutcome, ReportProcessingState, ReportTypeNumber, UnixTime};
 
 use crate::{
-    app::GetConfig, define_cmd_wrapper_write, read::DbRead, result::{Result, WrappedContextExt}, write::db_transaction, DataError
+    app::GetConfig, cache::{profile::UpdateLocationCacheState, CacheReadCommon, CacheWriteCommon}, define_cmd_wrapper_write, read::DbRead, result::{Result, WrappedContextExt}, write::db_transaction, DataError, IntoDataError
 };
 
-use crate::write::DbTransaction;
+use crate::write::{common_history::AdminAction, DbTransaction, DbTransactionWithAdminAction};
+
+/// Report processing which is saved to the database with
+/// [AdminAction::with_processed_report].
+#[derive(Debug)]
+pub struct ProcessedReport {
+    pub moderator_id: AccountIdInternal,
+    pub report_id: ReportIdDb,
+    pub outcome: ReportOutcome,
+    pub moderator_note: Option<String>,
+}
+
+impl ProcessedReport {
+    pub(crate) fn save(
+        self,
+        cmds: &mut DbWriteMode<'_>,
+    ) -> error_stack::Result<(), DieselDatabaseError> {
+        cmds.common_admin().report().mark_report_done(
+            self.moderator_id,
+            self.report_id,
+            self.outcome,
+            self.moderator_note,
+        )?;
+        cmds.common_admin().moderation_claim().complete_item(
+            self.moderator_id,
+            ModerationClaimQueue::Report,
+            *self.report_id.as_i64(),
+        )
+    }
+}
 
 define_cmd_wrapper_write!(WriteCommandsCommonAdminReport);
 
 impl WriteCommandsCommonAdminReport<'_> {
-    pub async fn process_report(
+    /// Get waiting reports and claim the reports for the moderator.
+    pub async fn claim_waiting_report_list(
         &self,
         moderator_id: AccountIdInternal,
+    ) -> Result<GetReportList, DataError> {
+        let components = self.config().components();
+        let lease = self.config().limits_common().moderation_claim.lease_duration;
+        db_transaction!(self, move |mut cmds| {
+            let (list, report_ids) = cmds
+                .read()
+                .common_admin()
+                .report()
+                .get_reports_page(moderator_id, components)?;
+            cmds.common_admin().moderation_claim().claim_items(
+                moderator_id,
+                ModerationClaimQueue::Report,
+                report_ids.iter().map(|v| *v.as_i64()).collect(),
+                UnixTime::current_time().add_seconds(lease.seconds),
+            )?;
+            Ok(list)
+        })
+    }
+
+    /// Get waiting ban appeals and claim the ban appeals for the moderator.
+    pub async fn claim_waiting_ban_appeal_list(
+        &self,
+        moderator_id: AccountIdInternal,
+    ) -> Result<GetReportList, DataError> {
+        let components = self.config().components();
+        let lease = self.config().limits_common().moderation_claim.lease_duration;
+        db_transaction!(self, move |mut cmds| {
+            let (list, report_ids) = cmds
+                .read()
+                .common_admin()
+                .report()
+                .get_ban_appeals_page(moderator_id, components)?;
+            cmds.common_admin().moderation_claim().claim_items(
+                moderator_id,
+                ModerationClaimQueue::BanAppeal,
+                report_ids.iter().map(|v| *v.as_i64()).collect(),
+                UnixTime::current_time().add_seconds(lease.seconds),
+            )?;
+            Ok(list)
+        })
+    }
+
+    /// Find the waiting report which matches the report content.
+    pub async fn report_to_process(
+        &self,
         creator: AccountIdInternal,
         target: AccountIdInternal,
         report_type: ReportTypeNumber,
         content: ReportContent,
-    ) -> Result<(), DataError> {
+    ) -> Result<ReportIdDb, DataError> {
         let components = self.config().components();
         let current_reports = self
             .db_read(move |mut cmds| cmds.common().report().get_all_detailed_reports(creator, target, report_type, components))
             .await?;
 
-        let matching_report = current_reports.iter().find(|v| v.report.content == content);
+        let matching_report = current_reports
+            .iter()
+            .find(|v| v.report.content == content && v.report.info.processing_state == ReportProcessingState::Waiting);
         if let Some(report) = matching_report {
-            let id = report.id;
-            db_transaction!(self, move |mut cmds| {
-                cmds.common_admin()
-                    .report()
-                    .mark_report_done(moderator_id, id)?;
-                Ok(())
-            })?;
-            Ok(())
+            Ok(report.id)
         } else {
             Err(DataError::NotAllowed.report())
         }
     }
+
+    /// Mark report processed without running any moderation action.
+    /// Use [AdminAction::with_processed_report] to set the report.
+    pub async fn save_processed_report(&self, admin_action: AdminAction) -> Result<(), DataError> {
+        self.db_transaction_with_admin_action(Some(admin_action), |_| Ok(()))
+            .await
+    }
+
+    /// Start automatic report triage if the target has enough
+    /// distinct reporters for the report type.
+    pub async fn automatic_report_triage(
+        &self,
+        target: AccountIdInternal,
+        report_type: ReportTypeNumber,
+    ) -> Result<(), DataError> {
+        let Some(config) = self.config().limits_common().report_triage else {
+            return Ok(());
+        };
+
+        let reports_created_after = UnixTime::current_time()
+            .subtract_seconds(config.window_duration.seconds);
+        let reporters = self
+            .db_read(move |mut cmds| cmds.common().report().distinct_reporter_count(target, report_type, reports_created_after))
+            .await?;
+        if reporters < config.distinct_reporters.get().into() {
+            return Ok(());
+        }
+
+        db_transaction!(self, move |mut cmds| {
+            cmds.common().report().prioritize_waiting_reports(target, report_type)
+        })?;
+
+        self.update_profile_hidden_pending_review(target, true).await
+    }
+
+    /// Show the profile again if automatic report triage has hidden it
+    /// and there are no prioritized waiting reports. Reports can also
+    /// disappear without processing (for example when the reporter account
+    /// is deleted), so this is also run from scheduled tasks.
+    pub async fn end_report_triage_if_reviewed(
+        &self,
+        target: AccountIdInternal,
+    ) -> Result<(), DataError> {
+        let hidden = self
+            .read_cache_common(target, |e| Ok(e.other_shared_state.profile_hidden_pending_review))
+            .await
+            .into_data_error(target)?;
+        if !hidden {
+            return Ok(());
+        }
+
+        let waiting = self
+            .db_read(move |mut cmds| cmds.common().report().prioritized_waiting_reports_exist(target))
+            .await?;
+        if waiting {
+            return Ok(());
+        }
+
+        self.update_profile_hidden_pending_review(target, false).await
+    }
+
+    /// Does nothing if the value is not changed.
+    pub async fn update_profile_hidden_pending_review(
+        &self,
+        id: AccountIdInternal,
+        value: bool,
+    ) -> Result<(), DataError> {
+        let current_value = self
+            .read_cache_common(id, |e| Ok(e.other_shared_state.profile_hidden_pending_review))
+            .await
+            .into_data_error(id)?;
+        if current_value == value {
+            return Ok(());
+        }
+
+        db_transaction!(self, move |mut cmds| {
+            cmds.common()
+                .state()
+                .update_profile_hidden_pending_review(id, value)
+        })?;
+
+        self.write_cache_common(id, |e| {
+            e.other_shared_state.profile_hidden_pending_review = value;
+            Ok(())
+        })
+        .await
+        .into_data_error(id)?;
+
+        if self.config().components().profile {
+            self.update_location_cache_profile(id).await?;
+        }
+
+        Ok(())
+    }
 }
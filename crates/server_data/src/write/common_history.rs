@@ -1,16 +1,82 @@
-
 use std::collections::HashMap;
 
-use database::history::write::GetDbHistoryWriteCommandsCommon;
+use database::{history::write::GetDbHistoryWriteCommandsCommon, DbWriteModeHistory};
+use model::{AccountIdInternal, AdminActionType};
+use serde::Serialize;
 use simple_backend_model::{MetricKey, PerfMetricValueArea};
 
 use crate::{
     define_cmd_wrapper_write,
-    result::Result,
+    result::{Result, WrappedResultExt},
+    write::common_admin::report::ProcessedReport,
     write::{db_transaction_history, DbTransactionHistory},
-    DataError,
+    DataError, DieselDatabaseError,
 };
 
+/// Admin action for the audit log.
+///
+/// Use [crate::write::DbTransactionWithAdminAction] to save the action
+/// in the same transaction as the database modifications of the action.
+#[derive(Debug)]
+pub struct AdminAction {
+    admin: AccountIdInternal,
+    action: AdminActionType,
+    target: Option<AccountIdInternal>,
+    parameters: String,
+    pub(crate) processed_report: Option<ProcessedReport>,
+}
+
+impl AdminAction {
+    pub fn new(
+        admin: AccountIdInternal,
+        action: AdminActionType,
+        target: Option<AccountIdInternal>,
+        parameters: impl Serialize,
+    ) -> Result<Self, DataError> {
+        let parameters =
+            serde_json::to_string(&parameters).change_context(DataError::Serialize)?;
+        Ok(Self {
+            admin,
+            action,
+            target,
+            parameters,
+            processed_report: None,
+        })
+    }
+
+    /// Mark the report processed in the same transaction as the
+    /// moderation action of the report outcome.
+    pub fn with_processed_report(mut self, report: ProcessedReport) -> Self {
+        self.processed_report = Some(report);
+        self
+    }
+
+    pub fn save(
+        self,
+        cmds: &mut DbWriteModeHistory<'_>,
+    ) -> error_stack::Result<(), DieselDatabaseError> {
+        cmds.common_admin_history().insert_admin_action(
+            self.admin.as_id(),
+            self.action,
+            self.target.map(|v| v.as_id()),
+            self.parameters,
+        )
+    }
+
+    /// Save the action with parameters which are available only in the
+    /// transaction, for example ID of a new item.
+    pub fn save_with_parameters(
+        mut self,
+        cmds: &mut DbWriteModeHistory<'_>,
+        parameters: impl Serialize,
+    ) -> error_stack::Result<(), DieselDatabaseError> {
+        self.parameters = serde_json::to_string(&parameters).map_err(|e| {
+            error_stack::report!(e).change_context(DieselDatabaseError::SerdeSerialize)
+        })?;
+        self.save(cmds)
+    }
+}
+
 define_cmd_wrapper_write!(WriteCommandsCommonHistory);
 
 impl WriteCommandsCommonHistory<'_> {
@@ -22,4 +88,20 @@ impl WriteCommandsCommonHistory<'_> {
             cmds.common_history().write_perf_data(data)
         })
     }
+
+    /// Save admin action to the audit log.
+    ///
+    /// Use this only for actions which do not modify the current
+    /// database, for example server manager actions. Other actions use
+    /// [crate::write::DbTransactionWithAdminAction].
+    pub async fn save_admin_action(
+        &self,
+        admin: AccountIdInternal,
+        action: AdminActionType,
+        target: Option<AccountIdInternal>,
+        parameters: impl Serialize,
+    ) -> Result<(), DataError> {
+        let action = AdminAction::new(admin, action, target, parameters)?;
+        db_transaction_history!(self, move |mut cmds| action.save(&mut cmds))
+    }
 }
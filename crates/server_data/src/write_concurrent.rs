@@ -1,16 +1,19 @@
 //! Write commands that can be run concurrently also with synchronous
 //! write commands.
 
-use std::{collections::HashMap, fmt::{self, Debug}, sync::{atomic::{AtomicI64, Ordering}, Arc}};
+use std::{collections::{HashMap, HashSet}, fmt::{self, Debug}, sync::{atomic::{AtomicI64, Ordering}, Arc}};
 
 use axum::body::BodyDataStream;
 use config::Config;
 use futures::Future;
-use model::{AccountId, AccountIdInternal, ContentProcessingId, ContentSlot, MatchId, ReceivedLikeId};
+use model::{
+    AccountId, AccountIdInternal, ContentProcessingId, ContentSlot, MatchId, ReceivedLikeId,
+    UnixTime,
+};
 use model_server_data::{
-    MatchesIteratorSessionId, NewsIteratorSessionId, ProfileIteratorSessionId,
-    ProfileIteratorSessionIdInternal, ProfileLink, PublicationId,
-    ReceivedLikesIteratorSessionId,
+    MatchesIteratorSessionId, MutualInterestRankingInfo, NewsIteratorSessionId, ProfileIteratorSessionId,
+    ProfileIteratorSessionIdInternal, ProfileLink, ProfileQueryMakerDetails, ProfileVisitId,
+    ProfileVisitorsIteratorSessionId, PublicationId, ReceivedLikesIteratorSessionId,
 };
 use tokio::sync::{Mutex, OwnedMutexGuard, RwLock};
 
@@ -220,10 +223,11 @@ impl ConcurrentWriteProfileHandleBlocking {
     pub fn reset_profile_iterator(
         &self,
         id: AccountIdInternal,
+        received_likes: HashSet<AccountId>,
     ) -> Result<ProfileIteratorSessionIdInternal, DataError> {
         self.write
             .user_write_commands_account()
-            .reset_profile_iterator(id)
+            .reset_profile_iterator(id, received_likes)
     }
 
     pub fn next_received_likes_iterator_state(
@@ -246,6 +250,16 @@ impl ConcurrentWriteProfileHandleBlocking {
             .next_matches_iterator_state(id, iterator_id)
     }
 
+    pub fn next_profile_visitors_iterator_state(
+        &self,
+        id: AccountIdInternal,
+        iterator_id: ProfileVisitorsIteratorSessionId,
+    ) -> Result<Option<DbIteratorState<ProfileVisitId>>, DataError> {
+        self.write
+            .user_write_commands_account()
+            .next_profile_visitors_iterator_state(id, iterator_id)
+    }
+
     pub fn into_lock(self) -> OwnedMutexGuard<AccountHandle> {
         self.account_write_lock
     }
@@ -269,6 +283,7 @@ pub struct WriteCommandsConcurrent<'a> {
     cache: &'a DatabaseCache,
     file_dir: &'a FileDir,
     location: LocationIndexIteratorHandle<'a>,
+    config: &'a Config,
 }
 
 impl<'a> WriteCommandsConcurrent<'a> {
@@ -276,11 +291,13 @@ impl<'a> WriteCommandsConcurrent<'a> {
         cache: &'a DatabaseCache,
         file_dir: &'a FileDir,
         location: LocationIndexIteratorHandle<'a>,
+        config: &'a Config,
     ) -> Self {
         Self {
             cache,
             file_dir,
             location,
+            config,
         }
     }
 
@@ -323,7 +340,7 @@ impl<'a> WriteCommandsConcurrent<'a> {
         id: AccountIdInternal,
         iterator_id_from_client: ProfileIteratorSessionId,
     ) -> Result<Option<Vec<ProfileLink>>, DataError> {
-        let (location, query_maker_filters, iterator_id_current) = self
+        let (location, query_maker_filters, iterator_id_current, ranked_profiles) = self
             .cache
             .read_cache_blocking(id.as_id(), |e| {
                 let p = e.profile.as_ref().ok_or(CacheError::FeatureNotEnabled)?;
@@ -331,6 +348,7 @@ impl<'a> WriteCommandsConcurrent<'a> {
                     p.location.clone(),
                     p.filters(),
                     p.profile_iterator_session_id,
+                    p.mutual_interest_ranked_profiles.is_some(),
                 ))
             })
             .into_data_error(id)??;
@@ -341,11 +359,18 @@ impl<'a> WriteCommandsConcurrent<'a> {
             return Ok(None);
         }
 
+        if ranked_profiles {
+            return self
+                .next_mutual_interest_ranked_profiles(id, &query_maker_filters)
+                .map(Some);
+        }
+
         let (mut next_state, profiles) = self
             .location
             .next_profiles(location.current_iterator, &query_maker_filters);
 
         let (next_state, profiles) = if let Some(mut profiles) = profiles {
+            self.remove_hidden_profiles(id, &mut profiles)?;
             loop {
                 if profiles.len() >= PROFILE_ITERATOR_PAGE_SIZE {
                     break (next_state, profiles);
@@ -355,7 +380,8 @@ impl<'a> WriteCommandsConcurrent<'a> {
                         .next_profiles(next_state, &query_maker_filters);
                     next_state = new_next_state;
 
-                    if let Some(new_profiles) = new_profiles {
+                    if let Some(mut new_profiles) = new_profiles {
+                        self.remove_hidden_profiles(id, &mut new_profiles)?;
                         profiles.extend(new_profiles);
                     } else {
                         break (next_state, profiles);
@@ -378,13 +404,138 @@ impl<'a> WriteCommandsConcurrent<'a> {
         Ok(Some(profiles))
     }
 
+    /// Remove passed profiles and if enabled also seen profiles.
+    fn remove_hidden_profiles(
+        &self,
+        id: AccountIdInternal,
+        profiles: &mut Vec<ProfileLink>,
+    ) -> Result<(), DataError> {
+        let current_time = UnixTime::current_time();
+        self.cache
+            .read_cache_blocking(id.as_id(), |e| {
+                let p = e.profile.as_ref().ok_or(CacheError::FeatureNotEnabled)?;
+                profiles.retain(|v| !p.is_hidden_from_profile_iterator(v.id(), current_time));
+                error_stack::Result::<_, CacheError>::Ok(())
+            })
+            .into_data_error(id)??;
+        Ok(())
+    }
+
+    /// The ranking is a snapshot from the iterator reset, so the
+    /// profiles are checked again before returning them.
+    fn next_mutual_interest_ranked_profiles(
+        &self,
+        id: AccountIdInternal,
+        query_maker_filters: &ProfileQueryMakerDetails,
+    ) -> Result<Vec<ProfileLink>, DataError> {
+        let mut page = vec![];
+        loop {
+            let missing = PROFILE_ITERATOR_PAGE_SIZE - page.len();
+            let candidates = self
+                .cache
+                .write_cache_blocking(id.as_id(), |e| {
+                    let p = e.profile_data_mut()?;
+                    let profiles = p.mutual_interest_ranked_profiles.get_or_insert_with(Vec::new);
+                    let start = profiles.len().saturating_sub(missing);
+                    Ok(profiles.drain(start..).rev().collect::<Vec<ProfileLink>>())
+                })
+                .into_data_error(id)?;
+
+            if candidates.is_empty() {
+                break;
+            }
+
+            page.extend(self.current_ranked_profile_links(candidates, query_maker_filters));
+            self.remove_hidden_profiles(id, &mut page)?;
+
+            if page.len() >= PROFILE_ITERATOR_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(page)
+    }
+
+    /// Replace ranked profile links with current links and remove profiles
+    /// which are not public anymore or do not match the filters anymore.
+    fn current_ranked_profile_links(
+        &self,
+        candidates: Vec<ProfileLink>,
+        query_maker_filters: &ProfileQueryMakerDetails,
+    ) -> Vec<ProfileLink> {
+        let current_time = UnixTime::current_time();
+        let attribute_info = self.config.profile_attributes();
+        candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                self.cache
+                    .read_cache_blocking(candidate.id(), |e| {
+                        let visibility = e
+                            .common
+                            .account_state_related_shared_state
+                            .profile_visibility();
+                        if !visibility.is_currently_public() {
+                            return None;
+                        }
+                        let data = e.location_index_profile_data().ok()?;
+                        if data.is_match(query_maker_filters, attribute_info, &current_time) {
+                            Some(data.to_profile_link_value())
+                        } else {
+                            None
+                        }
+                    })
+                    .ok()
+                    .flatten()
+            })
+            .collect()
+    }
+
+    /// The `received_likes` is used for mutual interest profile ranking.
     pub fn reset_profile_iterator(
         &self,
         id: AccountIdInternal,
+        received_likes: HashSet<AccountId>,
     ) -> Result<ProfileIteratorSessionIdInternal, DataError> {
+        let ranking_data = self
+            .cache
+            .read_cache_blocking(id.as_id(), |e| {
+                let p = e.profile.as_ref().ok_or(CacheError::FeatureNotEnabled)?;
+                let data = if p.state.mutual_interest_profile_order {
+                    Some((
+                        p.location.current_position.clone(),
+                        p.filters(),
+                        p.attributes.clone(),
+                    ))
+                } else {
+                    None
+                };
+                error_stack::Result::<_, CacheError>::Ok(data)
+            })
+            .into_data_error(id)??;
+
+        let ranked_profiles = ranking_data.map(|(area, query_maker_filters, attributes)| {
+            let ranking_info = MutualInterestRankingInfo {
+                attributes,
+                received_likes,
+            };
+            let mut profiles = self.location.mutual_interest_ranked_profiles(
+                &area,
+                &query_maker_filters,
+                &ranking_info,
+            );
+            profiles.reverse();
+            profiles
+        });
+
+        let current_time = UnixTime::current_time();
         self.cache
             .write_cache_blocking(id.as_id(), |e| {
                 let p = e.profile_data_mut()?;
+                p.remove_expired_seen_profiles(current_time);
+                let ranked_profiles = ranked_profiles.map(|mut profiles| {
+                    profiles.retain(|v| !p.is_hidden_from_profile_iterator(v.id(), current_time));
+                    profiles
+                });
                 let new_id = ProfileIteratorSessionIdInternal::create(
                     &mut p.profile_iterator_session_id_storage,
                 );
@@ -396,6 +547,7 @@ impl<'a> WriteCommandsConcurrent<'a> {
                     );
                 p.location.current_iterator = next_state;
                 p.profile_iterator_session_id = Some(new_id);
+                p.mutual_interest_ranked_profiles = ranked_profiles;
                 Ok(new_id)
             })
             .into_data_error(id)
@@ -434,6 +586,19 @@ impl<'a> WriteCommandsConcurrent<'a> {
             .into_data_error(id)
     }
 
+    pub fn next_profile_visitors_iterator_state(
+        &self,
+        id: AccountIdInternal,
+        iterator_session_id: ProfileVisitorsIteratorSessionId,
+    ) -> Result<Option<DbIteratorState<ProfileVisitId>>, DataError> {
+        self.cache
+            .write_cache_blocking(id.as_id(), |e| {
+                let p = e.profile_data_mut()?;
+                Ok(p.profile_visitors_iterator.get_and_increment(iterator_session_id))
+            })
+            .into_data_error(id)
+    }
+
     pub fn next_news_iterator_state(
         &self,
         id: AccountIdInternal,
@@ -14,6 +14,7 @@ pub mod delete;
 pub mod email;
 pub mod news;
 pub mod report;
+pub mod warning;
 
 define_cmd_wrapper_read!(ReadCommandsAccount);
 
@@ -37,6 +38,10 @@ impl<'a> ReadCommandsAccount<'a> {
     pub fn report(self) -> report::ReadCommandsAccountReport<'a> {
         report::ReadCommandsAccountReport::new(self.0)
     }
+
+    pub fn warning(self) -> warning::ReadCommandsAccountWarning<'a> {
+        warning::ReadCommandsAccountWarning::new(self.0)
+    }
 }
 
 impl ReadCommandsAccount<'_> {
@@ -0,0 +1,21 @@
+use database_account::current::read::GetDbReadCommandsAccount;
+use model::UnixTime;
+use model_account::{AccountIdInternal, GetAccountWarningList};
+use server_data::{db_manager::InternalReading, define_cmd_wrapper_read, read::DbRead, result::Result, DataError};
+
+define_cmd_wrapper_read!(ReadCommandsAccountWarning);
+
+impl ReadCommandsAccountWarning<'_> {
+    pub async fn active_warnings(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<GetAccountWarningList, DataError> {
+        let expiration = self.config().limits_account().account_warning_expiration_duration;
+        let expiration_start = UnixTime::current_time().subtract_seconds(expiration.seconds);
+        let values = self
+            .db_read(move |mut cmds| cmds.account().warning().warnings_created_after(id, expiration_start))
+            .await?;
+
+        Ok(GetAccountWarningList { values })
+    }
+}
@@ -4,6 +4,7 @@ pub mod news;
 pub mod search;
 pub mod permissions;
 pub mod report;
+pub mod role;
 
 define_cmd_wrapper_read!(ReadCommandsAccountAdmin);
 
@@ -20,4 +21,7 @@ impl<'a> ReadCommandsAccountAdmin<'a> {
     pub fn report(self) -> report::ReadCommandsAccountReport<'a> {
         report::ReadCommandsAccountReport::new(self.0)
     }
+    pub fn role(self) -> role::ReadCommandsAccountRoleAdmin<'a> {
+        role::ReadCommandsAccountRoleAdmin::new(self.0)
+    }
 }
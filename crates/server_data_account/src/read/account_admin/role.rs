@@ -0,0 +1,39 @@
+use database::current::read::GetDbReadCommandsCommon;
+use database_account::current::read::GetDbReadCommandsAccount;
+use model_account::{AccountIdInternal, GetAccountAdminRoles, GetAdminRoleList};
+use server_data::{
+    define_cmd_wrapper_read, read::DbRead, result::Result, DataError, IntoDataError,
+};
+
+define_cmd_wrapper_read!(ReadCommandsAccountRoleAdmin);
+
+impl ReadCommandsAccountRoleAdmin<'_> {
+    pub async fn all_roles(&self) -> Result<GetAdminRoleList, DataError> {
+        let roles = self
+            .db_read(move |mut cmds| cmds.account_admin().role().all_roles())
+            .await
+            .into_error()?;
+        Ok(GetAdminRoleList { roles })
+    }
+
+    pub async fn account_roles(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<GetAccountAdminRoles, DataError> {
+        self.db_read(move |mut cmds| {
+            let roles = cmds.account_admin().role().account_roles(id)?;
+            let individual_permissions =
+                if let Some(p) = cmds.account_admin().role().individual_permissions(id)? {
+                    p
+                } else {
+                    cmds.common().account(id)?.permissions()
+                };
+            Ok(GetAccountAdminRoles {
+                roles,
+                individual_permissions,
+            })
+        })
+        .await
+        .into_error()
+    }
+}
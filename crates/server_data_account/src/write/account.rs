@@ -21,6 +21,7 @@ pub mod delete;
 pub mod email;
 pub mod news;
 pub mod report;
+pub mod warning;
 
 #[derive(Debug, Clone, Copy)]
 pub struct IncrementAdminAccessGrantedCount;
@@ -43,6 +44,10 @@ impl<'a> WriteCommandsAccount<'a> {
     pub fn report(self) -> report::WriteCommandsAccountReport<'a> {
         report::WriteCommandsAccountReport::new(self.0)
     }
+
+    pub fn warning(self) -> warning::WriteCommandsAccountWarning<'a> {
+        warning::WriteCommandsAccountWarning::new(self.0)
+    }
 }
 
 impl WriteCommandsAccount<'_> {
@@ -3,7 +3,7 @@ use database_account::current::{read::GetDbReadCommandsAccount, write::GetDbWrit
 use model::Account;
 use model_account::AccountIdInternal;
 use server_data::{
-    db_manager::InternalWriting, define_cmd_wrapper_write, file::FileWrite, read::DbRead, result::Result, write::{DbTransaction, GetWriteCommandsCommon}, DataError
+    db_manager::InternalWriting, define_cmd_wrapper_write, file::FileWrite, read::DbRead, result::Result, write::{common_history::AdminAction, DbTransaction, DbTransactionWithAdminAction, GetWriteCommandsCommon}, DataError
 };
 
 use crate::write::GetWriteCommandsAccount;
@@ -57,6 +57,7 @@ impl WriteCommandsAccountDelete<'_> {
     pub async fn delete_account(
         &self,
         id: AccountIdInternal,
+        admin_action: Option<AdminAction>,
     ) -> Result<(), DataError> {
         self.handle().common().logout(id).await?;
 
@@ -66,9 +67,9 @@ impl WriteCommandsAccountDelete<'_> {
             Ok(())
         }).await?;
 
-        db_transaction!(self, move |mut cmds| {
+        self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
             cmds.account().delete().delete_account(id)
-        })?;
+        }).await?;
 
         self.cache().delete_account_which_is_logged_out(id.as_id()).await;
 
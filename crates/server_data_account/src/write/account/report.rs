@@ -1,29 +1,85 @@
-use database_account::current::write::GetDbWriteCommandsAccount;
-use model::AccountIdInternal;
+use database_account::current::{read::GetDbReadCommandsAccount, write::GetDbWriteCommandsAccount};
+use model::{AccountIdInternal, UpdateReportResult};
 use model_account::AccountReportContent;
 use server_data::{
+    app::GetConfig,
     define_cmd_wrapper_write,
-    result::Result,
-    write::DbTransaction,
+    read::DbRead,
+    result::{Result, WrappedContextExt},
+    write::{DbTransaction, GetWriteCommandsCommon},
     DataError,
 };
 
 define_cmd_wrapper_write!(WriteCommandsAccountReport);
 
 impl WriteCommandsAccountReport<'_> {
+    /// Starts automatic report triage if the report has the underaged
+    /// flag enabled and the triage is enabled for that.
     pub async fn update_report(
         &self,
         creator: AccountIdInternal,
         target: AccountIdInternal,
         reported_content: AccountReportContent,
     ) -> Result<(), DataError> {
+        let triage = reported_content.is_underaged &&
+            self.config()
+                .limits_common()
+                .report_triage
+                .is_some_and(|v| v.underaged_account_report);
+
         db_transaction!(self, move |mut cmds| {
             cmds.account()
                 .report()
                 .upsert_report(creator, target, reported_content)?;
+            if triage {
+                cmds.account()
+                    .report()
+                    .prioritize_waiting_report(creator, target)?;
+            }
             Ok(())
         })?;
 
+        if triage {
+            self.handle()
+                .common_admin()
+                .report()
+                .update_profile_hidden_pending_review(target, true)
+                .await?;
+        }
+
         Ok(())
     }
+
+    /// The account must be banned. Only one appeal per ban is allowed.
+    pub async fn appeal_ban(
+        &self,
+        id: AccountIdInternal,
+        appeal_text: String,
+    ) -> Result<UpdateReportResult, DataError> {
+        let (ban_state, ban_state_change_time) = self.db_read(move |mut cmds| {
+            let ban_state = cmds.account().ban().account_ban_time(id)?;
+            let ban_state_change_time = cmds.account().ban().account_ban_state_change_time(id)?;
+            Ok((ban_state, ban_state_change_time))
+        }).await?;
+
+        let (Some(_), Some(ban_state_change_time)) = (ban_state.banned_until, ban_state_change_time) else {
+            return Err(DataError::NotAllowed.report());
+        };
+
+        let appeal_exists = self
+            .db_read(move |mut cmds| cmds.account().ban().ban_appeal_exists(id, ban_state_change_time))
+            .await?;
+        if appeal_exists {
+            return Ok(UpdateReportResult::too_many_reports());
+        }
+
+        db_transaction!(self, move |mut cmds| {
+            cmds.account()
+                .report()
+                .insert_ban_appeal(id, ban_state_change_time, appeal_text)?;
+            Ok(())
+        })?;
+
+        Ok(UpdateReportResult::success())
+    }
 }
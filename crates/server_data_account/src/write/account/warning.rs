@@ -0,0 +1,46 @@
+use database::current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon};
+use database_account::current::write::GetDbWriteCommandsAccount;
+use model::Account;
+use model_account::AccountIdInternal;
+use server_data::{
+    define_cmd_wrapper_write, read::DbRead, result::Result, write::{DbTransaction, GetWriteCommandsCommon}, DataError
+};
+
+define_cmd_wrapper_write!(WriteCommandsAccountWarning);
+
+impl WriteCommandsAccountWarning<'_> {
+    /// Mark all warnings acknowledged and remove
+    /// [model::AccountState::WarningPending] from account state.
+    ///
+    /// Returns the modified Account.
+    pub async fn acknowledge_warnings(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<Account, DataError> {
+        let current_account = self
+            .db_read(move |mut cmds| cmds.common().account(id))
+            .await?;
+        let a = current_account.clone();
+        let new_account = db_transaction!(self, move |mut cmds| {
+            cmds.account().warning().acknowledge_warnings(id)?;
+
+            cmds.common()
+                .state()
+                .update_syncable_account_data(id, a, move |state_container, _, _| {
+                    state_container.set_warning_pending(false);
+                    Ok(())
+                })
+        })?;
+
+        self.handle()
+            .common()
+            .internal_handle_new_account_data_after_db_modification(
+                id,
+                &current_account,
+                &new_account,
+            )
+            .await?;
+
+        Ok(new_account)
+    }
+}
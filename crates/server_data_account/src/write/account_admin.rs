@@ -4,6 +4,8 @@ mod ban;
 mod news;
 mod permissions;
 mod report;
+mod role;
+mod warning;
 
 define_cmd_wrapper_write!(WriteCommandsAccountAdmin);
 
@@ -23,4 +25,12 @@ impl<'a> WriteCommandsAccountAdmin<'a> {
     pub fn report(self) -> report::WriteCommandsAccountReport<'a> {
         report::WriteCommandsAccountReport::new(self.0)
     }
+
+    pub fn role(self) -> role::WriteCommandsAccountRoleAdmin<'a> {
+        role::WriteCommandsAccountRoleAdmin::new(self.0)
+    }
+
+    pub fn warning(self) -> warning::WriteCommandsAccountWarningAdmin<'a> {
+        warning::WriteCommandsAccountWarningAdmin::new(self.0)
+    }
 }
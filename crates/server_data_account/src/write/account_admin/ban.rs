@@ -1,9 +1,9 @@
 use database::current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon};
 use database_account::current::{read::GetDbReadCommandsAccount, write::GetDbWriteCommandsAccount};
-use model::{Account, UnixTime};
+use model::{Account, BanAppealDecision, ModerationClaimQueue, ReportProcessingState, ReportTypeNumber, UnixTime};
 use model_account::{AccountBanReasonCategory, AccountBanReasonDetails, AccountIdInternal};
 use server_data::{
-    define_cmd_wrapper_write, read::DbRead, result::Result, write::{DbTransaction, GetWriteCommandsCommon}, DataError
+    db_manager::InternalWriting, define_cmd_wrapper_write, read::DbRead, result::{Result, WrappedContextExt}, write::{common_history::AdminAction, DbTransactionWithAdminAction, GetWriteCommandsCommon}, DataError
 };
 
 define_cmd_wrapper_write!(WriteCommandsAccountBan);
@@ -16,6 +16,7 @@ impl WriteCommandsAccountBan<'_> {
         banned_until: Option<UnixTime>,
         reason_category: Option<AccountBanReasonCategory>,
         reason_details: Option<AccountBanReasonDetails>,
+        admin_action: Option<AdminAction>,
     ) -> Result<Option<Account>, DataError> {
         let (ban_state, current_account) = self.db_read(move |mut cmds| {
             let ban_state = cmds.account().ban().account_ban_time(id)?;
@@ -24,10 +25,13 @@ impl WriteCommandsAccountBan<'_> {
         }).await?;
         if banned_until == ban_state.banned_until {
             // Already in correct state
+            if admin_action.is_some() {
+                self.db_transaction_with_admin_action(admin_action, |_| Ok(())).await?;
+            }
             return Ok(None);
         }
         let a = current_account.clone();
-        let new_account = db_transaction!(self, move |mut cmds| {
+        let new_account = self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
             let a = cmds.common()
                 .state()
                 .update_syncable_account_data(id, a, move |state_container, _, visibility| {
@@ -41,7 +45,7 @@ impl WriteCommandsAccountBan<'_> {
             cmds.account_admin().ban().set_banned_state(id, admin_id, banned_until, reason_category, reason_details)?;
 
             Ok(a)
-        })?;
+        }).await?;
 
         self.handle()
             .common()
@@ -54,4 +58,103 @@ impl WriteCommandsAccountBan<'_> {
 
         Ok(Some(new_account))
     }
+
+    /// Process the waiting ban appeal of the account.
+    ///
+    /// The ban change, the appeal decision and the admin action are saved
+    /// in the same transaction. Shortening the ban keeps the ban state
+    /// change time, so the ban appeal stays linked to the current ban
+    /// and a new appeal can not be created for the shortened ban.
+    ///
+    /// Returns `Some` if the account data changed.
+    pub async fn process_ban_appeal(
+        &self,
+        moderator_id: AccountIdInternal,
+        id: AccountIdInternal,
+        decision: BanAppealDecision,
+        ban_until: Option<UnixTime>,
+        moderator_note: Option<String>,
+        admin_action: AdminAction,
+    ) -> Result<Option<Account>, DataError> {
+        let components = self.config().components();
+        let (appeals, ban_state, current_account) = self.db_read(move |mut cmds| {
+            let appeals = cmds.common().report().get_all_detailed_reports(id, id, ReportTypeNumber::BanAppeal, components)?;
+            let ban_state = cmds.account().ban().account_ban_time(id)?;
+            let current_account = cmds.common().account(id)?;
+            Ok((appeals, ban_state, current_account))
+        }).await?;
+
+        let Some(appeal) = appeals
+            .iter()
+            .find(|v| v.report.info.processing_state == ReportProcessingState::Waiting) else {
+            return Err(DataError::NotAllowed.report());
+        };
+        let report_id = appeal.id;
+
+        let ban_change = match decision {
+            BanAppealDecision::Uphold => None,
+            BanAppealDecision::Shorten => {
+                let (Some(new_ban_until), Some(current_ban_until)) = (ban_until, ban_state.banned_until) else {
+                    return Err(DataError::NotAllowed.report());
+                };
+                if new_ban_until.ut >= current_ban_until.ut {
+                    return Err(DataError::NotAllowed.report());
+                }
+                Some(BanAppealBanChange::Shorten(new_ban_until))
+            }
+            BanAppealDecision::Lift if ban_state.banned_until.is_some() =>
+                Some(BanAppealBanChange::Lift),
+            BanAppealDecision::Lift => None,
+        };
+
+        let a = current_account.clone();
+        let new_account = self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
+            let new_account = if let Some(ban_change) = ban_change {
+                let banned = matches!(ban_change, BanAppealBanChange::Shorten(_));
+                let new_account = cmds.common()
+                    .state()
+                    .update_syncable_account_data(id, a, move |state_container, _, _| {
+                        state_container.set_banned(banned);
+                        Ok(())
+                    })?;
+                match ban_change {
+                    BanAppealBanChange::Shorten(new_ban_until) =>
+                        cmds.account_admin().ban().update_banned_until(id, new_ban_until)?,
+                    BanAppealBanChange::Lift =>
+                        cmds.account_admin().ban().set_banned_state(id, Some(moderator_id), None, None, None)?,
+                }
+                Some(new_account)
+            } else {
+                None
+            };
+            cmds.account_admin().ban().set_ban_appeal_decision(report_id, decision)?;
+            cmds.common_admin()
+                .report()
+                .mark_report_done(moderator_id, report_id, decision.report_outcome(), moderator_note)?;
+            cmds.common_admin().moderation_claim().complete_item(
+                moderator_id,
+                ModerationClaimQueue::BanAppeal,
+                *report_id.as_i64(),
+            )?;
+            Ok(new_account)
+        }).await?;
+
+        if let Some(new_account) = &new_account {
+            self.handle()
+                .common()
+                .internal_handle_new_account_data_after_db_modification(
+                    id,
+                    &current_account,
+                    new_account,
+                )
+                .await?;
+        }
+
+        Ok(new_account)
+    }
+}
+
+enum BanAppealBanChange {
+    Shorten(UnixTime),
+    Lift,
 }
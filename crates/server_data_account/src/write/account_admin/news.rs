@@ -1,20 +1,46 @@
 use database_account::current::write::GetDbWriteCommandsAccount;
 use model_account::{AccountIdInternal, NewsId, NewsLocale, UpdateNewsTranslation};
-use server_data::{define_cmd_wrapper_write, result::Result, write::DbTransaction, DataError};
+use server_data::{
+    db_manager::InternalWriting,
+    define_cmd_wrapper_write,
+    result::Result,
+    write::{common_history::AdminAction, DbTransactionWithAdminAction},
+    DataError, IntoDataError,
+};
 
 define_cmd_wrapper_write!(WriteCommandsAccountNewsAdmin);
 
 impl WriteCommandsAccountNewsAdmin<'_> {
-    pub async fn create_news_item(&self, id: AccountIdInternal) -> Result<NewsId, DataError> {
-        db_transaction!(self, move |mut cmds| {
-            cmds.account_admin().news().create_new_news_item(id)
-        })
+    /// The admin action parameters are replaced with the ID of
+    /// the new news item.
+    pub async fn create_news_item(
+        &self,
+        id: AccountIdInternal,
+        admin_action: AdminAction,
+    ) -> Result<NewsId, DataError> {
+        let r = self
+            .db_transaction_with_history(move |conn, mut history| {
+                let news_id = conn
+                    .into_conn()
+                    .account_admin()
+                    .news()
+                    .create_new_news_item(id)?;
+                admin_action.save_with_parameters(&mut history, news_id)?;
+                Ok(news_id)
+            })
+            .await;
+        r.into_error()
     }
 
-    pub async fn delete_news_item(&self, id: NewsId) -> Result<(), DataError> {
-        db_transaction!(self, move |mut cmds| {
+    pub async fn delete_news_item(
+        &self,
+        id: NewsId,
+        admin_action: AdminAction,
+    ) -> Result<(), DataError> {
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
             cmds.account_admin().news().delete_news_item(id)
         })
+        .await
     }
 
     pub async fn upsert_news_translation(
@@ -23,31 +49,41 @@ impl WriteCommandsAccountNewsAdmin<'_> {
         nid: NewsId,
         locale: NewsLocale,
         content: UpdateNewsTranslation,
+        admin_action: AdminAction,
     ) -> Result<(), DataError> {
-        db_transaction!(self, move |mut cmds| {
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
             cmds.account_admin()
                 .news()
                 .upsert_news_translation(id, nid, locale, content)
         })
+        .await
     }
 
     pub async fn delete_news_translation(
         &self,
         nid: NewsId,
         locale: NewsLocale,
+        admin_action: AdminAction,
     ) -> Result<(), DataError> {
-        db_transaction!(self, move |mut cmds| {
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
             cmds.account_admin()
                 .news()
                 .delete_news_translation(nid, locale)
         })
+        .await
     }
 
-    pub async fn set_news_publicity(&self, nid: NewsId, is_public: bool) -> Result<(), DataError> {
-        db_transaction!(self, move |mut cmds| {
+    pub async fn set_news_publicity(
+        &self,
+        nid: NewsId,
+        is_public: bool,
+        admin_action: AdminAction,
+    ) -> Result<(), DataError> {
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
             cmds.account_admin()
                 .news()
                 .set_news_publicity(nid, is_public)
         })
+        .await
     }
 }
@@ -1,7 +1,9 @@
-use model::Permissions;
+use database::current::read::GetDbReadCommandsCommon;
+use database_account::current::{read::GetDbReadCommandsAccount, write::GetDbWriteCommandsAccount};
+use model::{Account, Permissions, UnixTime};
 use model_account::AccountIdInternal;
 use server_data::{
-    define_cmd_wrapper_write, result::Result, DataError
+    cache::{CacheWriteCommon, ExpiringPermissions}, define_cmd_wrapper_write, read::DbRead, result::Result, write::{common_history::AdminAction, DbTransaction, DbTransactionWithAdminAction}, DataError, IntoDataError
 };
 
 use crate::write::GetWriteCommandsAccount;
@@ -9,23 +11,80 @@ use crate::write::GetWriteCommandsAccount;
 define_cmd_wrapper_write!(WriteCommandsAccountPermissionsAdmin);
 
 impl WriteCommandsAccountPermissionsAdmin<'_> {
+    /// Set individually granted permissions. Permissions from admin roles
+    /// are added to the effective permissions.
     pub async fn set_permissions(
         &self,
         id: AccountIdInternal,
         permissions: Permissions,
-    ) -> Result<(), DataError> {
-        self.handle()
+        admin_action: AdminAction,
+    ) -> Result<Account, DataError> {
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
+            cmds.account_admin()
+                .role()
+                .update_individual_permissions(id, &permissions)
+        }).await?;
+
+        self.update_effective_permissions(id).await
+    }
+
+    /// Combine individual permissions and permissions of the active admin
+    /// roles and save the result as the effective permissions.
+    ///
+    /// Returns the modified Account.
+    pub async fn update_effective_permissions(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<Account, DataError> {
+        let current_time = UnixTime::current_time();
+        let (individual, current, roles) = self
+            .db_read(move |mut cmds| {
+                let individual = cmds.account_admin().role().individual_permissions(id)?;
+                let current = cmds.common().account(id)?.permissions();
+                let roles = cmds
+                    .account_admin()
+                    .role()
+                    .active_role_permissions(id, current_time)?;
+                Ok((individual, current, roles))
+            })
+            .await?;
+
+        let individual = if let Some(individual) = individual {
+            individual
+        } else {
+            // Admin roles are used for the first time with this account,
+            // so current permissions are the individual permissions.
+            let p = current.clone();
+            db_transaction!(self, move |mut cmds| {
+                cmds.account_admin()
+                    .role()
+                    .update_individual_permissions(id, &p)
+            })?;
+            current
+        };
+
+        let expiring_permissions = ExpiringPermissions::new(individual, roles);
+        let mut effective = expiring_permissions.permanent.clone();
+        for (r, _) in &expiring_permissions.expiring {
+            effective.merge(r);
+        }
+
+        let account = self
+            .handle()
             .account()
-            .update_syncable_account_data(
-                id,
-                None,
-                |_, account_permissions, _| {
-                    *account_permissions = permissions;
-                    Ok(())
-                },
-            )
+            .update_syncable_account_data(id, None, move |_, account_permissions, _| {
+                *account_permissions = effective;
+                Ok(())
+            })
             .await?;
 
-        Ok(())
+        self.write_cache_common(id, |e| {
+            e.expiring_permissions = expiring_permissions;
+            Ok(())
+        })
+        .await
+        .into_data_error(id)?;
+
+        Ok(account)
     }
 }
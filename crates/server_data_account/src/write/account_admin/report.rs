@@ -5,7 +5,7 @@ use server_data::{
     define_cmd_wrapper_write,
     read::DbRead,
     result::{Result, WrappedContextExt},
-    write::DbTransaction,
+    write::{common_history::AdminAction, DbTransactionWithAdminAction, GetWriteCommandsCommon},
     DataError,
 };
 
@@ -18,6 +18,7 @@ impl WriteCommandsAccountReport<'_> {
         creator: AccountIdInternal,
         target: AccountIdInternal,
         content: AccountReportContent,
+        admin_action: AdminAction,
     ) -> Result<(), DataError> {
         let current_report = self
             .db_read(move |mut cmds| cmds.account().report().get_report(creator, target))
@@ -26,12 +27,18 @@ impl WriteCommandsAccountReport<'_> {
             return Err(DataError::NotAllowed.report());
         }
 
-        db_transaction!(self, move |mut cmds| {
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
             cmds.account_admin()
                 .report()
                 .mark_report_done(moderator_id, creator, target)?;
             Ok(())
-        })?;
+        }).await?;
+
+        self.handle()
+            .common_admin()
+            .report()
+            .end_report_triage_if_reviewed(target)
+            .await?;
 
         Ok(())
     }
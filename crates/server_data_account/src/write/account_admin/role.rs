@@ -0,0 +1,186 @@
+use database_account::current::{read::GetDbReadCommandsAccount, write::GetDbWriteCommandsAccount};
+use model::{Account, EventToClientInternal, UnixTime};
+use model_account::{AccountIdInternal, AdminRole};
+use server_data::{
+    db_manager::InternalWriting, define_cmd_wrapper_write, read::DbRead, result::{Result, WrappedContextExt}, write::{common_history::AdminAction, DbTransaction, DbTransactionWithAdminAction}, DataError
+};
+
+use crate::write::GetWriteCommandsAccount;
+
+define_cmd_wrapper_write!(WriteCommandsAccountRoleAdmin);
+
+impl WriteCommandsAccountRoleAdmin<'_> {
+    /// Create new role or replace permissions of an existing role.
+    ///
+    /// Effective permissions of accounts which have the role are updated
+    /// and [EventToClientInternal::AccountStateChanged] is sent to those
+    /// accounts.
+    pub async fn set_role(&self, role: AdminRole, admin_action: AdminAction) -> Result<(), DataError> {
+        self.set_role_internal(role, Some(admin_action)).await
+    }
+
+    async fn set_role_internal(
+        &self,
+        role: AdminRole,
+        admin_action: Option<AdminAction>,
+    ) -> Result<(), DataError> {
+        if !AdminRole::name_is_valid(&role.name) {
+            return Err(DataError::NotAllowed.report());
+        }
+
+        let name = role.name.clone();
+        self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
+            cmds.account_admin()
+                .role()
+                .upsert_role(role.name, &role.permissions)
+        }).await?;
+
+        let role_id = self.role_id(name).await?;
+        let accounts = self
+            .db_read(move |mut cmds| cmds.account_admin().role().accounts_with_role(role_id))
+            .await?;
+        self.update_permissions_and_send_events(accounts).await
+    }
+
+    /// Delete role and remove it from all accounts.
+    ///
+    /// Effective permissions of accounts which had the role are updated
+    /// and [EventToClientInternal::AccountStateChanged] is sent to those
+    /// accounts.
+    pub async fn delete_role(&self, name: String, admin_action: AdminAction) -> Result<(), DataError> {
+        let role_id = self.role_id(name).await?;
+        let accounts = self
+            .db_read(move |mut cmds| cmds.account_admin().role().accounts_with_role(role_id))
+            .await?;
+
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
+            cmds.account_admin().role().delete_role(role_id)
+        }).await?;
+
+        self.update_permissions_and_send_events(accounts).await
+    }
+
+    /// Returns the modified Account.
+    pub async fn assign_role(
+        &self,
+        id: AccountIdInternal,
+        name: String,
+        expires: Option<UnixTime>,
+        admin_action: AdminAction,
+    ) -> Result<Account, DataError> {
+        if let Some(expires) = expires {
+            if expires.ut <= UnixTime::current_time().ut {
+                return Err(DataError::NotAllowed.report());
+            }
+        }
+
+        let role_id = self.role_id(name).await?;
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
+            cmds.account_admin()
+                .role()
+                .upsert_account_role(id, role_id, expires)
+        }).await?;
+
+        self.handle()
+            .account_admin()
+            .permissions()
+            .update_effective_permissions(id)
+            .await
+    }
+
+    /// Returns the modified Account.
+    pub async fn remove_role(
+        &self,
+        id: AccountIdInternal,
+        name: String,
+        admin_action: AdminAction,
+    ) -> Result<Account, DataError> {
+        let role_id = self.role_id(name).await?;
+        self.db_transaction_with_admin_action(Some(admin_action), move |mut cmds| {
+            cmds.account_admin().role().delete_account_role(id, role_id)
+        }).await?;
+
+        self.handle()
+            .account_admin()
+            .permissions()
+            .update_effective_permissions(id)
+            .await
+    }
+
+    /// Remove expired role assignments.
+    ///
+    /// Effective permissions of the related accounts are updated
+    /// and [EventToClientInternal::AccountStateChanged] is sent to those
+    /// accounts.
+    pub async fn remove_expired_roles(&self) -> Result<(), DataError> {
+        let current_time = UnixTime::current_time();
+        let accounts = self
+            .db_read(move |mut cmds| {
+                cmds.account_admin()
+                    .role()
+                    .accounts_with_expired_roles(current_time)
+            })
+            .await?;
+
+        for id in accounts.iter().copied() {
+            db_transaction!(self, move |mut cmds| {
+                cmds.account_admin()
+                    .role()
+                    .delete_expired_account_roles(id, current_time)
+            })?;
+        }
+
+        self.update_permissions_and_send_events(accounts).await
+    }
+
+    /// Create or update roles from config file. Roles which are
+    /// already up to date are skipped.
+    ///
+    /// Updating works like [Self::set_role].
+    pub async fn create_roles_from_config(&self) -> Result<(), DataError> {
+        let roles = self.config().admin_role_config().cloned().unwrap_or_default();
+        let current_roles = self
+            .db_read(|mut cmds| cmds.account_admin().role().all_roles())
+            .await?;
+
+        for r in roles {
+            let role = AdminRole {
+                name: r.name,
+                permissions: r.permissions,
+            };
+            if current_roles.contains(&role) {
+                continue;
+            }
+
+            self.set_role_internal(role, None).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn role_id(&self, name: String) -> Result<i64, DataError> {
+        self.db_read(move |mut cmds| cmds.account_admin().role().role_id(name))
+            .await?
+            .ok_or(DataError::NotFound.report())
+    }
+
+    async fn update_permissions_and_send_events(
+        &self,
+        accounts: Vec<AccountIdInternal>,
+    ) -> Result<(), DataError> {
+        for id in accounts {
+            self.handle()
+                .account_admin()
+                .permissions()
+                .update_effective_permissions(id)
+                .await?;
+
+            self.handle()
+                .events()
+                .send_connected_event(id.uuid, EventToClientInternal::AccountStateChanged)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
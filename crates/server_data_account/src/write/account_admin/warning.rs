@@ -0,0 +1,77 @@
+use database::current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon};
+use database_account::current::{read::GetDbReadCommandsAccount, write::GetDbWriteCommandsAccount};
+use model::{Account, UnixTime};
+use model_account::{AccountBanReasonCategory, AccountBanReasonDetails, AccountIdInternal};
+use server_data::{
+    db_manager::InternalWriting, define_cmd_wrapper_write, read::DbRead, result::Result, write::{common_history::AdminAction, DbTransactionWithAdminAction, GetWriteCommandsCommon}, DataError
+};
+
+define_cmd_wrapper_write!(WriteCommandsAccountWarningAdmin);
+
+impl WriteCommandsAccountWarningAdmin<'_> {
+    /// Save new warning and change account state to
+    /// [model::AccountState::WarningPending].
+    ///
+    /// If the account reaches the strike threshold, the account is
+    /// banned temporarily in the same transaction. Removing the ban is
+    /// handled by the automatic unbanning.
+    ///
+    /// Returns the modified Account.
+    pub async fn warn_account(
+        &self,
+        id: AccountIdInternal,
+        admin_id: Option<AccountIdInternal>,
+        reason_category: Option<AccountBanReasonCategory>,
+        reason_details: Option<AccountBanReasonDetails>,
+        admin_action: Option<AdminAction>,
+    ) -> Result<Account, DataError> {
+        let limits = self.config().limits_account();
+        let strike_threshold = usize::from(limits.account_warning_strike_threshold);
+        let strike_window = limits
+            .account_warning_strike_window_duration
+            .seconds
+            .min(limits.account_warning_expiration_duration.seconds);
+        let strike_window_start = UnixTime::current_time().subtract_seconds(strike_window);
+        let strike_ban_until = UnixTime::current_time()
+            .add_seconds(limits.account_warning_strike_ban_duration.seconds);
+
+        let current_account = self
+            .db_read(move |mut cmds| cmds.common().account(id))
+            .await?;
+        let a = current_account.clone();
+        let new_account = self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
+            cmds.account_admin().warning().insert_warning(id, admin_id, reason_category, reason_details.clone())?;
+
+            let strikes = cmds.read().account().warning().warnings_created_after(id, strike_window_start)?;
+            let ban_state = cmds.read().account().ban().account_ban_time(id)?;
+            let strike_ban = strike_threshold != 0 &&
+                ban_state.banned_until.is_none() &&
+                strikes.len() >= strike_threshold;
+            if strike_ban {
+                cmds.account_admin().ban().set_banned_state(id, admin_id, Some(strike_ban_until), reason_category, reason_details)?;
+            }
+
+            cmds.common()
+                .state()
+                .update_syncable_account_data(id, a, move |state_container, _, visibility| {
+                    state_container.set_warning_pending(true);
+                    if strike_ban {
+                        state_container.set_banned(true);
+                        visibility.change_to_private_or_pending_private();
+                    }
+                    Ok(())
+                })
+        }).await?;
+
+        self.handle()
+            .common()
+            .internal_handle_new_account_data_after_db_modification(
+                id,
+                &current_account,
+                &new_account,
+            )
+            .await?;
+
+        Ok(new_account)
+    }
+}
@@ -5,19 +5,23 @@ use config::Config;
 use futures::{future::BoxFuture, FutureExt};
 use model::{
     Account, AccountIdInternal, EmailMessages, PendingNotification,
-    PendingNotificationWithData, SyncDataVersionFromClient,
+    PendingNotificationWithData, ProcessReport, ProfileVerificationContent,
+    SyncDataVersionFromClient,
 };
 use model_account::{EmailAddress, SignInWithInfo};
 use server_common::websocket::WebSocketError;
 use server_data::{
     app::DataAllUtils, db_manager::RouterDatabaseReadHandle,
-    write_commands::WriteCommandRunnerHandle, DataError,
+    write::common_history::AdminAction, write_commands::WriteCommandRunnerHandle, DataError,
 };
 use server_data_account::write::GetWriteCommandsAccount;
 use server_data_chat::read::GetReadChatCommands;
 use simple_backend::manager_client::ManagerApiClient;
 
-use crate::{register::RegisterAccount, unlimited_likes::UnlimitedLikesUpdate};
+use crate::{
+    profile_verification::ProfileVerificationUpdate, register::RegisterAccount,
+    unlimited_likes::UnlimitedLikesUpdate,
+};
 
 pub struct DataAllUtilsImpl;
 
@@ -40,6 +44,31 @@ impl DataAllUtils for DataAllUtilsImpl {
         .boxed()
     }
 
+    fn update_profile_verified<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        id: AccountIdInternal,
+        profile_verified: bool,
+        verified_content: Option<ProfileVerificationContent>,
+        admin_action: Option<AdminAction>,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>> {
+        async move {
+            write_command_runner
+                .write(move |cmds| async move {
+                    ProfileVerificationUpdate::new(cmds.deref())
+                        .update_profile_verified_value(
+                            id,
+                            profile_verified,
+                            verified_content,
+                            admin_action,
+                        )
+                        .await
+                })
+                .await
+        }
+        .boxed()
+    }
+
     fn register_impl<'a>(
         &self,
         write_command_runner: &'a WriteCommandRunnerHandle,
@@ -70,6 +99,29 @@ impl DataAllUtils for DataAllUtilsImpl {
         .boxed()
     }
 
+    fn process_report<'a>(
+        &self,
+        write_command_runner: &'a WriteCommandRunnerHandle,
+        moderator_id: AccountIdInternal,
+        creator: AccountIdInternal,
+        target: AccountIdInternal,
+        data: ProcessReport,
+        admin_action: AdminAction,
+    ) -> BoxFuture<'a, server_common::result::Result<(), DataError>> {
+        async move {
+            crate::report::process_report(
+                write_command_runner,
+                moderator_id,
+                creator,
+                target,
+                data,
+                admin_action,
+            )
+            .await
+        }
+        .boxed()
+    }
+
     fn handle_new_websocket_connection<'a>(
         &self,
         config: &'a Config,
@@ -19,7 +19,9 @@ macro_rules! db_transaction {
 pub mod app;
 pub mod initial_setup;
 pub mod load;
+pub mod profile_verification;
 pub mod push_notification;
 pub mod register;
+pub mod report;
 pub mod unlimited_likes;
 pub mod websocket;
@@ -4,17 +4,18 @@ use config::Config;
 use database::{
     current::read::GetDbReadCommandsCommon, CurrentReadHandle, DbReaderRaw, DieselDatabaseError,
 };
+use database_account::current::read::GetDbReadCommandsAccount;
 use database_chat::current::read::GetDbReadCommandsChat;
 use database_media::current::read::GetDbReadCommandsMedia;
 use database_profile::current::read::GetDbReadCommandsProfile;
 use error_stack::{Result, ResultExt};
-use model::AccountIdInternal;
+use model::{AccountIdInternal, UnixTime};
 pub use server_common::data::cache::CacheError;
 use server_common::data::WithInfo;
 use server_data::{
     cache::{
         account::CachedAccountComponentData, chat::CachedChatComponentData, media::CachedMedia,
-        profile::CachedProfile, DatabaseCache,
+        profile::CachedProfile, DatabaseCache, ExpiringPermissions,
     },
     index::{LocationIndexIteratorHandle, LocationIndexManager, LocationIndexWriteHandle},
 };
@@ -94,6 +95,20 @@ impl DbDataToCacheLoader {
             .db_read(move |mut cmds| cmds.common().state().account_permissions(account_id))
             .await?;
         entry.common.permissions = permissions;
+        if config.components().account {
+            let current_time = UnixTime::current_time();
+            let expiring_permissions = db
+                .db_read(move |mut cmds| {
+                    let individual = cmds.account_admin().role().individual_permissions(account_id)?;
+                    let roles = cmds
+                        .account_admin()
+                        .role()
+                        .active_role_permissions(account_id, current_time)?;
+                    Ok(individual.map(|individual| ExpiringPermissions::new(individual, roles)))
+                })
+                .await?;
+            entry.common.expiring_permissions = expiring_permissions.unwrap_or_default();
+        }
         let state = db
             .db_read(move |mut cmds| {
                 cmds.common()
@@ -154,6 +169,15 @@ impl DbDataToCacheLoader {
             let last_seen_unix_time = db
                 .db_read(move |mut cmds| cmds.profile().data().profile_last_seen_time(account_id))
                 .await?;
+            let passed_profiles = db
+                .db_read(move |mut cmds| cmds.profile().pass().passed_profiles(account_id))
+                .await?;
+            let current_time = UnixTime::current_time();
+            let seen_profiles = db
+                .db_read(move |mut cmds| {
+                    cmds.profile().pass().seen_profiles(account_id, current_time)
+                })
+                .await?;
 
             let mut profile_data = CachedProfile::new(
                 account_id.uuid,
@@ -163,6 +187,8 @@ impl DbDataToCacheLoader {
                 filters,
                 config,
                 last_seen_unix_time,
+                passed_profiles.into_iter().collect(),
+                seen_profiles.into_iter().collect(),
             );
 
             let location_area = index_writer.coordinates_to_area(profile_location, profile_data.state.max_distance_km_filter);
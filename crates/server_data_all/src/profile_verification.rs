@@ -0,0 +1,92 @@
+use database::current::write::GetDbWriteCommandsCommon;
+use database_media::current::read::GetDbReadCommandsMedia;
+use database_profile::current::write::GetDbWriteCommandsProfile;
+use model::ProfileVerificationContent;
+use model_profile::{AccountIdInternal, ProfileEditedTime, ProfileVersion};
+use server_data::{
+    app::GetConfig, cache::profile::UpdateLocationCacheState, db_manager::InternalWriting,
+    define_cmd_wrapper_write,
+    read::DbRead,
+    result::{Result, WrappedContextExt},
+    write::{common_history::AdminAction, DbTransactionWithAdminAction},
+    DataError, IntoDataError,
+};
+use server_data_profile::cache::CacheWriteProfile;
+
+define_cmd_wrapper_write!(ProfileVerificationUpdate);
+
+impl ProfileVerificationUpdate<'_> {
+    /// Media component decides the verification state and profile
+    /// component shows it, so update code is located in this crate.
+    ///
+    /// Does nothing if the cached value is not changed. Media content
+    /// changes revoke the verification from the database in the same
+    /// transaction as the content change, so this only syncs the cache
+    /// and profile version in that case.
+    ///
+    /// If `verified_content` is set, the content must be the current
+    /// accepted security content and primary profile content of the
+    /// account. Write commands are run one at a time, so the content
+    /// can not change between the check and the update.
+    pub async fn update_profile_verified_value(
+        &self,
+        id: AccountIdInternal,
+        profile_verified_value: bool,
+        verified_content: Option<ProfileVerificationContent>,
+        admin_action: Option<AdminAction>,
+    ) -> Result<(), DataError> {
+        if let Some(verified_content) = verified_content {
+            let media = self
+                .db_read(move |mut cmds| cmds.media().media_content().current_account_media(id))
+                .await?;
+            let security_content_matches = media.security_content_id.as_ref().is_some_and(|c| {
+                c.content_id() == verified_content.security_content_id && c.state().is_accepted()
+            });
+            let profile_content_matches = media.profile_content_id_0.as_ref().is_some_and(|c| {
+                c.content_id() == verified_content.profile_content_id && c.state().is_accepted()
+            });
+            if !security_content_matches || !profile_content_matches {
+                return Err(DataError::NotAllowed.report());
+            }
+        }
+
+        let current_value = self
+            .cache()
+            .read_cache_common(id, |e| Ok(e.other_shared_state.profile_verified))
+            .await
+            .into_data_error(id)?;
+        if current_value == profile_verified_value {
+            if admin_action.is_some() {
+                self.db_transaction_with_admin_action(admin_action, |_| Ok(())).await?;
+            }
+            return Ok(());
+        }
+
+        // Profile verified value is part of Profile, so update it's version
+        // (if profile component is enabled).
+        let new_profile_version = ProfileVersion::new_random();
+        let edited_time = ProfileEditedTime::current_time();
+        let is_profile_component_enabled = self.config().components().profile;
+        self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
+            if is_profile_component_enabled {
+                cmds.profile().data().required_changes_for_profile_update(id, new_profile_version, edited_time)?;
+            }
+            cmds.common()
+                .state()
+                .update_profile_verified(id, profile_verified_value)
+        }).await?;
+
+        self.write_cache_profile_and_common(id.as_id(), |p, e| {
+            e.other_shared_state.profile_verified = profile_verified_value;
+            p.data.version_uuid = new_profile_version;
+            p.state.profile_edited_time = edited_time;
+            Ok(())
+        })
+        .await
+        .into_data_error(id)?;
+
+        self.update_location_cache_profile(id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+use std::ops::Deref;
+
+use model::{
+    AccountIdInternal, EventToClientInternal, NotificationEvent, ProcessReport, ReportOutcome,
+    ReportTypeNumber,
+};
+use server_data::{
+    result::WrappedContextExt,
+    write::{
+        common_admin::report::ProcessedReport, common_history::AdminAction,
+        GetWriteCommandsCommon,
+    },
+    write_commands::WriteCommandRunnerHandle, DataError,
+};
+use server_data_account::write::GetWriteCommandsAccount;
+use server_data_media::{
+    read::GetReadMediaCommands,
+    write::{
+        media::InitialContentModerationResult, media_admin::content::ContentModerationMode,
+        GetWriteCommandsMedia,
+    },
+};
+use server_data_profile::write::{
+    profile_admin::profile_text::ModerateProfileTextMode, GetWriteCommandsProfile,
+};
+
+use crate::profile_verification::ProfileVerificationUpdate;
+
+/// Mark report as processed and run the moderation action related
+/// to the report outcome.
+///
+/// Everything runs in the same write command, so concurrent requests
+/// can't process the report twice. The report is marked processed in the
+/// same database transaction as the moderation action.
+pub async fn process_report(
+    write_handle: &WriteCommandRunnerHandle,
+    moderator_id: AccountIdInternal,
+    creator: AccountIdInternal,
+    target: AccountIdInternal,
+    data: ProcessReport,
+    admin_action: AdminAction,
+) -> server_common::result::Result<(), DataError> {
+    write_handle
+        .write(move |cmds| async move {
+            let report_id = cmds
+                .common_admin()
+                .report()
+                .report_to_process(creator, target, data.report_type, data.content.clone())
+                .await?;
+
+            let report = ProcessedReport {
+                moderator_id,
+                report_id,
+                outcome: data.outcome,
+                moderator_note: data.moderator_note,
+            };
+            let admin_action = admin_action.with_processed_report(report);
+
+            match data.outcome {
+                ReportOutcome::Dismissed => {
+                    cmds.common_admin()
+                        .report()
+                        .save_processed_report(admin_action)
+                        .await?;
+                }
+                ReportOutcome::Warned => {
+                    cmds.account_admin()
+                        .warning()
+                        .warn_account(target, Some(moderator_id), None, None, Some(admin_action))
+                        .await?;
+                    cmds.events()
+                        .send_connected_event(
+                            target,
+                            EventToClientInternal::AccountStateChanged,
+                        )
+                        .await?;
+                }
+                ReportOutcome::ContentRemoved => match data.report_type {
+                    ReportTypeNumber::ProfileName => {
+                        let name = data
+                            .content
+                            .profile_name
+                            .ok_or(DataError::NotAllowed.report())?;
+                        cmds.profile_admin()
+                            .profile_name_allowlist()
+                            .moderate_profile_name(moderator_id, target, name, false, Some(admin_action))
+                            .await?;
+                        cmds.events()
+                            .send_connected_event(target, EventToClientInternal::ProfileChanged)
+                            .await?;
+                    }
+                    ReportTypeNumber::ProfileText => {
+                        let text = data
+                            .content
+                            .profile_text
+                            .ok_or(DataError::NotAllowed.report())?;
+                        let mode = ModerateProfileTextMode::Moderate {
+                            moderator_id,
+                            accept: false,
+                            rejected_category: None,
+                            rejected_details: None,
+                        };
+                        cmds.profile_admin()
+                            .profile_text()
+                            .moderate_profile_text(mode, target, text, Some(admin_action))
+                            .await?;
+                        cmds.events()
+                            .send_connected_event(target, EventToClientInternal::ProfileChanged)
+                            .await?;
+                    }
+                    ReportTypeNumber::ProfileContent => {
+                        let content_id = data
+                            .content
+                            .profile_content
+                            .ok_or(DataError::NotAllowed.report())?;
+                        let content_id = cmds
+                            .read()
+                            .media()
+                            .content_id_internal(target, content_id)
+                            .await?;
+                        let mode = ContentModerationMode::Moderate {
+                            moderator_id,
+                            accept: false,
+                            rejected_category: None,
+                            rejected_details: None,
+                        };
+                        let info = cmds
+                            .media_admin()
+                            .content()
+                            .moderate_profile_content(mode, content_id, Some(admin_action))
+                            .await?;
+                        if info.profile_verification_revoke_needed {
+                            ProfileVerificationUpdate::new(cmds.deref())
+                                .update_profile_verified_value(target, false, None, None)
+                                .await?;
+                        }
+                        if let InitialContentModerationResult::AllModeratedAndNotAccepted =
+                            info.moderation_result
+                        {
+                            cmds.events()
+                                .send_notification(
+                                    target,
+                                    NotificationEvent::InitialContentModerationCompleted,
+                                )
+                                .await?;
+                        }
+                        cmds.events()
+                            .send_connected_event(
+                                target,
+                                EventToClientInternal::MediaContentChanged,
+                            )
+                            .await?;
+                    }
+                    ReportTypeNumber::ChatMessage | ReportTypeNumber::BanAppeal => {
+                        return Err(DataError::NotAllowed.report());
+                    }
+                },
+                ReportOutcome::Banned => {
+                    let ban_until = data.ban_until.ok_or(DataError::NotAllowed.report())?;
+                    let new_account = cmds
+                        .account_admin()
+                        .ban()
+                        .set_account_ban_state(
+                            target,
+                            Some(moderator_id),
+                            Some(ban_until),
+                            None,
+                            None,
+                            Some(admin_action),
+                        )
+                        .await?;
+                    if new_account.is_some() {
+                        cmds.events()
+                            .send_connected_event(
+                                target,
+                                EventToClientInternal::AccountStateChanged,
+                            )
+                            .await?;
+                    }
+                }
+            }
+
+            cmds.common_admin()
+                .report()
+                .end_report_triage_if_reviewed(target)
+                .await?;
+
+            // The notification does not contain any report related info,
+            // so the reporter can't find out what the outcome was.
+            cmds.events()
+                .send_notification(creator, NotificationEvent::ReportProcessed)
+                .await?;
+
+            Ok(())
+        })
+        .await
+}
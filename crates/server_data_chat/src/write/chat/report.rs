@@ -1,7 +1,7 @@
 use database_chat::current::write::GetDbWriteCommandsChat;
 use model::{AccountIdInternal, ReportTypeNumber, UpdateReportResult};
 use server_data::{
-    app::GetConfig, define_cmd_wrapper_write, read::DbRead, result::{Result, WrappedContextExt}, write::DbTransaction, DataError
+    app::GetConfig, define_cmd_wrapper_write, read::DbRead, result::{Result, WrappedContextExt}, write::{DbTransaction, GetWriteCommandsCommon}, DataError
 };
 use database::current::read::GetDbReadCommandsCommon;
 
@@ -45,6 +45,12 @@ impl WriteCommandsChatReport<'_> {
             Ok(())
         })?;
 
+        self.handle()
+            .common_admin()
+            .report()
+            .automatic_report_triage(target, ReportTypeNumber::ChatMessage)
+            .await?;
+
         Ok(UpdateReportResult::success())
     }
 }
@@ -1,6 +1,5 @@
 use database_media::current::read::GetDbReadCommandsMedia;
-use model::AccountIdInternal;
-use model_media::{GetProfileContentPendingModerationList, GetProfileContentPendingModerationParams};
+use model_media::{GetMediaContentDuplicateList, MediaContentDuplicateIteratorQuery};
 use server_data::{define_cmd_wrapper_read, read::DbRead, DataError, IntoDataError};
 
 use server_common::result::Result;
@@ -8,15 +7,14 @@ use server_common::result::Result;
 define_cmd_wrapper_read!(ReadCommandsMediaAdmin);
 
 impl ReadCommandsMediaAdmin<'_> {
-    pub async fn profile_content_pending_moderation_list(
+    pub async fn media_content_duplicate_page(
         &self,
-        moderator_id: AccountIdInternal,
-        params: GetProfileContentPendingModerationParams,
-    ) -> Result<GetProfileContentPendingModerationList, DataError> {
+        query: MediaContentDuplicateIteratorQuery,
+    ) -> Result<GetMediaContentDuplicateList, DataError> {
         self.db_read(move |mut cmds| {
             cmds.media_admin()
-                .content()
-                .profile_content_pending_moderation_list(moderator_id, params)
+                .duplicate()
+                .media_content_duplicate_page(query)
         })
         .await
         .into_error()
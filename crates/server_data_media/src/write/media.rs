@@ -3,9 +3,9 @@ use std::collections::HashSet;
 use database::current::{read::GetDbReadCommandsCommon, write::GetDbWriteCommandsCommon};
 use database_media::current::{read::GetDbReadCommandsMedia, write::GetDbWriteCommandsMedia};
 use error_stack::ResultExt;
-use model::{Account, AccountState, ContentIdInternal, ProfileVisibility};
+use model::{Account, AccountState, ContentIdInternal, PerceptualHash, ProfileVisibility};
 use model_media::{
-    AccountIdInternal, ContentId, ContentIdDb, ContentSlot, CurrentAccountMediaInternal, NewContentParams, ProfileContent, ProfileContentEditedTime, ProfileContentVersion, SetProfileContent
+    AccountIdInternal, ContentId, ContentIdDb, ContentSlot, CurrentAccountMediaInternal, NewContentParams, NsfwScores, ProfileContent, ProfileContentEditedTime, ProfileContentVersion, SetProfileContent
 };
 use server_data::{
     app::GetConfig, cache::profile::UpdateLocationCacheState, define_cmd_wrapper_write, file::{utils::TmpContentFile, FileWrite}, read::DbRead, result::{Result, WrappedContextExt}, write::{DbTransaction, GetWriteCommandsCommon}, DataError, DieselDatabaseError
@@ -24,10 +24,29 @@ pub enum InitialContentModerationResult {
     NoChange,
 }
 
+pub struct UpdateProfileContentResult {
+    pub initial_content_moderation: InitialContentModerationResult,
+    /// Primary profile content changed, so profile verification
+    /// was revoked from the database in the same transaction.
+    /// Cache and profile version update is still needed.
+    pub profile_verification_revoke_needed: bool,
+}
+
+pub struct UpdateSecurityContentResult {
+    /// Security content changed, so profile verification
+    /// was revoked from the database in the same transaction.
+    /// Cache and profile version update is still needed.
+    pub profile_verification_revoke_needed: bool,
+}
+
 pub struct DeleteContentResult {
     /// User can't remove in use images so this is true
     /// only when admin removes in use image.
     pub current_media_content_refresh_needed: bool,
+    /// Admin removed primary profile content or security content,
+    /// so profile verification was revoked from the database in the
+    /// same transaction. Cache and profile version update is still needed.
+    pub profile_verification_revoke_needed: bool,
 }
 
 define_cmd_wrapper_write!(WriteCommandsMedia);
@@ -40,6 +59,8 @@ impl<'a> WriteCommandsMedia<'a> {
 
 impl WriteCommandsMedia<'_> {
     /// Completes previous save_to_tmp.
+    ///
+    /// If `nsfw_move_to_human` is true, the content skips bot moderation.
     pub async fn save_img(
         &self,
         id: AccountIdInternal,
@@ -47,6 +68,9 @@ impl WriteCommandsMedia<'_> {
         slot: ContentSlot,
         new_content_params: NewContentParams,
         face_detected: bool,
+        perceptual_hash: PerceptualHash,
+        nsfw_scores: Option<NsfwScores>,
+        nsfw_move_to_human: bool,
     ) -> Result<ContentId, DataError> {
         let account = self.db_read(move |mut cmds| cmds.common().account(id)).await?;
         let slot = if account.state() == AccountState::InitialSetup {
@@ -81,6 +105,28 @@ impl WriteCommandsMedia<'_> {
         }
 
         let files = self.files().clone();
+        let duplicate_detection = self
+            .config()
+            .limits_media()
+            .duplicate_image_detection
+            .clone();
+        // Find duplicates before the write transaction. Content uploaded
+        // concurrently is not detected as duplicate.
+        let duplicates = if let Some(config) = &duplicate_detection {
+            let max_hash_distance = config.max_hash_distance;
+            self.db_read(move |mut cmds| {
+                cmds.media().media_content().find_duplicate_content(
+                    id,
+                    perceptual_hash,
+                    max_hash_distance,
+                )
+            })
+            .await?
+        } else {
+            vec![]
+        };
+        let move_duplicates_to_human_moderation = !duplicates.is_empty()
+            && duplicate_detection.is_some_and(|v| v.move_duplicates_to_human_moderation);
         let content_id = self.db_transaction(move |mut cmds| {
             let content_id = cmds.media()
                 .get_next_unique_content_id(id)?;
@@ -96,8 +142,26 @@ impl WriteCommandsMedia<'_> {
                     slot,
                     new_content_params,
                     face_detected,
+                    Some(perceptual_hash),
+                    nsfw_scores,
                 )?;
 
+            let content_id_internal = cmds.read()
+                .media()
+                .media_content()
+                .content_id_internal(id, content_id)?;
+            cmds.media()
+                .media_content()
+                .insert_perceptual_hash_bands(content_id_internal, perceptual_hash)?;
+            cmds.media()
+                .media_content()
+                .save_duplicate_content(content_id_internal, duplicates)?;
+            if nsfw_move_to_human || move_duplicates_to_human_moderation {
+                cmds.media()
+                    .media_content()
+                    .require_human_moderation(content_id_internal)?;
+            }
+
             // Move content from tmp dir to content dir
             tmp_img
                 .move_to_blocking(&processed_content_path)
@@ -121,13 +185,14 @@ impl WriteCommandsMedia<'_> {
         &self,
         id: AccountIdInternal,
         new: SetProfileContent,
-    ) -> Result<InitialContentModerationResult, DataError> {
+    ) -> Result<UpdateProfileContentResult, DataError> {
         let content_before_update = self.db_read(move |mut cmds| cmds.media().media_content().current_account_media(id)).await?;
 
         let version = ProfileContentVersion::new_random();
         let edit_time = ProfileContentEditedTime::current_time();
 
-        db_transaction!(self, move |mut cmds| {
+        let content_before_update_clone = content_before_update.clone();
+        let profile_verification_revoke_needed = db_transaction!(self, move |mut cmds| {
             cmds.media().media_content().required_changes_for_public_profile_content_update(
                 id,
                 version,
@@ -137,31 +202,53 @@ impl WriteCommandsMedia<'_> {
                 id,
                 new,
             )?;
-            cmds.media().media_content().increment_media_content_sync_version(id)
+            cmds.media().media_content().increment_media_content_sync_version(id)?;
+            let content_after_update = cmds.read().media().media_content().current_account_media(id)?;
+            let revoke_needed = content_after_update.profile_verification_content_changed(&content_before_update_clone);
+            if revoke_needed {
+                cmds.common().state().update_profile_verified(id, false)?;
+            }
+            Ok(revoke_needed)
         })?;
 
         self.public_profile_content_cache_update(id, (version, edit_time)).await?;
 
         self.update_content_usage(id, content_before_update).await?;
 
-        self.remove_pending_state_from_profile_visibility_if_needed(id).await
+        let initial_content_moderation = self.remove_pending_state_from_profile_visibility_if_needed(id).await?;
+
+        Ok(UpdateProfileContentResult {
+            initial_content_moderation,
+            profile_verification_revoke_needed,
+        })
     }
 
     pub async fn update_security_content(
         &self,
         content_id: ContentIdInternal,
-    ) -> Result<(), DataError> {
+    ) -> Result<UpdateSecurityContentResult, DataError> {
         let content_before_update = self.db_read(move |mut cmds| cmds.media().media_content().current_account_media(content_id.content_owner())).await?;
 
-        db_transaction!(self, move |mut cmds| {
+        let content_before_update_clone = content_before_update.clone();
+        let profile_verification_revoke_needed = db_transaction!(self, move |mut cmds| {
             cmds.media()
                 .media_content()
                 .update_security_content(content_id)?;
 
-            cmds.media().media_content().increment_media_content_sync_version(content_id.content_owner())
+            cmds.media().media_content().increment_media_content_sync_version(content_id.content_owner())?;
+            let content_after_update = cmds.read().media().media_content().current_account_media(content_id.content_owner())?;
+            let revoke_needed = content_after_update.profile_verification_content_changed(&content_before_update_clone);
+            if revoke_needed {
+                cmds.common().state().update_profile_verified(content_id.content_owner(), false)?;
+            }
+            Ok(revoke_needed)
         })?;
 
-        self.update_content_usage(content_id.content_owner(), content_before_update).await
+        self.update_content_usage(content_id.content_owner(), content_before_update).await?;
+
+        Ok(UpdateSecurityContentResult {
+            profile_verification_revoke_needed,
+        })
     }
 
     pub async fn delete_content(
@@ -175,6 +262,11 @@ impl WriteCommandsMedia<'_> {
                 .delete_content(content_id)?;
             let media_content_new = cmds.read().media().media_content().current_account_media(content_id.content_owner())?;
             let current_media_changed = media_content != media_content_new;
+            let profile_verification_revoke_needed =
+                media_content_new.profile_verification_content_changed(&media_content);
+            if profile_verification_revoke_needed {
+                cmds.common().state().update_profile_verified(content_id.content_owner(), false)?;
+            }
             let cache_update = if current_media_changed {
                 // Admin removed in use image, so current media content changed
                 cmds.media().media_content().increment_media_content_sync_version(content_id.content_owner())?;
@@ -197,6 +289,7 @@ impl WriteCommandsMedia<'_> {
             };
             let r = DeleteContentResult {
                 current_media_content_refresh_needed: current_media_changed,
+                profile_verification_revoke_needed,
             };
             Ok((r, cache_update))
         })?;
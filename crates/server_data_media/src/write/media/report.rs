@@ -3,7 +3,7 @@ use database_media::current::{read::GetDbReadCommandsMedia, write::GetDbWriteCom
 use model::{ContentId, ContentIdInternal, ReportTypeNumber, UpdateReportResult};
 use model_media::{AccountIdInternal, ContentModerationState, EventToClientInternal};
 use server_data::{
-    app::GetConfig, define_cmd_wrapper_write, read::DbRead, result::Result, write::DbTransaction, DataError
+    app::GetConfig, define_cmd_wrapper_write, read::DbRead, result::Result, write::{DbTransaction, GetWriteCommandsCommon}, DataError
 };
 
 use crate::write::{media_admin::content::ContentModerationMode, GetWriteCommandsMedia};
@@ -30,6 +30,7 @@ impl WriteCommandsMediaReport<'_> {
                 self.handle().media_admin().content().moderate_profile_content(
                     ContentModerationMode::MoveToHumanModeration,
                     content_id_internal,
+                    None,
                 ).await?;
                 send_event = true;
             }
@@ -68,6 +69,12 @@ impl WriteCommandsMediaReport<'_> {
             Ok(())
         })?;
 
+        self.handle()
+            .common_admin()
+            .report()
+            .automatic_report_triage(target, ReportTypeNumber::ProfileContent)
+            .await?;
+
         Ok(UpdateReportResult::success())
     }
 }
@@ -1,7 +1,8 @@
+use database::current::write::GetDbWriteCommandsCommon;
 use database_media::current::{read::GetDbReadCommandsMedia, write::GetDbWriteCommandsMedia};
-use model::{ContentIdInternal, AccountIdInternal, ProfileContentVersion};
-use model_media::{ProfileContentEditedTime, ProfileContentModerationRejectedReasonCategory, ProfileContentModerationRejectedReasonDetails};
-use server_data::{define_cmd_wrapper_write, read::DbRead, result::WrappedContextExt, write::DbTransaction, DataError};
+use model::{ContentIdInternal, AccountIdInternal, ModerationClaimQueue, ProfileContentVersion, UnixTime};
+use model_media::{GetProfileContentPendingModerationList, MediaContentRaw, GetProfileContentPendingModerationParams, ProfileContentEditedTime, ProfileContentModerationRejectedReasonCategory, ProfileContentModerationRejectedReasonDetails};
+use server_data::{db_manager::InternalWriting, define_cmd_wrapper_write, read::DbRead, result::WrappedContextExt, write::{common_history::AdminAction, DbTransaction, DbTransactionWithAdminAction}, DataError};
 
 use server_common::result::Result;
 
@@ -9,16 +10,45 @@ use crate::write::{media::InitialContentModerationResult, GetWriteCommandsMedia}
 
 pub struct ModerationResult {
     pub moderation_result: InitialContentModerationResult,
+    /// Primary profile content or security content was rejected,
+    /// so profile verification was revoked from the database in the
+    /// same transaction. Cache and profile version update is still needed.
+    pub profile_verification_revoke_needed: bool,
 }
 
 define_cmd_wrapper_write!(WriteCommandsProfileAdminContent);
 
 impl WriteCommandsProfileAdminContent<'_> {
+    /// Get pending profile content moderations and claim the content
+    /// for the moderator.
+    pub async fn claim_profile_content_pending_moderation_list(
+        &self,
+        moderator_id: AccountIdInternal,
+        params: GetProfileContentPendingModerationParams,
+    ) -> Result<GetProfileContentPendingModerationList, DataError> {
+        let lease = self.config().limits_common().moderation_claim.lease_duration;
+        db_transaction!(self, move |mut cmds| {
+            let (list, content_ids) = cmds
+                .read()
+                .media_admin()
+                .content()
+                .profile_content_pending_moderation_list(moderator_id, params)?;
+            cmds.common_admin().moderation_claim().claim_items(
+                moderator_id,
+                ModerationClaimQueue::MediaContent,
+                content_ids.iter().map(|v| *v.as_i64()).collect(),
+                UnixTime::current_time().add_seconds(lease.seconds),
+            )?;
+            Ok(list)
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn moderate_profile_content(
         &self,
         mode: ContentModerationMode,
         content_id: ContentIdInternal,
+        admin_action: Option<AdminAction>,
     ) -> Result<ModerationResult, DataError> {
         let current_content = self
             .db_read(move |mut cmds| cmds.media().media_content().get_media_content_raw(content_id))
@@ -30,7 +60,9 @@ impl WriteCommandsProfileAdminContent<'_> {
             return Err(DataError::NotAllowed.report());
         }
 
-        let cache_update = db_transaction!(self, move |mut cmds| {
+        let rejected = matches!(mode, ContentModerationMode::Moderate { accept: false, .. });
+
+        let (cache_update, profile_verification_revoke_needed) = self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
             cmds.media()
                 .media_content()
                 .increment_media_content_sync_version(content_id.content_owner())?;
@@ -54,11 +86,27 @@ impl WriteCommandsProfileAdminContent<'_> {
                         rejected_category,
                         rejected_details,
                     )?;
+                    cmds.common_admin().moderation_claim().complete_item(
+                        moderator_id,
+                        ModerationClaimQueue::MediaContent,
+                        *content_id.as_db_id().as_i64(),
+                    )?;
                 }
             };
 
             let current_account_media = cmds.read().media().media_content().current_account_media(content_id.content_owner())?;
-            if current_account_media.iter_current_profile_content().any(|v| v.content_id() == content_id.content_id()) {
+
+            let is_verification_content = |c: Option<&MediaContentRaw>| {
+                c.map(|c| c.content_id() == content_id.content_id()).unwrap_or_default()
+            };
+            let profile_verification_revoke_needed = rejected
+                && (is_verification_content(current_account_media.profile_content_id_0.as_ref())
+                    || is_verification_content(current_account_media.security_content_id.as_ref()));
+            if profile_verification_revoke_needed {
+                cmds.common().state().update_profile_verified(content_id.content_owner(), false)?;
+            }
+
+            let cache_update = if current_account_media.iter_current_profile_content().any(|v| v.content_id() == content_id.content_id()) {
                 // Public profile content accepted value might have
                 // changed, so update public profile content version
                 // and edit time.
@@ -67,11 +115,13 @@ impl WriteCommandsProfileAdminContent<'_> {
                 cmds.media()
                     .media_content()
                     .required_changes_for_public_profile_content_update(content_id.content_owner(), version, edit_time)?;
-                Ok(Some((version, edit_time)))
+                Some((version, edit_time))
             } else {
-                Ok(None)
-            }
-        })?;
+                None
+            };
+
+            Ok((cache_update, profile_verification_revoke_needed))
+        }).await?;
 
         if let Some(update) = cache_update {
             self.handle().media().public_profile_content_cache_update(content_id.content_owner(), update).await?;
@@ -81,6 +131,7 @@ impl WriteCommandsProfileAdminContent<'_> {
 
         Ok(ModerationResult {
             moderation_result: visibility_change,
+            profile_verification_revoke_needed,
         })
     }
 }
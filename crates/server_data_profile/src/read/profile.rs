@@ -1,17 +1,20 @@
+use std::collections::HashSet;
+
 use database_profile::current::read::GetDbReadCommandsProfile;
 use model_profile::{
-    AcceptedProfileAges, AccountIdInternal, GetMyProfileResult, Location, Profile,
+    AcceptedProfileAges, AccountId, AccountIdInternal, GetMyProfileResult, Location, Profile,
     ProfileAndProfileVersion, GetProfileFilteringSettings, ProfileInternal, ProfileStateInternal,
     UnixTime,
 };
 use server_data::{
-    define_cmd_wrapper_read, read::DbRead, result::Result, DataError, IntoDataError,
+    db_manager::InternalReading, define_cmd_wrapper_read, read::DbRead, result::Result, DataError, IntoDataError,
 };
 
 use crate::cache::CacheReadProfile;
 
 mod statistics;
 mod report;
+mod visit;
 
 define_cmd_wrapper_read!(ReadCommandsProfile);
 
@@ -23,6 +26,10 @@ impl<'a> ReadCommandsProfile<'a> {
     pub fn report(self) -> report::ReadCommandsProfileReport<'a> {
         report::ReadCommandsProfileReport::new(self.0)
     }
+
+    pub fn visit(self) -> visit::ReadCommandsProfileVisit<'a> {
+        visit::ReadCommandsProfileVisit::new(self.0)
+    }
 }
 
 impl ReadCommandsProfile<'_> {
@@ -39,14 +46,16 @@ impl ReadCommandsProfile<'_> {
         &self,
         id: AccountIdInternal,
     ) -> Result<ProfileAndProfileVersion, DataError> {
+        let attribute_info = self.config().profile_attributes();
         self.read_cache_profile_and_common(id, move |data, c| {
             Ok(ProfileAndProfileVersion {
                 profile: Profile::new(
                     data.data.clone(),
                     data.state.profile_name_moderation_state,
                     data.state.profile_text_moderation_state,
-                    data.attributes.attributes().clone(),
+                    data.attributes.attributes_with_met_conditions(attribute_info),
                     c.other_shared_state.unlimited_likes,
+                    c.other_shared_state.profile_verified,
                 ),
                 version: data.data.version_uuid,
                 last_seen_time: data.last_seen_time(c),
@@ -68,6 +77,28 @@ impl ReadCommandsProfile<'_> {
             .into_error()
     }
 
+    /// Accounts which have liked the account. The list is empty if
+    /// mutual interest profile order is disabled.
+    pub async fn mutual_interest_ranking_received_likes(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<HashSet<AccountId>, DataError> {
+        let enabled = self
+            .read_cache_profile_and_common(id, move |p, _| {
+                Ok(p.state.mutual_interest_profile_order)
+            })
+            .await?;
+
+        if !enabled {
+            return Ok(HashSet::new());
+        }
+
+        self.db_read(move |mut cmds| cmds.profile().favorite().received_like_senders(id))
+            .await
+            .map(HashSet::from_iter)
+            .into_error()
+    }
+
     pub async fn profile_location(&self, id: AccountIdInternal) -> Result<Location, DataError> {
         self.db_read(move |mut cmds| cmds.profile().data().profile_location(id))
             .await
@@ -103,10 +134,13 @@ impl ReadCommandsProfile<'_> {
                 filters,
                 last_seen_time_filter: state.last_seen_time_filter,
                 unlimited_likes_filter: state.unlimited_likes_filter,
+                verified_filter: state.verified_filter,
                 max_distance_km_filter: state.max_distance_km_filter,
                 profile_created_filter: state.profile_created_time_filter,
                 profile_edited_filter: state.profile_edited_time_filter,
                 random_profile_order: state.random_profile_order,
+                mutual_interest_profile_order: state.mutual_interest_profile_order,
+                hide_seen_profiles: state.hide_seen_profiles,
             })
         })
         .await
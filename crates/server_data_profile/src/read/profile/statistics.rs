@@ -1,7 +1,8 @@
+use database_profile::current::read::GetDbReadCommandsProfile;
 use model_profile::{
     ProfileAgeCounts, ProfileStatisticsInternal, PublicProfileCounts, StatisticsGender, StatisticsProfileVisibility, UnixTime
 };
-use server_data::{define_cmd_wrapper_read, result::Result, DataError};
+use server_data::{define_cmd_wrapper_read, read::DbRead, result::Result, DataError, IntoDataError};
 
 use crate::cache::CacheReadProfile;
 
@@ -58,11 +59,22 @@ impl ReadCommandsProfileStatistics<'_> {
         })
         .await?;
 
+        let visit_count_start_time = generation_time.subtract_seconds(60 * 60 * 24);
+        let profile_visit_count = self
+            .db_read(move |mut cmds| {
+                cmds.profile()
+                    .visit()
+                    .profile_visit_count_since(visit_count_start_time)
+            })
+            .await
+            .into_error()?;
+
         Ok(ProfileStatisticsInternal::new(
             generation_time,
             age_counts,
             account_count,
             public_profile_counts,
+            profile_visit_count,
         ))
     }
 }
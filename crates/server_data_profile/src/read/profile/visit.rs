@@ -0,0 +1,35 @@
+use database_profile::current::read::GetDbReadCommandsProfile;
+use model_profile::{AccountIdInternal, ProfileVisitId, ProfileVisitor};
+use server_data::{
+    cache::db_iterator::DbIteratorState, define_cmd_wrapper_read, read::DbRead, result::Result,
+    DataError, IntoDataError,
+};
+
+use crate::cache::CacheReadProfile;
+
+define_cmd_wrapper_read!(ReadCommandsProfileVisit);
+
+impl ReadCommandsProfileVisit<'_> {
+    pub async fn profile_visit_tracking(&self, id: AccountIdInternal) -> Result<bool, DataError> {
+        self.read_cache_profile_and_common(id, |p, _| Ok(p.state.profile_visit_tracking))
+            .await
+            .into_error()
+    }
+
+    pub async fn profile_visitors_page(
+        &self,
+        id: AccountIdInternal,
+        state: DbIteratorState<ProfileVisitId>,
+    ) -> Result<Vec<ProfileVisitor>, DataError> {
+        self.db_read(move |mut cmds| {
+            let value = cmds.profile().visit().paged_profile_visitors(
+                id,
+                state.id_at_reset(),
+                state.page().try_into().unwrap_or(i64::MAX),
+            )?;
+            Ok(value)
+        })
+        .await
+        .into_error()
+    }
+}
@@ -1,12 +1,13 @@
 use database::current::read::GetDbReadCommandsCommon;
 use database_profile::current::{read::GetDbReadCommandsProfile, write::GetDbWriteCommandsProfile};
 use model_profile::{
-    AccountIdInternal, Location, ProfileEditedTime, ProfileFilteringSettingsUpdateValidated, ProfileSearchAgeRangeValidated, ProfileStateInternal, ProfileUpdateValidated, ProfileVersion, ValidatedSearchGroups
+    AccountId, AccountIdInternal, Location, ProfileEditedTime, ProfileFilteringSettingsUpdateValidated, ProfileSearchAgeRangeValidated, ProfileStateInternal, ProfileUpdateValidated, ProfileVersion, UnixTime, ValidatedSearchGroups
 };
 use server_data::{
     app::GetConfig,
     cache::profile::UpdateLocationCacheState,
     define_cmd_wrapper_write,
+    id::ToAccountIdInternal,
     index::LocationWrite,
     read::DbRead,
     result::Result,
@@ -18,6 +19,7 @@ use tracing::info;
 use crate::cache::{CacheReadProfile, CacheWriteProfile};
 
 pub mod report;
+pub mod visit;
 
 define_cmd_wrapper_write!(WriteCommandsProfile);
 
@@ -25,6 +27,10 @@ impl<'a> WriteCommandsProfile<'a> {
     pub fn report(self) -> report::WriteCommandsProfileReport<'a> {
         report::WriteCommandsProfileReport::new(self.0)
     }
+
+    pub fn visit(self) -> visit::WriteCommandsProfileVisit<'a> {
+        visit::WriteCommandsProfileVisit::new(self.0)
+    }
 }
 
 impl WriteCommandsProfile<'_> {
@@ -175,12 +181,16 @@ impl WriteCommandsProfile<'_> {
     ) -> Result<(), DataError> {
         let config = self.config_arc().clone();
         let filters_clone = filters.clone();
+        let hide_seen_profiles = filters.hide_seen_profiles;
         let (new_filters, location) = db_transaction!(self, move |mut cmds| {
             cmds.profile().data().update_profile_filtering_settings(
                 id,
                 filters_clone,
                 config.profile_attributes(),
             )?;
+            if !hide_seen_profiles {
+                cmds.profile().pass().remove_all_seen_profiles(id)?;
+            }
             let attribute_filters = cmds.read().profile().data().profile_attribute_filters(id)?;
             let location = cmds.read().profile().data().profile_location(id)?;
             Ok((attribute_filters, location))
@@ -190,10 +200,16 @@ impl WriteCommandsProfile<'_> {
             p.filters = new_filters;
             p.state.last_seen_time_filter = filters.last_seen_time_filter;
             p.state.unlimited_likes_filter = filters.unlimited_likes_filter;
+            p.state.verified_filter = filters.verified_filter;
             p.state.max_distance_km_filter = filters.max_distance_km_filter;
             p.state.profile_created_time_filter = filters.profile_created_filter;
             p.state.profile_edited_time_filter = filters.profile_edited_filter;
             p.state.random_profile_order = filters.random_profile_order;
+            p.state.mutual_interest_profile_order = filters.mutual_interest_profile_order;
+            p.state.hide_seen_profiles = filters.hide_seen_profiles;
+            if !filters.hide_seen_profiles {
+                p.seen_profiles.clear();
+            }
 
             p.location.current_position = self.location().coordinates_to_area(location, filters.max_distance_km_filter);
 
@@ -245,6 +261,102 @@ impl WriteCommandsProfile<'_> {
         })
     }
 
+    /// Profile iterator will not return passed profiles.
+    /// Passing own profile is ignored.
+    pub async fn insert_passed_profile(
+        &self,
+        id: AccountIdInternal,
+        passed: AccountIdInternal,
+    ) -> Result<(), DataError> {
+        if id.as_id() == passed.as_id() {
+            return Ok(());
+        }
+
+        db_transaction!(self, move |mut cmds| {
+            cmds.profile().pass().insert_passed_profile(id, passed)
+        })?;
+
+        self.write_cache_profile(id.as_id(), |p| {
+            p.passed_profiles.insert(passed.as_id());
+            Ok(())
+        })
+        .await
+        .into_data_error(id)?;
+
+        Ok(())
+    }
+
+    pub async fn remove_all_passed_profiles(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<(), DataError> {
+        db_transaction!(self, move |mut cmds| {
+            cmds.profile().pass().remove_all_passed_profiles(id)
+        })?;
+
+        self.write_cache_profile(id.as_id(), |p| {
+            p.passed_profiles.clear();
+            Ok(())
+        })
+        .await
+        .into_data_error(id)?;
+
+        Ok(())
+    }
+
+    /// Seen profiles are recorded only when hiding seen profiles is enabled.
+    pub async fn insert_seen_profiles(
+        &self,
+        id: AccountIdInternal,
+        profiles: Vec<AccountId>,
+    ) -> Result<(), DataError> {
+        let hide_seen_profiles = self
+            .read_cache_profile_and_common(id.as_id(), |p, _| Ok(p.state.hide_seen_profiles))
+            .await
+            .into_data_error(id)?;
+        if !hide_seen_profiles || profiles.is_empty() {
+            return Ok(());
+        }
+
+        let mut seen = vec![];
+        for p in &profiles {
+            seen.push(self.to_account_id_internal(*p).await?);
+        }
+
+        let current_time = UnixTime::current_time();
+        let expiration_time = current_time.add_seconds(
+            self.config()
+                .limits_profile()
+                .seen_profile_expiration_duration
+                .seconds,
+        );
+
+        db_transaction!(self, move |mut cmds| {
+            cmds.profile()
+                .pass()
+                .upsert_seen_profiles(id, &seen, expiration_time)
+        })?;
+
+        self.write_cache_profile(id.as_id(), |p| {
+            p.remove_expired_seen_profiles(current_time);
+            p.mark_profiles_seen(&profiles, expiration_time);
+            Ok(())
+        })
+        .await
+        .into_data_error(id)?;
+
+        Ok(())
+    }
+
+    pub async fn delete_expired_seen_profiles(&self) -> Result<(), DataError> {
+        let current_time = UnixTime::current_time();
+        db_transaction!(self, move |mut cmds| {
+            cmds.profile()
+                .pass()
+                .delete_expired_seen_profiles(current_time)
+        })
+    }
+
     /// Updates the profile attributes sha256 and sync version for it for every
     /// account if needed.
     pub async fn update_profile_attributes_sha256_and_sync_versions(
@@ -314,6 +426,15 @@ impl WriteCommandsProfile<'_> {
         .await
     }
 
+    pub async fn update_profile_visit_tracking(
+        &self,
+        id: AccountIdInternal,
+        value: bool,
+    ) -> Result<(), DataError> {
+        self.modify_profile_state(id, |s| s.profile_visit_tracking = value)
+            .await
+    }
+
     pub async fn benchmark_update_profile_bypassing_cache(
         &self,
         id: AccountIdInternal,
@@ -2,7 +2,7 @@ use database::current::read::GetDbReadCommandsCommon;
 use database_profile::current::{read::GetDbReadCommandsProfile, write::GetDbWriteCommandsProfile};
 use model_profile::{AccountIdInternal, EventToClientInternal, ProfileNameModerationState, ProfileTextModerationState, ReportTypeNumber, UpdateReportResult};
 use server_data::{
-    app::GetConfig, define_cmd_wrapper_write, read::DbRead, result::Result, write::DbTransaction, DataError
+    app::GetConfig, define_cmd_wrapper_write, read::DbRead, result::Result, write::{DbTransaction, GetWriteCommandsCommon}, DataError
 };
 use tracing::warn;
 
@@ -49,6 +49,12 @@ impl WriteCommandsProfileReport<'_> {
             Ok(())
         })?;
 
+        self.handle()
+            .common_admin()
+            .report()
+            .automatic_report_triage(target, ReportTypeNumber::ProfileName)
+            .await?;
+
         Ok(UpdateReportResult::success())
     }
 
@@ -71,6 +77,7 @@ impl WriteCommandsProfileReport<'_> {
                 ModerateProfileTextMode::MoveToHumanModeration,
                 target,
                 profile_text.to_string(),
+                None,
             ).await?;
 
             self.handle()
@@ -100,6 +107,12 @@ impl WriteCommandsProfileReport<'_> {
             Ok(())
         })?;
 
+        self.handle()
+            .common_admin()
+            .report()
+            .automatic_report_triage(target, ReportTypeNumber::ProfileText)
+            .await?;
+
         Ok(UpdateReportResult::success())
     }
 }
@@ -0,0 +1,78 @@
+use database_profile::current::{read::GetDbReadCommandsProfile, write::GetDbWriteCommandsProfile};
+use model_profile::{
+    AccountIdInternal, ProfileVisitDay, ProfileVisitorsIteratorSessionIdInternal, UnixTime,
+};
+use server_data::{
+    define_cmd_wrapper_write, read::DbRead, result::Result, write::DbTransaction, DataError,
+    IntoDataError,
+};
+
+use crate::cache::CacheWriteProfile;
+
+define_cmd_wrapper_write!(WriteCommandsProfileVisit);
+
+impl WriteCommandsProfileVisit<'_> {
+    /// Only the first visit during a day is stored. Visits to own
+    /// profile are ignored.
+    pub async fn insert_profile_visit(
+        &self,
+        target: AccountIdInternal,
+        visitor: AccountIdInternal,
+    ) -> Result<(), DataError> {
+        if target.as_id() == visitor.as_id() {
+            return Ok(());
+        }
+
+        let day = ProfileVisitDay::from_unix_time(UnixTime::current_time());
+        let new_visit = self
+            .write_cache_profile(visitor.as_id(), |p| {
+                Ok(p.recorded_profile_visits.mark_recorded(target.as_id(), day))
+            })
+            .await
+            .into_data_error(visitor)?;
+        if !new_visit {
+            return Ok(());
+        }
+
+        let result = db_transaction!(self, move |mut cmds| {
+            cmds.profile().visit().insert_profile_visit(target, visitor)
+        });
+
+        if result.is_err() {
+            self.write_cache_profile(visitor.as_id(), |p| {
+                p.recorded_profile_visits.remove(target.as_id());
+                Ok(())
+            })
+            .await
+            .into_data_error(visitor)?;
+        }
+
+        result
+    }
+
+    pub async fn handle_reset_profile_visitors_iterator(
+        &self,
+        id: AccountIdInternal,
+    ) -> Result<ProfileVisitorsIteratorSessionIdInternal, DataError> {
+        let latest_used_id = self
+            .db_read(|mut cmds| cmds.profile().visit().latest_profile_visit_id())
+            .await?;
+        let session_id = self
+            .write_cache_profile(id.as_id(), |p| {
+                Ok(p.profile_visitors_iterator.reset(latest_used_id))
+            })
+            .await
+            .into_data_error(id)?;
+
+        Ok(session_id)
+    }
+
+    pub async fn delete_profile_visits_older_than(
+        &self,
+        time: UnixTime,
+    ) -> Result<(), DataError> {
+        db_transaction!(self, move |mut cmds| {
+            cmds.profile().visit().delete_profile_visits_older_than(time)
+        })
+    }
+}
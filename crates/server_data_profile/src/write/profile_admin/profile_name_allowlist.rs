@@ -1,11 +1,16 @@
+use database::current::write::GetDbWriteCommandsCommon;
 use database_profile::current::{read::GetDbReadCommandsProfile, write::GetDbWriteCommandsProfile};
-use model_profile::{AccountIdInternal, ProfileEditedTime, ProfileVersion};
+use model_profile::{
+    AccountIdInternal, GetProfileNamePendingModerationList, ModerationClaimQueue,
+    ProfileEditedTime, ProfileVersion, UnixTime,
+};
 use server_data::{
     cache::profile::UpdateLocationCacheState,
+    db_manager::InternalWriting,
     define_cmd_wrapper_write,
     read::DbRead,
     result::{Result, WrappedContextExt},
-    write::DbTransaction,
+    write::{common_history::AdminAction, DbTransaction, DbTransactionWithAdminAction},
     DataError, IntoDataError,
 };
 
@@ -14,12 +19,36 @@ use crate::cache::CacheWriteProfile;
 define_cmd_wrapper_write!(WriteCommandsProfileAdminProfileNameAllowlist);
 
 impl WriteCommandsProfileAdminProfileNameAllowlist<'_> {
+    /// Get pending profile name moderations and claim the names
+    /// for the moderator.
+    pub async fn claim_profile_name_pending_moderation_list(
+        &self,
+        moderator_id: AccountIdInternal,
+    ) -> Result<GetProfileNamePendingModerationList, DataError> {
+        let lease = self.config().limits_common().moderation_claim.lease_duration;
+        db_transaction!(self, move |mut cmds| {
+            let (list, owner_ids) = cmds
+                .read()
+                .profile_admin()
+                .profile_name_allowlist()
+                .profile_name_pending_moderation_list(moderator_id)?;
+            cmds.common_admin().moderation_claim().claim_items(
+                moderator_id,
+                ModerationClaimQueue::ProfileName,
+                owner_ids.iter().map(|v| *v.as_i64()).collect(),
+                UnixTime::current_time().add_seconds(lease.seconds),
+            )?;
+            Ok(list)
+        })
+    }
+
     pub async fn moderate_profile_name(
         &self,
         moderator_id: AccountIdInternal,
         name_owner_id: AccountIdInternal,
         name: String,
         accept: bool,
+        admin_action: Option<AdminAction>,
     ) -> Result<(), DataError> {
         let current_profile = self
             .db_read(move |mut cmds| cmds.profile().data().profile(name_owner_id))
@@ -40,7 +69,7 @@ impl WriteCommandsProfileAdminProfileNameAllowlist<'_> {
         // Profile name accepted value is part of Profile, so update it's version
         let new_profile_version = ProfileVersion::new_random();
         let edit_time = ProfileEditedTime::current_time();
-        let new_state = db_transaction!(self, move |mut cmds| {
+        let new_state = self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
             cmds.profile()
                 .data()
                 .required_changes_for_profile_update(name_owner_id, new_profile_version, edit_time)?;
@@ -48,8 +77,13 @@ impl WriteCommandsProfileAdminProfileNameAllowlist<'_> {
                 .profile_admin()
                 .profile_name_allowlist()
                 .moderate_profile_name(moderator_id, name_owner_id, name, accept)?;
+            cmds.common_admin().moderation_claim().complete_item(
+                moderator_id,
+                ModerationClaimQueue::ProfileName,
+                *name_owner_id.as_db_id().as_i64(),
+            )?;
             Ok(new_state)
-        })?;
+        }).await?;
 
         self.write_cache_profile(name_owner_id.as_id(), |p| {
             p.state.profile_name_moderation_state = new_state;
@@ -1,13 +1,15 @@
+use database::current::write::GetDbWriteCommandsCommon;
 use database_profile::current::{read::GetDbReadCommandsProfile, write::GetDbWriteCommandsProfile};
 use model_profile::{
-    AccountIdInternal, ProfileEditedTime, ProfileTextModerationRejectedReasonCategory, ProfileTextModerationRejectedReasonDetails, ProfileVersion
+    AccountIdInternal, GetProfileTextPendingModerationList, GetProfileTextPendingModerationParams, ModerationClaimQueue, ProfileEditedTime, ProfileTextModerationRejectedReasonCategory, ProfileTextModerationRejectedReasonDetails, ProfileVersion, UnixTime
 };
 use server_data::{
     cache::profile::UpdateLocationCacheState,
+    db_manager::InternalWriting,
     define_cmd_wrapper_write,
     read::DbRead,
     result::{Result, WrappedContextExt},
-    write::DbTransaction,
+    write::{common_history::AdminAction, DbTransaction, DbTransactionWithAdminAction},
     DataError, IntoDataError,
 };
 
@@ -16,12 +18,37 @@ use crate::cache::CacheWriteProfile;
 define_cmd_wrapper_write!(WriteCommandsProfileAdminProfileText);
 
 impl WriteCommandsProfileAdminProfileText<'_> {
+    /// Get pending profile text moderations and claim the texts
+    /// for the moderator.
+    pub async fn claim_profile_text_pending_moderation_list(
+        &self,
+        moderator_id: AccountIdInternal,
+        params: GetProfileTextPendingModerationParams,
+    ) -> Result<GetProfileTextPendingModerationList, DataError> {
+        let lease = self.config().limits_common().moderation_claim.lease_duration;
+        db_transaction!(self, move |mut cmds| {
+            let (list, owner_ids) = cmds
+                .read()
+                .profile_admin()
+                .profile_text()
+                .profile_text_pending_moderation_list(moderator_id, params)?;
+            cmds.common_admin().moderation_claim().claim_items(
+                moderator_id,
+                ModerationClaimQueue::ProfileText,
+                owner_ids.iter().map(|v| *v.as_i64()).collect(),
+                UnixTime::current_time().add_seconds(lease.seconds),
+            )?;
+            Ok(list)
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn moderate_profile_text(
         &self,
         mode: ModerateProfileTextMode,
         data_owner_id: AccountIdInternal,
         text: String,
+        admin_action: Option<AdminAction>,
     ) -> Result<(), DataError> {
         let current_profile = self
             .db_read(move |mut cmds| cmds.profile().data().profile(data_owner_id))
@@ -42,7 +69,7 @@ impl WriteCommandsProfileAdminProfileText<'_> {
         // Profile text accepted value is part of Profile, so update it's version
         let new_profile_version = ProfileVersion::new_random();
         let edit_time = ProfileEditedTime::current_time();
-        let new_state = db_transaction!(self, move |mut cmds| {
+        let new_state = self.db_transaction_with_admin_action(admin_action, move |mut cmds| {
             cmds.profile()
                 .data()
                 .required_changes_for_profile_update(data_owner_id, new_profile_version, edit_time)?;
@@ -56,16 +83,23 @@ impl WriteCommandsProfileAdminProfileText<'_> {
                     accept,
                     rejected_category,
                     rejected_details
-                } => cmds.profile_admin().profile_text().moderate_profile_text(
-                    moderator_id,
-                    data_owner_id,
-                    accept,
-                    rejected_category,
-                    rejected_details,
-                )?,
+                } => {
+                    cmds.common_admin().moderation_claim().complete_item(
+                        moderator_id,
+                        ModerationClaimQueue::ProfileText,
+                        *data_owner_id.as_db_id().as_i64(),
+                    )?;
+                    cmds.profile_admin().profile_text().moderate_profile_text(
+                        moderator_id,
+                        data_owner_id,
+                        accept,
+                        rejected_category,
+                        rejected_details,
+                    )?
+                }
             };
             Ok(new_state)
-        })?;
+        }).await?;
 
         self.write_cache_profile(data_owner_id.as_id(), |p| {
             p.state.profile_text_moderation_state = new_state;
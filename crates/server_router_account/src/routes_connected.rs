@@ -22,6 +22,8 @@ impl ConnectedApp {
             .merge(api::common_admin::router_config(self.state.clone()))
             .merge(api::common_admin::router_perf(self.state.clone()))
             .merge(api::common_admin::router_report(self.state.clone()))
+            .merge(api::common_admin::router_audit_log(self.state.clone()))
+            .merge(api::common_admin::router_moderation_claim(self.state.clone()))
             .route_layer({
                 middleware::from_fn_with_state(
                     self.state.s.clone(),
@@ -40,13 +42,16 @@ impl ConnectedApp {
             .merge(api::account::router_state(self.state.clone()))
             .merge(api::account::router_news(self.state.clone()))
             .merge(api::account::router_account_report(self.state.clone()))
+            .merge(api::account::router_warning(self.state.clone()))
             .merge(api::account_admin::router_admin_ban(self.state.clone()))
             .merge(api::account_admin::router_admin_delete(self.state.clone()))
             .merge(api::account_admin::router_admin_news(self.state.clone()))
             .merge(api::account_admin::router_admin_search(self.state.clone()))
             .merge(api::account_admin::router_admin_permissions(self.state.clone()))
+            .merge(api::account_admin::router_admin_role(self.state.clone()))
             .merge(api::account_admin::router_admin_state(self.state.clone()))
-            .merge(api::account_admin::router_admin_account_report(self.state.clone()));
+            .merge(api::account_admin::router_admin_account_report(self.state.clone()))
+            .merge(api::account_admin::router_admin_warning(self.state.clone()));
 
         private.route_layer({
             middleware::from_fn_with_state(
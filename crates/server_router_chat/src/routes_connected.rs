@@ -17,19 +17,23 @@ impl ConnectedApp {
 
 
     pub fn private_chat_server_router(&self) -> Router {
+        let interaction = Router::new()
+            .merge(api::chat::like::router_like(self.state.clone()))
+            .merge(api::chat::message::router_message(self.state.clone()))
+            .merge(
+                api::chat::report::router_chat_report(self.state.clone()),
+            )
+            .route_layer(middleware::from_fn(api::utils::reject_if_warning_pending));
+
         Router::new()
             // Chat
-            .merge(api::chat::like::router_like(self.state.clone()))
+            .merge(interaction)
             .merge(api::chat::block::router_block(self.state.clone()))
             .merge(api::chat::match_routes::router_match(self.state.clone()))
-            .merge(api::chat::message::router_message(self.state.clone()))
             .merge(api::chat::public_key::router_public_key(self.state.clone()))
             .merge(
                 api::chat::push_notifications::router_push_notification_private(self.state.clone()),
             )
-            .merge(
-                api::chat::report::router_chat_report(self.state.clone()),
-            )
             .route_layer({
                 middleware::from_fn_with_state(
                     self.state.s.clone(),
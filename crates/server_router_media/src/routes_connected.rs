@@ -28,6 +28,12 @@ impl ConnectedApp {
             .merge(api::media_admin::router_admin_moderation(
                 self.state.clone(),
             ))
+            .merge(api::media_admin::router_admin_profile_verification(
+                self.state.clone(),
+            ))
+            .merge(api::media_admin::router_admin_duplicate(
+                self.state.clone(),
+            ))
             .route_layer({
                 middleware::from_fn_with_state(
                     self.state.s.clone(),
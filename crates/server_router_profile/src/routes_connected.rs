@@ -21,13 +21,19 @@ impl ConnectedApp {
     }
 
     pub fn private_profile_server_router(&self) -> Router {
+        let interaction = Router::new()
+            .merge(api::profile::router_profile_report(self.state.clone()))
+            .merge(api::profile::router_favorite(self.state.clone()))
+            .merge(api::profile::router_pass(self.state.clone()))
+            .merge(api::profile::router_iterate_profiles(self.state.clone()))
+            .merge(api::profile::router_profile_visit(self.state.clone()))
+            .route_layer(middleware::from_fn(api::utils::reject_if_warning_pending));
+
         let private = Router::new()
+            .merge(interaction)
             .merge(api::profile::router_filters(self.state.clone()))
             .merge(api::profile::router_profile_data(self.state.clone()))
-            .merge(api::profile::router_profile_report(self.state.clone()))
             .merge(api::profile::router_location(self.state.clone()))
-            .merge(api::profile::router_favorite(self.state.clone()))
-            .merge(api::profile::router_iterate_profiles(self.state.clone()))
             .merge(api::profile::router_statistics(self.state.clone()))
             .merge(api::profile_admin::router_admin_statistics(
                 self.state.clone(),
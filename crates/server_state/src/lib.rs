@@ -9,7 +9,7 @@ use axum::extract::ws::WebSocket;
 use config::Config;
 use model::{
     Account, AccountIdInternal, PendingNotification, PendingNotificationWithData,
-    SyncDataVersionFromClient,
+    ProcessReport, ProfileVerificationContent, SyncDataVersionFromClient,
 };
 use model_chat::SignInWithInfo;
 use model_server_data::EmailAddress;
@@ -17,7 +17,7 @@ use server_common::{push_notifications::PushNotificationSender, websocket::WebSo
 use server_data::{
     app::{DataAllUtils, GetConfig}, content_processing::ContentProcessingManagerData,
     db_manager::RouterDatabaseReadHandle, statistics::ProfileStatisticsCache,
-    write_commands::WriteCommandRunnerHandle,
+    write::common_history::AdminAction, write_commands::WriteCommandRunnerHandle,
 };
 use simple_backend::app::SimpleBackendAppState;
 
@@ -27,6 +27,7 @@ use crate::demo::DemoModeManager;
 pub mod app;
 pub mod demo;
 pub mod internal_api;
+pub mod route_permission;
 pub mod state_impl;
 pub mod utils;
 
@@ -126,6 +127,23 @@ impl DataAllAccess<'_> {
         cmd.await
     }
 
+    pub async fn update_profile_verified(
+        &self,
+        id: AccountIdInternal,
+        profile_verified: bool,
+        verified_content: Option<ProfileVerificationContent>,
+        admin_action: Option<AdminAction>,
+    ) -> server_common::result::Result<(), DataError> {
+        let cmd = self.utils().update_profile_verified(
+            self.write(),
+            id,
+            profile_verified,
+            verified_content,
+            admin_action,
+        );
+        cmd.await
+    }
+
     pub async fn register_impl(
         &self,
         sign_in_with: SignInWithInfo,
@@ -137,6 +155,20 @@ impl DataAllAccess<'_> {
         cmd.await
     }
 
+    pub async fn process_report(
+        &self,
+        moderator_id: AccountIdInternal,
+        creator: AccountIdInternal,
+        target: AccountIdInternal,
+        data: ProcessReport,
+        admin_action: AdminAction,
+    ) -> server_common::result::Result<(), DataError> {
+        let cmd = self
+            .utils()
+            .process_report(self.write(), moderator_id, creator, target, data, admin_action);
+        cmd.await
+    }
+
     pub async fn handle_new_websocket_connection(
         &self,
         socket: &mut WebSocket,
@@ -290,7 +322,8 @@ macro_rules! __route {
             use utoipa_axum::PathItemExt;
             let mut paths = utoipa::openapi::path::Paths::new();
             let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
-            let (path, item, types) = utoipa_axum::routes!(@resolve_types $route_name : schemas);
+            let (path, mut item, types) = utoipa_axum::routes!(@resolve_types $route_name : schemas);
+            $crate::route_permission::document_route_handler_permissions(&$route_name, &mut item);
             let path = $crate::obfuscate_api_path(&$state, path);
             #[allow(unused_mut)]
             let mut method_router = types.iter().by_ref().fold(axum::routing::MethodRouter::new(), |router, path_type| {
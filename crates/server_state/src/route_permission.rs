@@ -0,0 +1,85 @@
+//! Find permissions which route handler requires from the handler's
+//! argument types, so that API doc can be generated from the same
+//! extractors which check the permissions.
+
+use std::net::SocketAddr;
+
+use axum::{
+    body::Body,
+    extract::{ws::WebSocketUpgrade, ConnectInfo, Path, Query, State},
+    http::HeaderMap,
+    Extension,
+};
+use simple_backend::web_socket::WebSocketManager;
+use utoipa::openapi::{path::Operation, security::SecurityRequirement};
+
+/// Route handler argument which might require a permission.
+pub trait RouteArgumentPermission {
+    /// [model::Permissions] field name
+    const REQUIRED_PERMISSION: Option<&'static str> = None;
+}
+
+impl<T> RouteArgumentPermission for State<T> {}
+impl<T> RouteArgumentPermission for Extension<T> {}
+impl<T> RouteArgumentPermission for Path<T> {}
+impl<T> RouteArgumentPermission for Query<T> {}
+impl RouteArgumentPermission for ConnectInfo<SocketAddr> {}
+impl RouteArgumentPermission for Body {}
+impl RouteArgumentPermission for HeaderMap {}
+impl RouteArgumentPermission for WebSocketUpgrade {}
+impl RouteArgumentPermission for WebSocketManager {}
+// Optional argument is not required, so permission is also optional.
+impl<T> RouteArgumentPermission for Option<T> {}
+
+pub trait RouteHandlerPermissions<M> {
+    fn required_permissions() -> Vec<&'static str>;
+}
+
+macro_rules! impl_route_handler_permissions {
+    ( $( $arg:ident ),* ) => {
+        impl<F, Fut, $( $arg, )*> RouteHandlerPermissions<( $( $arg, )* )> for F
+        where
+            F: FnOnce( $( $arg, )* ) -> Fut,
+            $( $arg: RouteArgumentPermission, )*
+        {
+            fn required_permissions() -> Vec<&'static str> {
+                let permissions: &[Option<&'static str>] = &[
+                    $( <$arg as RouteArgumentPermission>::REQUIRED_PERMISSION, )*
+                ];
+                permissions.iter().flatten().copied().collect()
+            }
+        }
+    };
+}
+
+impl_route_handler_permissions!();
+impl_route_handler_permissions!(T1);
+impl_route_handler_permissions!(T1, T2);
+impl_route_handler_permissions!(T1, T2, T3);
+impl_route_handler_permissions!(T1, T2, T3, T4);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5, T6);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5, T6, T7);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5, T6, T7, T8);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_route_handler_permissions!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+/// Replace "access_token" security scopes with permissions which
+/// the route handler requires. Operations without required permissions
+/// are not modified.
+pub fn document_route_handler_permissions<M, H: RouteHandlerPermissions<M>>(
+    _handler: &H,
+    operation: &mut Operation,
+) {
+    let permissions = H::required_permissions();
+    if permissions.is_empty() {
+        return;
+    }
+
+    operation.security = Some(vec![SecurityRequirement::new(
+        "access_token",
+        permissions,
+    )]);
+}
@@ -265,6 +265,9 @@ impl ManagerEventHandler for S {
                     EventToClientInternal::ScheduledMaintenanceStatus(status),
                 ).await
             }
+            ServerEventType::SoftwareRollback(rollback) => {
+                tracing::warn!("Backend was rolled back: {:?}", rollback);
+            }
         }
     }
 }
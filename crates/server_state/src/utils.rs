@@ -2,8 +2,10 @@ use axum::response::{IntoResponse, Response};
 use config::file::ConfigFileError;
 use manager_api::ClientError;
 use server_common::{data::cache::CacheError, internal_api::InternalApiError};
+use serde::Serialize;
 use server_data::{content_processing::ContentProcessingError, event::EventError};
 use simple_backend::sign_in_with::{apple::SignInWithAppleError, google::SignInWithGoogleError};
+use utoipa::ToSchema;
 
 use crate::DataError;
 
@@ -13,6 +15,8 @@ pub enum StatusCode {
     BAD_REQUEST,
     /// 401
     UNAUTHORIZED,
+    /// 403
+    FORBIDDEN,
     /// 500
     INTERNAL_SERVER_ERROR,
     /// 406
@@ -21,6 +25,9 @@ pub enum StatusCode {
     NOT_FOUND,
     /// 304
     NOT_MODIFIED,
+    /// 403 with [PermissionDenied] body. The value is
+    /// [model::Permissions] field name.
+    PERMISSION_DENIED(&'static str),
 }
 
 impl From<StatusCode> for hyper::StatusCode {
@@ -28,21 +35,44 @@ impl From<StatusCode> for hyper::StatusCode {
         match value {
             StatusCode::BAD_REQUEST => hyper::StatusCode::BAD_REQUEST,
             StatusCode::UNAUTHORIZED => hyper::StatusCode::UNAUTHORIZED,
+            StatusCode::FORBIDDEN => hyper::StatusCode::FORBIDDEN,
             StatusCode::INTERNAL_SERVER_ERROR => hyper::StatusCode::INTERNAL_SERVER_ERROR,
             StatusCode::NOT_ACCEPTABLE => hyper::StatusCode::NOT_ACCEPTABLE,
             StatusCode::NOT_FOUND => hyper::StatusCode::NOT_FOUND,
             StatusCode::NOT_MODIFIED => hyper::StatusCode::NOT_MODIFIED,
+            StatusCode::PERMISSION_DENIED(_) => hyper::StatusCode::FORBIDDEN,
         }
     }
 }
 
 impl IntoResponse for StatusCode {
     fn into_response(self) -> Response {
+        if let StatusCode::PERMISSION_DENIED(required_permission) = self {
+            return PermissionDenied {
+                required_permission: required_permission.to_string(),
+            }
+            .into_response();
+        }
+
         let status: hyper::StatusCode = self.into();
         status.into_response()
     }
 }
 
+/// Response body for [hyper::StatusCode::FORBIDDEN] when API caller does
+/// not have the permission which the route requires.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PermissionDenied {
+    /// [model::Permissions] field name
+    pub required_permission: String,
+}
+
+impl IntoResponse for PermissionDenied {
+    fn into_response(self) -> Response {
+        (hyper::StatusCode::FORBIDDEN, axum::Json(self)).into_response()
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 enum RequestError {
     #[error("Data reading or writing failed")]
@@ -34,6 +34,7 @@ use axum::Router;
 use futures::future::poll_fn;
 use hyper::body::Incoming;
 use hyper_util::rt::{TokioExecutor, TokioIo};
+use manager_model::{BACKEND_LOG_FILE_NAME_PREFIX, BACKEND_LOG_FILE_NAME_SUFFIX};
 use manager_client::{ManagerApiClient, ManagerConnectionManager, ManagerEventHandler};
 use media_backup::MediaBackupHandle;
 use perf::AllCounters;
@@ -185,6 +186,29 @@ impl<T: BusinessLogic> SimpleBackend<T> {
             )
         };
 
+        // Keep the guard until the server quits so that all
+        // log messages are written to the log file.
+        let (log_file_layer, _log_file_guard) = if let Some(config) = self.config.log_file() {
+            let mut builder = tracing_appender::rolling::Builder::new()
+                .rotation(tracing_appender::rolling::Rotation::DAILY)
+                .filename_prefix(BACKEND_LOG_FILE_NAME_PREFIX)
+                .filename_suffix(BACKEND_LOG_FILE_NAME_SUFFIX);
+            if let Some(max_files) = config.max_files {
+                builder = builder.max_log_files(max_files);
+            }
+            let appender = builder
+                .build(&config.dir)
+                .expect("Log file appender init failed");
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = tracing_subscriber::fmt::layer()
+                .json()
+                .with_writer(writer)
+                .with_filter(EnvFilter::from_default_env());
+            (Some(layer), Some(guard))
+        } else {
+            (None, None)
+        };
+
         // tokio-console is disabled currently
         // let tokio_console_layer = if cfg!(debug_assertions) {
         //     Some(console_subscriber::spawn())
@@ -196,6 +220,7 @@ impl<T: BusinessLogic> SimpleBackend<T> {
             // .with(tokio_console_layer)
             .with(log_with_timestamp_layer)
             .with(log_without_timestamp_layer)
+            .with(log_file_layer)
             .init();
 
         info!(
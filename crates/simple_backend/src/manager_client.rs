@@ -165,6 +165,7 @@ impl <T: ManagerEventHandler> ManagerConnectionManager<T> {
                     };
                     self.client.latest_scheduled_reboot.store(ut, Ordering::Relaxed);
                 }
+                ServerEventType::SoftwareRollback(_) => (),
             }
             self.event_handler.handle(event.event()).await;
         }
@@ -26,6 +26,12 @@ pub const DEFAULT_CONFIG_FILE_TEXT: &str = r#"
 # [general]
 # log_timestamp = true
 
+# Write log messages also as JSON lines to daily rotated files.
+# Manager log API reads these files.
+# [log_file]
+# dir = "logs"
+# max_files = 14 # optional, by default old files are not removed
+
 [socket]
 public_api = "127.0.0.1:3000"
 public_bot_api = "127.0.0.1:3001"
@@ -113,6 +119,20 @@ name = "history"
 # detection_threshold = 2.8
 # pyramid_scale_factor = 0.5
 
+# Neural network based NSFW detection. Images exceeding reject thresholds
+# are not saved. Images exceeding move_to_human thresholds are moved to
+# human moderation. If detection fails, the image is moved to human
+# moderation. Detection scores are visible in the moderation queue.
+# [image_processing.nsfw_detection]
+# model_file = "model.onnx"
+# [image_processing.nsfw_detection.reject]
+# porn = 0.9
+# hentai = 0.9
+# [image_processing.nsfw_detection.move_to_human]
+# porn = 0.5
+# hentai = 0.5
+# sexy = 0.7
+
 "#;
 
 // TODO(prod): Consider changing manager config
@@ -139,6 +159,7 @@ pub struct SimpleBackendConfigFile {
     pub socket: SocketConfig,
 
     pub data: DataConfig,
+    pub log_file: Option<LogFileConfig>,
     pub tile_map: Option<TileMapConfig>,
     pub manager: Option<AppManagerConfig>,
     pub sign_in_with_google: Option<SignInWithGoogleConfig>,
@@ -245,6 +266,14 @@ pub struct GeneralConfig {
     pub log_timestamp: Option<bool>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LogFileConfig {
+    pub dir: PathBuf,
+    /// Maximum count of log files. Oldest files are removed when
+    /// a new file is created.
+    pub max_files: Option<usize>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DataConfig {
     /// Data directory for SQLite databases and other files.
@@ -540,6 +569,7 @@ pub struct ImageProcessingConfig {
     #[serde(default = "default_jpeg_quality")]
     jpeg_quality: u8,
     pub seetaface: Option<SeetaFaceConfig>,
+    pub nsfw_detection: Option<NsfwDetectionConfig>,
 }
 
 fn default_jpeg_quality() -> u8 {
@@ -551,6 +581,7 @@ impl Default for ImageProcessingConfig {
         Self {
             jpeg_quality: default_jpeg_quality(),
             seetaface: None,
+            nsfw_detection: None,
         }
     }
 }
@@ -567,3 +598,21 @@ pub struct SeetaFaceConfig {
     pub detection_threshold: f64,
     pub pyramid_scale_factor: f32,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NsfwDetectionConfig {
+    pub model_file: PathBuf,
+    /// Thresholds for image rejection.
+    pub reject: Option<NsfwDetectionThresholds>,
+    /// Thresholds for moving image to human moderation.
+    pub move_to_human: Option<NsfwDetectionThresholds>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NsfwDetectionThresholds {
+    pub drawings: Option<f32>,
+    pub hentai: Option<f32>,
+    pub neutral: Option<f32>,
+    pub porn: Option<f32>,
+    pub sexy: Option<f32>,
+}
@@ -196,6 +196,10 @@ impl SimpleBackendConfig {
         self.file.general.log_timestamp.unwrap_or(true)
     }
 
+    pub fn log_file(&self) -> Option<&file::LogFileConfig> {
+        self.file.log_file.as_ref()
+    }
+
     pub fn email_sending(&self) -> Option<&file::EmailSendingConfig> {
         self.file.email_sending.as_ref()
     }
@@ -1,11 +1,12 @@
-use std::io::Write;
+use std::{io::Write, panic::AssertUnwindSafe};
 
 use error_stack::{report, Result, ResultExt};
-use image::{DynamicImage, EncodableLayout, GrayImage, ImageDecoder, ImageReader};
+use image::{DynamicImage, EncodableLayout, GrayImage, ImageDecoder, ImageReader, RgbaImage};
+use nsfw::model::Metric;
 use serde::{Deserialize, Serialize};
 use simple_backend_config::{
     args::{ImageProcessModeArgs, InputFileType},
-    file::ImageProcessingConfig,
+    file::{ImageProcessingConfig, NsfwDetectionConfig, NsfwDetectionThresholds},
 };
 
 const SOURCE_IMG_MIN_WIDTH_AND_HEIGHT: u32 = 512;
@@ -39,6 +40,12 @@ pub enum ImageProcessError {
     #[error("Face detection panic detect")]
     FaceDetectionPanic,
 
+    #[error("NSFW detection error")]
+    NsfwDetection,
+
+    #[error("NSFW detection panic detected")]
+    NsfwDetectionPanic,
+
     #[error("Stdout error")]
     Stdout,
 }
@@ -47,6 +54,21 @@ pub enum ImageProcessError {
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct ImageProcessingInfo {
     pub face_detected: bool,
+    /// Difference hash (dHash) of the processed image. Near-duplicate
+    /// images have hashes with small Hamming distance.
+    pub perceptual_hash: u64,
+    pub nsfw_detection: NsfwDetectionInfo,
+}
+
+/// NSFW detection is done in the image process, so that the detection
+/// model is not loaded to the server process memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+pub enum NsfwDetectionInfo {
+    #[default]
+    Disabled,
+    /// Detection failed, so human moderators should check the image.
+    Failed,
+    Detected(NsfwScores),
 }
 
 pub fn handle_image(
@@ -80,6 +102,9 @@ pub fn handle_image(
     let width = img.width();
     let height = img.height();
     let data_face_detection = img.to_luma8();
+    let perceptual_hash = difference_hash(&data_face_detection);
+    let data_nsfw_detection = config.nsfw_detection.as_ref().map(|_| img.to_rgba8());
+
     let data = img.into_rgb8();
 
     let result = std::panic::catch_unwind(|| -> Result<Vec<u8>, ImageProcessError> {
@@ -122,15 +147,38 @@ pub fn handle_image(
 
     std::fs::write(&args.output, data).change_context(ImageProcessError::FileWriting)?;
 
-    let info = match detect_face(config, data_face_detection) {
-        Ok(info) => info,
+    let nsfw_detection = match (config.nsfw_detection.clone(), data_nsfw_detection) {
+        (Some(nsfw_config), Some(data)) => {
+            match NsfwDetector::load(nsfw_config).and_then(|detector| detector.detect(&data)) {
+                Ok(result) => NsfwDetectionInfo::Detected(result.scores),
+                Err(e) => {
+                    eprintln!("{:?}", e);
+                    NsfwDetectionInfo::Failed
+                }
+            }
+        }
+        _ => NsfwDetectionInfo::Disabled,
+    };
+
+    let face_detected = match detect_face(config, data_face_detection) {
+        Ok(face_detected) => face_detected,
         Err(e) => {
             // Ignore
             eprintln!("{:?}", e);
-            ImageProcessingInfo::default()
+            false
         }
     };
 
+    let info = ImageProcessingInfo {
+        face_detected,
+        perceptual_hash,
+        nsfw_detection,
+    };
+
+    write_info(info)
+}
+
+fn write_info(info: ImageProcessingInfo) -> Result<(), ImageProcessError> {
     let mut stdout = std::io::stdout();
     serde_json::to_writer(&stdout, &info).change_context(ImageProcessError::Stdout)?;
     stdout.flush().change_context(ImageProcessError::Stdout)?;
@@ -157,14 +205,38 @@ fn resize_image_if_needed(img: DynamicImage) -> DynamicImage {
     }
 }
 
-fn detect_face(
-    config: ImageProcessingConfig,
-    data: GrayImage,
-) -> Result<ImageProcessingInfo, ImageProcessError> {
+/// Calculate difference hash (dHash) from the image. The image is
+/// scaled to 9x8 pixels and every bit of the hash tells whether the pixel is
+/// brighter than its right neighbour.
+fn difference_hash(img: &GrayImage) -> u64 {
+    const HASH_WIDTH: u32 = 8;
+    const HASH_HEIGHT: u32 = 8;
+
+    let small = image::imageops::resize(
+        img,
+        HASH_WIDTH + 1,
+        HASH_HEIGHT,
+        image::imageops::FilterType::Triangle,
+    );
+
+    let mut hash = 0u64;
+    for y in 0..HASH_HEIGHT {
+        for x in 0..HASH_WIDTH {
+            let left = small.get_pixel(x, y).0[0];
+            let right = small.get_pixel(x + 1, y).0[0];
+            hash <<= 1;
+            if left > right {
+                hash |= 1;
+            }
+        }
+    }
+
+    hash
+}
+
+fn detect_face(config: ImageProcessingConfig, data: GrayImage) -> Result<bool, ImageProcessError> {
     let Some(config) = config.seetaface else {
-        return Ok(ImageProcessingInfo {
-            face_detected: true,
-        });
+        return Ok(true);
     };
 
     let data = rustface::ImageData::new(&data, data.width(), data.height());
@@ -194,7 +266,215 @@ fn detect_face(
     }
     .change_context(ImageProcessError::FaceDetection)?;
 
-    Ok(ImageProcessingInfo {
-        face_detected: !data.is_empty(),
-    })
+    Ok(!data.is_empty())
+}
+
+/// NSFW detection scores. Scores are between 0.0 and 1.0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+pub struct NsfwScores {
+    pub drawings: f32,
+    pub hentai: f32,
+    pub neutral: f32,
+    pub porn: f32,
+    pub sexy: f32,
+}
+
+impl NsfwScores {
+    fn score(&self, metric: Metric) -> f32 {
+        match metric {
+            Metric::Drawings => self.drawings,
+            Metric::Hentai => self.hentai,
+            Metric::Neutral => self.neutral,
+            Metric::Porn => self.porn,
+            Metric::Sexy => self.sexy,
+        }
+    }
+
+    fn score_mut(&mut self, metric: Metric) -> &mut f32 {
+        match metric {
+            Metric::Drawings => &mut self.drawings,
+            Metric::Hentai => &mut self.hentai,
+            Metric::Neutral => &mut self.neutral,
+            Metric::Porn => &mut self.porn,
+            Metric::Sexy => &mut self.sexy,
+        }
+    }
+
+    /// Returns true if some score is equal or greater than the
+    /// related threshold.
+    pub fn exceeds(&self, thresholds: &Option<NsfwDetectionThresholds>) -> bool {
+        let Some(thresholds) = thresholds else {
+            return false;
+        };
+        [
+            (Metric::Drawings, thresholds.drawings),
+            (Metric::Hentai, thresholds.hentai),
+            (Metric::Neutral, thresholds.neutral),
+            (Metric::Porn, thresholds.porn),
+            (Metric::Sexy, thresholds.sexy),
+        ]
+        .into_iter()
+        .any(|(metric, threshold)| threshold.is_some_and(|threshold| self.score(metric) >= threshold))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NsfwDetectionResult {
+    pub scores: NsfwScores,
+    /// Reject thresholds were exceeded.
+    pub rejected: bool,
+    /// Human moderation thresholds were exceeded.
+    pub move_to_human: bool,
+}
+
+impl NsfwDetectionResult {
+    pub fn new(scores: NsfwScores, config: &NsfwDetectionConfig) -> Self {
+        Self {
+            scores,
+            rejected: scores.exceeds(&config.reject),
+            move_to_human: scores.exceeds(&config.move_to_human),
+        }
+    }
+}
+
+/// NSFW detection model.
+pub struct NsfwDetector {
+    model: nsfw::Model,
+    config: NsfwDetectionConfig,
+}
+
+impl NsfwDetector {
+    pub fn load(config: NsfwDetectionConfig) -> Result<Self, ImageProcessError> {
+        let file = std::fs::File::open(&config.model_file)
+            .change_context(ImageProcessError::NsfwDetection)?;
+        let model = nsfw::create_model(file).map_err(|e| {
+            report!(ImageProcessError::NsfwDetection).attach_printable(e.to_string())
+        })?;
+        Ok(Self { model, config })
+    }
+
+    pub fn detect(&self, img: &RgbaImage) -> Result<NsfwDetectionResult, ImageProcessError> {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            nsfw::examine(&self.model, img).map_err(|e| {
+                report!(ImageProcessError::NsfwDetection).attach_printable(e.to_string())
+            })
+        }));
+
+        let results = match result {
+            Ok(result) => result,
+            Err(e) => {
+                let error = e
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .unwrap_or_default();
+                return Err(report!(ImageProcessError::NsfwDetectionPanic).attach_printable(error));
+            }
+        }?;
+
+        let mut scores = NsfwScores::default();
+        for c in results {
+            *scores.score_mut(c.metric) = c.score;
+        }
+
+        Ok(NsfwDetectionResult::new(scores, &self.config))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use image::{GrayImage, Luma};
+
+    use simple_backend_config::file::{NsfwDetectionConfig, NsfwDetectionThresholds};
+
+    use super::{difference_hash, NsfwDetectionResult, NsfwScores};
+
+    fn thresholds(porn: Option<f32>, sexy: Option<f32>) -> Option<NsfwDetectionThresholds> {
+        Some(NsfwDetectionThresholds {
+            drawings: None,
+            hentai: None,
+            neutral: None,
+            porn,
+            sexy,
+        })
+    }
+
+    fn horizontal_gradient(width: u32, height: u32, decreasing: bool) -> GrayImage {
+        GrayImage::from_fn(width, height, |x, _| {
+            let value = (x * 255 / (width - 1)) as u8;
+            if decreasing {
+                Luma([255 - value])
+            } else {
+                Luma([value])
+            }
+        })
+    }
+
+    #[test]
+    fn difference_hash_of_uniform_image_is_zero() {
+        let img = GrayImage::from_pixel(64, 64, Luma([128]));
+        assert_eq!(difference_hash(&img), 0);
+    }
+
+    #[test]
+    fn difference_hash_sets_bits_when_left_pixel_is_brighter() {
+        let increasing = horizontal_gradient(90, 80, false);
+        let decreasing = horizontal_gradient(90, 80, true);
+        assert_eq!(difference_hash(&increasing), 0);
+        assert_eq!(difference_hash(&decreasing), u64::MAX);
+    }
+
+    #[test]
+    fn difference_hash_does_not_depend_on_image_size() {
+        let small = horizontal_gradient(90, 80, true);
+        let large = horizontal_gradient(900, 800, true);
+        assert_eq!(difference_hash(&small), difference_hash(&large));
+    }
+
+    #[test]
+    fn nsfw_scores_do_not_exceed_missing_thresholds() {
+        let scores = NsfwScores {
+            porn: 1.0,
+            ..NsfwScores::default()
+        };
+        assert!(!scores.exceeds(&None));
+        assert!(!scores.exceeds(&thresholds(None, None)));
+    }
+
+    #[test]
+    fn nsfw_scores_exceed_threshold_when_score_is_equal_or_greater() {
+        let scores = NsfwScores {
+            porn: 0.5,
+            sexy: 0.2,
+            ..NsfwScores::default()
+        };
+        assert!(scores.exceeds(&thresholds(Some(0.5), None)));
+        assert!(scores.exceeds(&thresholds(Some(0.9), Some(0.1))));
+        assert!(!scores.exceeds(&thresholds(Some(0.6), Some(0.3))));
+    }
+
+    #[test]
+    fn nsfw_detection_result_uses_reject_and_move_to_human_thresholds() {
+        let config = NsfwDetectionConfig {
+            model_file: "model.onnx".into(),
+            reject: thresholds(Some(0.9), None),
+            move_to_human: thresholds(Some(0.5), None),
+        };
+        let scores = |porn| NsfwScores {
+            porn,
+            ..NsfwScores::default()
+        };
+
+        let result = NsfwDetectionResult::new(scores(0.95), &config);
+        assert!(result.rejected);
+        assert!(result.move_to_human);
+        assert_eq!(result.scores, scores(0.95));
+
+        let result = NsfwDetectionResult::new(scores(0.6), &config);
+        assert!(!result.rejected);
+        assert!(result.move_to_human);
+
+        let result = NsfwDetectionResult::new(scores(0.1), &config);
+        assert!(!result.rejected);
+        assert!(!result.move_to_human);
+    }
 }
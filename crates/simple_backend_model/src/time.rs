@@ -58,6 +58,14 @@ impl UnixTime {
             ut: self.ut + seconds,
         }
     }
+
+    /// Implemented using `saturating_sub`.
+    pub fn subtract_seconds(&self, seconds: u32) -> Self {
+        let seconds: i64 = seconds.into();
+        Self {
+            ut: self.ut.saturating_sub(seconds),
+        }
+    }
 }
 
 diesel_i64_wrapper!(UnixTime);
@@ -126,6 +126,10 @@ pub struct DurationValue {
 }
 
 impl DurationValue {
+    pub const fn from_minutes(minutes: u32) -> Self {
+        Self { seconds: minutes * 60 }
+    }
+
     pub const fn from_days(days: u32) -> Self {
         Self { seconds: days * 60 * 60 * 24 }
     }
@@ -273,6 +273,7 @@ pub enum AccountState {
     Normal,
     Banned,
     PendingDeletion,
+    WarningPending,
 }
 
 impl AccountState {
@@ -282,22 +283,32 @@ impl AccountState {
                 initial_setup_completed: Some(true),
                 banned: None,
                 pending_deletion: None,
+                warning_pending: None,
             },
             Self::Normal => AccountStateContainer {
                 initial_setup_completed: None,
                 banned: None,
                 pending_deletion: None,
+                warning_pending: None,
             },
             Self::Banned => AccountStateContainer {
                 initial_setup_completed: None,
                 banned: Some(true),
                 pending_deletion: None,
+                warning_pending: None,
             },
             Self::PendingDeletion => AccountStateContainer {
                 initial_setup_completed: None,
                 banned: None,
                 pending_deletion: Some(true),
-            }
+                warning_pending: None,
+            },
+            Self::WarningPending => AccountStateContainer {
+                initial_setup_completed: None,
+                banned: None,
+                pending_deletion: None,
+                warning_pending: Some(true),
+            },
         }
     }
 }
@@ -310,6 +321,8 @@ impl From<AccountStateContainer> for AccountState {
             Self::Banned
         } else if !value.initial_setup_completed.unwrap_or(true) {
             Self::InitialSetup
+        } else if value.warning_pending.unwrap_or_default() {
+            Self::WarningPending
         } else {
             Self::Normal
         }
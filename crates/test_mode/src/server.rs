@@ -145,6 +145,7 @@ fn new_config(
             email: EmailAddress(TEST_ADMIN_ACCESS_EMAIL.to_string()),
         }
         .into(),
+        admin_role: None,
         api: ApiConfig::default(),
         config_files: ConfigFileConfig::default(),
         components: Some(components),
@@ -2,3 +2,4 @@
 
 mod admin;
 mod initial_setup;
+mod warning;
@@ -0,0 +1,48 @@
+use api_client::{
+    apis::{
+        account_admin_api::post_warn_account,
+        account_api::{get_account_state, post_acknowledge_account_warnings},
+    },
+    models::WarnAccount,
+};
+use test_mode_macro::server_test;
+
+use crate::{
+    bot::actions::account::AccountState, runner::server_tests::assert::assert_eq, TestContext,
+    TestResult,
+};
+
+#[server_test]
+async fn warning_strike_threshold_bans_account(mut context: TestContext) -> TestResult {
+    let admin = context.new_admin().await?;
+    let account = context.new_account().await?;
+
+    // Default strike threshold is 3 warnings
+    for _ in 0..2 {
+        post_warn_account(
+            admin.account().account_api(),
+            WarnAccount::new(account.account_id()),
+        )
+        .await?;
+        assert_eq(
+            AccountState::WarningPending,
+            get_account_state(account.account_api()).await?.into(),
+        )?;
+
+        post_acknowledge_account_warnings(account.account_api()).await?;
+        assert_eq(
+            AccountState::Normal,
+            get_account_state(account.account_api()).await?.into(),
+        )?;
+    }
+
+    post_warn_account(
+        admin.account().account_api(),
+        WarnAccount::new(account.account_id()),
+    )
+    .await?;
+    assert_eq(
+        AccountState::Banned,
+        get_account_state(account.account_api()).await?.into(),
+    )
+}
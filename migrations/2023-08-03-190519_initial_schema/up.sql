@@ -73,12 +73,43 @@ CREATE TABLE IF NOT EXISTS account_permissions(
     admin_news_create                            BOOLEAN NOT NULL DEFAULT 0,
     admin_news_edit_all                          BOOLEAN NOT NULL DEFAULT 0,
     admin_profile_statistics                     BOOLEAN NOT NULL DEFAULT 0,
+    admin_view_audit_log                         BOOLEAN NOT NULL DEFAULT 0,
+    -- Individually granted permissions as JSON. The boolean columns
+    -- contain the effective permissions which are the individual
+    -- permissions combined with the permissions of the assigned admin roles.
+    -- Null if admin roles have not been used with the account.
+    individual_permissions                       TEXT,
     FOREIGN KEY (account_id)
         REFERENCES account_id (id)
             ON DELETE CASCADE
             ON UPDATE CASCADE
 );
 
+-- Named admin permission sets.
+CREATE TABLE IF NOT EXISTS admin_role(
+    id           INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name         TEXT                              NOT NULL  UNIQUE,
+    -- Permissions as JSON
+    permissions  TEXT                              NOT NULL
+);
+
+-- Admin roles assigned to accounts.
+CREATE TABLE IF NOT EXISTS account_admin_role(
+    account_id         INTEGER NOT NULL,
+    role_id            INTEGER NOT NULL,
+    -- Null if the role does not expire.
+    expires_unix_time  INTEGER,
+    PRIMARY KEY (account_id, role_id),
+    FOREIGN KEY (account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (role_id)
+        REFERENCES admin_role (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 -- TODO(prod): Add subscription level to shared_state
 
 -- Shared state between server components.
@@ -89,6 +120,7 @@ CREATE TABLE IF NOT EXISTS shared_state(
     account_state_initial_setup_completed   BOOLEAN             NOT NULL DEFAULT 0,
     account_state_banned                    BOOLEAN             NOT NULL DEFAULT 0,
     account_state_pending_deletion          BOOLEAN             NOT NULL DEFAULT 0,
+    account_state_warning_pending           BOOLEAN             NOT NULL DEFAULT 0,
     -- pending private = 0
     -- pending public = 1
     -- private = 2
@@ -106,6 +138,13 @@ CREATE TABLE IF NOT EXISTS shared_state(
     is_bot_account            BOOLEAN              NOT NULL DEFAULT 0,
     -- Profile component uses this info for profile filtering.
     initial_setup_completed_unix_time INTEGER      NOT NULL DEFAULT 0,
+    -- Admin has verified that the primary profile content matches
+    -- the security content. Media component revokes the verification
+    -- when primary profile content or security content changes.
+    profile_verified          BOOLEAN              NOT NULL DEFAULT 0,
+    -- Automatic report triage hides the profile from profile iterator
+    -- until reports related to the account are processed.
+    profile_hidden_pending_review BOOLEAN          NOT NULL DEFAULT 0,
     FOREIGN KEY (account_id)
         REFERENCES account_id (id)
             ON DELETE CASCADE
@@ -148,6 +187,9 @@ CREATE TABLE IF NOT EXISTS common_report(
     target_account_id       INTEGER             NOT NULL,
     -- 0 = profile name
     -- 1 = profile text
+    -- 2 = profile content
+    -- 3 = chat message
+    -- 4 = ban appeal
     report_type_number      INTEGER             NOT NULL,
     creation_unix_time      INTEGER             NOT NULL,
     moderator_account_id    INTEGER,
@@ -156,6 +198,17 @@ CREATE TABLE IF NOT EXISTS common_report(
     -- 2 = Done
     processing_state        INTEGER             NOT NULL    DEFAULT 0,
     processing_state_change_unix_time INTEGER   NOT NULL,
+    -- Set when report is processed.
+    -- 0 = Dismissed
+    -- 1 = Content removed
+    -- 2 = Warned
+    -- 3 = Banned
+    processing_outcome      INTEGER,
+    -- Moderator written note about the processing outcome.
+    moderator_note          TEXT,
+    -- Automatic report triage moves the report to the front of
+    -- the waiting report queue.
+    prioritized             BOOLEAN             NOT NULL    DEFAULT 0,
     FOREIGN KEY (creator_account_id)
         REFERENCES account_id (id)
             ON DELETE CASCADE
@@ -170,6 +223,32 @@ CREATE TABLE IF NOT EXISTS common_report(
             ON UPDATE CASCADE
 );
 
+-- Moderation queue item claims. Fetching moderation queue page
+-- claims the returned items for the moderator until the lease
+-- expires. Completed claims are kept for moderator throughput
+-- statistics.
+CREATE TABLE IF NOT EXISTS moderation_claim(
+    id                          INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    -- 0 = media content
+    -- 1 = profile text
+    -- 2 = profile name
+    -- 3 = report
+    -- 4 = ban appeal
+    queue_type                  INTEGER             NOT NULL,
+    -- Media content ID, account ID or report ID depending
+    -- on the queue type.
+    item_id                     INTEGER             NOT NULL,
+    moderator_account_id        INTEGER             NOT NULL,
+    claim_unix_time             INTEGER             NOT NULL,
+    lease_expiration_unix_time  INTEGER             NOT NULL,
+    -- Set when the moderator has moderated the item.
+    completed_unix_time         INTEGER,
+    FOREIGN KEY (moderator_account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 ---------- Tables for server component account ----------
 
 -- Sign in with related IDs for account
@@ -246,6 +325,26 @@ CREATE TABLE IF NOT EXISTS account_state(
             ON UPDATE CASCADE
 );
 
+-- Warnings which moderators have given to accounts.
+CREATE TABLE IF NOT EXISTS account_warning(
+    id                      INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    account_id              INTEGER                           NOT NULL,
+    admin_account_id        INTEGER,
+    creation_unix_time      INTEGER                           NOT NULL,
+    reason_category         INTEGER,
+    reason_details          TEXT,
+    -- Null if account has not acknowledged the warning yet.
+    acknowledged_unix_time  INTEGER,
+    FOREIGN KEY (account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (admin_account_id)
+        REFERENCES account_id (id)
+            ON DELETE SET NULL
+            ON UPDATE CASCADE
+);
+
 CREATE TABLE IF NOT EXISTS account_report(
     creator_account_id      INTEGER           NOT NULL,
     target_account_id       INTEGER           NOT NULL,
@@ -257,6 +356,9 @@ CREATE TABLE IF NOT EXISTS account_report(
     -- 2 = Done
     processing_state        INTEGER           NOT NULL    DEFAULT 0,
     processing_state_change_unix_time INTEGER NOT NULL,
+    -- Automatic report triage moves the report to the front of
+    -- the waiting report queue.
+    prioritized        BOOLEAN                NOT NULL    DEFAULT 0,
     -- Report content
     is_bot             BOOLEAN                NOT NULL,
     is_scammer         BOOLEAN                NOT NULL,
@@ -278,6 +380,25 @@ CREATE TABLE IF NOT EXISTS account_report(
             ON UPDATE CASCADE
 );
 
+-- Ban appeal content for common_report. The appeal creator and target
+-- are the banned account.
+CREATE TABLE IF NOT EXISTS account_report_ban_appeal(
+    report_id                   INTEGER PRIMARY KEY NOT NULL,
+    -- Ban state change time of the appealed ban. Only one appeal
+    -- is allowed for one ban.
+    ban_state_change_unix_time  INTEGER             NOT NULL,
+    appeal_text                 TEXT,
+    -- Set when appeal is processed.
+    -- 0 = Uphold
+    -- 1 = Shorten
+    -- 2 = Lift
+    decision                    INTEGER,
+    FOREIGN KEY (report_id)
+        REFERENCES common_report (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 -- Demo mode user created accounts
 CREATE TABLE IF NOT EXISTS demo_mode_account_ids(
     id               INTEGER PRIMARY KEY NOT NULL,
@@ -353,6 +474,8 @@ CREATE TABLE IF NOT EXISTS profile_state(
     last_seen_time_filter      INTEGER,
     -- Filter setting for unlimited likes.
     unlimited_likes_filter     BOOLEAN,
+    -- Filter setting for verified profiles.
+    verified_filter            BOOLEAN,
     -- Filter setting for profile iterator max distance in kilometers.
     max_distance_km_filter     INTEGER,
     -- Filter setting for profile created time in seconds.
@@ -361,6 +484,14 @@ CREATE TABLE IF NOT EXISTS profile_state(
     profile_edited_time_filter INTEGER,
     -- Profile iterator setting for random profile order.
     random_profile_order       BOOLEAN              NOT NULL    DEFAULT 0,
+    -- Profile iterator setting for mutual interest profile ranking.
+    mutual_interest_profile_order BOOLEAN           NOT NULL    DEFAULT 0,
+    -- Profile iterator setting for hiding recently seen profiles.
+    hide_seen_profiles         BOOLEAN              NOT NULL    DEFAULT 0,
+    -- Profile visit tracking setting. If enabled, visits to this
+    -- account's profile are recorded and visits from this account to
+    -- other profiles are recorded.
+    profile_visit_tracking     BOOLEAN              NOT NULL    DEFAULT 0,
     latitude                   DOUBLE               NOT NULL    DEFAULT 0.0,
     longitude                  DOUBLE               NOT NULL    DEFAULT 0.0,
     -- Sync version for profile attributes config file.
@@ -492,6 +623,66 @@ CREATE TABLE IF NOT EXISTS favorite_profile(
             ON UPDATE CASCADE
 );
 
+CREATE TABLE IF NOT EXISTS passed_profile(
+    -- Account which passed the profile.
+    account_id          INTEGER               NOT NULL,
+    -- Account which profile is passed.
+    passed_account_id   INTEGER               NOT NULL,
+    -- Unix timestamp when pass was added.
+    unix_time           INTEGER               NOT NULL,
+    PRIMARY KEY (account_id, passed_account_id),
+    FOREIGN KEY (account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (passed_account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+-- Profiles which profile iterator has returned. Only recorded when
+-- hiding seen profiles is enabled.
+CREATE TABLE IF NOT EXISTS seen_profile(
+    -- Account which has seen the profile.
+    account_id            INTEGER               NOT NULL,
+    -- Account which profile is seen.
+    seen_account_id       INTEGER               NOT NULL,
+    -- Unix timestamp when the profile is not considered seen anymore.
+    expiration_unix_time  INTEGER               NOT NULL,
+    PRIMARY KEY (account_id, seen_account_id),
+    FOREIGN KEY (account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (seen_account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+-- Profile visits. Visits are deduplicated per day.
+CREATE TABLE IF NOT EXISTS profile_visit(
+    id                  INTEGER PRIMARY KEY   NOT NULL,
+    -- Account which profile was visited.
+    target_account_id   INTEGER               NOT NULL,
+    -- Account which visited the profile.
+    visitor_account_id  INTEGER               NOT NULL,
+    -- Unix time divided by seconds in a day.
+    visit_day           INTEGER               NOT NULL,
+    -- Unix timestamp when visit happened.
+    unix_time           INTEGER               NOT NULL,
+    UNIQUE (target_account_id, visitor_account_id, visit_day),
+    FOREIGN KEY (target_account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (visitor_account_id)
+        REFERENCES account_id (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 CREATE TABLE IF NOT EXISTS profile_name_allowlist(
     profile_name              TEXT    PRIMARY KEY NOT NULL,
     name_creator_account_id   INTEGER             NOT NULL,
@@ -637,12 +828,58 @@ CREATE TABLE IF NOT EXISTS media_content(
     moderation_moderator_account_id     INTEGER,
     usage_start_unix_time  INTEGER,
     usage_end_unix_time    INTEGER,
+    -- Difference hash of the image as signed integer.
+    -- Null if the hash is not available.
+    perceptual_hash        INTEGER,
+    -- Content has near-duplicates and moving the content to
+    -- moderation moves it directly to human moderation.
+    human_moderation_required BOOLEAN    NOT NULL    DEFAULT 0,
+    -- NSFW detection scores between 0.0 and 1.0.
+    -- Null if NSFW detection was not run.
+    nsfw_score_drawings    REAL,
+    nsfw_score_hentai      REAL,
+    nsfw_score_neutral     REAL,
+    nsfw_score_porn        REAL,
+    nsfw_score_sexy        REAL,
     FOREIGN KEY (account_id)
         REFERENCES account_id (id)
             ON DELETE CASCADE
             ON UPDATE CASCADE
 );
 
+-- Near-duplicate images detected using perceptual hashes.
+-- The duplicate is owned by other account or it is rejected content.
+CREATE TABLE IF NOT EXISTS media_content_duplicate(
+    content_id              INTEGER             NOT NULL,
+    duplicate_content_id    INTEGER             NOT NULL,
+    -- Hamming distance between the perceptual hashes
+    hash_distance           INTEGER             NOT NULL,
+    detection_unix_time     INTEGER             NOT NULL,
+    PRIMARY KEY (content_id, duplicate_content_id),
+    FOREIGN KEY (content_id)
+        REFERENCES media_content (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE,
+    FOREIGN KEY (duplicate_content_id)
+        REFERENCES media_content (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
+-- 16 bit parts of perceptual hashes. Used for finding near-duplicate
+-- candidates without comparing all hashes.
+CREATE TABLE IF NOT EXISTS media_content_hash_band(
+    -- Band index from 0 to 3
+    band_index              INTEGER             NOT NULL,
+    band_value              INTEGER             NOT NULL,
+    content_id              INTEGER             NOT NULL,
+    PRIMARY KEY (band_index, band_value, content_id),
+    FOREIGN KEY (content_id)
+        REFERENCES media_content (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 CREATE TABLE IF NOT EXISTS used_content_ids(
     account_id            INTEGER                           NOT NULL,
     uuid                  BLOB                              NOT NULL,
@@ -854,6 +1091,20 @@ CREATE TABLE IF NOT EXISTS history_performance_statistics_metric_value(
             ON UPDATE CASCADE
 );
 
+-- Append-only audit log of privileged admin API calls.
+-- The history database does not have the account_id table, so
+-- account IDs are stored as UUIDs.
+CREATE TABLE IF NOT EXISTS history_admin_action(
+    id                 INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    unix_time          INTEGER                           NOT NULL,
+    admin_account_id   BLOB                              NOT NULL,
+    -- Action type number
+    action             INTEGER                           NOT NULL,
+    target_account_id  BLOB,
+    -- Action parameters as JSON
+    parameters         TEXT                              NOT NULL
+);
+
 ---------- History tables for server component account ----------
 
 ---------- History tables for server component profile ----------
@@ -952,4 +1203,13 @@ CREATE TABLE IF NOT EXISTS history_profile_statistics_count_changes_all_genders(
             ON UPDATE CASCADE
 );
 
+CREATE TABLE IF NOT EXISTS history_profile_statistics_count_changes_profile_visits(
+    save_time_id     INTEGER PRIMARY KEY NOT NULL,
+    count INTEGER             NOT NULL,
+    FOREIGN KEY (save_time_id)
+        REFERENCES history_profile_statistics_save_time (id)
+            ON DELETE CASCADE
+            ON UPDATE CASCADE
+);
+
 ---------- History tables for server component media ----------